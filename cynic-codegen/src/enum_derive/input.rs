@@ -29,6 +29,9 @@ pub struct EnumDeriveInput {
 
     #[darling(default)]
     pub(super) non_exhaustive: bool,
+
+    #[darling(default)]
+    pub(super) schema_docs: bool,
 }
 
 impl EnumDeriveInput {
@@ -51,6 +54,9 @@ pub struct EnumDeriveVariant {
     #[darling(default)]
     pub(super) fallback: SpannedValue<bool>,
 
+    #[darling(default)]
+    pub(super) allow_deprecated: bool,
+
     pub(super) fields: darling::ast::Fields<()>,
 }
 
@@ -6,10 +6,12 @@ use {
 use crate::{
     error::Errors,
     idents::RenameAll,
+    marker_references::{deprecation_warning, doc_reference},
     schema::{
         types::{EnumType, EnumValue},
         Schema, Unvalidated,
     },
+    schema_docs::doc_attr,
 };
 
 pub(crate) mod input;
@@ -95,6 +97,34 @@ pub fn enum_derive_impl(
         let schema_module = input.schema_module();
         let ident = input.ident;
 
+        let value_module = enum_def.field_module().to_path(&schema_module);
+        let doc_references = pairs
+            .iter()
+            .filter(|(_, value)| input.schema_docs && value.description.is_some())
+            .map(|(variant, value)| {
+                doc_reference(
+                    &value.marker_ident().to_path(&value_module),
+                    variant.ident.span(),
+                )
+            });
+
+        let doc = doc_attr(
+            enum_def
+                .description
+                .as_deref()
+                .filter(|_| input.schema_docs),
+        );
+
+        let deprecations = pairs
+            .iter()
+            .filter(|(variant, value)| value.deprecated.is_some() && !variant.allow_deprecated)
+            .map(|(variant, value)| {
+                deprecation_warning(
+                    &value.marker_ident().to_path(&value_module),
+                    variant.ident.span(),
+                )
+            });
+
         let fallback_ser_branch = match fallback {
             None => quote! {},
             Some(fallback) if fallback.fields.fields.is_empty() => {
@@ -143,6 +173,7 @@ pub fn enum_derive_impl(
 
         Ok(quote! {
             #[automatically_derived]
+            #doc
             impl cynic::Enum for #ident {
                 type SchemaType = #schema_module::#enum_marker_ident;
             }
@@ -183,6 +214,9 @@ pub fn enum_derive_impl(
             impl #schema_module::variable::Variable for #ident {
                 const TYPE: cynic::variables::VariableType = cynic::variables::VariableType::Named(#graphql_type_name);
             }
+
+            #(#deprecations)*
+            #(#doc_references)*
         })
     } else {
         Err(syn::Error::new(
@@ -305,24 +339,31 @@ mod tests {
                 ident: proc_macro2::Ident::new(enum_variant_1, Span::call_site()),
                 rename: None,
                 fallback: Default::default(),
+                allow_deprecated: false,
                 fields: darling::ast::Style::Unit.into(),
             },
             EnumDeriveVariant {
                 ident: proc_macro2::Ident::new(enum_variant_2, Span::call_site()),
                 rename: None,
                 fallback: Default::default(),
+                allow_deprecated: false,
                 fields: darling::ast::Style::Unit.into(),
             },
         ];
         let mut gql_enum = EnumType {
             name: "Desserts".into(),
+            description: None,
             values: vec![],
         };
         gql_enum.values.push(EnumValue {
             name: FieldName::new(enum_value_1),
+            description: None,
+            deprecated: None,
         });
         gql_enum.values.push(EnumValue {
             name: FieldName::new(enum_value_2),
+            description: None,
+            deprecated: None,
         });
 
         let result = join_variants(
@@ -357,24 +398,31 @@ mod tests {
                 ident: proc_macro2::Ident::new("Cheesecake", Span::call_site()),
                 rename: None,
                 fallback: Default::default(),
+                allow_deprecated: false,
                 fields: darling::ast::Style::Unit.into(),
             },
             EnumDeriveVariant {
                 ident: proc_macro2::Ident::new("IceCream", Span::call_site()),
                 rename: Some(SpannedValue::new("iced-goodness".into(), Span::call_site())),
                 fallback: Default::default(),
+                allow_deprecated: false,
                 fields: darling::ast::Style::Unit.into(),
             },
         ];
         let mut gql_enum = EnumType {
             name: "Desserts".into(),
+            description: None,
             values: vec![],
         };
         gql_enum.values.push(EnumValue {
             name: FieldName::new("CHEESECAKE"),
+            description: None,
+            deprecated: None,
         });
         gql_enum.values.push(EnumValue {
             name: FieldName::new("iced-goodness"),
+            description: None,
+            deprecated: None,
         });
 
         let result = join_variants(
@@ -408,17 +456,23 @@ mod tests {
             ident: proc_macro2::Ident::new("CHEESECAKE", Span::call_site()),
             rename: None,
             fallback: Default::default(),
+            allow_deprecated: false,
             fields: darling::ast::Style::Unit.into(),
         }];
         let mut gql_enum = EnumType {
             name: "Desserts".into(),
+            description: None,
             values: vec![],
         };
         gql_enum.values.push(EnumValue {
             name: FieldName::new("CHEESECAKE"),
+            description: None,
+            deprecated: None,
         });
         gql_enum.values.push(EnumValue {
             name: FieldName::new("ICE_CREAM"),
+            description: None,
+            deprecated: None,
         });
 
         let result = join_variants(
@@ -440,24 +494,31 @@ mod tests {
                 ident: proc_macro2::Ident::new("FIRST", Span::call_site()),
                 rename: None,
                 fallback: Default::default(),
+                allow_deprecated: false,
                 fields: darling::ast::Style::Unit.into(),
             },
             EnumDeriveVariant {
                 ident: proc_macro2::Ident::new("FALLBACK", Span::call_site()),
                 rename: None,
                 fallback: SpannedValue::new(true, Span::call_site()),
+                allow_deprecated: false,
                 fields: darling::ast::Style::Unit.into(),
             },
         ];
         let mut gql_enum = EnumType {
             name: "Enum".into(),
+            description: None,
             values: vec![],
         };
         gql_enum.values.push(EnumValue {
             name: FieldName::new("FIRST"),
+            description: None,
+            deprecated: None,
         });
         gql_enum.values.push(EnumValue {
             name: FieldName::new("SECOND"),
+            description: None,
+            deprecated: None,
         });
 
         let result = join_variants(
@@ -478,14 +539,18 @@ mod tests {
             ident: proc_macro2::Ident::new("CHEESECAKE", Span::call_site()),
             rename: None,
             fallback: Default::default(),
+            allow_deprecated: false,
             fields: darling::ast::Style::Unit.into(),
         }];
         let mut gql_enum = EnumType {
             name: "Desserts".into(),
+            description: None,
             values: vec![],
         };
         gql_enum.values.push(EnumValue {
             name: FieldName::new("ICE_CREAM"),
+            description: None,
+            deprecated: None,
         });
 
         let result = join_variants(
@@ -9,7 +9,8 @@ use {
     crate::{
         error::Errors,
         schema::types::{
-            self as schema, EnumType, InputObjectType, InputType, InputValue, TypeRef,
+            self as schema, Directive, DirectiveLocation, EnumType, InputObjectType, InputType,
+            InputValue, TypeRef,
         },
     },
 };
@@ -21,6 +22,13 @@ pub struct AnalysedArguments<'a> {
     pub variants: Vec<Rc<VariantDetails<'a>>>,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct AnalysedDirective<'a> {
+    pub directive: Directive<'a>,
+    pub arguments: Vec<Field<'a>>,
+    pub variants: Vec<Rc<VariantDetails<'a>>>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Object<'a> {
     pub schema_obj: InputObjectType<'a>,
@@ -82,6 +90,49 @@ pub fn analyse<'a>(
     })
 }
 
+pub fn analyse_directive<'a>(
+    schema: &'a Schema<'a, Unvalidated>,
+    parsed: parsing::ParsedDirective,
+    location: Option<DirectiveLocation>,
+    variables_fields: Option<&syn::Path>,
+) -> Result<AnalysedDirective<'a>, Errors> {
+    let directive = schema
+        .lookup_directive(&parsed.name.to_string())
+        .map_err(|error| syn::Error::new(parsed.name.span(), error))?;
+
+    if let Some(location) = location {
+        if !directive.allowed_on(location) {
+            return Err(syn::Error::new(
+                parsed.name.span(),
+                format!("The @{} directive can't be used on {location}", parsed.name),
+            )
+            .into());
+        }
+    }
+
+    let mut analysis = Analysis {
+        variables_fields,
+        variants: HashSet::new(),
+    };
+
+    let arguments = analyse_fields(
+        &mut analysis,
+        parsed.arguments,
+        &directive.arguments,
+        parsed.span,
+        schema,
+    )?;
+
+    let mut variants = analysis.variants.into_iter().collect::<Vec<_>>();
+    variants.sort_by_key(|v| (v.en.name.clone(), v.variant.clone()));
+
+    Ok(AnalysedDirective {
+        directive,
+        arguments,
+        variants,
+    })
+}
+
 struct Analysis<'schema, 'a> {
     variables_fields: Option<&'a syn::Path>,
     variants: HashSet<Rc<VariantDetails<'schema>>>,
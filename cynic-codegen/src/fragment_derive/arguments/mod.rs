@@ -2,14 +2,48 @@ mod analyse;
 mod output;
 mod parsing;
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 
 use crate::{
     error::Errors,
-    schema::{Schema, Unvalidated},
+    schema::{types::DirectiveLocation, Schema, Unvalidated},
 };
 
-pub use self::{output::Output, parsing::arguments_from_field_attrs};
+pub use self::{
+    output::{DirectiveOutput, Output},
+    parsing::{arguments_from_field_attrs, directives_from_attrs, ParsedDirective},
+};
+
+/// The part of a query that a directive is being applied to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirectiveTarget {
+    Field,
+    InlineFragment,
+    Operation,
+}
+
+impl DirectiveTarget {
+    /// The location this directive will be used in, if we can tell from here.
+    ///
+    /// We don't know which kind of operation a fragment will be used for, so
+    /// operation directives get checked by the `OperationDirective` trait
+    /// instead.
+    fn location(self) -> Option<DirectiveLocation> {
+        match self {
+            DirectiveTarget::Field => Some(DirectiveLocation::Field),
+            DirectiveTarget::InlineFragment => Some(DirectiveLocation::InlineFragment),
+            DirectiveTarget::Operation => None,
+        }
+    }
+
+    fn builder_call(self) -> TokenStream {
+        match self {
+            DirectiveTarget::Field => quote::quote! { field_builder.directive },
+            DirectiveTarget::InlineFragment => quote::quote! { fragment_builder.directive },
+            DirectiveTarget::Operation => quote::quote! { builder.operation_directive },
+        }
+    }
+}
 
 pub fn process_arguments<'a>(
     schema: &'a Schema<'a, Unvalidated>,
@@ -27,5 +61,22 @@ pub fn process_arguments<'a>(
     })
 }
 
+pub fn process_directive<'a>(
+    schema: &'a Schema<'a, Unvalidated>,
+    directive: parsing::ParsedDirective,
+    target: DirectiveTarget,
+    schema_module: syn::Path,
+    variables_fields: Option<&syn::Path>,
+) -> Result<DirectiveOutput<'a>, Errors> {
+    let analysed =
+        analyse::analyse_directive(schema, directive, target.location(), variables_fields)?;
+
+    Ok(DirectiveOutput {
+        analysed,
+        target,
+        schema_module,
+    })
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,14 +1,23 @@
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 
-use crate::idents::to_pascal_case;
+use crate::{
+    fragment_derive::static_query::{escape_string, StaticParts},
+    idents::to_pascal_case,
+};
 
-use super::analyse::{AnalysedArguments, ArgumentValue, VariantDetails};
+use super::analyse::{AnalysedArguments, AnalysedDirective, ArgumentValue, VariantDetails};
 
 pub struct Output<'a> {
     pub(super) analysed: AnalysedArguments<'a>,
     pub(super) schema_module: syn::Path,
 }
 
+pub struct DirectiveOutput<'a> {
+    pub(super) analysed: AnalysedDirective<'a>,
+    pub(super) target: super::DirectiveTarget,
+    pub(super) schema_module: syn::Path,
+}
+
 impl ToTokens for Output<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         if self.analysed.arguments.is_empty() {
@@ -59,6 +68,183 @@ impl ToTokens for Output<'_> {
     }
 }
 
+impl Output<'_> {
+    /// Writes these arguments into `parts`.
+    ///
+    /// Returns `None` if they can't be written at compile time.
+    pub fn static_parts(&self, parts: &mut StaticParts) -> Option<()> {
+        if self.analysed.arguments.is_empty() {
+            return Some(());
+        }
+
+        parts.push_text("(");
+        for (index, argument) in self.analysed.arguments.iter().enumerate() {
+            if index != 0 {
+                parts.push_text(", ");
+            }
+            parts.push_text(argument.schema_field.name.as_str());
+            parts.push_text(": ");
+            static_value(&argument.value, parts)?;
+        }
+        parts.push_text(")");
+
+        Some(())
+    }
+
+    /// The markers for any deprecated arguments, input fields or enum values
+    /// used in these arguments
+    pub fn deprecated_markers(&self) -> Vec<syn::Path> {
+        let argument_module = self
+            .analysed
+            .schema_field
+            .argument_module()
+            .to_path(&self.schema_module);
+
+        let mut markers = vec![];
+        for argument in &self.analysed.arguments {
+            if argument.schema_field.deprecated.is_some() {
+                markers.push(
+                    argument
+                        .schema_field
+                        .marker_ident()
+                        .to_path(&argument_module),
+                );
+            }
+            deprecated_value_markers(&argument.value, &self.schema_module, &mut markers);
+        }
+        markers
+    }
+}
+
+fn deprecated_value_markers(
+    value: &ArgumentValue<'_>,
+    schema_module: &syn::Path,
+    markers: &mut Vec<syn::Path>,
+) {
+    match value {
+        ArgumentValue::Object(obj) => {
+            let field_module = obj.schema_obj.field_module().to_path(schema_module);
+            for field in &obj.fields {
+                if field.schema_field.deprecated.is_some() {
+                    markers.push(field.schema_field.marker_ident().to_path(&field_module));
+                }
+                deprecated_value_markers(&field.value, schema_module, markers);
+            }
+        }
+        ArgumentValue::List(items) => {
+            for item in items {
+                deprecated_value_markers(item, schema_module, markers);
+            }
+        }
+        ArgumentValue::Some(inner) => deprecated_value_markers(inner, schema_module, markers),
+        ArgumentValue::Variant(details) => {
+            let value =
+                details.en.values.iter().find(|value| {
+                    value.name.as_str() == details.variant && value.deprecated.is_some()
+                });
+            if let Some(value) = value {
+                let value_module = details.en.field_module().to_path(schema_module);
+                markers.push(value.marker_ident().to_path(&value_module));
+            }
+        }
+        ArgumentValue::Literal(_)
+        | ArgumentValue::Expression(_)
+        | ArgumentValue::Variable(_)
+        | ArgumentValue::Null => {}
+    }
+}
+
+fn static_value(value: &ArgumentValue<'_>, parts: &mut StaticParts) -> Option<()> {
+    match value {
+        ArgumentValue::Object(obj) => {
+            parts.push_text("{");
+            for field in &obj.fields {
+                parts.push_text(field.schema_field.name.as_str());
+                parts.push_text(": ");
+                static_value(&field.value, parts)?;
+                parts.push_text(", ");
+            }
+            parts.push_text("}");
+        }
+        ArgumentValue::List(items) => {
+            parts.push_text("[");
+            for item in items {
+                static_value(item, parts)?;
+                parts.push_text(", ");
+            }
+            parts.push_text("]");
+        }
+        ArgumentValue::Literal(syn::Lit::Str(lit)) => {
+            parts.push_text(&format!("\"{}\"", escape_string(&lit.value())));
+        }
+        ArgumentValue::Literal(syn::Lit::Int(lit)) => {
+            // Integers are serialized as an i32 at runtime, so we need to
+            // normalise the literal in the same way
+            parts.push_text(&lit.base10_parse::<i32>().ok()?.to_string());
+        }
+        ArgumentValue::Literal(syn::Lit::Bool(lit)) => {
+            parts.push_text(&lit.value.to_string());
+        }
+        ArgumentValue::Variant(details) => parts.push_text(&details.variant),
+        ArgumentValue::Variable(var) => {
+            let var_ident = &var.ident;
+            let variables_fields = &var.variables_fields_struct;
+            parts.push_variable(quote! { #variables_fields::#var_ident().name });
+        }
+        ArgumentValue::Some(inner) => static_value(inner, parts)?,
+        ArgumentValue::Null => parts.push_text("null"),
+        ArgumentValue::Literal(_) | ArgumentValue::Expression(_) => return None,
+    }
+
+    Some(())
+}
+
+impl ToTokens for DirectiveOutput<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let schema_module = &self.schema_module;
+        let directive = &self.analysed.directive;
+        let directive_marker = directive.to_path(schema_module);
+        let argument_module = directive.argument_module_path(schema_module);
+        let builder_call = self.target.builder_call();
+
+        let variant_structs = self
+            .analysed
+            .variants
+            .iter()
+            .map(|details| VariantDetailsTokens {
+                details,
+                schema_module,
+            });
+
+        let arg_markers = self
+            .analysed
+            .arguments
+            .iter()
+            .map(|arg| arg.schema_field.marker_ident().to_rust_ident());
+
+        let arg_values = self
+            .analysed
+            .arguments
+            .iter()
+            .map(|arg| ArgumentValueTokens {
+                value: &arg.value,
+                schema_module,
+            });
+
+        tokens.append_all(quote! {
+            {
+                #(#variant_structs)*
+                #[allow(unused_mut, unused_variables)]
+                let mut directive_builder = #builder_call::<#directive_marker>();
+                #(
+                    directive_builder.argument::<#argument_module::#arg_markers>()
+                    #arg_values;
+                )*
+            }
+        })
+    }
+}
+
 struct ArgumentValueTokens<'a> {
     value: &'a ArgumentValue<'a>,
     schema_module: &'a syn::Path,
@@ -19,6 +19,63 @@ pub fn arguments_from_field_attrs(
     Ok(None)
 }
 
+pub fn directives_from_attrs(attrs: &[syn::Attribute]) -> Result<Vec<ParsedDirective>> {
+    let mut directives = vec![];
+    for attr in attrs {
+        if attr.path().is_ident("directives") {
+            let parsed: CynicDirectives = attr.parse_args()?;
+            directives.extend(parsed.directives);
+        }
+    }
+    Ok(directives)
+}
+
+/// Implements syn::Parse to parse out directives from the directives
+/// attribute.
+#[derive(Debug)]
+pub struct CynicDirectives {
+    pub directives: Punctuated<ParsedDirective, Token![,]>,
+}
+
+impl Parse for CynicDirectives {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Ok(CynicDirectives {
+            directives: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedDirective {
+    pub name: Ident,
+    pub arguments: Vec<FieldArgument>,
+    pub span: Span,
+}
+
+impl Parse for ParsedDirective {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let name = input.call(Ident::parse_any)?;
+        let mut span = name.span();
+        let mut arguments = vec![];
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            let parens = syn::parenthesized!(content in input);
+            span = span.join(parens.span.join()).unwrap_or(span);
+            arguments = content
+                .parse_terminated(FieldArgument::parse, Token![,])?
+                .into_iter()
+                .collect();
+        }
+
+        Ok(ParsedDirective {
+            name,
+            arguments,
+            span,
+        })
+    }
+}
+
 /// Implements syn::Parse to parse out arguments from the arguments
 /// attribute.
 #[derive(Debug)]
@@ -146,6 +203,28 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn test_parsing_directives() {
+        let parsed: CynicDirectives =
+            parse_quote! { skip(if: $flag), include(if: true), lowercase };
+        let directives = parsed.directives.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(directives.len(), 3);
+        assert_eq!(directives[0].name.to_string(), "skip");
+        assert_eq!(directives[0].arguments[0].argument_name.to_string(), "if");
+        assert_matches!(
+            directives[0].arguments[0].value,
+            FieldArgumentValue::Literal(ArgumentLiteral::Variable(_, _))
+        );
+        assert_eq!(directives[1].name.to_string(), "include");
+        assert_matches!(
+            directives[1].arguments[0].value,
+            FieldArgumentValue::Literal(ArgumentLiteral::Literal(syn::Lit::Bool(_)))
+        );
+        assert_eq!(directives[2].name.to_string(), "lowercase");
+        assert!(directives[2].arguments.is_empty());
+    }
+
     #[test]
     fn test_parsing_string_literal() {
         let parsed: CynicArguments = parse_quote! { x: "abcd" };
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote_spanned;
 
 use {
-    super::FragmentDeriveField,
+    super::{arguments::directives_from_attrs, FragmentDeriveField},
     crate::{generics_for_serde, schema::types as schema},
 };
 
@@ -32,6 +32,8 @@ struct Field {
     is_flattened: bool,
     is_recurse: bool,
     is_feature_flagged: bool,
+    is_skippable: bool,
+    is_deferred: bool,
 }
 
 impl<'a> DeserializeImpl<'a> {
@@ -118,7 +120,7 @@ impl quote::ToTokens for StandardDeserializeImpl<'_> {
 
         let field_unwraps = self.fields.iter().zip(&serialized_names).map(|(field, serialized_name)| {
             let rust_name = &field.rust_name;
-            if field.is_recurse || field.is_feature_flagged {
+            if field.is_recurse || field.is_feature_flagged || field.is_skippable {
                 let span = rust_name.span();
                 quote_spanned!{ span =>
                     let #rust_name = #rust_name.unwrap_or_default();
@@ -216,7 +218,11 @@ impl quote::ToTokens for SpreadingDeserializeImpl<'_> {
         let field_inserts = self.fields.iter().map(|f| {
             let field_name = &f.rust_name;
             let field_ty = &f.ty;
-            if f.is_spread {
+            if f.is_spread && f.is_deferred {
+                quote! {
+                    #field_name: spreadable.deserialize_deferred_spread()?
+                }
+            } else if f.is_spread {
                 quote! {
                     #field_name: <#field_ty as cynic::serde::Deserialize<'de>>::deserialize(
                         spreadable.spread_deserializer()
@@ -239,8 +245,14 @@ impl quote::ToTokens for SpreadingDeserializeImpl<'_> {
                         .as_deref()
                         .expect("non spread fields must have a serialized_name"),
                 );
-                quote! {
-                    #field_name: spreadable.deserialize_field(#serialized_name)?
+                if f.is_skippable {
+                    quote! {
+                        #field_name: spreadable.deserialize_optional_field(#serialized_name)?
+                    }
+                } else {
+                    quote! {
+                        #field_name: spreadable.deserialize_field(#serialized_name)?
+                    }
                 }
             }
         });
@@ -267,6 +279,16 @@ impl quote::ToTokens for SpreadingDeserializeImpl<'_> {
     }
 }
 
+/// The name a field will have in the response, if it isn't a spread
+pub(super) fn serialized_name(
+    field: &FragmentDeriveField,
+    schema_field: Option<&schema::Field<'_>>,
+) -> Option<String> {
+    field
+        .alias()
+        .or_else(|| schema_field.map(|f| f.name.as_str().to_string()))
+}
+
 fn process_field(field: &FragmentDeriveField, schema_field: Option<&schema::Field<'_>>) -> Field {
     // Should be ok to unwrap since we only accept struct style input
     let rust_name = field.ident.as_ref().unwrap();
@@ -274,14 +296,21 @@ fn process_field(field: &FragmentDeriveField, schema_field: Option<&schema::Fiel
 
     Field {
         field_variant_name,
-        serialized_name: field
-            .alias()
-            .or_else(|| schema_field.map(|f| f.name.as_str().to_string())),
+        serialized_name: serialized_name(field, schema_field),
         rust_name: rust_name.clone(),
         ty: field.ty.clone(),
         is_spread: *field.spread,
         is_flattened: *field.flatten,
         is_recurse: field.recurse.is_some(),
         is_feature_flagged: field.feature.is_some(),
+        is_skippable: *field.defer
+            || directives_from_attrs(&field.attrs)
+                .map(|directives| {
+                    directives
+                        .iter()
+                        .any(|directive| directive.name == "skip" || directive.name == "include")
+                })
+                .unwrap_or_default(),
+        is_deferred: *field.defer,
     }
 }
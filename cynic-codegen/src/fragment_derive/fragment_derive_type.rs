@@ -9,6 +9,7 @@ use crate::schema::{
 pub struct FragmentDeriveType<'a> {
     pub fields: Vec<Field<'a>>,
     pub name: Cow<'a, str>,
+    pub description: Option<Cow<'a, str>>,
     pub marker_ident: TypeMarkerIdent<'a>,
     pub field_module: FieldMarkerModule<'a>,
 }
@@ -31,12 +32,14 @@ impl<'a> TryFrom<Type<'a>> for FragmentDeriveType<'a> {
                 marker_ident: iface.marker_ident(),
                 field_module: iface.field_module(),
                 name: iface.name,
+                description: iface.description,
                 fields: iface.fields,
             }),
             Type::Object(obj) => Ok(FragmentDeriveType {
                 marker_ident: obj.marker_ident(),
                 field_module: obj.field_module(),
                 name: obj.name,
+                description: obj.description,
                 fields: obj.fields,
             }),
             other => Err(SchemaError::unexpected_kind(other, Kind::ObjectOrInterface)),
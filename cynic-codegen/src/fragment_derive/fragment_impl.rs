@@ -6,17 +6,26 @@ use {
 
 use crate::{
     error::Errors,
+    marker_references::{deprecation_warning, doc_reference},
     schema::{
-        types::{Field, OutputType},
+        types::{Field, OutputType, TypeRef},
         Schema, Unvalidated,
     },
-    types::{self, check_spread_type, check_types_are_compatible, CheckMode},
+    schema_docs::doc_attr,
+    types::{
+        self, check_deferred_spread_type, check_spread_type, check_types_are_compatible,
+        outer_type_is_option, CheckMode,
+    },
     variables_fields_path,
 };
 
 use super::{
-    arguments::{arguments_from_field_attrs, process_arguments},
+    arguments::{
+        arguments_from_field_attrs, directives_from_attrs, process_arguments, process_directive,
+        DirectiveOutput, DirectiveTarget,
+    },
     fragment_derive_type::FragmentDeriveType,
+    static_query::StaticParts,
 };
 
 use super::input::FragmentDeriveField;
@@ -25,9 +34,16 @@ pub struct FragmentImpl<'schema, 'a> {
     target_struct: &'a proc_macro2::Ident,
     generics: &'a syn::Generics,
     selections: Vec<Selection<'schema>>,
+    operation_directives: Vec<DirectiveOutput<'schema>>,
     variables_fields: syn::Type,
     graphql_type_name: String,
     schema_type_path: syn::Path,
+    named_fragment: bool,
+    description: Option<String>,
+    cache_key_field: Option<syn::Path>,
+    static_parts: Option<Vec<TokenStream>>,
+    operation_kind: Option<proc_macro2::Ident>,
+    variables: Option<syn::Path>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -39,19 +55,25 @@ enum Selection<'a> {
 struct FieldSelection<'a> {
     rust_field_type: syn::Type,
     field_marker_type_path: syn::Path,
+    schema_module_path: syn::Path,
     graphql_field_kind: FieldKind,
     graphql_field: &'a Field<'a>,
     arguments: super::arguments::Output<'a>,
+    directives: Vec<DirectiveOutput<'a>>,
     flatten: bool,
     alias: Option<String>,
     recurse_limit: Option<u8>,
     span: proc_macro2::Span,
     requires_feature: Option<String>,
+    defer: bool,
+    stream: bool,
+    marker_references: Vec<TokenStream>,
 }
 
 struct SpreadSelection {
     rust_field_type: syn::Type,
     span: proc_macro2::Span,
+    defer: bool,
 }
 
 enum FieldKind {
@@ -73,6 +95,9 @@ impl<'schema, 'a: 'schema> FragmentImpl<'schema, 'a> {
         schema_module_path: &syn::Path,
         graphql_type_name: &str,
         variables: Option<&syn::Path>,
+        attrs: &[syn::Attribute],
+        named_fragment: bool,
+        schema_docs: bool,
     ) -> Result<Self, Errors> {
         let target_struct = name;
 
@@ -93,10 +118,64 @@ impl<'schema, 'a: 'schema> FragmentImpl<'schema, 'a> {
                     &field_module_path,
                     schema_module_path,
                     variables_fields,
+                    schema_docs,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let operation_directives = directives_from_attrs(attrs)?
+            .into_iter()
+            .map(|directive| {
+                process_directive(
+                    schema,
+                    directive,
+                    DirectiveTarget::Operation,
+                    schema_module_path.clone(),
+                    variables_fields,
                 )
             })
             .collect::<Result<Vec<_>, _>>()?;
 
+        // Normalized caches identify objects by their id, so we need to
+        // select it if the type has one
+        let cache_key_field = schema_type
+            .fields
+            .iter()
+            .find(|field| field.name == *"id")
+            .filter(|field| {
+                matches!(
+                    field.field_type.inner_type(schema).as_kind(),
+                    FieldKind::Scalar
+                )
+            })
+            .map(|field| field.marker_ident().to_path(&field_module_path));
+
+        // Queries can only be built at compile time if we know exactly what
+        // they'll contain
+        let static_parts =
+            (generics.params.is_empty() && !named_fragment && operation_directives.is_empty())
+                .then(|| static_parts(&selections))
+                .flatten();
+
+        let operation_kind = schema.root_types().ok().and_then(|roots| {
+            let kind = if roots.query.name == graphql_type_name {
+                "Query"
+            } else if roots
+                .mutation
+                .is_some_and(|mutation| mutation.name == graphql_type_name)
+            {
+                "Mutation"
+            } else if roots
+                .subscription
+                .is_some_and(|subscription| subscription.name == graphql_type_name)
+            {
+                "Subscription"
+            } else {
+                return None;
+            };
+            Some(proc_macro2::Ident::new(kind, Span::call_site()))
+        });
+
         let variables_fields = if let Some(vars) = variables_fields {
             let span = vars.span();
             syn::parse2(quote_spanned! { span => #vars })?
@@ -106,15 +185,37 @@ impl<'schema, 'a: 'schema> FragmentImpl<'schema, 'a> {
 
         Ok(FragmentImpl {
             selections,
+            operation_directives,
             target_struct,
             generics,
             variables_fields,
             graphql_type_name: graphql_type_name.to_string(),
             schema_type_path,
+            named_fragment,
+            description: schema_type
+                .description
+                .as_ref()
+                .filter(|_| schema_docs)
+                .map(|description| description.to_string()),
+            cache_key_field,
+            static_parts,
+            operation_kind,
+            variables: variables.cloned(),
         })
     }
 }
 
+fn static_parts(selections: &[Selection<'_>]) -> Option<Vec<TokenStream>> {
+    let mut parts = StaticParts::default();
+    for selection in selections {
+        match selection {
+            Selection::Field(field) => field.static_parts(&mut parts)?,
+            Selection::Spread(_) => return None,
+        }
+    }
+    Some(parts.into_parts())
+}
+
 fn process_field<'a>(
     schema: &'a Schema<'a, Unvalidated>,
     field: &FragmentDeriveField,
@@ -122,13 +223,30 @@ fn process_field<'a>(
     field_module_path: &syn::Path,
     schema_module_path: &syn::Path,
     variables_fields: Option<&syn::Path>,
+    schema_docs: bool,
 ) -> Result<Selection<'a>, Errors> {
+    let directives = directives_from_attrs(&field.attrs)?;
+
     if field.type_check_mode() == CheckMode::Spreading {
-        check_spread_type(&field.ty)?;
+        let rust_field_type = if *field.defer {
+            check_deferred_spread_type(&field.ty)?
+        } else {
+            check_spread_type(&field.ty)?;
+            field.ty.clone()
+        };
+
+        if let Some(directive) = directives.first() {
+            return Err(syn::Error::new(
+                directive.span,
+                "Directives can't be used on spread fields",
+            )
+            .into());
+        }
 
         return Ok(Selection::Spread(SpreadSelection {
-            rust_field_type: field.ty.clone(),
+            rust_field_type,
             span: field.ty.span(),
+            defer: *field.defer,
         }));
     }
 
@@ -146,14 +264,81 @@ fn process_field<'a>(
         argument_span,
     )?;
 
+    if let Some(directive) = directives
+        .iter()
+        .find(|directive| directive.name == "skip" || directive.name == "include")
+    {
+        if !outer_type_is_option(&field.ty) {
+            return Err(syn::Error::new(
+                directive.name.span(),
+                format!(
+                    "The server may omit fields with @{}, so they must be wrapped in Option",
+                    directive.name
+                ),
+            )
+            .into());
+        }
+    }
+
+    if *field.defer && !outer_type_is_option(&field.ty) {
+        return Err(syn::Error::new(
+            field.defer.span(),
+            "The server may omit fields with @defer, so they must be wrapped in Option",
+        )
+        .into());
+    }
+
+    if *field.stream && !is_list(&schema_field.field_type) {
+        return Err(
+            syn::Error::new(field.stream.span(), "Only list fields can be streamed").into(),
+        );
+    }
+
+    let directives = directives
+        .into_iter()
+        .map(|directive| {
+            process_directive(
+                schema,
+                directive,
+                DirectiveTarget::Field,
+                schema_module_path.clone(),
+                variables_fields,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     check_types_are_compatible(&schema_field.field_type, &field.ty, field.type_check_mode())?;
 
     let field_marker_type_path = schema_field.marker_ident().to_path(field_module_path);
 
+    let span = field
+        .ident
+        .as_ref()
+        .map(|ident| ident.span())
+        .unwrap_or_else(|| field.ty.span());
+
+    let mut marker_references = vec![];
+    if !field.allow_deprecated {
+        marker_references.extend(
+            schema_field
+                .deprecated
+                .is_some()
+                .then(|| field_marker_type_path.clone())
+                .into_iter()
+                .chain(arguments.deprecated_markers())
+                .map(|marker| deprecation_warning(&marker, span)),
+        );
+    }
+    if schema_docs && schema_field.description.is_some() {
+        marker_references.push(doc_reference(&field_marker_type_path, span));
+    }
+
     Ok(Selection::Field(FieldSelection {
         rust_field_type: field.ty.clone(),
         arguments,
+        directives,
         field_marker_type_path,
+        schema_module_path: schema_module_path.clone(),
         graphql_field: schema_field,
         recurse_limit: field.recurse.as_ref().map(|f| **f),
         span: field.ty.span(),
@@ -164,131 +349,446 @@ fn process_field<'a>(
             .feature
             .as_ref()
             .map(|feature| feature.as_ref().clone()),
+        defer: *field.defer,
+        stream: *field.stream,
+        marker_references,
     }))
 }
 
+fn is_list(field_type: &TypeRef<'_, OutputType<'_>>) -> bool {
+    match field_type {
+        TypeRef::Nullable(inner) => is_list(inner),
+        TypeRef::List(_) => true,
+        TypeRef::Named(..) => false,
+    }
+}
+
+impl FragmentImpl<'_, '_> {
+    /// Outputs an impl of `QueryFragmentFor` for the graphql_type of this
+    /// fragment, for fragments with several graphql_types.
+    ///
+    /// `generics` should be the generics of the fragment with any bounds the
+    /// impl needs added.
+    pub(super) fn query_fragment_for_impl(&self, generics: &syn::Generics) -> TokenStream {
+        let variables_fields = &self.variables_fields;
+        let target_struct = &self.target_struct;
+        let graphql_type = proc_macro2::Literal::string(&self.graphql_type_name);
+        let schema_type = &self.schema_type_path;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let query_body = self.query_body();
+        let uses_field_results = self.uses_field_results();
+        let marker_references = self.marker_references();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics cynic::QueryFragmentFor<#schema_type> for #target_struct #ty_generics #where_clause {
+                type VariablesFields = #variables_fields;
+
+                const TYPE: Option<&'static str> = Some(#graphql_type);
+                #[allow(deprecated)]
+                const USES_FIELD_RESULTS: bool = #uses_field_results;
+
+                fn query_for(mut builder: cynic::queries::SelectionBuilder<'_, #schema_type, Self::VariablesFields>) {
+                    #![allow(unused_mut, deprecated)]
+
+                    #query_body
+                }
+            }
+
+            #(#marker_references)*
+        }
+    }
+
+    pub(super) fn variables_fields(&self) -> &syn::Type {
+        &self.variables_fields
+    }
+
+    /// The fragments that select the children of the fields of this
+    /// fragment, along with the schema types they're selected on.
+    pub(super) fn child_fragments(&self) -> impl Iterator<Item = (syn::Type, syn::Path)> + '_ {
+        self.selections
+            .iter()
+            .filter_map(|selection| match selection {
+                Selection::Field(field)
+                    if !field.flatten
+                        && field.recurse_limit.is_none()
+                        && !matches!(
+                            field.graphql_field_kind,
+                            FieldKind::Scalar | FieldKind::Enum
+                        ) =>
+                {
+                    let (_, fragment_type, schema_type) = field.child_fragment();
+                    Some((fragment_type, schema_type))
+                }
+                _ => None,
+            })
+    }
+
+    fn uses_field_results(&self) -> TokenStream {
+        let selections = self
+            .selections
+            .iter()
+            .map(|selection| selection.uses_field_results());
+
+        quote! { false #(|| #selections)* }
+    }
+
+    fn query_body(&self) -> TokenStream {
+        let selections = &self.selections;
+        let operation_directives = &self.operation_directives;
+
+        let cache_key = self.cache_key_field.as_ref().map(|field_marker| {
+            quote! {
+                builder.select_cache_key::<#field_marker>();
+            }
+        });
+
+        let selections = if self.named_fragment {
+            quote! {
+                #[allow(unused_variables)]
+                let build = |mut builder: cynic::queries::SelectionBuilder<
+                    '_,
+                    Self::SchemaType,
+                    Self::VariablesFields
+                >| {
+                    #(#selections)*
+                    #cache_key
+                };
+                builder.named_fragment::<Self>(build);
+            }
+        } else {
+            quote! {
+                #(#selections)*
+                #cache_key
+            }
+        };
+
+        quote! {
+            #(#operation_directives)*
+            #selections
+        }
+    }
+
+    /// The selections have to allow deprecated markers, so any references
+    /// that should output deprecation warnings are output separately
+    fn marker_references(&self) -> impl Iterator<Item = &TokenStream> {
+        self.selections
+            .iter()
+            .flat_map(|selection| match selection {
+                Selection::Field(field) => field.marker_references.as_slice(),
+                Selection::Spread(_) => &[],
+            })
+    }
+}
+
 impl quote::ToTokens for FragmentImpl<'_, '_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         use quote::TokenStreamExt;
 
         let variables_fields = &self.variables_fields;
         let target_struct = &self.target_struct;
-        let selections = &self.selections;
         let graphql_type = proc_macro2::Literal::string(&self.graphql_type_name);
         let schema_type = &self.schema_type_path;
         let fragment_name = proc_macro2::Literal::string(&target_struct.to_string());
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let query_body = self.query_body();
+        let uses_field_results = self.uses_field_results();
+
+        let static_selection = self.static_parts.as_ref().map(|parts| {
+            quote! {
+                const STATIC_SELECTION: Option<cynic::queries::StaticSelection> = {
+                    const PARTS: &[cynic::__private::static_query::Part] = &[#(#parts),*];
+                    const LEN: usize = cynic::__private::static_query::selections::<0>(PARTS).len;
+                    const SELECTIONS: [u8; LEN] =
+                        cynic::__private::static_query::selections::<LEN>(PARTS).bytes;
+                    const VARIABLES_LEN: usize =
+                        cynic::__private::static_query::variables_len(PARTS);
+                    const VARIABLES: [&str; VARIABLES_LEN] =
+                        cynic::__private::static_query::variables::<VARIABLES_LEN>(PARTS);
+                    cynic::__private::static_query::selection(PARTS, &SELECTIONS, &VARIABLES)
+                };
+            }
+        });
+
+        let marker_references = self.marker_references();
+
+        let doc = doc_attr(self.description.as_deref());
+
+        let static_operation = self
+            .operation_kind
+            .as_ref()
+            .filter(|_| self.static_parts.is_some())
+            .map(|kind| {
+                let variables = match &self.variables {
+                    Some(variables) => quote! { #variables },
+                    None => quote! { () },
+                };
+                quote! {
+                    const STATIC_OPERATION: Option<cynic::StaticOperation> = {
+                        const KIND: cynic::__private::static_query::OperationKind =
+                            cynic::__private::static_query::OperationKind::#kind;
+                        const SELECTION: Option<cynic::queries::StaticSelection> =
+                            <#target_struct as cynic::QueryFragment>::STATIC_SELECTION;
+                        const VARIABLES: &[(&str, cynic::variables::VariableType)] =
+                            <#variables as cynic::QueryVariables>::VARIABLES;
+                        const LEN: usize = cynic::__private::static_query::operation::<0>(
+                            KIND, #fragment_name, SELECTION, VARIABLES
+                        ).len;
+                        const DOCUMENT: [u8; LEN] = cynic::__private::static_query::operation::<LEN>(
+                            KIND, #fragment_name, SELECTION, VARIABLES
+                        ).bytes;
+                        cynic::__private::static_query::static_operation(
+                            KIND, #fragment_name, SELECTION, &DOCUMENT, VARIABLES
+                        )
+                    };
+                }
+            });
 
         tokens.append_all(quote! {
             #[automatically_derived]
+            #doc
             impl #impl_generics cynic::QueryFragment for #target_struct #ty_generics #where_clause {
                 type SchemaType = #schema_type;
                 type VariablesFields = #variables_fields;
 
                 const TYPE: Option<&'static str> = Some(#graphql_type);
+                #[allow(deprecated)]
+                const USES_FIELD_RESULTS: bool = #uses_field_results;
+                #static_selection
+                #static_operation
 
                 fn query(mut builder: cynic::queries::SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>)
                 where
                 {
-                    #![allow(unused_mut)]
+                    #![allow(unused_mut, deprecated)]
 
-                    #(#selections)*
+                    #query_body
                 }
 
                 fn name() -> Option<std::borrow::Cow<'static, str>> {
                     Some(std::borrow::Cow::Borrowed(#fragment_name))
                 }
             }
+
+            #(#marker_references)*
         })
     }
 }
 
-impl quote::ToTokens for Selection<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        match self {
-            Selection::Field(inner) => inner.to_tokens(tokens),
-            Selection::Spread(inner) => inner.to_tokens(tokens),
+impl FieldSelection<'_> {
+    /// Writes this field into `parts`.
+    ///
+    /// Returns `None` if it can't be written at compile time.
+    fn static_parts(&self, parts: &mut StaticParts) -> Option<()> {
+        if self.flatten
+            || self.recurse_limit.is_some()
+            || self.requires_feature.is_some()
+            || self.defer
+            || self.stream
+            || !self.directives.is_empty()
+        {
+            return None;
         }
-    }
-}
-
-enum SelectionMode {
-    Composite,
-    FlattenComposite,
-    FlattenLeaf,
-    Recurse(u8),
-    Leaf,
-}
 
-impl quote::ToTokens for FieldSelection<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        use quote::TokenStreamExt;
+        if let Some(alias) = &self.alias {
+            parts.push_text(&format!("{alias}: "));
+        }
+        parts.push_text(self.graphql_field.name.as_str());
+        self.arguments.static_parts(parts)?;
+
+        match self.graphql_field_kind {
+            FieldKind::Scalar | FieldKind::Enum => parts.push_text("\n"),
+            FieldKind::Composite | FieldKind::Interface | FieldKind::Union => {
+                let (_, fragment_type, schema_type) = self.child_fragment();
+                parts.push_selection_set(&fragment_type, &schema_type)?;
+            }
+        }
 
-        let field_marker_type_path = &self.field_marker_type_path;
-        let field_type = &self.rust_field_type;
-        let arguments = &self.arguments;
+        Some(())
+    }
 
-        let alias = self.alias.as_deref().map(|alias| {
-            let alias = proc_macro2::Literal::string(alias);
-            quote! {
-                field_builder.alias(#alias);
-            }
-        });
+    /// The fragment that selects the children of this field & the schema type
+    /// it's selected on, along with the number of `Option` & `Vec` wrappers
+    /// around them.
+    ///
+    /// Fragments are selected through `QueryFragmentFor` with the schema type
+    /// of the field, so generic fragments with several `graphql_type`s use
+    /// the one for this field.
+    fn child_fragment(&self) -> (usize, syn::Type, syn::Path) {
+        let aligned_type =
+            types::align_output_type(&self.rust_field_type, &self.graphql_field.field_type);
+        let (depth, fragment_type, schema_type) =
+            types::unwrap_output_type(&aligned_type, &self.graphql_field.field_type);
+
+        (
+            depth,
+            fragment_type,
+            schema_type.marker_type().to_path(&self.schema_module_path),
+        )
+    }
+}
 
-        let selection_mode = match (&self.graphql_field_kind, self.flatten, self.recurse_limit) {
+impl FieldSelection<'_> {
+    fn selection_mode(&self) -> SelectionMode {
+        match (&self.graphql_field_kind, self.flatten, self.recurse_limit) {
             (FieldKind::Enum | FieldKind::Scalar, true, _) => SelectionMode::FlattenLeaf,
             (FieldKind::Enum | FieldKind::Scalar, false, _) => SelectionMode::Leaf,
             (_, true, None) => SelectionMode::FlattenComposite,
             (_, false, None) => SelectionMode::Composite,
             (_, false, Some(limit)) => SelectionMode::Recurse(limit),
             _ => panic!("Uncertain how to select for this field."),
-        };
+        }
+    }
 
-        let aligned_type = match selection_mode {
+    fn aligned_type(&self) -> syn::Type {
+        match self.selection_mode() {
             SelectionMode::Composite | SelectionMode::Leaf => {
                 // If we're doing a normal select we need to align types.
-                types::align_output_type(field_type, &self.graphql_field.field_type)
+                types::align_output_type(&self.rust_field_type, &self.graphql_field.field_type)
             }
             _ => {
                 // Recursive & flatten selections don't need types aligned
                 // according to the graphql rules as they have special rules.
-                field_type.clone()
+                self.rust_field_type.clone()
             }
-        };
+        }
+    }
 
-        let schema_type_lookup = match self.graphql_field_kind {
+    /// The aligned type of this field as the trait for its kind of GraphQL
+    /// type, e.g. `<String as cynic::schema::IsScalar<_>>`
+    fn type_lookup(&self) -> TokenStream {
+        let aligned_type = self.aligned_type();
+        let field_marker_type_path = &self.field_marker_type_path;
+
+        match self.graphql_field_kind {
             FieldKind::Interface | FieldKind::Composite | FieldKind::Union => {
                 quote_spanned! { self.span =>
-                    <#aligned_type as cynic::QueryFragment>::SchemaType
+                    <#aligned_type as cynic::QueryFragment>
                 }
             }
             FieldKind::Scalar if self.flatten => quote_spanned! { self.span =>
-                <#aligned_type as cynic::schema::IsScalar<#aligned_type>>::SchemaType
+                <#aligned_type as cynic::schema::IsScalar<#aligned_type>>
             },
             FieldKind::Scalar => quote_spanned! { self.span =>
                 <#aligned_type as cynic::schema::IsScalar<
                     <#field_marker_type_path as cynic::schema::Field>::Type
-                >>::SchemaType
+                >>
             },
             FieldKind::Enum => quote_spanned! { self.span =>
-                <#aligned_type as cynic::Enum>::SchemaType
+                <#aligned_type as cynic::Enum>
             },
+        }
+    }
+
+    /// An expression for whether the type of this field contains any
+    /// `FieldResult`s
+    fn uses_field_results(&self) -> TokenStream {
+        match self.selection_mode() {
+            // The fragments of recursive fields would depend on their own
+            // `USES_FIELD_RESULTS`, so we have to assume they might
+            SelectionMode::Recurse(_) => quote! { true },
+            SelectionMode::Composite => {
+                let (_, fragment_type, schema_type) = self.child_fragment();
+                quote_spanned! { self.span =>
+                    <#fragment_type as cynic::QueryFragmentFor<#schema_type>>::USES_FIELD_RESULTS
+                }
+            }
+            _ => {
+                let type_lookup = self.type_lookup();
+                quote_spanned! { self.span =>
+                    #type_lookup::USES_FIELD_RESULTS
+                }
+            }
+        }
+    }
+}
+
+impl Selection<'_> {
+    /// An expression for whether this selection contains any `FieldResult`s
+    fn uses_field_results(&self) -> TokenStream {
+        match self {
+            Selection::Field(field) => field.uses_field_results(),
+            Selection::Spread(spread) => {
+                let field_type = &spread.rust_field_type;
+                quote_spanned! { spread.span =>
+                    <#field_type as cynic::QueryFragment>::USES_FIELD_RESULTS
+                }
+            }
+        }
+    }
+}
+
+impl quote::ToTokens for Selection<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Selection::Field(inner) => inner.to_tokens(tokens),
+            Selection::Spread(inner) => inner.to_tokens(tokens),
+        }
+    }
+}
+
+enum SelectionMode {
+    Composite,
+    FlattenComposite,
+    FlattenLeaf,
+    Recurse(u8),
+    Leaf,
+}
+
+impl quote::ToTokens for FieldSelection<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        use quote::TokenStreamExt;
+
+        let field_marker_type_path = &self.field_marker_type_path;
+        let arguments = &self.arguments;
+        let directives = &self.directives;
+        let stream = self.stream.then(|| {
+            quote! {
+                field_builder.stream();
+            }
+        });
+
+        let alias = self.alias.as_deref().map(|alias| {
+            let alias = proc_macro2::Literal::string(alias);
+            quote! {
+                field_builder.alias(#alias);
+            }
+        });
+
+        let selection_mode = self.selection_mode();
+        let aligned_type = self.aligned_type();
+        let type_lookup = self.type_lookup();
+        let schema_type_lookup = quote_spanned! { self.span =>
+            #type_lookup::SchemaType
         };
 
         let select_tokens = match selection_mode {
             SelectionMode::Composite => {
+                let field_schema_type = self
+                    .graphql_field
+                    .field_type
+                    .marker_type()
+                    .to_path(&self.schema_module_path);
+                let (depth, fragment_type, schema_type) = self.child_fragment();
+                let into_inner = std::iter::repeat(quote! { .into_inner() }).take(depth);
+
                 quote_spanned! { self.span =>
                     let mut field_builder = builder
                         .select_field::<
                             #field_marker_type_path,
-                            #schema_type_lookup
+                            #field_schema_type
                         >();
 
                     #alias
                     #arguments
-
-                    <#aligned_type as cynic::QueryFragment>::query(
-                        field_builder.select_children()
+                    #(#directives)*
+                    #stream
+
+                    <#fragment_type as cynic::QueryFragmentFor<#schema_type>>::query_for(
+                        field_builder.select_children::<
+                            <#fragment_type as cynic::QueryFragmentFor<#schema_type>>::VariablesFields
+                        >()
+                        #(#into_inner)*
                     );
                 }
             }
@@ -303,9 +803,13 @@ impl quote::ToTokens for FieldSelection<'_> {
 
                     #alias
                     #arguments
+                    #(#directives)*
+                    #stream
 
                     <#aligned_type as cynic::QueryFragment>::query(
-                        field_builder.select_children()
+                        field_builder.select_children::<
+                            <#aligned_type as cynic::QueryFragment>::VariablesFields
+                        >()
                     );
                 }
             }
@@ -320,6 +824,8 @@ impl quote::ToTokens for FieldSelection<'_> {
 
                     #alias
                     #arguments
+                    #(#directives)*
+                    #stream
                 }
             }
             SelectionMode::Recurse(limit) => {
@@ -332,9 +838,13 @@ impl quote::ToTokens for FieldSelection<'_> {
                     {
                         #alias
                         #arguments
+                        #(#directives)*
+                    #stream
 
                         <#aligned_type as cynic::QueryFragment>::query(
-                            field_builder.select_children()
+                            field_builder.select_children::<
+                                <#aligned_type as cynic::QueryFragment>::VariablesFields
+                            >()
                         );
                     }
                 }
@@ -349,10 +859,24 @@ impl quote::ToTokens for FieldSelection<'_> {
 
                     #alias
                     #arguments
+                    #(#directives)*
+                    #stream
                 }
             }
         };
 
+        let select_tokens = if self.defer {
+            quote! {
+                {
+                    let mut fragment_builder = builder.defer();
+                    let mut builder = fragment_builder.select_children::<Self::VariablesFields>();
+                    #select_tokens
+                }
+            }
+        } else {
+            select_tokens
+        };
+
         match &self.requires_feature {
             Some(required_feature) => {
                 let string_lit = proc_macro2::Literal::string(required_feature);
@@ -372,10 +896,15 @@ impl quote::ToTokens for SpreadSelection {
         use quote::TokenStreamExt;
         let field_type = &self.rust_field_type;
 
+        let fragment_builder = if self.defer {
+            quote! { builder.defer() }
+        } else {
+            quote! { builder.inline_fragment() }
+        };
+
         tokens.append_all(quote_spanned! { self.span =>
             <#field_type as cynic::QueryFragment>::query(
-                builder
-                    .inline_fragment()
+                #fragment_builder
                     .select_children::<<#field_type as cynic::QueryFragment>::VariablesFields>()
             );
         })
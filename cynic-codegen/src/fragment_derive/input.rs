@@ -1,13 +1,19 @@
 use {darling::util::SpannedValue, proc_macro2::Span, std::collections::HashSet};
 
-use crate::{idents::RenamableFieldIdent, schema::SchemaInput, types::CheckMode, Errors};
+use crate::{
+    idents::{RenamableFieldIdent, RenameAll},
+    schema::SchemaInput,
+    types::CheckMode,
+    Errors,
+};
 
 #[derive(darling::FromDeriveInput)]
-#[darling(attributes(cynic), supports(struct_named))]
+#[darling(attributes(cynic), supports(struct_named), forward_attrs(directives))]
 pub struct FragmentDeriveInput {
     pub(super) ident: proc_macro2::Ident,
     pub(super) data: darling::ast::Data<(), FragmentDeriveField>,
     pub(super) generics: syn::Generics,
+    pub(super) attrs: Vec<syn::Attribute>,
 
     #[darling(default)]
     schema: Option<SpannedValue<String>>,
@@ -17,11 +23,20 @@ pub struct FragmentDeriveInput {
     #[darling(default, rename = "schema_module")]
     schema_module_: Option<syn::Path>,
 
-    #[darling(default)]
-    pub graphql_type: Option<SpannedValue<String>>,
+    #[darling(multiple)]
+    pub graphql_type: Vec<SpannedValue<String>>,
 
     #[darling(default)]
     variables: Option<syn::Path>,
+
+    #[darling(default)]
+    pub(super) named_fragment: bool,
+
+    #[darling(default)]
+    pub(super) schema_docs: bool,
+
+    #[darling(default)]
+    pub(super) rename_all: Option<RenameAll>,
 }
 
 impl FragmentDeriveInput {
@@ -32,18 +47,14 @@ impl FragmentDeriveInput {
         syn::parse2(quote::quote! { schema }).unwrap()
     }
 
-    pub fn graphql_type_name(&self) -> String {
-        self.graphql_type
-            .as_ref()
-            .map(|sp| sp.to_string())
-            .unwrap_or_else(|| self.ident.to_string())
-    }
-
-    pub fn graphql_type_span(&self) -> Span {
-        self.graphql_type
-            .as_ref()
-            .map(|val| val.span())
-            .unwrap_or_else(|| self.ident.span())
+    /// The names of the GraphQL types this fragment is for.
+    ///
+    /// This is usually just one type, but generic structs can provide several.
+    pub fn graphql_types(&self) -> Vec<SpannedValue<String>> {
+        if self.graphql_type.is_empty() {
+            return vec![SpannedValue::new(self.ident.to_string(), self.ident.span())];
+        }
+        self.graphql_type.clone()
     }
 
     pub fn validate(&self) -> Result<(), Errors> {
@@ -112,7 +123,7 @@ impl FragmentDeriveInput {
 }
 
 #[derive(darling::FromField, Clone)]
-#[darling(attributes(cynic), forward_attrs(arguments))]
+#[darling(attributes(cynic), forward_attrs(arguments, directives))]
 pub struct FragmentDeriveField {
     pub(super) ident: Option<proc_macro2::Ident>,
     pub(super) ty: syn::Type,
@@ -136,6 +147,15 @@ pub struct FragmentDeriveField {
 
     #[darling(default)]
     pub(super) feature: Option<SpannedValue<String>>,
+
+    #[darling(default)]
+    pub(super) defer: SpannedValue<bool>,
+
+    #[darling(default)]
+    pub(super) stream: SpannedValue<bool>,
+
+    #[darling(default)]
+    pub(super) allow_deprecated: bool,
 }
 
 impl FragmentDeriveField {
@@ -164,6 +184,14 @@ impl FragmentDeriveField {
             .into());
         }
 
+        if *self.spread && *self.stream {
+            return Err(syn::Error::new(
+                self.stream.span(),
+                "A field can't be streamed if it's being spread",
+            )
+            .into());
+        }
+
         if *self.alias && self.rename.is_none() {
             return Err(syn::Error::new(
                 self.alias.span(),
@@ -187,16 +215,22 @@ impl FragmentDeriveField {
         }
     }
 
-    pub(super) fn graphql_ident(&self) -> RenamableFieldIdent {
-        let mut ident = RenamableFieldIdent::from(
-            self.ident
-                .clone()
-                .expect("FragmentDerive only supports named structs"),
-        );
+    pub(super) fn graphql_ident(&self, rename_all: Option<RenameAll>) -> RenamableFieldIdent {
+        let rust_ident = self
+            .ident
+            .clone()
+            .expect("FragmentDerive only supports named structs");
+        // Introspection fields like __typename are named the same in every schema
+        let is_introspection_field = rust_ident.to_string().starts_with("__");
+        let span = rust_ident.span();
+
+        let mut ident = RenamableFieldIdent::from(rust_ident);
         if let Some(rename) = &self.rename {
             let span = rename.span();
             let rename = (**rename).clone();
             ident.set_rename(rename, span)
+        } else if let (Some(rename_all), false) = (rename_all, is_introspection_field) {
+            ident.rename_with(rename_all, span)
         }
         ident
     }
@@ -230,6 +264,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("field_two")),
@@ -241,6 +278,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("field_three")),
@@ -252,6 +292,9 @@ mod tests {
                         rename: Some("fieldThree".to_string().into()),
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("some_spread")),
@@ -263,15 +306,22 @@ mod tests {
                         rename: Some("fieldThree".to_string().into()),
                         alias: true.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                 ],
             )),
             generics: Default::default(),
+            attrs: vec![],
             schema: None,
             schema_path: Some("abcd".to_string().into()),
             schema_module_: None,
-            graphql_type: Some("abcd".to_string().into()),
+            graphql_type: vec!["abcd".to_string().into()],
             variables: None,
+            named_fragment: false,
+            schema_docs: false,
+            rename_all: None,
         };
 
         assert_matches!(input.validate(), Ok(()));
@@ -294,6 +344,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("field_two")),
@@ -305,6 +358,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("field_three")),
@@ -316,6 +372,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("some_spread")),
@@ -327,6 +386,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("some_other_spread")),
@@ -338,6 +400,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("some_other_spread")),
@@ -349,15 +414,22 @@ mod tests {
                         rename: None,
                         alias: true.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                 ],
             )),
             generics: Default::default(),
+            attrs: vec![],
             schema: None,
             schema_path: Some("abcd".to_string().into()),
             schema_module_: Some(syn::parse2(quote::quote! { abcd }).unwrap()),
-            graphql_type: Some("abcd".to_string().into()),
+            graphql_type: vec!["abcd".to_string().into()],
             variables: None,
+            named_fragment: false,
+            schema_docs: false,
+            rename_all: None,
         };
 
         let errors = input.validate().unwrap_err();
@@ -373,11 +445,15 @@ mod tests {
                 vec![],
             )),
             generics: Default::default(),
+            attrs: vec![],
             schema: None,
             schema_path: Some("abcd".to_string().into()),
             schema_module_: Some(syn::parse2(quote::quote! { abcd }).unwrap()),
-            graphql_type: Some("abcd".to_string().into()),
+            graphql_type: vec!["abcd".to_string().into()],
             variables: None,
+            named_fragment: false,
+            schema_docs: false,
+            rename_all: None,
         };
         let errors = input.validate().unwrap_err();
         insta::assert_snapshot!(errors.to_compile_errors().to_string(), @r###":: core :: compile_error ! { "At least one field should be selected for `TestInput`." }"###);
@@ -400,6 +476,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("field_two")),
@@ -411,6 +490,9 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                     FragmentDeriveField {
                         ident: Some(format_ident!("field_three")),
@@ -422,15 +504,22 @@ mod tests {
                         rename: None,
                         alias: false.into(),
                         feature: None,
+                        defer: false.into(),
+                        stream: false.into(),
+                        allow_deprecated: false,
                     },
                 ],
             )),
             generics: Default::default(),
+            attrs: vec![],
             schema: None,
             schema_path: Some("abcd".to_string().into()),
             schema_module_: Some(syn::parse2(quote::quote! { abcd }).unwrap()),
-            graphql_type: None,
+            graphql_type: vec![],
             variables: None,
+            named_fragment: false,
+            schema_docs: false,
+            rename_all: None,
         };
 
         assert_matches!(input.validate(), Ok(()));
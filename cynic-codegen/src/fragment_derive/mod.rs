@@ -1,6 +1,7 @@
 use proc_macro2::{Span, TokenStream};
 
 use crate::{
+    idents::RenameAll,
     schema::{
         types::{self as schema},
         Schema,
@@ -9,10 +10,12 @@ use crate::{
     Errors,
 };
 
-mod arguments;
+pub(crate) mod arguments;
 mod deserialize_impl;
 mod fragment_derive_type;
 mod fragment_impl;
+mod multiple_graphql_types;
+mod static_query;
 mod type_ext;
 
 pub(crate) mod input;
@@ -42,15 +45,20 @@ pub fn fragment_derive_impl(input: FragmentDeriveInput) -> Result<TokenStream, E
 
     let schema = Schema::new(input.schema_input()?);
 
+    let graphql_types = input.graphql_types();
+    if graphql_types.len() > 1 {
+        return multiple_graphql_types::derive(&schema, input, &graphql_types);
+    }
+
     let schema_type = schema
-        .lookup::<FragmentDeriveType<'_>>(&input.graphql_type_name())
-        .map_err(|e| syn::Error::new(input.graphql_type_span(), e))?;
+        .lookup::<FragmentDeriveType<'_>>(&graphql_types[0])
+        .map_err(|e| syn::Error::new(graphql_types[0].span(), e))?;
 
-    let graphql_name = &(input.graphql_type_name());
+    let graphql_name = graphql_types[0].as_str();
     let schema_module = input.schema_module();
     let variables = input.variables();
     if let darling::ast::Data::Struct(fields) = input.data {
-        let fields = pair_fields(fields.into_iter(), &schema_type)?;
+        let fields = pair_fields(fields.into_iter(), &schema_type, input.rename_all)?;
 
         let fragment_impl = FragmentImpl::new_for(
             &schema,
@@ -61,6 +69,9 @@ pub fn fragment_derive_impl(input: FragmentDeriveInput) -> Result<TokenStream, E
             &schema_module,
             graphql_name,
             variables.as_ref(),
+            &input.attrs,
+            input.named_fragment,
+            input.schema_docs,
         )?;
 
         let deserialize_impl = DeserializeImpl::new(&fields, &input.ident, &input.generics);
@@ -81,11 +92,12 @@ pub fn fragment_derive_impl(input: FragmentDeriveInput) -> Result<TokenStream, E
 fn pair_fields<'a>(
     rust_fields: impl IntoIterator<Item = FragmentDeriveField>,
     schema_type: &FragmentDeriveType<'a>,
+    rename_all: Option<RenameAll>,
 ) -> Result<Vec<(FragmentDeriveField, Option<schema::Field<'a>>)>, Errors> {
     let mut result = Vec::new();
     let mut unknown_fields = Vec::new();
     for field in rust_fields {
-        let ident = field.graphql_ident();
+        let ident = field.graphql_ident(rename_all);
         match (schema_type.field(&ident), *field.spread) {
             (Some(schema_field), _) => result.push((field, Some(schema_field.clone()))),
             (None, false) => unknown_fields.push(ident),
@@ -0,0 +1,147 @@
+//! Support for generic fragments with several `graphql_type`s.
+//!
+//! A struct like `Connection<N>` can be used for every connection type in a
+//! schema by providing each of them as a `graphql_type`.  Rust won't let us
+//! implement `QueryFragment` for `Connection<N>` once per type, so instead we
+//! implement `QueryFragmentFor` for each of them.  Fields always select their
+//! children through `QueryFragmentFor` with the schema type of the field, so
+//! the field a fragment is selected on decides which `graphql_type` is used.
+
+use {
+    darling::util::SpannedValue,
+    proc_macro2::{Span, TokenStream, TokenTree},
+    quote::quote,
+    std::collections::HashSet,
+    syn::parse_quote,
+};
+
+use crate::{
+    schema::{Schema, Unvalidated},
+    Errors,
+};
+
+use super::{
+    deserialize_impl::serialized_name, fragment_derive_type::FragmentDeriveType, pair_fields,
+    DeserializeImpl, FragmentDeriveInput, FragmentImpl,
+};
+
+pub(super) fn derive(
+    schema: &Schema<'_, Unvalidated>,
+    input: FragmentDeriveInput,
+    graphql_types: &[SpannedValue<String>],
+) -> Result<TokenStream, Errors> {
+    if input.named_fragment {
+        return Err(syn::Error::new(
+            graphql_types[1].span(),
+            "named_fragment can't be used on a QueryFragment with more than one graphql_type",
+        )
+        .into());
+    }
+
+    let schema_module = input.schema_module();
+    let variables = input.variables();
+    let darling::ast::Data::Struct(fields) = input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "QueryFragment can only be derived from a struct".to_string(),
+        )
+        .into());
+    };
+    let fields = fields.fields;
+
+    let mut paired_fields = Vec::with_capacity(graphql_types.len());
+    for graphql_type in graphql_types {
+        let schema_type = schema
+            .lookup::<FragmentDeriveType<'_>>(graphql_type)
+            .map_err(|e| syn::Error::new(graphql_type.span(), e))?;
+
+        let fields = pair_fields(fields.iter().cloned(), &schema_type, input.rename_all)?;
+
+        paired_fields.push((schema_type, fields));
+    }
+
+    // There's only one Deserialize impl for the struct, so each field has to
+    // have the same name in the response for every graphql_type
+    let (_, first_fields) = &paired_fields[0];
+    for ((_, fields), graphql_type) in paired_fields.iter().zip(graphql_types).skip(1) {
+        for ((field, schema_field), (_, first_schema_field)) in fields.iter().zip(first_fields) {
+            let name = serialized_name(field, schema_field.as_ref());
+            let first_name = serialized_name(field, first_schema_field.as_ref());
+            if name != first_name {
+                return Err(syn::Error::new(
+                    graphql_type.span(),
+                    format!(
+                        "{} & {} can't both be used as graphql_types here: this field is {} on {} but {} on {}",
+                        graphql_types[0].as_str(),
+                        graphql_type.as_str(),
+                        first_name.unwrap_or_default(),
+                        graphql_types[0].as_str(),
+                        name.unwrap_or_default(),
+                        graphql_type.as_str(),
+                    ),
+                )
+                .into());
+            }
+        }
+    }
+
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect::<HashSet<_>>();
+
+    let mut query_fragment_impls = Vec::with_capacity(graphql_types.len());
+    for ((schema_type, fields), graphql_type) in paired_fields.iter().zip(graphql_types) {
+        let fragment_impl = FragmentImpl::new_for(
+            schema,
+            fields,
+            &input.ident,
+            &input.generics,
+            schema_type,
+            &schema_module,
+            graphql_type,
+            variables.as_ref(),
+            &input.attrs,
+            false,
+            input.schema_docs,
+        )?;
+
+        // Any fields that use the type parameters need bounds that say they
+        // can be selected on this graphql_type
+        let variables_fields = fragment_impl.variables_fields();
+        let mut generics = input.generics.clone();
+        let predicates = &mut generics.make_where_clause().predicates;
+        for (fragment_type, field_schema_type) in fragment_impl.child_fragments() {
+            if !uses_type_params(quote! { #fragment_type }, &type_params) {
+                continue;
+            }
+            predicates.push(parse_quote! {
+                #fragment_type: cynic::QueryFragmentFor<#field_schema_type>
+            });
+            predicates.push(parse_quote! {
+                #variables_fields: cynic::queries::VariableMatch<
+                    <#fragment_type as cynic::QueryFragmentFor<#field_schema_type>>::VariablesFields
+                >
+            });
+        }
+
+        query_fragment_impls.push(fragment_impl.query_fragment_for_impl(&generics));
+    }
+
+    let deserialize_impl = DeserializeImpl::new(first_fields, &input.ident, &input.generics);
+
+    Ok(quote! {
+        #(#query_fragment_impls)*
+
+        #deserialize_impl
+    })
+}
+
+fn uses_type_params(tokens: TokenStream, type_params: &HashSet<String>) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => type_params.contains(&ident.to_string()),
+        TokenTree::Group(group) => uses_type_params(group.stream(), type_params),
+        _ => false,
+    })
+}
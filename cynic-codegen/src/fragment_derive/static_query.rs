@@ -0,0 +1,90 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+/// The parts of a selection set that will be written out at compile time by
+/// the `STATIC_SELECTION` of a `QueryFragment`.
+#[derive(Default)]
+pub struct StaticParts {
+    parts: Vec<TokenStream>,
+    text: String,
+}
+
+impl StaticParts {
+    pub fn push_text(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Pushes a variable, given an expression that evaluates to its name
+    pub fn push_variable(&mut self, name: TokenStream) {
+        self.flush_text();
+        self.parts.push(quote! {
+            cynic::__private::static_query::Part::Variable(#name)
+        });
+    }
+
+    /// Pushes the selection set of `fragment_type` when selected on
+    /// `schema_type`.
+    ///
+    /// Returns `None` if the selection set can't be built at compile time.
+    pub fn push_selection_set(
+        &mut self,
+        fragment_type: &syn::Type,
+        schema_type: &syn::Path,
+    ) -> Option<()> {
+        // The consts these parts end up in can't refer to `Self`
+        if mentions_self(quote! { #fragment_type }) {
+            return None;
+        }
+
+        self.flush_text();
+        self.parts.push(quote! {
+            cynic::__private::static_query::Part::SelectionSet(
+                <#fragment_type as cynic::QueryFragmentFor<#schema_type>>::STATIC_SELECTION
+            )
+        });
+        Some(())
+    }
+
+    pub fn into_parts(mut self) -> Vec<TokenStream> {
+        self.flush_text();
+        self.parts
+    }
+
+    fn flush_text(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+        let text = proc_macro2::Literal::string(&std::mem::take(&mut self.text));
+        self.parts.push(quote! {
+            cynic::__private::static_query::Part::Text(#text)
+        });
+    }
+}
+
+/// Escapes a string in the same way as the `InputLiteral` display impl in
+/// cynic.
+pub fn escape_string(src: &str) -> String {
+    let mut dest = String::with_capacity(src.len());
+
+    for character in src.chars() {
+        match character {
+            '"' | '\\' | '\n' | '\r' | '\t' => {
+                dest.extend(character.escape_default());
+            }
+            other if other.is_control() => {
+                dest.extend(character.escape_default());
+            }
+            _ => dest.push(character),
+        }
+    }
+
+    dest
+}
+
+fn mentions_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
@@ -124,6 +124,22 @@ use super::fragment_derive;
         }
     )
 )]
+#[case::schema_docs(
+    "schema_docs",
+    parse_quote!(
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(
+            schema_path = "../schemas/starwars.schema.graphql",
+            schema_module = "schema",
+            schema_docs
+        )]
+        struct Film {
+            title: Option<String>,
+            #[cynic(rename = "episodeID")]
+            episode_id: Option<i32>,
+        }
+    )
+)]
 #[case::argument_literals(
     "argument_literals",
     parse_quote!(
@@ -153,6 +169,21 @@ use super::fragment_derive;
         }
     ),
 )]
+#[case::multiple_graphql_types(
+    "multiple_graphql_types",
+    parse_quote!(
+        #[cynic(
+            schema_path = "../schemas/starwars.schema.graphql",
+            schema_module = "schema",
+            graphql_type = "FilmsEdge",
+            graphql_type = "PeopleEdge"
+        )]
+        struct Edge<N> {
+            cursor: String,
+            node: Option<N>,
+        }
+    ),
+)]
 fn snapshot_fragment_derive(#[case] snapshot_name: &str, #[case] input: syn::DeriveInput) {
     let tokens = fragment_derive(&input).unwrap();
 
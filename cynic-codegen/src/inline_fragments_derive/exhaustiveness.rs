@@ -5,6 +5,7 @@ use proc_macro2::Span;
 
 use crate::{
     error::Errors,
+    idents::RenameAll,
     suggestions::{format_guess, guess_field},
 };
 
@@ -13,11 +14,12 @@ use super::{input::InlineFragmentsDeriveVariant, InlineFragmentType};
 pub(super) fn exhaustiveness_check(
     variants: &[SpannedValue<InlineFragmentsDeriveVariant>],
     target_type: &InlineFragmentType<'_>,
+    rename_all: RenameAll,
 ) -> Result<(), Errors> {
     let variant_names = variants
         .iter()
         .filter(|v| !*v.fallback)
-        .map(|v| v.ident.to_string())
+        .map(|v| v.graphql_ident(rename_all).graphql_name())
         .collect::<HashSet<_>>();
 
     let InlineFragmentType::Union(union_type) = target_type else {
@@ -39,7 +41,10 @@ pub(super) fn exhaustiveness_check(
         for unexpected_variant_name in variant_names.difference(&required_variants) {
             let variant = variants
                 .iter()
-                .find(|v| v.ident == *unexpected_variant_name)
+                .find(|v| {
+                    !*v.fallback
+                        && v.graphql_ident(rename_all).graphql_name() == *unexpected_variant_name
+                })
                 .unwrap();
             let guess_field = guess_field(
                 required_variants
@@ -11,7 +11,7 @@ pub enum Fallback {
 
 pub struct InlineFragmentsImpl<'a> {
     pub(super) target_enum: syn::Ident,
-    pub(super) fragments: &'a [super::Fragment],
+    pub(super) fragments: &'a [super::Fragment<'a>],
     pub(super) fallback: Option<Fallback>,
     pub(super) generics: &'a syn::Generics,
 }
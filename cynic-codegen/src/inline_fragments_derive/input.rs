@@ -1,6 +1,10 @@
 use {darling::util::SpannedValue, proc_macro2::Span};
 
-use crate::{error::Errors, schema::SchemaInput};
+use crate::{
+    error::Errors,
+    idents::{RenamableFieldIdent, RenameAll},
+    schema::SchemaInput,
+};
 
 #[derive(darling::FromDeriveInput)]
 #[darling(attributes(cynic), supports(enum_newtype, enum_unit))]
@@ -25,6 +29,9 @@ pub struct InlineFragmentsDeriveInput {
 
     #[darling(default)]
     variables: Option<syn::Path>,
+
+    #[darling(default)]
+    pub(super) rename_all: Option<RenameAll>,
 }
 
 impl InlineFragmentsDeriveInput {
@@ -112,10 +119,11 @@ impl InlineFragmentsDeriveInput {
 }
 
 #[derive(darling::FromVariant)]
-#[darling(attributes(cynic))]
+#[darling(attributes(cynic), forward_attrs(directives))]
 pub(super) struct InlineFragmentsDeriveVariant {
     pub(super) ident: proc_macro2::Ident,
     pub fields: darling::ast::Fields<InlineFragmentsDeriveField>,
+    pub(super) attrs: Vec<syn::Attribute>,
 
     #[darling(default)]
     pub(super) fallback: SpannedValue<bool>,
@@ -134,6 +142,13 @@ pub(super) enum ValidationMode {
 }
 
 impl InlineFragmentsDeriveVariant {
+    /// The name of the GraphQL type this variant is expected to match
+    pub(super) fn graphql_ident(&self, rename_rule: RenameAll) -> RenamableFieldIdent {
+        let mut ident = RenamableFieldIdent::from(self.ident.clone());
+        ident.rename_with(rename_rule, self.ident.span());
+        ident
+    }
+
     fn validate(&self, mode: ValidationMode, span: proc_macro2::Span) -> Result<(), Errors> {
         use {
             darling::ast::Style::{Struct, Tuple, Unit},
@@ -4,11 +4,15 @@ use {
 };
 
 use crate::{
+    fragment_derive::arguments::{
+        directives_from_attrs, process_directive, DirectiveOutput, DirectiveTarget,
+    },
+    idents::RenameAll,
     inline_fragments_derive::input::ValidationMode,
     schema::{
         markers::TypeMarkerIdent,
         types::{InterfaceType, Kind, Type, UnionType},
-        Schema, SchemaError,
+        Schema, SchemaError, Unvalidated,
     },
     variables_fields_path, Errors,
 };
@@ -54,14 +58,20 @@ pub(crate) fn inline_fragments_derive_impl(
 
     if let darling::ast::Data::Enum(variants) = &input.data {
         if input.exhaustive.map(|e| *e).unwrap_or_default() {
-            exhaustiveness::exhaustiveness_check(variants, &target_type)?;
+            let rename_all = input.rename_all.unwrap_or(RenameAll::None);
+            exhaustiveness::exhaustiveness_check(variants, &target_type, rename_all)?;
         }
 
         let fallback = check_fallback(variants, &target_type)?;
 
         let type_lock = target_type.marker_ident().to_path(&input.schema_module());
 
-        let fragments = fragments_from_variants(variants)?;
+        let fragments = fragments_from_variants(
+            variants,
+            &schema,
+            &input.schema_module(),
+            variables_fields_path(variables.as_ref()).as_ref(),
+        )?;
 
         let query_fragment_impl = QueryFragmentImpl {
             target_enum: input.ident.clone(),
@@ -93,17 +103,30 @@ pub(crate) fn inline_fragments_derive_impl(
     }
 }
 
-struct Fragment {
+struct Fragment<'a> {
     rust_variant_name: syn::Ident,
     inner_type: syn::Type,
+    directives: Vec<DirectiveOutput<'a>>,
 }
 
-fn fragments_from_variants(
+fn fragments_from_variants<'a>(
     variants: &[SpannedValue<InlineFragmentsDeriveVariant>],
-) -> Result<Vec<Fragment>, syn::Error> {
+    schema: &'a Schema<'a, Unvalidated>,
+    schema_module: &syn::Path,
+    variables_fields: Option<&syn::Path>,
+) -> Result<Vec<Fragment<'a>>, Errors> {
     let mut result = vec![];
     for variant in variants {
+        let directives = directives_from_attrs(&variant.attrs)?;
+
         if *variant.fallback {
+            if let Some(directive) = directives.first() {
+                return Err(syn::Error::new(
+                    directive.span,
+                    "Directives can't be used on fallback variants",
+                )
+                .into());
+            }
             continue;
         }
 
@@ -111,12 +134,25 @@ fn fragments_from_variants(
             return Err(syn::Error::new(
                 variant.span(),
                 "InlineFragments derive requires enum variants to have one unnamed field",
-            ));
+            )
+            .into());
         }
         let field = variant.fields.fields.first().unwrap();
         result.push(Fragment {
             rust_variant_name: variant.ident.clone(),
             inner_type: field.ty.clone(),
+            directives: directives
+                .into_iter()
+                .map(|directive| {
+                    process_directive(
+                        schema,
+                        directive,
+                        DirectiveTarget::InlineFragment,
+                        schema_module.clone(),
+                        variables_fields,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?,
         });
     }
     Ok(result)
@@ -180,7 +216,7 @@ struct QueryFragmentImpl<'a> {
     generics: &'a syn::Generics,
     type_lock: syn::Path,
     variables: Option<syn::Path>,
-    fragments: &'a [Fragment],
+    fragments: &'a [Fragment<'a>],
     graphql_type_name: String,
     fallback: Option<Fallback>,
 }
@@ -201,6 +237,11 @@ impl quote::ToTokens for QueryFragmentImpl<'_> {
             .iter()
             .map(|fragment| &fragment.inner_type)
             .collect();
+        let directives = self
+            .fragments
+            .iter()
+            .map(|fragment| &fragment.directives)
+            .collect::<Vec<_>>();
         let graphql_type = proc_macro2::Literal::string(&self.graphql_type_name);
         let fallback_selection = match &self.fallback {
             Some(Fallback::InterfaceVariant(_, fallback_fragment)) => quote! {
@@ -208,6 +249,12 @@ impl quote::ToTokens for QueryFragmentImpl<'_> {
             },
             _ => quote! {},
         };
+        let fallback_uses_field_results = match &self.fallback {
+            Some(Fallback::InterfaceVariant(_, fallback_fragment)) => quote! {
+                || <#fallback_fragment as cynic::QueryFragment>::USES_FIELD_RESULTS
+            },
+            _ => quote! {},
+        };
 
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
@@ -218,11 +265,15 @@ impl quote::ToTokens for QueryFragmentImpl<'_> {
                 type VariablesFields = #variables_fields;
 
                 const TYPE: Option<&'static str> = Some(#graphql_type);
+                const USES_FIELD_RESULTS: bool = false
+                    #(|| <#inner_types as cynic::QueryFragment>::USES_FIELD_RESULTS)*
+                    #fallback_uses_field_results;
 
                 fn query(mut builder: cynic::queries::SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
                     #(
                         let fragment_builder = builder.inline_fragment();
                         let mut fragment_builder = fragment_builder.on::<<#inner_types as cynic::QueryFragment>::SchemaType>();
+                        #(#directives)*
                         <#inner_types as cynic::QueryFragment>::query(
                             fragment_builder.select_children()
                         );
@@ -9,6 +9,7 @@ use {
 use {
     super::InputObjectDeriveField,
     crate::{
+        marker_references::deprecation_warning,
         schema::types::{InputType, InputValue},
         types::{self, align_input_type, check_input_types_are_compatible},
     },
@@ -51,6 +52,24 @@ impl<'a> FieldSerializer<'a> {
         None
     }
 
+    /// Outputs a deprecation warning if the field is deprecated in the schema
+    /// and the rust field doesn't opt out of that.
+    pub fn deprecation_warning(&self, field_module: &syn::Path) -> Option<TokenStream> {
+        if self.graphql_field.deprecated.is_none() || self.rust_field.allow_deprecated {
+            return None;
+        }
+
+        let marker = self.graphql_field.marker_ident().to_path(field_module);
+        let span = self
+            .rust_field
+            .ident
+            .as_ref()
+            .map(|ident| ident.span())
+            .unwrap_or_else(|| self.rust_field.ty.span());
+
+        Some(deprecation_warning(&marker, span))
+    }
+
     pub fn type_check(
         &self,
         impl_generics: &syn::ImplGenerics<'_>,
@@ -43,6 +43,9 @@ pub struct InputObjectDeriveField {
 
     #[darling(default)]
     pub(super) rename: Option<SpannedValue<String>>,
+
+    #[darling(default)]
+    pub(super) allow_deprecated: bool,
 }
 
 impl InputObjectDeriveInput {
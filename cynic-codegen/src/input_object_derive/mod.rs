@@ -60,6 +60,7 @@ pub fn input_object_derive_impl(
         let input_marker_ident = input_object.marker_ident().to_rust_ident();
         let schema_module = input.schema_module();
         let graphql_type_name = proc_macro2::Literal::string(input_object.name.as_ref());
+        let field_module = input_object.field_module().to_path(&schema_module);
 
         let pairs = pair_fields(
             &fields.fields,
@@ -95,6 +96,10 @@ pub fn input_object_derive_impl(
 
         let map_len = field_serializers.len();
 
+        let deprecations = field_serializers
+            .iter()
+            .filter_map(|fs| fs.deprecation_warning(&field_module));
+
         Ok(quote! {
             #[automatically_derived]
             impl #impl_generics cynic::InputObject for #ident #ty_generics #where_clause_with_ser {
@@ -124,6 +129,8 @@ pub fn input_object_derive_impl(
             impl #impl_generics #schema_module::variable::Variable for #ident #ty_generics #where_clause {
                 const TYPE: cynic::variables::VariableType = cynic::variables::VariableType::Named(#graphql_type_name);
             }
+
+            #(#deprecations)*
         })
     } else {
         Err(syn::Error::new(
@@ -245,6 +252,7 @@ mod test {
             ty: syn::parse_quote! { String },
             rename: None,
             skip_serializing_if: None,
+            allow_deprecated: false,
         }];
 
         let result = pair_fields(
@@ -268,6 +276,7 @@ mod test {
             ty: syn::parse_quote! { String },
             rename: None,
             skip_serializing_if: None,
+            allow_deprecated: false,
         }];
 
         let result = pair_fields(
@@ -291,6 +300,7 @@ mod test {
             ty: syn::parse_quote! { String },
             rename: None,
             skip_serializing_if: None,
+            allow_deprecated: false,
         }];
 
         let result = pair_fields(
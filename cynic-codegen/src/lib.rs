@@ -4,6 +4,10 @@ pub mod fragment_derive;
 pub mod generics_for_serde;
 pub mod inline_fragments_derive;
 pub mod input_object_derive;
+#[cfg(feature = "query-document")]
+pub mod operation_manifest;
+#[cfg(feature = "query-document")]
+pub mod query_document;
 pub mod query_variables_derive;
 pub mod registration;
 pub mod scalar_derive;
@@ -13,12 +17,17 @@ pub mod use_schema;
 
 mod error;
 mod idents;
+mod marker_references;
 mod schema;
+mod schema_docs;
 mod suggestions;
 mod types;
 
 pub use self::{idents::RenameAll, registration::register_schema};
 
+#[cfg(feature = "query-document")]
+pub use self::operation_manifest::operation_manifest;
+
 use error::Errors;
 
 #[deprecated(
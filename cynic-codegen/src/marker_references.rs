@@ -0,0 +1,51 @@
+//! References to schema markers, spanned to the rust items that use them.
+
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{quote_spanned, ToTokens};
+
+/// Outputs a reference to the deprecated schema marker at `marker_path`, so
+/// that rustc emits a deprecation warning for it.
+///
+/// rustc doesn't emit deprecation warnings for code inside derive expansions,
+/// so the reference is spanned to `span` - usually the rust field or variant
+/// that selects the marker.  This also makes the warning point there.
+pub fn deprecation_warning(marker_path: &syn::Path, span: Span) -> TokenStream {
+    marker_reference(marker_path, span)
+}
+
+/// Outputs a reference to the schema marker at `marker_path`, spanned to the
+/// rust field or variant at `span`.
+///
+/// IDEs resolve hovers on that field or variant through this reference, which
+/// shows the docs `use_schema` put on the marker.
+pub fn doc_reference(marker_path: &syn::Path, span: Span) -> TokenStream {
+    let reference = marker_reference(marker_path, span);
+
+    quote_spanned! { span =>
+        #[allow(deprecated)]
+        #reference
+    }
+}
+
+fn marker_reference(marker_path: &syn::Path, span: Span) -> TokenStream {
+    let marker_path = respan(marker_path.to_token_stream(), span);
+
+    quote_spanned! { span =>
+        const _: Option<#marker_path> = None;
+    }
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut new_group = Group::new(group.delimiter(), respan(group.stream(), span));
+                new_group.set_span(span);
+                token = TokenTree::Group(new_group);
+            }
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
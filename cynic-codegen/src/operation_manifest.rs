@@ -0,0 +1,413 @@
+//! Build script helpers for generating persisted operation manifests from
+//! GraphQL documents.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use graphql_parser::query::{
+    Definition, Document, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+};
+use sha2::{Digest, Sha256};
+
+/// Creates an empty manifest of persisted operations.
+///
+/// Operations are extracted from GraphQL documents at build time, so the
+/// manifest can be uploaded to a server or gateway by CI.  Each operation is
+/// assigned an id, which `query_document!` outputs as a `DOCUMENT_ID`
+/// constant on the operations struct.  The operation can then be sent by id
+/// alone with `Operation::persisted_document`.
+///
+/// This is designed to be called from `build.rs`:
+///
+/// ```rust,no_run
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+///
+/// cynic_codegen::operation_manifest()
+///     .add_document_file("queries/film-director.graphql")
+///     .unwrap()
+///     .write_apollo(format!("{out_dir}/persisted-operations.json"))
+///     .unwrap();
+/// ```
+pub fn operation_manifest() -> OperationManifest {
+    OperationManifest::default()
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum OperationManifestError {
+    #[error("IOError: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Could not parse {0}: {1}")]
+    ParseError(String, String),
+}
+
+/// A manifest of persisted operations.
+///
+/// Created by [`operation_manifest`]
+#[derive(Debug, Clone, Default)]
+pub struct OperationManifest {
+    operations: BTreeMap<String, ManifestOperation>,
+}
+
+/// An operation in an [`OperationManifest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestOperation {
+    /// The id of the operation - the SHA-256 hash of its document
+    pub id: String,
+
+    /// The name of the operation, if it has one
+    pub name: Option<String>,
+
+    /// The kind of operation
+    pub kind: OperationKind,
+
+    /// The GraphQL document for the operation, including any fragments it
+    /// uses
+    pub document: String,
+}
+
+/// The kind of an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+impl OperationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationKind::Query => "query",
+            OperationKind::Mutation => "mutation",
+            OperationKind::Subscription => "subscription",
+        }
+    }
+}
+
+impl OperationManifest {
+    /// Adds the operations in the GraphQL document at `path` to the manifest
+    pub fn add_document_file(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<&mut Self, OperationManifestError> {
+        let path = path.as_ref();
+        let document = std::fs::read_to_string(path)?;
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        self.add_operations(&document, &path.display().to_string())
+    }
+
+    /// Adds the operations in a GraphQL document to the manifest
+    pub fn add_document(&mut self, document: &str) -> Result<&mut Self, OperationManifestError> {
+        self.add_operations(document, "document")
+    }
+
+    fn add_operations(
+        &mut self,
+        document: &str,
+        document_name: &str,
+    ) -> Result<&mut Self, OperationManifestError> {
+        let operations = extract_operations(document).map_err(|error| {
+            OperationManifestError::ParseError(document_name.to_string(), error)
+        })?;
+
+        for operation in operations {
+            self.operations.insert(operation.id.clone(), operation);
+        }
+
+        Ok(self)
+    }
+
+    /// The operations in this manifest, ordered by id
+    pub fn operations(&self) -> impl Iterator<Item = &ManifestOperation> + '_ {
+        self.operations.values()
+    }
+
+    /// Serializes the manifest in the format used by Apollo's persisted
+    /// query lists.
+    pub fn apollo_json(&self) -> String {
+        #[derive(serde::Serialize)]
+        struct Manifest<'a> {
+            format: &'static str,
+            version: u32,
+            operations: Vec<Operation<'a>>,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Operation<'a> {
+            id: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<&'a str>,
+            #[serde(rename = "type")]
+            kind: &'static str,
+            body: &'a str,
+        }
+
+        let manifest = Manifest {
+            format: "apollo-persisted-query-manifest",
+            version: 1,
+            operations: self
+                .operations()
+                .map(|operation| Operation {
+                    id: &operation.id,
+                    name: operation.name.as_deref(),
+                    kind: operation.kind.as_str(),
+                    body: &operation.document,
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&manifest).expect("manifests to serialize")
+    }
+
+    /// Serializes the manifest as a Relay style JSON object of
+    /// `id -> document`.
+    pub fn relay_json(&self) -> String {
+        let manifest = self
+            .operations()
+            .map(|operation| (&operation.id, &operation.document))
+            .collect::<BTreeMap<_, _>>();
+
+        serde_json::to_string_pretty(&manifest).expect("manifests to serialize")
+    }
+
+    /// Writes the manifest to `path` in Apollo's persisted query list format
+    pub fn write_apollo(&self, path: impl AsRef<Path>) -> Result<(), OperationManifestError> {
+        std::fs::write(path, self.apollo_json())?;
+        Ok(())
+    }
+
+    /// Writes the manifest to `path` in Relay's `id -> document` format
+    pub fn write_relay(&self, path: impl AsRef<Path>) -> Result<(), OperationManifestError> {
+        std::fs::write(path, self.relay_json())?;
+        Ok(())
+    }
+}
+
+/// Extracts each operation in `document`, along with the fragments it uses.
+pub(crate) fn extract_operations(document: &str) -> Result<Vec<ManifestOperation>, String> {
+    let document =
+        graphql_parser::parse_query::<&str>(document).map_err(|error| error.to_string())?;
+
+    let fragments = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Fragment(fragment) => Some((fragment.name, fragment)),
+            Definition::Operation(_) => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Operation(operation) => Some(operation),
+            Definition::Fragment(_) => None,
+        })
+        .map(|operation| {
+            let (kind, name, selection_set) = match operation {
+                OperationDefinition::SelectionSet(selection_set) => {
+                    (OperationKind::Query, None, selection_set)
+                }
+                OperationDefinition::Query(query) => {
+                    (OperationKind::Query, query.name, &query.selection_set)
+                }
+                OperationDefinition::Mutation(mutation) => (
+                    OperationKind::Mutation,
+                    mutation.name,
+                    &mutation.selection_set,
+                ),
+                OperationDefinition::Subscription(subscription) => (
+                    OperationKind::Subscription,
+                    subscription.name,
+                    &subscription.selection_set,
+                ),
+            };
+
+            let mut used_fragments = BTreeSet::new();
+            collect_fragments(selection_set, &fragments, &mut used_fragments)?;
+
+            // Fragments are output in the order they appear in the document
+            let definitions = std::iter::once(Definition::Operation(operation.clone()))
+                .chain(
+                    document
+                        .definitions
+                        .iter()
+                        .filter_map(|definition| match definition {
+                            Definition::Fragment(fragment)
+                                if used_fragments.contains(fragment.name) =>
+                            {
+                                Some(Definition::Fragment(fragment.clone()))
+                            }
+                            _ => None,
+                        }),
+                )
+                .collect();
+
+            let document = Document { definitions }.to_string();
+
+            Ok(ManifestOperation {
+                id: format!("{:x}", Sha256::digest(document.as_bytes())),
+                name: name.map(Into::into),
+                kind,
+                document,
+            })
+        })
+        .collect()
+}
+
+fn collect_fragments<'a>(
+    selection_set: &SelectionSet<'a, &'a str>,
+    fragments: &BTreeMap<&'a str, &FragmentDefinition<'a, &'a str>>,
+    used_fragments: &mut BTreeSet<&'a str>,
+) -> Result<(), String> {
+    for selection in &selection_set.items {
+        match selection {
+            Selection::Field(field) => {
+                collect_fragments(&field.selection_set, fragments, used_fragments)?
+            }
+            Selection::InlineFragment(fragment) => {
+                collect_fragments(&fragment.selection_set, fragments, used_fragments)?
+            }
+            Selection::FragmentSpread(spread) => {
+                let fragment = fragments
+                    .get(spread.fragment_name)
+                    .ok_or_else(|| format!("unknown fragment: {}", spread.fragment_name))?;
+
+                if used_fragments.insert(spread.fragment_name) {
+                    collect_fragments(&fragment.selection_set, fragments, used_fragments)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DOCUMENT: &str = r#"
+        query AllFilms {
+          allFilms {
+            films {
+              ...FilmFields
+            }
+          }
+        }
+
+        fragment FilmFields on Film {
+          title
+        }
+
+        fragment PlanetFields on Planet {
+          name
+        }
+
+        mutation querySomething($id: ID!) {
+          signIn(id: $id)
+        }
+
+        subscription {
+          filmAdded {
+            ...FilmFields
+          }
+        }
+    "#;
+
+    #[test]
+    fn test_kinds_come_from_the_parser() {
+        let operations = extract_operations(DOCUMENT).unwrap();
+
+        let kinds = operations
+            .iter()
+            .map(|operation| (operation.name.as_deref(), operation.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            [
+                (Some("AllFilms"), OperationKind::Query),
+                (Some("querySomething"), OperationKind::Mutation),
+                (None, OperationKind::Subscription),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operations_include_the_fragments_they_use() {
+        let operations = extract_operations(DOCUMENT).unwrap();
+
+        insta::assert_snapshot!(operations[0].document, @r###"
+        query AllFilms {
+          allFilms {
+            films {
+              ...FilmFields
+            }
+          }
+        }
+
+        fragment FilmFields on Film {
+          title
+        }
+        "###);
+        assert!(!operations[1].document.contains("fragment"));
+    }
+
+    #[test]
+    fn test_ids_are_hashes_of_the_documents() {
+        let operations = extract_operations("{ __typename }").unwrap();
+
+        assert_eq!(operations[0].document, "{\n  __typename\n}\n");
+        assert_eq!(
+            operations[0].id,
+            "8d8f7365e9e86fa8e3313fcaf2131b801eafe9549de22373089cf27511858b39"
+        );
+    }
+
+    #[test]
+    fn test_unknown_fragments_are_errors() {
+        let error = extract_operations("query { ...Missing }").unwrap_err();
+
+        assert_eq!(error, "unknown fragment: Missing");
+    }
+
+    #[test]
+    fn test_apollo_manifest() {
+        let mut manifest = operation_manifest();
+        manifest
+            .add_document("query AllFilms { allFilms { totalCount } }")
+            .unwrap();
+
+        insta::assert_snapshot!(manifest.apollo_json(), @r###"
+        {
+          "format": "apollo-persisted-query-manifest",
+          "version": 1,
+          "operations": [
+            {
+              "id": "1e31f51606e825c086d81e06e466e1003153fbf712957e092035a09349b88767",
+              "name": "AllFilms",
+              "type": "query",
+              "body": "query AllFilms {\n  allFilms {\n    totalCount\n  }\n}\n"
+            }
+          ]
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_relay_manifest() {
+        let mut manifest = operation_manifest();
+        manifest
+            .add_document("query AllFilms { allFilms { totalCount } }")
+            .unwrap();
+
+        insta::assert_snapshot!(manifest.relay_json(), @r###"
+        {
+          "1e31f51606e825c086d81e06e466e1003153fbf712957e092035a09349b88767": "query AllFilms {\n  allFilms {\n    totalCount\n  }\n}\n"
+        }
+        "###);
+    }
+}
@@ -0,0 +1,296 @@
+//! The `query_document` macro, which runs cynic-querygen at compile time to
+//! generate the structs for a GraphQL document.
+
+mod params;
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use {proc_macro2::TokenStream, quote::quote};
+
+use cynic_querygen::{document_to_fragment_structs, QueryGenOptions};
+
+use crate::{operation_manifest::extract_operations, schema::registered_schema_sdl};
+
+pub use params::QueryDocumentParams;
+
+pub fn query_document(params: QueryDocumentParams) -> Result<TokenStream, syn::Error> {
+    let span = params.document_path.span();
+    let document_name = params.document_path.value();
+    let document_path = manifest_relative(&document_name);
+    let document = std::fs::read_to_string(&document_path).map_err(|error| {
+        syn::Error::new(span, format!("Could not read {document_name}: {error}"))
+    })?;
+
+    let schema_name = params.schema.as_ref().map(syn::LitStr::value);
+    let (schema_path, schema) = registered_schema_sdl(schema_name.as_deref()).map_err(|error| {
+        let schema_span = params.schema.as_ref().map(|schema| schema.span());
+        error.into_syn_error(schema_span.unwrap_or(span))
+    })?;
+
+    let options = QueryGenOptions {
+        schema_module_name: params
+            .schema_module
+            .as_ref()
+            .map(syn::LitStr::value)
+            .unwrap_or_else(|| "schema".into()),
+        schema_name,
+    };
+
+    let structs =
+        generate_structs(&document, &document_name, &schema, &options).map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|message| syn::Error::new(span, message))
+                .reduce(|mut combined, error| {
+                    combined.combine(error);
+                    combined
+                })
+                .expect("generate_structs to return at least one error")
+        })?;
+
+    // Including the files makes cargo rebuild whenever they change
+    let document_path = document_path.to_string_lossy();
+    let schema_path = schema_path.to_string_lossy();
+
+    Ok(quote! {
+        #structs
+
+        const _: &str = include_str!(#document_path);
+        const _: &str = include_str!(#schema_path);
+    })
+}
+
+/// Validates `document` against `schema` and runs querygen on it, returning
+/// the generated structs or a list of error messages.
+fn generate_structs(
+    document: &str,
+    document_name: &str,
+    schema: &str,
+    options: &QueryGenOptions,
+) -> Result<TokenStream, Vec<String>> {
+    validate(document, document_name, schema)?;
+
+    let structs = document_to_fragment_structs(document, schema, options)
+        .map_err(|error| vec![format!("{document_name}: {error}")])?;
+
+    let structs = structs
+        .parse::<TokenStream>()
+        .map_err(|error| vec![format!("{document_name}: generated invalid rust: {error}")])?;
+
+    let document_ids = document_ids(document, &structs)
+        .map_err(|error| vec![format!("{document_name}: {error}")])?;
+
+    Ok(quote! {
+        #structs
+        #document_ids
+    })
+}
+
+/// Outputs a `DOCUMENT_ID` constant for each named operation in the document,
+/// matching the ids in any manifest generated by `operation_manifest`.
+fn document_ids(document: &str, structs: &TokenStream) -> Result<TokenStream, String> {
+    let file = syn::parse2::<syn::File>(structs.clone()).map_err(|error| error.to_string())?;
+    let struct_names = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(item) => Some(item.ident.to_string()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let impls = extract_operations(document)?
+        .into_iter()
+        .filter_map(|operation| {
+            // querygen names the struct for an operation after the operation.
+            // Unnamed operations don't have a predictable name so are skipped.
+            let name = operation.name.filter(|name| struct_names.contains(name))?;
+            let ident = proc_macro2::Ident::new(&name, proc_macro2::Span::call_site());
+            let id = operation.id;
+
+            Some(quote! {
+                impl #ident {
+                    /// The id of this operation in a persisted operation manifest
+                    pub const DOCUMENT_ID: &'static str = #id;
+                }
+            })
+        });
+
+    Ok(quote! { #(#impls)* })
+}
+
+/// Validates the document with cynic-parser, which tracks the location of
+/// each error.
+///
+/// querygen does some validation of its own, but can't tell us where in the
+/// document any problems are.
+fn validate(document: &str, document_name: &str, schema: &str) -> Result<(), Vec<String>> {
+    let executable = cynic_parser::parse_executable_document(document).map_err(|error| {
+        vec![located_message(
+            document,
+            document_name,
+            error.span(),
+            &error.to_string(),
+        )]
+    })?;
+
+    let Ok(schema) = cynic_parser::parse_type_system_document(schema) else {
+        // The schema was already validated when it was registered, so any
+        // failure here is a limitation of cynic-parser.  querygen will still
+        // check the document, so we skip this step.
+        return Ok(());
+    };
+
+    cynic_parser::validation::validate(&schema, &executable).map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| located_message(document, document_name, error.span(), &error.to_string()))
+            .collect()
+    })
+}
+
+/// Formats an error message that points at `span` in a GraphQL document.
+///
+/// Proc macros can only put spans in rust files, so we render the location &
+/// the offending line into the message ourselves.
+fn located_message(
+    document: &str,
+    document_name: &str,
+    span: cynic_parser::Span,
+    message: &str,
+) -> String {
+    let start = span.start.min(document.len());
+    let end = span.end.clamp(start, document.len());
+
+    let line_start = document[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = document[start..]
+        .find('\n')
+        .map(|i| start + i)
+        .unwrap_or(document.len());
+    let line_number = document[..start].matches('\n').count() + 1;
+    let line = document[line_start..line_end].trim_end();
+
+    let column = document[line_start..start].chars().count() + 1;
+    let width = document[start..end.min(line_end)].chars().count().max(1);
+
+    let gutter = " ".repeat(line_number.to_string().len());
+    let padding = " ".repeat(column - 1);
+    let underline = "^".repeat(width);
+
+    format!(
+        "{message}\n\
+         {gutter}--> {document_name}:{line_number}:{column}\n\
+         {gutter} |\n\
+         {line_number} | {line}\n\
+         {gutter} | {padding}{underline}"
+    )
+}
+
+fn manifest_relative(path: impl AsRef<Path>) -> PathBuf {
+    let mut pathbuf = PathBuf::new();
+
+    if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+        pathbuf.push(manifest_dir);
+    }
+    pathbuf.push(path);
+
+    pathbuf
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use super::*;
+
+    static SCHEMA: &str = include_str!("../../../schemas/starwars.schema.graphql");
+
+    fn options() -> QueryGenOptions {
+        QueryGenOptions {
+            schema_module_name: "schema".into(),
+            schema_name: Some("starwars".into()),
+        }
+    }
+
+    #[test]
+    fn test_generating_structs() {
+        let document = r#"
+            query AllFilms {
+              allFilms {
+                films {
+                  title
+                }
+              }
+            }
+        "#;
+
+        let structs = generate_structs(document, "films.graphql", SCHEMA, &options()).unwrap();
+
+        let file = syn::parse2::<syn::File>(structs).unwrap();
+        let names = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Struct(item) => Some(item.ident.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["AllFilms", "FilmsConnection", "Film"]);
+    }
+
+    #[test]
+    fn test_document_ids_match_the_manifest() {
+        let document = "query AllFilms { allFilms { totalCount } }";
+
+        let structs = generate_structs(document, "films.graphql", SCHEMA, &options()).unwrap();
+        let id = &extract_operations(document).unwrap()[0].id;
+
+        let file = syn::parse2::<syn::File>(structs).unwrap();
+        let document_id = file
+            .items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Impl(item) => item.items.iter().find_map(|item| match item {
+                    syn::ImplItem::Const(item) => Some(item.expr.to_token_stream().to_string()),
+                    _ => None,
+                }),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(document_id, format!("{id:?}"));
+    }
+
+    #[test]
+    fn test_validation_errors_point_into_the_document() {
+        let document = "query AllFilms {\n  allFilms {\n    flims {\n      title\n    }\n  }\n}\n";
+
+        let errors = generate_structs(document, "films.graphql", SCHEMA, &options()).unwrap_err();
+
+        insta::assert_snapshot!(errors.join("\n\n"), @r###"
+        no field named flims on FilmsConnection
+         --> films.graphql:3:5
+          |
+        3 |     flims {
+          |     ^^^^^
+        "###);
+    }
+
+    #[test]
+    fn test_parse_errors_point_into_the_document() {
+        let document = "query AllFilms {\n  allFilms(first: ) {\n    totalCount\n  }\n}\n";
+
+        let errors = generate_structs(document, "films.graphql", SCHEMA, &options()).unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].contains(" --> films.graphql:2:19\n"),
+            "{}",
+            errors[0]
+        );
+    }
+}
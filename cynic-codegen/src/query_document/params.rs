@@ -0,0 +1,74 @@
+use syn::{LitStr, Token};
+
+#[derive(Debug)]
+pub struct QueryDocumentParams {
+    pub document_path: LitStr,
+    pub schema: Option<LitStr>,
+    pub schema_module: Option<LitStr>,
+}
+
+impl syn::parse::Parse for QueryDocumentParams {
+    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        let mut params = QueryDocumentParams {
+            document_path: input.parse()?,
+            schema: None,
+            schema_module: None,
+        };
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<syn::Ident>()?;
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+
+            let slot = match key.to_string().as_str() {
+                "schema" => &mut params.schema,
+                "schema_module" => &mut params.schema_module,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "unknown argument: expected one of schema or schema_module",
+                    ))
+                }
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("{key} was provided more than once"),
+                ));
+            }
+            *slot = Some(value);
+        }
+
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsing_params() {
+        let params: QueryDocumentParams =
+            syn::parse_quote! { "queries/user.graphql", schema = "github", };
+
+        assert_eq!(params.document_path.value(), "queries/user.graphql");
+        assert_eq!(params.schema.unwrap().value(), "github");
+        assert!(params.schema_module.is_none());
+    }
+
+    #[test]
+    fn test_unknown_params_are_rejected() {
+        let result = syn::parse_str::<QueryDocumentParams>(r#""user.graphql", schema_path = "x""#);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unknown argument: expected one of schema or schema_module"
+        );
+    }
+}
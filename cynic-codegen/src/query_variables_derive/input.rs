@@ -31,6 +31,9 @@ pub(super) struct QueryVariableField {
 
     #[darling(default)]
     pub(super) rename: Option<SpannedValue<String>>,
+
+    #[darling(default)]
+    pub(super) flatten: SpannedValue<bool>,
 }
 
 impl QueryVariablesDeriveInput {
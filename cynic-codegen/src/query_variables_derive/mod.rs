@@ -8,7 +8,7 @@ mod input;
 
 use crate::{generics_for_serde, variables_fields_ident};
 
-use self::input::QueryVariablesDeriveInput;
+use self::input::{QueryVariableField, QueryVariablesDeriveInput};
 
 pub fn query_variables_derive(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
     use darling::FromDeriveInput;
@@ -39,10 +39,31 @@ pub fn query_variables_derive_impl(
     let mut field_inserts = Vec::new();
     let mut coercion_checks = Vec::new();
     let mut field_output_types = Vec::new();
+    let mut variable_parts = Vec::new();
+    let mut flattened_types = Vec::new();
 
     for (field_idx, f) in input_fields.into_iter().enumerate() {
         let name = f.ident.as_ref().unwrap();
         let ty = &f.ty;
+
+        if *f.flatten {
+            if let Some(error) = flatten_error(&f, &input.generics) {
+                return Err(error);
+            }
+
+            variable_parts.push(quote! {
+                <#ty as cynic::QueryVariables>::VARIABLES
+            });
+            field_inserts.push(quote! {
+                cynic::serde::Serialize::serialize(
+                    &self.#name,
+                    cynic::__private::query_variables::FlattenSerializer::new(&mut map_serializer),
+                )?;
+            });
+            flattened_types.push(ty.clone());
+            continue;
+        }
+
         let mut ty_for_fields_struct = match f.graphql_type {
             None => ty.clone(),
             Some(ref graphql_type) => {
@@ -86,14 +107,16 @@ pub fn query_variables_derive_impl(
             proc_macro2::Literal::string(&f.graphql_ident(input.rename_all).graphql_name());
 
         field_funcs.push(quote! {
-            #vis fn #name() -> cynic::variables::VariableDefinition<Self, #ty_for_fields_struct> {
+            #vis const fn #name() -> cynic::variables::VariableDefinition<Self, #ty_for_fields_struct> {
                 cynic::variables::VariableDefinition::new(#name_str)
             }
         });
 
-        variables.push(quote! {
+        let variable = quote! {
             (#name_str, <#ty as #schema_module::variable::Variable>::TYPE)
-        });
+        };
+        variable_parts.push(quote! { &[#variable] });
+        variables.push(variable);
 
         match f.skip_serializing_if {
             Some(skip_check_fn) => {
@@ -110,7 +133,29 @@ pub fn query_variables_derive_impl(
         }
     }
 
-    let map_len = field_inserts.len();
+    let map_len = if flattened_types.is_empty() {
+        let map_len = field_inserts.len();
+        quote! { Some(#map_len) }
+    } else {
+        quote! { None }
+    };
+
+    let variables = if flattened_types.is_empty() {
+        quote! { &[#(#variables),*] }
+    } else {
+        // The variables of flattened fields need to be concatenated with our
+        // own, which has to be done in a const
+        quote! {
+            {
+                const PARTS: &[&[(&str, cynic::variables::VariableType)]] =
+                    &[#(#variable_parts),*];
+                const LEN: usize = cynic::__private::query_variables::variables_len(PARTS);
+                const VARIABLES: [(&str, cynic::variables::VariableType); LEN] =
+                    cynic::__private::query_variables::concat_variables::<LEN>(PARTS);
+                &VARIABLES
+            }
+        }
+    };
 
     let ident_span = ident.span();
     let fields_struct = quote_spanned! { ident_span =>
@@ -120,6 +165,11 @@ pub fn query_variables_derive_impl(
 
         impl cynic::queries::VariableMatch<#fields_struct_ident> for #fields_struct_ident {}
 
+        #(
+            impl cynic::queries::VariableMatch<<#flattened_types as cynic::QueryVariables>::Fields>
+                for #fields_struct_ident {}
+        )*
+
         const _: () = {
             #(
                 #field_output_types
@@ -139,7 +189,7 @@ pub fn query_variables_derive_impl(
         impl #impl_generics cynic::QueryVariables for #ident #ty_generics #where_clause {
             type Fields = #fields_struct_ident;
             const VARIABLES: &'static [(&'static str, cynic::variables::VariableType)]
-                = &[#(#variables),*];
+                = #variables;
         }
 
         #[automatically_derived]
@@ -151,7 +201,7 @@ pub fn query_variables_derive_impl(
                 use cynic::serde::ser::SerializeMap;
                 #(#coercion_checks)*
 
-                let mut map_serializer = serializer.serialize_map(Some(#map_len))?;
+                let mut map_serializer = serializer.serialize_map(#map_len)?;
 
                 #(#field_inserts)*
 
@@ -163,6 +213,29 @@ pub fn query_variables_derive_impl(
     })
 }
 
+fn flatten_error(field: &QueryVariableField, generics: &syn::Generics) -> Option<syn::Error> {
+    let span = field.flatten.span();
+    if field.graphql_type.is_some() || field.skip_serializing_if.is_some() {
+        return Some(syn::Error::new(
+            span,
+            "flatten can't be used alongside graphql_type or skip_serializing_if",
+        ));
+    }
+    if field.rename.is_some() {
+        return Some(syn::Error::new(
+            span,
+            "flatten can't be used alongside rename",
+        ));
+    }
+    if !generics.params.is_empty() {
+        return Some(syn::Error::new(
+            span,
+            "flatten isn't supported on generic QueryVariables",
+        ));
+    }
+    None
+}
+
 struct TurnLifetimesToStatic;
 impl VisitMut for TurnLifetimesToStatic {
     fn visit_lifetime_mut(&mut self, i: &mut syn::Lifetime) {
@@ -129,6 +129,11 @@ impl SchemaRegistration<'_> {
         std::fs::create_dir_all(filename.parent().expect("filename to have a parent"))?;
         #[cfg(feature = "rkyv")]
         {
+            // The SDL is still needed by the query_document macro, so we write
+            // it out alongside the archive.
+            filename.set_extension("graphql");
+            std::fs::write(&filename, self.data.as_bytes())?;
+
             filename.set_extension("rkyv");
 
             let optimised = self.schema()?.optimise();
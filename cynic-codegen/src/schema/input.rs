@@ -12,30 +12,16 @@ pub enum SchemaInput {
 impl SchemaInput {
     /// Parses a SchemaInput from a filename passed to a macro
     pub(crate) fn default() -> Result<SchemaInput, SchemaLoadError> {
-        Self::from_schema_name("default").map_err(|error| match error {
-            SchemaLoadError::NamedSchemaNotFound(_) => SchemaLoadError::DefaultSchemaNotFound,
-            SchemaLoadError::UnknownOutDirWithNamedSchema(_) => {
-                SchemaLoadError::UnknownOutDirWithDefaultSchema
-            }
-            _ => error,
-        })
+        Self::from_schema_name("default").map_err(default_schema_error)
     }
 
     /// Parses a SchemaInput from a name passed to a macro
     pub(crate) fn from_schema_name(name: &str) -> Result<SchemaInput, SchemaLoadError> {
-        let out_dir = std::env::var("OUT_DIR")
-            .map_err(|_| SchemaLoadError::UnknownOutDirWithNamedSchema(name.to_string()))?;
-
-        let mut path = std::path::PathBuf::from(out_dir);
-        path.push("cynic-schemas");
         #[cfg(feature = "rkyv")]
         let extension = "rkyv";
         #[cfg(not(feature = "rkyv"))]
         let extension = "graphql";
-        path.push(format!("{name}.{extension}"));
-        if !path.exists() {
-            return Err(SchemaLoadError::NamedSchemaNotFound(name.to_string()));
-        }
+        let path = registered_schema_path(name, extension)?;
 
         #[cfg(feature = "rkyv")]
         {
@@ -76,6 +62,50 @@ impl SchemaInput {
     }
 }
 
+/// Loads the SDL of a schema registered in build.rs, or the default schema if
+/// `name` is `None`.
+///
+/// Returns the path the SDL was loaded from along with the SDL itself.
+#[cfg(feature = "query-document")]
+pub(crate) fn registered_schema_sdl(
+    name: Option<&str>,
+) -> Result<(std::path::PathBuf, String), SchemaLoadError> {
+    let path = match name {
+        Some(name) => registered_schema_path(name, "graphql")?,
+        None => registered_schema_path("default", "graphql").map_err(default_schema_error)?,
+    };
+    let sdl = std::fs::read_to_string(&path)?;
+
+    Ok((path, sdl))
+}
+
+fn registered_schema_path(
+    name: &str,
+    extension: &str,
+) -> Result<std::path::PathBuf, SchemaLoadError> {
+    let out_dir = std::env::var("OUT_DIR")
+        .map_err(|_| SchemaLoadError::UnknownOutDirWithNamedSchema(name.to_string()))?;
+
+    let mut path = std::path::PathBuf::from(out_dir);
+    path.push("cynic-schemas");
+    path.push(format!("{name}.{extension}"));
+    if !path.exists() {
+        return Err(SchemaLoadError::NamedSchemaNotFound(name.to_string()));
+    }
+
+    Ok(path)
+}
+
+fn default_schema_error(error: SchemaLoadError) -> SchemaLoadError {
+    match error {
+        SchemaLoadError::NamedSchemaNotFound(_) => SchemaLoadError::DefaultSchemaNotFound,
+        SchemaLoadError::UnknownOutDirWithNamedSchema(_) => {
+            SchemaLoadError::UnknownOutDirWithDefaultSchema
+        }
+        _ => error,
+    }
+}
+
 fn document_from_path(
     filename: impl AsRef<std::path::Path>,
 ) -> Result<Option<Document>, SchemaLoadError> {
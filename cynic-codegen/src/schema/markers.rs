@@ -227,6 +227,28 @@ impl<'a> InterfaceType<'a> {
     }
 }
 
+impl<'a> EnumValue<'a> {
+    /// Whether `use_schema` outputs a marker for this value.  Only values that
+    /// are deprecated or have a description need one.
+    pub fn has_marker(&self) -> bool {
+        self.deprecated.is_some() || self.description.is_some()
+    }
+
+    pub fn marker_ident(&'a self) -> FieldMarkerIdent<'a> {
+        FieldMarkerIdent {
+            graphql_name: self.name.as_str(),
+        }
+    }
+}
+
+impl<'a> EnumType<'a> {
+    pub fn field_module(&self) -> FieldMarkerModule<'a> {
+        FieldMarkerModule {
+            type_name: self.name.clone(),
+        }
+    }
+}
+
 impl<'a> InputObjectType<'a> {
     pub fn field_module(&'a self) -> FieldMarkerModule<'a> {
         FieldMarkerModule {
@@ -244,6 +266,37 @@ impl<'a> Field<'a> {
     }
 }
 
+impl<'a> Directive<'a> {
+    pub fn marker_ident(&self) -> proc_macro2::Ident {
+        format_ident!("{}", transform_keywords(self.name.as_ref()))
+    }
+
+    pub fn to_path(&self, schema_module_path: &syn::Path) -> syn::Path {
+        let mut path = directives_module_path(schema_module_path);
+        path.push(self.marker_ident());
+        path
+    }
+
+    pub fn argument_module_ident(&self) -> proc_macro2::Ident {
+        format_ident!("_{}_arguments", to_snake_case(self.name.as_ref()))
+    }
+
+    pub fn argument_module_path(&self, schema_module_path: &syn::Path) -> syn::Path {
+        let mut path = directives_module_path(schema_module_path);
+        path.push(self.argument_module_ident());
+        path
+    }
+}
+
+fn directives_module_path(schema_module_path: &syn::Path) -> syn::Path {
+    let mut path = schema_module_path.clone();
+    path.push(proc_macro2::Ident::new(
+        "__directives",
+        schema_module_path.span(),
+    ));
+    path
+}
+
 impl<'a> ObjectRef<'a> {
     pub fn marker_ident(&self) -> TypeMarkerIdent<'a> {
         TypeMarkerIdent {
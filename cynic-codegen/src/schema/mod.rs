@@ -11,6 +11,8 @@ pub mod markers;
 use std::convert::Infallible;
 use std::marker::PhantomData;
 
+#[cfg(feature = "query-document")]
+pub(crate) use self::input::registered_schema_sdl;
 pub use self::{
     input::SchemaInput,
     names::FieldName,
@@ -67,18 +69,31 @@ impl<'a> Schema<'a, Unvalidated> {
         Kind::try_from(self.type_index.lookup_valid_type(name)?).map_err(Into::into)
         // TODO: Suggestion logic should probably be implemented here (or in type_index)
     }
+
+    pub fn lookup_directive(&self, name: &str) -> Result<types::Directive<'_>, SchemaError> {
+        self.type_index.lookup_valid_directive(name)
+    }
 }
 
-impl<'a> Schema<'a, Validated> {
+impl<'a, State> Schema<'a, State> {
     pub fn root_types(&self) -> Result<SchemaRoots<'_>, SchemaError> {
         self.type_index.root_types()
     }
+}
 
+impl<'a> Schema<'a, Validated> {
     pub fn iter(&self) -> impl Iterator<Item = types::Type<'_>> {
         // unsafe_iter is safe because we're in a validated schema
         self.type_index.unsafe_iter()
     }
 
+    /// Iterates over the directives of the schema, including the built in
+    /// `@skip` & `@include` directives
+    pub fn directives(&self) -> impl Iterator<Item = types::Directive<'_>> {
+        // unsafe_directive_iter is safe because we're in a validated schema
+        self.type_index.unsafe_directive_iter()
+    }
+
     // Looks up a kind that we're not certain is in the validated schema.
     pub fn try_lookup<'b, Kind>(&'b self, name: &str) -> Result<Kind, SchemaError>
     where
@@ -117,6 +132,9 @@ pub enum SchemaError {
     CouldNotFindType {
         name: String,
     },
+    CouldNotFindDirective {
+        name: String,
+    },
 }
 
 impl std::fmt::Display for SchemaError {
@@ -137,6 +155,13 @@ impl std::fmt::Display for SchemaError {
             Self::CouldNotFindType { name } => {
                 write!(f, "Could not find a type named `{}` in the schema", name)
             }
+            Self::CouldNotFindDirective { name } => {
+                write!(
+                    f,
+                    "Could not find a directive named `@{}` in the schema",
+                    name
+                )
+            }
         }
     }
 }
@@ -13,6 +13,9 @@ pub type Definition = graphql_parser::schema::Definition<'static, String>;
 pub type TypeDefinition = graphql_parser::schema::TypeDefinition<'static, String>;
 pub type ScalarType = graphql_parser::schema::ScalarType<'static, String>;
 pub type InputValue = graphql_parser::schema::InputValue<'static, String>;
+pub type Directive = graphql_parser::schema::Directive<'static, String>;
+pub type Value = graphql_parser::schema::Value<'static, String>;
+pub type DirectiveDefinition = graphql_parser::schema::DirectiveDefinition<'static, String>;
 
 /// Loads a schema from a string
 pub fn load_schema(sdl: &str) -> Result<Document, SchemaLoadError> {
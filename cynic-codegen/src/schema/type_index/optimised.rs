@@ -5,7 +5,7 @@ use rkyv::Deserialize;
 
 use crate::schema::{
     self,
-    types::{SchemaRoots, Type},
+    types::{Directive, SchemaRoots, Type},
     Schema, SchemaError,
 };
 
@@ -16,6 +16,7 @@ use crate::schema::{
 pub struct OptimisedTypes<'a> {
     types: HashMap<String, Type<'a>>,
     schema_roots: SchemaRoots<'a>,
+    directives: Vec<Directive<'a>>,
 }
 
 impl Schema<'_, schema::Validated> {
@@ -27,6 +28,7 @@ impl Schema<'_, schema::Validated> {
                 .map(|ty| (ty.name().to_string(), ty))
                 .collect(),
             schema_roots: self.type_index.root_types().expect("valid root types"),
+            directives: self.type_index.unsafe_directive_iter().collect(),
         }
     }
 }
@@ -73,6 +75,19 @@ impl super::TypeIndex for ArchiveBacked {
             .expect("infallible"))
     }
 
+    fn lookup_valid_directive<'a>(&'a self, name: &str) -> Result<Directive<'a>, SchemaError> {
+        Ok(self
+            .borrow_archived()
+            .directives
+            .iter()
+            .find(|directive| directive.name == name)
+            .ok_or_else(|| SchemaError::CouldNotFindDirective {
+                name: name.to_string(),
+            })?
+            .deserialize(&mut rkyv::Infallible)
+            .expect("infallible"))
+    }
+
     fn unsafe_lookup<'a>(&'a self, name: &str) -> Option<Type<'a>> {
         Some(
             self.borrow_archived()
@@ -91,6 +106,19 @@ impl super::TypeIndex for ArchiveBacked {
                 .expect("infallible")
         }))
     }
+
+    fn unsafe_directive_iter<'a>(&'a self) -> Box<dyn Iterator<Item = Directive<'a>> + 'a> {
+        Box::new(
+            self.borrow_archived()
+                .directives
+                .iter()
+                .map(|archived_directive| {
+                    archived_directive
+                        .deserialize(&mut rkyv::Infallible)
+                        .expect("infallible")
+                }),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -153,5 +181,18 @@ mod tests {
         let all_schema_backed = schema_backed.unsafe_iter().collect::<HashSet<_>>();
         let all_archive_backed = archive_backed.unsafe_iter().collect::<HashSet<_>>();
         assert_eq!(all_archive_backed, all_schema_backed);
+
+        for directive in schema_backed.unsafe_directive_iter() {
+            assert_eq!(
+                archive_backed
+                    .lookup_valid_directive(&directive.name)
+                    .unwrap(),
+                directive
+            );
+        }
+        assert_eq!(
+            archive_backed.unsafe_directive_iter().collect::<Vec<_>>(),
+            schema_backed.unsafe_directive_iter().collect::<Vec<_>>()
+        );
     }
 }
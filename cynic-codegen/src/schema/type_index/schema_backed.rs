@@ -8,7 +8,7 @@ use once_cell::sync::Lazy;
 
 use crate::schema::{
     names::FieldName,
-    parser::{self, Definition, Document, TypeDefinition, TypeExt},
+    parser::{self, Definition, DirectiveDefinition, Document, TypeDefinition, TypeExt},
     types::*,
     SchemaError,
 };
@@ -22,6 +22,9 @@ pub struct SchemaBackedTypeIndex {
     #[borrows(document)]
     #[covariant]
     types: HashMap<&'this str, &'this TypeDefinition>,
+    #[borrows(document)]
+    #[covariant]
+    directives: HashMap<&'this str, &'this DirectiveDefinition>,
 }
 
 impl SchemaBackedTypeIndex {
@@ -58,6 +61,15 @@ impl SchemaBackedTypeIndex {
                 }
                 types
             },
+            |document| {
+                let mut directives = HashMap::new();
+                for definition in &document.definitions {
+                    if let Definition::DirectiveDefinition(def) = definition {
+                        directives.insert(def.name.as_str(), def);
+                    }
+                }
+                directives
+            },
         )
     }
 }
@@ -77,7 +89,26 @@ impl super::TypeIndex for SchemaBackedTypeIndex {
     }
 
     fn validate_all(&self) -> Result<(), SchemaError> {
-        self.validate(self.borrow_types().values().copied().collect())
+        self.validate(self.borrow_types().values().copied().collect())?;
+        for directive in self.borrow_directives().values() {
+            self.validate_directive(directive)?;
+        }
+        Ok(())
+    }
+
+    fn lookup_valid_directive<'b>(&'b self, name: &str) -> Result<Directive<'b>, SchemaError> {
+        let Some(def) = self.borrow_directives().get(name).copied() else {
+            return Directive::builtins()
+                .into_iter()
+                .find(|directive| directive.name == name)
+                .ok_or_else(|| SchemaError::CouldNotFindDirective {
+                    name: name.to_string(),
+                });
+        };
+
+        self.validate_directive(def)?;
+
+        Ok(build_directive(def))
     }
 
     fn root_types(&self) -> Result<SchemaRoots<'_>, SchemaError> {
@@ -115,10 +146,12 @@ impl super::TypeIndex for SchemaBackedTypeIndex {
         Some(match type_def {
             TypeDefinition::Scalar(def) => Type::Scalar(ScalarType {
                 name: Cow::Borrowed(&def.name),
+                description: def.description.as_deref().map(Cow::Borrowed),
                 builtin: scalar_is_builtin(&def.name),
             }),
             TypeDefinition::Object(def) => Type::Object(ObjectType {
                 name: Cow::Borrowed(&def.name),
+                description: def.description.as_deref().map(Cow::Borrowed),
                 fields: def
                     .fields
                     .iter()
@@ -132,6 +165,7 @@ impl super::TypeIndex for SchemaBackedTypeIndex {
             }),
             TypeDefinition::Interface(def) => Type::Interface(InterfaceType {
                 name: Cow::Borrowed(&def.name),
+                description: def.description.as_deref().map(Cow::Borrowed),
                 fields: def
                     .fields
                     .iter()
@@ -140,6 +174,7 @@ impl super::TypeIndex for SchemaBackedTypeIndex {
             }),
             TypeDefinition::Union(def) => Type::Union(UnionType {
                 name: Cow::Borrowed(&def.name),
+                description: def.description.as_deref().map(Cow::Borrowed),
                 types: def
                     .types
                     .iter()
@@ -148,16 +183,20 @@ impl super::TypeIndex for SchemaBackedTypeIndex {
             }),
             TypeDefinition::Enum(def) => Type::Enum(EnumType {
                 name: Cow::Borrowed(&def.name),
+                description: def.description.as_deref().map(Cow::Borrowed),
                 values: def
                     .values
                     .iter()
                     .map(|val| EnumValue {
                         name: FieldName::new(&val.name),
+                        description: val.description.as_deref().map(Cow::Borrowed),
+                        deprecated: deprecation(&val.directives),
                     })
                     .collect(),
             }),
             TypeDefinition::InputObject(def) => Type::InputObject(InputObjectType {
                 name: Cow::Borrowed(&def.name),
+                description: def.description.as_deref().map(Cow::Borrowed),
                 fields: def.fields.iter().map(convert_input_value).collect(),
             }),
         })
@@ -171,6 +210,26 @@ impl super::TypeIndex for SchemaBackedTypeIndex {
                 .map(|name| self.unsafe_lookup(name).unwrap()),
         )
     }
+
+    fn unsafe_directive_iter<'b>(&'b self) -> Box<dyn Iterator<Item = Directive<'b>> + 'b> {
+        let mut directives = Directive::builtins()
+            .into_iter()
+            .filter(|directive| {
+                !self
+                    .borrow_directives()
+                    .contains_key(directive.name.as_ref())
+            })
+            .chain(
+                self.borrow_directives()
+                    .values()
+                    .map(|def| build_directive(def)),
+            )
+            .collect::<Vec<_>>();
+
+        directives.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+
+        Box::new(directives.into_iter())
+    }
 }
 
 impl SchemaBackedTypeIndex {
@@ -179,6 +238,28 @@ impl SchemaBackedTypeIndex {
         self.borrow_types().get(name).map(|d| *d)
     }
 
+    /// Validates that the arguments of a directive are all input types that exist
+    fn validate_directive(&self, def: &DirectiveDefinition) -> Result<(), SchemaError> {
+        let mut defs = Vec::new();
+        for argument in &def.arguments {
+            let name = argument.value_type.inner_name();
+            match self.lookup_type(name) {
+                Some(
+                    def @ (TypeDefinition::InputObject(_)
+                    | TypeDefinition::Enum(_)
+                    | TypeDefinition::Scalar(_)),
+                ) => defs.push(def),
+                _ => {
+                    return Err(SchemaError::InvalidTypeInSchema {
+                        name: name.to_string(),
+                        details: "expected to be an input type".to_string(),
+                    })
+                }
+            }
+        }
+        self.validate(defs)
+    }
+
     /// Validates that all the types contained within the given types do exist.
     ///
     /// So we can just directly use refs to them.
@@ -337,8 +418,10 @@ fn convert_input_value(val: &parser::InputValue) -> InputValue<'_> {
         name: FieldName {
             graphql_name: Cow::Borrowed(&val.name),
         },
+        description: val.description.as_deref().map(Cow::Borrowed),
         value_type: build_type_ref::<InputType<'_>>(&val.value_type),
         has_default: val.default_value.is_some(),
+        deprecated: deprecation(&val.directives),
     }
 }
 
@@ -363,17 +446,62 @@ fn build_type_ref<T>(ty: &parser::Type) -> TypeRef<'_, T> {
     inner_fn::<T>(ty, true)
 }
 
+fn build_directive(def: &DirectiveDefinition) -> Directive<'_> {
+    use graphql_parser::schema::DirectiveLocation as Location;
+
+    Directive {
+        name: Cow::Borrowed(&def.name),
+        arguments: def.arguments.iter().map(convert_input_value).collect(),
+        locations: def
+            .locations
+            .iter()
+            .filter_map(|location| {
+                Some(match location {
+                    Location::Query => DirectiveLocation::Query,
+                    Location::Mutation => DirectiveLocation::Mutation,
+                    Location::Subscription => DirectiveLocation::Subscription,
+                    Location::Field => DirectiveLocation::Field,
+                    Location::FragmentDefinition => DirectiveLocation::FragmentDefinition,
+                    Location::FragmentSpread => DirectiveLocation::FragmentSpread,
+                    Location::InlineFragment => DirectiveLocation::InlineFragment,
+                    _ => return None,
+                })
+            })
+            .collect(),
+    }
+}
+
 fn build_field<'a>(field: &'a parser::Field, parent_type_name: &'a str) -> Field<'a> {
     Field {
         name: FieldName {
             graphql_name: Cow::Borrowed(&field.name),
         },
+        description: field.description.as_deref().map(Cow::Borrowed),
         arguments: field.arguments.iter().map(convert_input_value).collect(),
         field_type: build_type_ref::<OutputType<'_>>(&field.field_type),
         parent_type_name: Cow::Borrowed(parent_type_name),
+        deprecated: deprecation(&field.directives),
     }
 }
 
+/// Extracts the reason from any `@deprecated` directive in `directives`
+fn deprecation(directives: &[parser::Directive]) -> Option<Cow<'_, str>> {
+    let directive = directives
+        .iter()
+        .find(|directive| directive.name == "deprecated")?;
+
+    let reason = directive
+        .arguments
+        .iter()
+        .find_map(|(name, value)| match value {
+            parser::Value::String(reason) if name == "reason" => Some(reason.as_str()),
+            _ => None,
+        });
+
+    // This is the default reason in the GraphQL spec
+    Some(Cow::Borrowed(reason.unwrap_or("No longer supported")))
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
@@ -393,6 +521,57 @@ mod tests {
         index.validate_all().unwrap();
     }
 
+    #[test]
+    fn test_directive_lookup() {
+        let document = parser::parse_schema(
+            r#"
+            type Query { hello: String }
+            directive @cached(ttl: Int!) on QUERY | FIELD | FIELD_DEFINITION
+            directive @key(fields: String!) on OBJECT
+            "#,
+        )
+        .unwrap();
+        let index = SchemaBackedTypeIndex::for_schema(document);
+        index.validate_all().unwrap();
+
+        let cached = index.lookup_valid_directive("cached").unwrap();
+        assert_eq!(cached.arguments.len(), 1);
+        assert_eq!(
+            cached.locations,
+            vec![DirectiveLocation::Query, DirectiveLocation::Field]
+        );
+
+        assert!(!index.lookup_valid_directive("key").unwrap().is_executable());
+        assert!(index.lookup_valid_directive("skip").is_ok());
+        assert_matches!(
+            index.lookup_valid_directive("nope"),
+            Err(SchemaError::CouldNotFindDirective { .. })
+        );
+
+        let names = index
+            .unsafe_directive_iter()
+            .map(|directive| directive.name.into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["cached", "include", "key", "skip"]);
+    }
+
+    #[test]
+    fn test_directive_with_unknown_argument_type() {
+        let document = parser::parse_schema(
+            r#"
+            type Query { hello: String }
+            directive @cached(ttl: Duration!) on FIELD
+            "#,
+        )
+        .unwrap();
+        let index = SchemaBackedTypeIndex::for_schema(document);
+
+        assert_matches!(
+            index.lookup_valid_directive("cached"),
+            Err(SchemaError::InvalidTypeInSchema { .. })
+        );
+    }
+
     #[test]
     fn test_build_type_ref_non_null_type() {
         let non_null_type =
@@ -64,6 +64,8 @@ pub enum OutputType<'a> {
 pub struct ScalarType<'a> {
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub builtin: bool,
 }
 
@@ -76,6 +78,8 @@ pub struct ScalarType<'a> {
 pub struct ObjectType<'a> {
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub implements_interfaces: Vec<InterfaceRef<'a>>,
     pub fields: Vec<Field<'a>>,
 }
@@ -88,10 +92,15 @@ pub struct ObjectType<'a> {
 )]
 pub struct Field<'a> {
     pub name: FieldName<'a>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub arguments: Vec<InputValue<'a>>,
     pub field_type: TypeRef<'a, OutputType<'a>>,
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub(super) parent_type_name: Cow<'a, str>,
+    /// The reason this is deprecated, if it is
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub deprecated: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -102,8 +111,13 @@ pub struct Field<'a> {
 )]
 pub struct InputValue<'a> {
     pub name: FieldName<'a>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub value_type: TypeRef<'a, InputType<'a>>,
     pub has_default: bool,
+    /// The reason this is deprecated, if it is
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub deprecated: Option<Cow<'a, str>>,
 }
 
 impl InputValue<'_> {
@@ -116,6 +130,88 @@ impl InputValue<'_> {
     }
 }
 
+/// A directive that can be applied to parts of an executable document.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct Directive<'a> {
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
+    pub name: Cow<'a, str>,
+    pub arguments: Vec<InputValue<'a>>,
+    /// The executable locations this directive can be used in.  Type system
+    /// locations are not included.
+    pub locations: Vec<DirectiveLocation>,
+}
+
+/// The places in an executable document that a directive can be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum DirectiveLocation {
+    Query,
+    Mutation,
+    Subscription,
+    Field,
+    FragmentDefinition,
+    FragmentSpread,
+    InlineFragment,
+}
+
+impl Directive<'_> {
+    pub fn is_executable(&self) -> bool {
+        !self.locations.is_empty()
+    }
+
+    pub fn allowed_on(&self, location: DirectiveLocation) -> bool {
+        self.locations.contains(&location)
+    }
+}
+
+impl Directive<'static> {
+    /// The `@skip` & `@include` directives, which all schemas support
+    pub fn builtins() -> Vec<Directive<'static>> {
+        ["skip", "include"]
+            .into_iter()
+            .map(|name| Directive {
+                name: Cow::Borrowed(name),
+                arguments: vec![InputValue {
+                    name: FieldName::new("if"),
+                    description: None,
+                    value_type: TypeRef::Named(Cow::Borrowed("Boolean"), PhantomData),
+                    has_default: false,
+                    deprecated: None,
+                }],
+                locations: vec![
+                    DirectiveLocation::Field,
+                    DirectiveLocation::FragmentSpread,
+                    DirectiveLocation::InlineFragment,
+                ],
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for DirectiveLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DirectiveLocation::Query => "queries",
+            DirectiveLocation::Mutation => "mutations",
+            DirectiveLocation::Subscription => "subscriptions",
+            DirectiveLocation::Field => "fields",
+            DirectiveLocation::FragmentDefinition => "fragment definitions",
+            DirectiveLocation::FragmentSpread => "fragment spreads",
+            DirectiveLocation::InlineFragment => "inline fragments",
+        };
+        write!(f, "{s}")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "rkyv",
@@ -125,6 +221,8 @@ impl InputValue<'_> {
 pub struct InterfaceType<'a> {
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub fields: Vec<Field<'a>>,
 }
 
@@ -137,6 +235,8 @@ pub struct InterfaceType<'a> {
 pub struct UnionType<'a> {
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub types: Vec<ObjectRef<'a>>,
 }
 
@@ -149,6 +249,8 @@ pub struct UnionType<'a> {
 pub struct EnumType<'a> {
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub values: Vec<EnumValue<'a>>,
 }
 
@@ -160,6 +262,11 @@ pub struct EnumType<'a> {
 )]
 pub struct EnumValue<'a> {
     pub name: FieldName<'a>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
+    /// The reason this is deprecated, if it is
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub deprecated: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -171,6 +278,8 @@ pub struct EnumValue<'a> {
 pub struct InputObjectType<'a> {
     #[cfg_attr(feature = "rkyv", with(rkyv::with::AsOwned))]
     pub name: Cow<'a, str>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<rkyv::with::AsOwned>))]
+    pub description: Option<Cow<'a, str>>,
     pub fields: Vec<InputValue<'a>>,
 }
 
@@ -247,6 +356,12 @@ impl std::fmt::Display for ObjectRef<'_> {
     }
 }
 
+impl std::fmt::Display for InterfaceRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "rkyv",
@@ -0,0 +1,101 @@
+//! Conversion of schema descriptions into rustdoc.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Outputs a `#[doc]` attribute for an item, if it has a description in the
+/// schema.
+pub(crate) fn doc_attr(description: Option<&str>) -> Option<TokenStream> {
+    let description = proc_macro2::Literal::string(&rustdoc_safe(description?));
+    Some(quote! { #[doc = #description] })
+}
+
+/// Marks any code blocks in a schema description as `text`.
+///
+/// Schema descriptions are markdown, but their code blocks are almost never
+/// rust.  rustdoc treats unlabelled code blocks as rust & tries to run them as
+/// doctests, so we convert indented blocks into fenced ones and give any
+/// unlabelled fences a `text` label.
+fn rustdoc_safe(description: &str) -> String {
+    let mut output = Vec::new();
+    let mut in_fence = false;
+    let mut in_indented_block = false;
+    let mut previous_blank = true;
+
+    for line in description.lines() {
+        let trimmed = line.trim_start();
+        let blank = trimmed.is_empty();
+
+        if in_indented_block && !blank && !line.starts_with("    ") {
+            trim_trailing_blanks(&mut output);
+            output.push("```".to_string());
+            output.push(String::new());
+            in_indented_block = false;
+        }
+
+        if in_indented_block {
+            output.push(line.get(4..).unwrap_or_default().to_string());
+        } else if trimmed.starts_with("```") {
+            let indent = &line[..line.len() - trimmed.len()];
+            if !in_fence && trimmed.trim_start_matches('`').trim().is_empty() {
+                output.push(format!("{indent}```text"));
+            } else {
+                output.push(line.to_string());
+            }
+            in_fence = !in_fence;
+        } else if !in_fence && previous_blank && line.starts_with("    ") && !blank {
+            output.push("```text".to_string());
+            output.push(line[4..].to_string());
+            in_indented_block = true;
+        } else {
+            output.push(line.to_string());
+        }
+
+        previous_blank = blank;
+    }
+
+    if in_indented_block {
+        trim_trailing_blanks(&mut output);
+        output.push("```".to_string());
+    }
+
+    output.join("\n")
+}
+
+fn trim_trailing_blanks(lines: &mut Vec<String>) {
+    while lines.last().map(|line| line.trim().is_empty()) == Some(true) {
+        lines.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptions_without_code_are_unchanged() {
+        let description = "A git ref.\n\nThe ref must be a `branch`.\n  - Or not";
+
+        assert_eq!(rustdoc_safe(description), description);
+    }
+
+    #[test]
+    fn test_unlabelled_fences_are_marked_as_text() {
+        assert_eq!(
+            rustdoc_safe("Example:\n\n```\n{ \"id\": 1 }\n```\n\n```json\n{}\n```"),
+            "Example:\n\n```text\n{ \"id\": 1 }\n```\n\n```json\n{}\n```"
+        );
+    }
+
+    #[test]
+    fn test_indented_blocks_are_fenced() {
+        assert_eq!(
+            rustdoc_safe("Example:\n\n    {\n      \"id\": 1\n    }\n\nMore text"),
+            "Example:\n\n```text\n{\n  \"id\": 1\n}\n```\n\nMore text"
+        );
+        assert_eq!(
+            rustdoc_safe("Example:\n\n    { \"id\": 1 }\n"),
+            "Example:\n\n```text\n{ \"id\": 1 }\n```"
+        );
+    }
+}
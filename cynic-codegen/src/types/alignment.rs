@@ -49,6 +49,30 @@ fn align_output_type_impl<'a>(
     }
 }
 
+/// Splits an aligned output type into the number of `Option` & `Vec`
+/// wrappers it shares with the schema type, the rust type inside those
+/// wrappers and the schema type inside them.
+pub fn unwrap_output_type<'a, 'b>(
+    ty: &syn::Type,
+    gql_ty: &'b TypeRef<'a, OutputType<'a>>,
+) -> (usize, syn::Type, &'b TypeRef<'a, OutputType<'a>>) {
+    fn unwrap_impl<'a, 'b>(
+        ty: &RustType<'_>,
+        gql_ty: &'b TypeRef<'a, OutputType<'a>>,
+        depth: usize,
+    ) -> (usize, syn::Type, &'b TypeRef<'a, OutputType<'a>>) {
+        match (ty, gql_ty) {
+            (RustType::Optional { inner, .. }, TypeRef::Nullable(inner_gql))
+            | (RustType::List { inner, .. }, TypeRef::List(inner_gql)) => {
+                unwrap_impl(inner, inner_gql, depth + 1)
+            }
+            _ => (depth, ty.to_syn(), gql_ty),
+        }
+    }
+
+    unwrap_impl(&parse_rust_type(ty), gql_ty, 0)
+}
+
 pub fn align_input_type(
     ty: &syn::Type,
     gql_ty: &TypeRef<'_, InputType<'_>>,
@@ -6,9 +6,9 @@ mod parsing;
 mod validation;
 
 pub use self::{
-    alignment::{align_input_type, align_output_type},
+    alignment::{align_input_type, align_output_type, unwrap_output_type},
     validation::{
-        check_input_types_are_compatible, check_spread_type, check_types_are_compatible,
-        outer_type_is_option, CheckMode,
+        check_deferred_spread_type, check_input_types_are_compatible, check_spread_type,
+        check_types_are_compatible, outer_type_is_option, CheckMode,
     },
 };
@@ -85,7 +85,7 @@ pub fn parse_rust_type(ty: &syn::Type) -> RustType<'_> {
                 }
 
                 match last_segment.ident.to_string().as_str() {
-                    "Box" | "Arc" | "Rc" => {
+                    "Box" | "Arc" | "Rc" | "FieldResult" => {
                         if let Some(inner_type) = extract_generic_argument(last_segment) {
                             return RustType::Ref {
                                 syn: Cow::Borrowed(ty),
@@ -281,6 +281,11 @@ mod tests {
         quote! { Vec<i32> },
         quote! { Arc<Vec<i32>> },
     )]
+    #[case::replace_on_field_result(
+        quote! { cynic::FieldResult<i32, Extensions> },
+        quote! { Vec<i32> },
+        quote! { cynic::FieldResult<Vec<i32>, Extensions> },
+    )]
     #[case::replace_with_complex_inner(
         quote! { Arc<i32> },
         quote! { Vec<chrono::DateTime<chrono::Utc>> },
@@ -74,6 +74,24 @@ pub fn check_spread_type(rust_type: &syn::Type) -> Result<(), syn::Error> {
     inner_fn(&parse_rust_type(rust_type))
 }
 
+/// Checks the type of a deferred spread field, returning the type inside its
+/// Option.
+pub fn check_deferred_spread_type(rust_type: &syn::Type) -> Result<syn::Type, syn::Error> {
+    match parse_rust_type(rust_type) {
+        RustType::Optional { inner, .. } => {
+            let inner = inner.to_syn();
+            check_spread_type(&inner)?;
+            Ok(inner)
+        }
+        RustType::Unknown { .. } => Ok(rust_type.clone()),
+        other => Err(TypeValidationError::DeferredSpreadWithoutOption {
+            provided_type: rust_type.to_string(),
+            span: other.span(),
+        }
+        .into()),
+    }
+}
+
 /// Returns the type inside `Option` if the type is `Option`.
 /// Otherwise returns None
 pub fn outer_type_is_option(rust_type: &syn::Type) -> bool {
@@ -231,6 +249,7 @@ enum TypeValidationError {
     RecursiveFieldWithoutOption { provided_type: String, span: Span },
     SpreadOnOption { span: Span },
     SpreadOnVec { span: Span },
+    DeferredSpreadWithoutOption { provided_type: String, span: Span },
 }
 
 impl From<TypeValidationError> for syn::Error {
@@ -255,6 +274,9 @@ impl From<TypeValidationError> for syn::Error {
             }
             TypeValidationError::SpreadOnOption { .. } => "You can't spread on an optional field".to_string(),
             TypeValidationError::SpreadOnVec { .. } => "You can't spread on a list field".to_string(),
+            TypeValidationError::DeferredSpreadWithoutOption { provided_type, .. } => {
+                format!("Deferred spreads may not have arrived yet, so must be wrapped in Option.  Did you mean Option<{}>", provided_type)
+            }
         };
 
         syn::Error::new(span, message)
@@ -278,6 +300,7 @@ impl TypeValidationError {
             TypeValidationError::RecursiveFieldWithoutOption { span, .. } => *span,
             TypeValidationError::SpreadOnOption { span } => *span,
             TypeValidationError::SpreadOnVec { span } => *span,
+            TypeValidationError::DeferredSpreadWithoutOption { span, .. } => *span,
         }
     }
 }
@@ -0,0 +1,104 @@
+use {
+    quote::{quote, ToTokens, TokenStreamExt},
+    syn::parse_quote,
+};
+
+use crate::schema::types::{Directive, DirectiveLocation, InputValue, SchemaRoots};
+
+pub struct DirectiveOutput<'a> {
+    pub(super) directive: &'a Directive<'a>,
+    pub(super) roots: &'a SchemaRoots<'a>,
+}
+
+struct ArgumentOutput<'a> {
+    argument: &'a InputValue<'a>,
+    directive_marker: &'a proc_macro2::Ident,
+}
+
+impl ToTokens for DirectiveOutput<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let directive_marker = &self.directive.marker_ident();
+        let name_literal = proc_macro2::Literal::string(self.directive.name.as_ref());
+
+        tokens.append_all(quote! {
+            pub struct #directive_marker;
+
+            impl cynic::schema::Directive for #directive_marker {
+                const NAME: &'static str = #name_literal;
+            }
+        });
+
+        for location in &self.directive.locations {
+            let root = match location {
+                DirectiveLocation::Field => {
+                    tokens.append_all(quote! {
+                        impl cynic::schema::FieldDirective for #directive_marker {}
+                    });
+                    continue;
+                }
+                DirectiveLocation::InlineFragment => {
+                    tokens.append_all(quote! {
+                        impl cynic::schema::InlineFragmentDirective for #directive_marker {}
+                    });
+                    continue;
+                }
+                DirectiveLocation::Query => Some(&self.roots.query),
+                DirectiveLocation::Mutation => self.roots.mutation.as_ref(),
+                DirectiveLocation::Subscription => self.roots.subscription.as_ref(),
+                DirectiveLocation::FragmentDefinition | DirectiveLocation::FragmentSpread => {
+                    // cynic doesn't output named fragments so there's nothing to do here.
+                    continue;
+                }
+            };
+
+            if let Some(root) = root {
+                let root = root.marker_ident().to_path(&parse_quote! { super });
+                tokens.append_all(quote! {
+                    impl cynic::schema::OperationDirective<#root> for #directive_marker {}
+                });
+            }
+        }
+
+        if !self.directive.arguments.is_empty() {
+            let argument_module = self.directive.argument_module_ident();
+            let arguments = self
+                .directive
+                .arguments
+                .iter()
+                .map(|argument| ArgumentOutput {
+                    argument,
+                    directive_marker,
+                });
+
+            tokens.append_all(quote! {
+                pub mod #argument_module {
+                    #(#arguments)*
+                }
+            });
+        }
+    }
+}
+
+impl ToTokens for ArgumentOutput<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = proc_macro2::Literal::string(self.argument.name.as_str());
+        let argument_ident = self.argument.marker_ident().to_rust_ident();
+        let directive_marker = self.directive_marker;
+
+        let schema_type = self
+            .argument
+            .value_type
+            .marker_type()
+            .to_path(&parse_quote! { super::super });
+
+        tokens.append_all(quote! {
+            pub struct #argument_ident;
+
+            impl cynic::schema::HasArgument<#argument_ident> for super::#directive_marker {
+                type ArgumentType = #schema_type;
+
+                const NAME: &'static str = #name;
+            }
+        })
+    }
+}
@@ -0,0 +1,58 @@
+use quote::{quote, ToTokens, TokenStreamExt};
+
+use crate::{schema::types::EnumType, schema_docs::doc_attr};
+
+use super::fields::deprecated_attr;
+
+pub struct EnumOutput<'a> {
+    en: EnumType<'a>,
+}
+
+impl<'a> EnumOutput<'a> {
+    pub fn new(en: EnumType<'a>) -> Self {
+        EnumOutput { en }
+    }
+
+    /// Appends markers for any values of the enum that are deprecated or have
+    /// a description.
+    ///
+    /// Other values don't need markers, so we don't output them.
+    pub fn append_fields(&self, field_module: &mut proc_macro2::TokenStream) {
+        let value_markers = self
+            .en
+            .values
+            .iter()
+            .filter(|value| value.has_marker())
+            .map(|value| {
+                let doc = doc_attr(value.description.as_deref());
+                let deprecated = deprecated_attr(value.deprecated.as_deref());
+                let marker = value.marker_ident().to_rust_ident();
+                quote! {
+                    #doc
+                    #deprecated
+                    pub struct #marker;
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !value_markers.is_empty() {
+            let field_module_ident = self.en.field_module().ident();
+            field_module.append_all(quote! {
+                pub mod #field_module_ident {
+                    #(#value_markers)*
+                }
+            });
+        }
+    }
+}
+
+impl ToTokens for EnumOutput<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let ident = self.en.marker_ident().to_rust_ident();
+        let doc = doc_attr(self.en.description.as_deref());
+        tokens.append_all(quote! {
+            #doc
+            pub struct #ident {}
+        });
+    }
+}
@@ -3,7 +3,10 @@ use {
     syn::parse_quote,
 };
 
-use crate::schema::types::{Field, InputValue};
+use crate::{
+    schema::types::{Field, InputValue},
+    schema_docs::doc_attr,
+};
 
 pub struct FieldOutput<'a> {
     pub(super) field: &'a Field<'a>,
@@ -27,7 +30,12 @@ impl ToTokens for FieldOutput<'_> {
             .marker_type()
             .to_path(&parse_quote! { super::super });
 
+        let doc = doc_attr(self.field.description.as_deref());
+        let deprecated = deprecated_attr(self.field.deprecated.as_deref());
+
         tokens.append_all(quote! {
+            #doc
+            #deprecated
             pub struct #field_marker;
 
             impl cynic::schema::Field for #field_marker{
@@ -69,7 +77,12 @@ impl ToTokens for ArgumentOutput<'_> {
             .marker_type()
             .to_path(&parse_quote! { super::super::super  });
 
+        let doc = doc_attr(self.argument.description.as_deref());
+        let deprecated = deprecated_attr(self.argument.deprecated.as_deref());
+
         tokens.append_all(quote! {
+            #doc
+            #deprecated
             pub struct #argument_ident;
 
             impl cynic::schema::HasArgument<#argument_ident> for super::#field_marker {
@@ -80,3 +93,10 @@ impl ToTokens for ArgumentOutput<'_> {
         })
     }
 }
+
+/// Outputs a `#[deprecated]` attribute for a marker, if the item it represents
+/// is deprecated in the schema.
+pub(super) fn deprecated_attr(reason: Option<&str>) -> Option<proc_macro2::TokenStream> {
+    let reason = proc_macro2::Literal::string(reason?);
+    Some(quote! { #[deprecated(note = #reason)] })
+}
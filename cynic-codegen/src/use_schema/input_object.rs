@@ -3,7 +3,12 @@ use {
     syn::parse_quote,
 };
 
-use crate::schema::types::{InputObjectType, InputValue};
+use crate::{
+    schema::types::{InputObjectType, InputValue},
+    schema_docs::doc_attr,
+};
+
+use super::fields::deprecated_attr;
 
 pub struct InputObjectOutput<'a> {
     object: InputObjectType<'a>,
@@ -42,7 +47,9 @@ impl<'a> InputObjectOutput<'a> {
 impl ToTokens for InputObjectOutput<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let object_marker = &self.object_marker;
+        let doc = doc_attr(self.object.description.as_deref());
         tokens.append_all(quote! {
+            #doc
             pub struct #object_marker;
 
             impl cynic::schema::InputObjectMarker for #object_marker {}
@@ -62,7 +69,12 @@ impl ToTokens for FieldOutput<'_> {
             .marker_type()
             .to_path(&parse_quote! { super::super });
 
+        let doc = doc_attr(self.field.description.as_deref());
+        let deprecated = deprecated_attr(self.field.deprecated.as_deref());
+
         tokens.append_all(quote! {
+            #doc
+            #deprecated
             pub struct #field_marker;
 
             impl cynic::schema::Field for #field_marker {
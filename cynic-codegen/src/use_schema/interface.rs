@@ -1,6 +1,6 @@
 use quote::{quote, ToTokens, TokenStreamExt};
 
-use crate::schema::types::InterfaceType;
+use crate::{schema::types::InterfaceType, schema_docs::doc_attr};
 
 use super::fields::FieldOutput;
 
@@ -36,7 +36,9 @@ impl<'a> InterfaceOutput<'a> {
 impl ToTokens for InterfaceOutput<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let marker_ident = &self.marker_ident;
+        let doc = doc_attr(self.iface.description.as_deref());
         tokens.append_all(quote! {
+            #doc
             pub struct #marker_ident;
         });
     }
@@ -1,3 +1,5 @@
+mod directives;
+mod enum_type;
 mod fields;
 mod input_object;
 mod interface;
@@ -6,22 +8,26 @@ mod object;
 mod params;
 mod schema_roots;
 mod subtype_markers;
+mod type_info;
 
 pub use params::UseSchemaParams;
 
 use {
     proc_macro2::TokenStream,
     quote::{quote, ToTokens},
+    std::collections::HashMap,
 };
 
 use crate::{
     error::Errors,
     schema::{types::Type, Schema, SchemaInput, Validated},
+    schema_docs::doc_attr,
 };
 
 use self::{
-    input_object::InputObjectOutput, interface::InterfaceOutput, named_type::NamedType,
-    object::ObjectOutput, subtype_markers::SubtypeMarkers,
+    directives::DirectiveOutput, enum_type::EnumOutput, input_object::InputObjectOutput,
+    interface::InterfaceOutput, named_type::NamedType, object::ObjectOutput,
+    subtype_markers::SubtypeMarkers, type_info::TypeInfo,
 };
 
 pub fn use_schema(input: UseSchemaParams) -> Result<TokenStream, Errors> {
@@ -43,17 +49,33 @@ pub(crate) fn use_schema_impl(schema: &Schema<'_, Validated>) -> Result<TokenStr
         #root_types
     });
 
+    let mut implementations = HashMap::<String, Vec<String>>::new();
+    for definition in schema.iter() {
+        if let Type::Object(def) = definition {
+            for iface in &def.implements_interfaces {
+                implementations
+                    .entry(iface.to_string())
+                    .or_default()
+                    .push(def.name.to_string());
+            }
+        }
+    }
+
     let mut subtype_markers = Vec::new();
     let mut named_types = Vec::new();
+    let mut type_infos = Vec::new();
 
     for definition in schema.iter() {
         named_types.extend(NamedType::from_def(&definition));
+        type_infos.extend(TypeInfo::from_def(&definition, &implementations));
 
         match definition {
             Type::Scalar(def) if !def.builtin => {
                 let name = proc_macro2::Literal::string(def.name.as_ref());
                 let ident = def.marker_ident().to_rust_ident();
+                let doc = doc_attr(def.description.as_deref());
                 output.append_all(quote! {
+                    #doc
                     pub struct #ident {}
                     impl cynic::schema::NamedType for #ident {
                         const NAME: &'static str = #name;
@@ -79,15 +101,16 @@ pub(crate) fn use_schema_impl(schema: &Schema<'_, Validated>) -> Result<TokenStr
                 subtype_markers.extend(SubtypeMarkers::from_union(&def));
 
                 let ident = def.marker_ident().to_rust_ident();
+                let doc = doc_attr(def.description.as_deref());
                 output.append_all(quote! {
+                    #doc
                     pub struct #ident {}
                 });
             }
             Type::Enum(def) => {
-                let ident = def.marker_ident().to_rust_ident();
-                output.append_all(quote! {
-                    pub struct #ident {}
-                });
+                let en = EnumOutput::new(def);
+                en.to_tokens(&mut output);
+                en.append_fields(&mut field_module);
             }
             Type::InputObject(def) => {
                 let object = InputObjectOutput::new(def);
@@ -97,15 +120,30 @@ pub(crate) fn use_schema_impl(schema: &Schema<'_, Validated>) -> Result<TokenStr
         }
     }
 
+    let directives = schema
+        .directives()
+        .filter(|directive| directive.is_executable())
+        .collect::<Vec<_>>();
+    let directives = directives.iter().map(|directive| DirectiveOutput {
+        directive,
+        roots: &root_types,
+    });
+
     output.append_all(quote! {
         #(#subtype_markers)*
         #(#named_types)*
+        #(#type_infos)*
 
-        #[allow(non_snake_case, non_camel_case_types)]
+        #[allow(non_snake_case, non_camel_case_types, deprecated)]
         pub mod __fields {
             #field_module
         }
 
+        #[allow(non_snake_case, non_camel_case_types)]
+        pub mod __directives {
+            #(#directives)*
+        }
+
         pub type Boolean = bool;
         pub type String = std::string::String;
         pub type Float = f64;
@@ -1,6 +1,6 @@
 use quote::{quote, ToTokens, TokenStreamExt};
 
-use crate::schema::types::ObjectType;
+use crate::{schema::types::ObjectType, schema_docs::doc_attr};
 
 use super::fields::FieldOutput;
 
@@ -33,7 +33,9 @@ impl<'a> ObjectOutput<'a> {
 impl ToTokens for ObjectOutput<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let object_marker = self.object.marker_ident().to_rust_ident();
+        let doc = doc_attr(self.object.description.as_deref());
         tokens.append_all(quote! {
+            #doc
             pub struct #object_marker;
         });
     }
@@ -0,0 +1,123 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use proc_macro2::TokenStream;
+
+use crate::schema::{markers::TypeMarkerIdent, types::Type};
+
+/// Outputs a `HasTypeInfo` implementation for an output type.
+///
+/// This goes through a macro in cynic, which only outputs the implementation
+/// if cynic's `testing` feature is enabled.
+pub struct TypeInfo<'a> {
+    marker_ident: TypeMarkerIdent<'a>,
+    kind: TypeInfoKind<'a>,
+}
+
+enum TypeInfoKind<'a> {
+    Scalar(Cow<'a, str>),
+    Enum(Cow<'a, str>, Vec<String>),
+    Object(Cow<'a, str>),
+    Interface(Cow<'a, str>, Vec<String>),
+    Union(Cow<'a, str>, Vec<String>),
+}
+
+impl<'a> TypeInfo<'a> {
+    /// Builds the `TypeInfo` for `def`.
+    ///
+    /// `implementations` should map the name of each interface to the names
+    /// of the objects that implement it.
+    pub fn from_def(
+        def: &Type<'a>,
+        implementations: &HashMap<String, Vec<String>>,
+    ) -> Option<Self> {
+        match def {
+            Type::Scalar(def) if !def.builtin => Some(TypeInfo {
+                marker_ident: def.marker_ident(),
+                kind: TypeInfoKind::Scalar(def.name.clone()),
+            }),
+            Type::Scalar(_) => None,
+            Type::InputObject(_) => None,
+
+            Type::Enum(def) => Some(TypeInfo {
+                marker_ident: def.marker_ident(),
+                kind: TypeInfoKind::Enum(
+                    def.name.clone(),
+                    def.values
+                        .iter()
+                        .map(|value| value.name.as_str().to_string())
+                        .collect(),
+                ),
+            }),
+            Type::Object(def) => Some(TypeInfo {
+                marker_ident: def.marker_ident(),
+                kind: TypeInfoKind::Object(def.name.clone()),
+            }),
+            Type::Interface(def) => Some(TypeInfo {
+                marker_ident: def.marker_ident(),
+                kind: TypeInfoKind::Interface(
+                    def.name.clone(),
+                    implementations
+                        .get(def.name.as_ref())
+                        .cloned()
+                        .unwrap_or_default(),
+                ),
+            }),
+            Type::Union(def) => Some(TypeInfo {
+                marker_ident: def.marker_ident(),
+                kind: TypeInfoKind::Union(
+                    def.name.clone(),
+                    def.types.iter().map(ToString::to_string).collect(),
+                ),
+            }),
+        }
+    }
+}
+
+impl quote::ToTokens for TypeInfo<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        use quote::{quote, TokenStreamExt};
+
+        let target_struct = self.marker_ident.to_rust_ident();
+        let type_info = match &self.kind {
+            TypeInfoKind::Scalar(name) => {
+                let name = name.as_ref();
+                quote! { cynic::schema::TypeInfo::Scalar(#name) }
+            }
+            TypeInfoKind::Enum(name, values) => {
+                let name = name.as_ref();
+                quote! {
+                    cynic::schema::TypeInfo::Enum {
+                        name: #name,
+                        values: &[#(#values),*],
+                    }
+                }
+            }
+            TypeInfoKind::Object(name) => {
+                let name = name.as_ref();
+                quote! { cynic::schema::TypeInfo::Object(#name) }
+            }
+            TypeInfoKind::Interface(name, possible_types) => {
+                let name = name.as_ref();
+                quote! {
+                    cynic::schema::TypeInfo::Interface {
+                        name: #name,
+                        possible_types: &[#(#possible_types),*],
+                    }
+                }
+            }
+            TypeInfoKind::Union(name, possible_types) => {
+                let name = name.as_ref();
+                quote! {
+                    cynic::schema::TypeInfo::Union {
+                        name: #name,
+                        possible_types: &[#(#possible_types),*],
+                    }
+                }
+            }
+        };
+
+        tokens.append_all(quote! {
+            cynic::__impl_has_type_info!(#target_struct, #type_info);
+        });
+    }
+}
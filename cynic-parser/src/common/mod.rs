@@ -4,7 +4,7 @@ mod types;
 pub use id_range::IdRange;
 pub use types::*;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum OperationType {
     Query,
     Mutation,
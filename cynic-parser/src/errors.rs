@@ -1,5 +1,5 @@
 #[cfg(feature = "report")]
-mod report;
+pub(crate) mod report;
 
 use std::fmt;
 
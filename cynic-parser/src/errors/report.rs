@@ -9,6 +9,12 @@ pub struct Report<'doc> {
     document: &'doc str,
 }
 
+impl<'doc> Report<'doc> {
+    pub(crate) fn new(inner: ariadne::Report<'static>, document: &'doc str) -> Self {
+        Report { inner, document }
+    }
+}
+
 impl Error {
     pub fn to_report<'a>(&self, document: &'a str) -> Report<'a> {
         let (message, label, note) = self.components();
@@ -1,4 +1,4 @@
-use crate::AstLookup;
+use crate::{AstLookup, Span};
 
 use super::{
     ids::{ArgumentId, StringId, ValueId},
@@ -9,6 +9,7 @@ use super::{
 pub struct ArgumentRecord {
     pub name: StringId,
     pub value: ValueId,
+    pub span: Span,
 }
 
 #[derive(Clone, Copy)]
@@ -25,6 +26,11 @@ impl<'a> Argument<'a> {
 
         ast.read(ast.lookup(self.0.id).value)
     }
+
+    /// The span of this argument, including its value
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for ArgumentId {
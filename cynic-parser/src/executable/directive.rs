@@ -1,4 +1,4 @@
-use crate::{common::IdRange, AstLookup};
+use crate::{common::IdRange, AstLookup, Span};
 
 use super::{
     argument::Argument,
@@ -9,6 +9,7 @@ use super::{
 pub struct DirectiveRecord {
     pub name: StringId,
     pub arguments: IdRange<ArgumentId>,
+    pub span: Span,
 }
 
 #[derive(Clone, Copy)]
@@ -29,6 +30,11 @@ impl<'a> Directive<'a> {
             .iter()
             .map(|id| ast.read(id))
     }
+
+    /// The span of the `@` & name of this directive
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for DirectiveId {
@@ -1,4 +1,4 @@
-use crate::{common::IdRange, AstLookup};
+use crate::{common::IdRange, AstLookup, Span};
 
 use super::{
     directive::Directive,
@@ -12,6 +12,7 @@ pub struct FragmentDefinitionRecord {
     pub type_condition: StringId,
     pub directives: IdRange<DirectiveId>,
     pub selection_set: IdRange<SelectionId>,
+    pub span: Span,
 }
 
 #[derive(Clone, Copy)]
@@ -45,6 +46,11 @@ impl<'a> FragmentDefinition<'a> {
             .iter()
             .map(|id| self.0.document.read(id))
     }
+
+    /// The span of the name & type condition of this fragment
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for FragmentDefinitionId {
@@ -1,6 +1,6 @@
 use crate::{
     common::{IdRange, OperationType},
-    AstLookup,
+    AstLookup, Span,
 };
 
 use super::{
@@ -17,6 +17,7 @@ pub struct OperationDefinitionRecord {
     pub variable_definitions: IdRange<VariableDefinitionId>,
     pub directives: IdRange<DirectiveId>,
     pub selection_set: IdRange<SelectionId>,
+    pub span: Span,
 }
 
 #[derive(Clone, Copy)]
@@ -59,6 +60,13 @@ impl<'a> OperationDefinition<'a> {
             .iter()
             .map(|id| self.0.document.read(id))
     }
+
+    /// The span of the operation type & name of this operation.
+    ///
+    /// This is empty for query shorthand operations
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for OperationDefinitionId {
@@ -1,4 +1,4 @@
-use crate::{common::IdRange, AstLookup};
+use crate::{common::IdRange, AstLookup, Span};
 
 use super::{
     argument::Argument,
@@ -22,19 +22,23 @@ pub struct FieldSelectionRecord {
     pub arguments: IdRange<ArgumentId>,
     pub directives: IdRange<DirectiveId>,
     pub selection_set: IdRange<SelectionId>,
+    pub span: Span,
 }
 
 pub struct InlineFragmentRecord {
     pub type_condition: Option<StringId>,
     pub directives: IdRange<DirectiveId>,
     pub selection_set: IdRange<SelectionId>,
+    pub span: Span,
 }
 
 pub struct FragmentSpreadRecord {
     pub fragment_name: StringId,
     pub directives: IdRange<DirectiveId>,
+    pub span: Span,
 }
 
+#[derive(Clone, Copy)]
 pub enum Selection<'a> {
     Field(FieldSelection<'a>),
     InlineFragment(InlineFragment<'a>),
@@ -103,6 +107,11 @@ impl<'a> FieldSelection<'a> {
             .iter()
             .map(|id| self.0.document.read(id))
     }
+
+    /// The span of the alias & name of this field
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for FieldSelectionId {
@@ -143,6 +152,11 @@ impl<'a> InlineFragment<'a> {
             .iter()
             .map(|id| self.0.document.read(id))
     }
+
+    /// The span of the `...` & type condition of this fragment
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for InlineFragmentId {
@@ -173,6 +187,11 @@ impl<'a> FragmentSpread<'a> {
             .iter()
             .map(|id| self.0.document.read(id))
     }
+
+    /// The span of the `...` & fragment name of this spread
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for FragmentSpreadId {
@@ -1,4 +1,4 @@
-use crate::{common::IdRange, AstLookup};
+use crate::{common::IdRange, AstLookup, Span};
 
 use super::{
     directive::Directive,
@@ -13,6 +13,7 @@ pub struct VariableDefinitionRecord {
     pub ty: TypeId,
     pub default_value: Option<ValueId>,
     pub directives: IdRange<DirectiveId>,
+    pub span: Span,
 }
 
 #[derive(Clone, Copy)]
@@ -46,6 +47,11 @@ impl<'a> VariableDefinition<'a> {
             .iter()
             .map(|id| self.0.document.read(id))
     }
+
+    /// The span of the name of this variable, including the `$`
+    pub fn span(&self) -> Span {
+        self.0.document.lookup(self.0.id).span
+    }
 }
 
 impl ExecutableId for VariableDefinitionId {
@@ -1,6 +1,7 @@
 pub mod common;
 pub mod executable;
 pub mod type_system;
+pub mod validation;
 
 mod errors;
 mod lexer;
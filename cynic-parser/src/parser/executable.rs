@@ -1,9 +1,10 @@
 // auto-generated: "lalrpop 0.20.0"
-// sha3: b77e15abc1d8e6bc1fe79b787991aad929cc351c539cf9db62f2286c15794716
+// sha3: 9329535dccb59e5c6fd7de9ca22e667e349ca222f80dcd103866fc1a3692f29f
 use crate::lexer;
 use crate::{
     common::{IdRange, OperationType, TypeWrappers, WrappingType},
     executable::{ids::*, storage::*, writer::ExecutableAstWriter},
+    Span,
 };
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
@@ -21,7 +22,8 @@ mod __parse__ExecutableDocument {
     executable::{
         storage::*, ids::*, writer::ExecutableAstWriter
     },
-    common::{OperationType, IdRange, WrappingType, TypeWrappers}
+    common::{OperationType, IdRange, WrappingType, TypeWrappers},
+    Span
 };
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -35,34 +37,35 @@ mod __parse__ExecutableDocument {
      {
         Variant0(lexer::Token<'input>),
         Variant1(&'input str),
-        Variant2(StringId),
-        Variant3(core::option::Option<StringId>),
-        Variant4(ArgumentRecord),
-        Variant5(alloc::vec::Vec<ArgumentRecord>),
-        Variant6(Vec<ArgumentRecord>),
-        Variant7(core::option::Option<Vec<ArgumentRecord>>),
-        Variant8(ValueId),
-        Variant9(core::option::Option<ValueId>),
-        Variant10(()),
-        Variant11(alloc::vec::Vec<()>),
-        Variant12(IdRange<DirectiveId>),
-        Variant13(ExecutableDefinitionId),
-        Variant14(alloc::vec::Vec<ExecutableDefinitionId>),
-        Variant15(FragmentDefinitionRecord),
-        Variant16((StringId, ValueId)),
-        Variant17(alloc::vec::Vec<(StringId, ValueId)>),
-        Variant18(OperationDefinitionRecord),
-        Variant19(OperationType),
-        Variant20(SelectionRecord),
-        Variant21(alloc::vec::Vec<SelectionRecord>),
-        Variant22(IdRange<SelectionId>),
-        Variant23(core::option::Option<IdRange<SelectionId>>),
-        Variant24(TypeId),
-        Variant25(alloc::vec::Vec<ValueId>),
-        Variant26(Vec<()>),
-        Variant27(core::option::Option<Vec<()>>),
-        Variant28(WrappingType),
-        Variant29(alloc::vec::Vec<WrappingType>),
+        Variant2(usize),
+        Variant3(StringId),
+        Variant4(core::option::Option<StringId>),
+        Variant5(ArgumentRecord),
+        Variant6(alloc::vec::Vec<ArgumentRecord>),
+        Variant7(Vec<ArgumentRecord>),
+        Variant8(core::option::Option<Vec<ArgumentRecord>>),
+        Variant9(ValueId),
+        Variant10(core::option::Option<ValueId>),
+        Variant11(()),
+        Variant12(alloc::vec::Vec<()>),
+        Variant13(IdRange<DirectiveId>),
+        Variant14(ExecutableDefinitionId),
+        Variant15(alloc::vec::Vec<ExecutableDefinitionId>),
+        Variant16(FragmentDefinitionRecord),
+        Variant17((StringId, ValueId)),
+        Variant18(alloc::vec::Vec<(StringId, ValueId)>),
+        Variant19(OperationDefinitionRecord),
+        Variant20(OperationType),
+        Variant21(SelectionRecord),
+        Variant22(alloc::vec::Vec<SelectionRecord>),
+        Variant23(IdRange<SelectionId>),
+        Variant24(core::option::Option<IdRange<SelectionId>>),
+        Variant25(TypeId),
+        Variant26(alloc::vec::Vec<ValueId>),
+        Variant27(Vec<()>),
+        Variant28(core::option::Option<Vec<()>>),
+        Variant29(WrappingType),
+        Variant30(alloc::vec::Vec<WrappingType>),
     }
     const __ACTION: &[i16] = &[
         // State 0
@@ -70,19 +73,19 @@ mod __parse__ExecutableDocument {
         // State 1
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 59, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0,
         // State 2
-        0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 65, -22, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
+        0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 65, -24, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 3
         0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 4
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 88, 0, 89, 90, 91, 92, 93, 94, 95, 96, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105,
         // State 5
-        0, -23, 0, 0, -23, -23, 0, 0, 11, 0, 0, -23, -23, 0, -23, 0, 0, 0, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
+        0, -25, 0, 0, -25, -25, 0, 0, 11, 0, 0, -25, -25, 0, -25, 0, 0, 0, -25, 0, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
         // State 6
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
         0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
@@ -90,17 +93,17 @@ mod __parse__ExecutableDocument {
         // State 11
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 12
-        0, 0, 0, 26, 0, -22, 109, 0, 11, 0, 0, -22, -22, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        0, 0, 0, 26, 0, -24, 109, 0, 11, 0, 0, -24, -24, 0, -24, 0, 0, 0, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 13
         0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 65, 0, 0, 111, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 87, -22, 0, 0, 0, 0, 0, 88, 0, 89, 90, 91, 92, 93, 94, 95, 96, 97, 30, 98, 99, 100, 101, 102, 103, 104, 105,
+        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 87, -24, 0, 0, 0, 0, 0, 88, 0, 89, 90, 91, 92, 93, 94, 95, 96, 97, 30, 98, 99, 100, 101, 102, 103, 104, 105,
         // State 15
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
@@ -108,33 +111,33 @@ mod __parse__ExecutableDocument {
         // State 20
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 21
-        0, -17, 0, 26, -17, -17, 0, 0, -17, 0, 0, -17, -17, 0, -17, 0, 0, 0, -17, 0, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17,
+        0, -19, 0, 26, -19, -19, 0, 0, -19, 0, 0, -19, -19, 0, -19, 0, 0, 0, -19, 0, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
         // State 22
-        0, 0, 0, 26, 0, -22, 0, 0, 11, 0, 0, -22, -22, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        0, 0, 0, 26, 0, -24, 0, 0, 11, 0, 0, -24, -24, 0, -24, 0, 0, 0, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 23
-        0, 0, 0, 0, 0, -22, 0, 0, 11, 0, 0, -22, -22, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        0, 0, 0, 0, 0, -24, 0, 0, 11, 0, 0, -24, -24, 0, -24, 0, 0, 0, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 24
-        0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, -102, 4, 0, -102, 0, 0, 0, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102,
+        0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, -104, 4, 0, -104, 0, 0, 0, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104,
         // State 25
         0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 26
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, -22, 0, 0, 11, 0, 0, -22, 0, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        0, 0, 0, 0, 0, -24, 0, 0, 11, 0, 0, -24, 0, 0, -24, 0, 0, 0, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, -22, 0, 0, 11, 0, 0, -22, -22, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        0, 0, 0, 0, 0, -24, 0, 0, 11, 0, 0, -24, -24, 0, -24, 0, 0, 0, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 33
-        0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, -98, 4, 0, -98, 0, 0, 0, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98,
-        // State 34
         0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, -100, 4, 0, -100, 0, 0, 0, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100,
+        // State 34
+        0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, -102, 4, 0, -102, 0, 0, 0, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102,
         // State 35
         0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 36
@@ -144,13 +147,13 @@ mod __parse__ExecutableDocument {
         // State 38
         0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 39
-        0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, -96, 4, 0, -96, 0, 0, 0, -96, 0, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+        0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, -98, 4, 0, -98, 0, 0, 0, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98,
         // State 40
         0, 45, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 47, 0, 0, 136, 137, 138, 139, 140, 0, 0, 141, 0, 0, 142, 0, 0, 143, 0, 144, 0, 0, 145, 0, 146, 147, 0,
         // State 41
-        149, -114, 0, 0, -114, 0, 0, -114, -114, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        149, -116, 0, 0, -116, 0, 0, -116, -116, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, -22, 0, 0, -22, 0, 0, 50, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -24, 0, 0, -24, 0, 0, 50, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
         0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 65, 0, 0, 0, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 44
@@ -160,9 +163,9 @@ mod __parse__ExecutableDocument {
         // State 46
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 158, 0, 0, 0, 66, 0, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
         // State 47
-        149, -115, 0, 0, -115, 0, 0, -115, -115, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        149, -117, 0, 0, -117, 0, 0, -117, -117, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, -22, 0, 0, -22, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -24, 0, 0, -24, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
         0, 45, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 47, 0, 0, 136, 137, 138, 139, 140, 0, 0, 141, 0, 0, 142, 0, 0, 143, 0, 144, 0, 0, 145, 0, 146, 147, 0,
         // State 50
@@ -172,240 +175,240 @@ mod __parse__ExecutableDocument {
         // State 52
         0, 45, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 47, 0, 0, 136, 137, 138, 139, 140, 0, 0, 141, 0, 0, 142, 0, 0, 143, 0, 144, 0, 0, 145, 0, 146, 147, 0,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, -33, 0, 0, 0, -33, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, -35, 0, 0, -35, 0, 0, 0, -35, 0, 0, 0,
         // State 54
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, 0, 0, 0, -30, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, 0, 0, 0, -32, 0, 0, 0,
         // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, -29, 0, 0, -29, 0, 0, 0, -29, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, 0, 0, 0, -31, 0, 0, 0,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, 0, 0, 0, -72, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, -74, 0, 0, -74, 0, 0, 0, -74, 0, 0, 0,
         // State 58
-        0, 0, 0, -74, 0, 0, 0, 0, -74, 0, 0, -74, -74, 0, 0, 0, 0, 0, -74, 0, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74,
+        0, 0, 0, -76, 0, 0, 0, 0, -76, 0, 0, -76, -76, 0, 0, 0, 0, 0, -76, 0, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76,
         // State 59
-        0, 0, 0, -73, 0, 0, 0, 0, -73, 0, 0, -73, -73, 0, 0, 0, 0, 0, -73, 0, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73,
-        // State 60
         0, 0, 0, -75, 0, 0, 0, 0, -75, 0, 0, -75, -75, 0, 0, 0, 0, 0, -75, 0, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75,
+        // State 60
+        0, 0, 0, -77, 0, 0, 0, 0, -77, 0, 0, -77, -77, 0, 0, 0, 0, 0, -77, 0, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77,
         // State 61
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, 0, 0, 0, -34, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, -36, 0, 0, -36, 0, 0, 0, -36, 0, 0, 0,
         // State 62
-        0, -20, 0, 0, -20, -20, 0, 0, -20, 0, 0, -20, -20, 0, -20, 0, 0, 0, -20, 0, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
         // State 63
-        0, -59, 0, -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59,
+        0, -61, 0, -61, -61, -61, -61, 0, -61, -61, -61, -61, -61, 0, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61,
         // State 64
-        -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51,
+        -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53,
         // State 65
-        -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
         // State 66
-        -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55,
+        -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57,
         // State 67
-        -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54,
+        -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56,
         // State 68
-        -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
+        -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
         // State 69
-        -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58,
+        -60, -60, 0, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60,
         // State 70
-        -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
+        -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51,
         // State 71
-        -45, -45, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
+        -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
         // State 72
-        -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50,
+        -52, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52,
         // State 73
-        -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
+        -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
         // State 74
-        -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
+        -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50,
         // State 75
-        -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57,
+        -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59,
         // State 76
-        -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43,
         // State 77
-        -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56,
+        -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58,
         // State 78
-        -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53,
+        -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55,
         // State 79
-        -40, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
+        -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
         // State 80
-        -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43,
+        -45, -45, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
         // State 81
-        -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
+        -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
         // State 82
-        -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
+        -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
         // State 83
-        -52, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52,
+        -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54,
         // State 84
-        0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, -106, 0, 0, -106, 0, 0, 0, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106,
+        0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108,
         // State 85
-        0, 0, 0, 0, 0, -38, 0, 0, -38, 0, 0, -38, 0, 0, -38, 0, 0, 0, -38, 0, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        0, 0, 0, 0, 0, -40, 0, 0, -40, 0, 0, -40, 0, 0, -40, 0, 0, 0, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
         // State 86
-        0, 0, 0, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, 0, -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88,
+        0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, -90, 0, 0, -90, 0, 0, 0, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90,
         // State 87
-        0, 0, 0, 0, 0, -76, 0, 0, -76, 0, 0, -76, 0, 0, -76, 0, 0, 0, -76, 0, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76,
+        0, 0, 0, 0, 0, -78, 0, 0, -78, 0, 0, -78, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78,
         // State 88
-        0, 0, 0, 0, 0, -92, 0, 0, -92, 0, 0, -92, 0, 0, -92, 0, 0, 0, -92, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92,
+        0, 0, 0, 0, 0, -94, 0, 0, -94, 0, 0, -94, 0, 0, -94, 0, 0, 0, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94,
         // State 89
-        0, 0, 0, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, 0, 0, -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91,
+        0, 0, 0, 0, 0, -93, 0, 0, -93, 0, 0, -93, 0, 0, -93, 0, 0, 0, -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93,
         // State 90
-        0, 0, 0, 0, 0, -84, 0, 0, -84, 0, 0, -84, 0, 0, -84, 0, 0, 0, -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84,
+        0, 0, 0, 0, 0, -86, 0, 0, -86, 0, 0, -86, 0, 0, -86, 0, 0, 0, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86,
         // State 91
-        0, 0, 0, 0, 0, -94, 0, 0, -94, 0, 0, -94, 0, 0, -94, 0, 0, 0, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94,
+        0, 0, 0, 0, 0, -96, 0, 0, -96, 0, 0, -96, 0, 0, -96, 0, 0, 0, -96, 0, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
         // State 92
-        0, 0, 0, 0, 0, -86, 0, 0, -86, 0, 0, -86, 0, 0, -86, 0, 0, 0, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86,
+        0, 0, 0, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, 0, -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88,
         // State 93
-        0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, -82, 0, 0, -82, 0, 0, 0, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82,
+        0, 0, 0, 0, 0, -84, 0, 0, -84, 0, 0, -84, 0, 0, -84, 0, 0, 0, -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84,
         // State 94
-        0, 0, 0, 0, 0, -87, 0, 0, -87, 0, 0, -87, 0, 0, -87, 0, 0, 0, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87,
+        0, 0, 0, 0, 0, -89, 0, 0, -89, 0, 0, -89, 0, 0, -89, 0, 0, 0, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89,
         // State 95
-        0, 0, 0, 0, 0, -79, 0, 0, -79, 0, 0, -79, 0, 0, -79, 0, 0, 0, -79, 0, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79,
+        0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, -81, 0, 0, -81, 0, 0, 0, -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81,
         // State 96
-        0, 0, 0, 0, 0, -85, 0, 0, -85, 0, 0, -85, 0, 0, -85, 0, 0, 0, -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85,
+        0, 0, 0, 0, 0, -87, 0, 0, -87, 0, 0, -87, 0, 0, -87, 0, 0, 0, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87,
         // State 97
-        0, 0, 0, 0, 0, -78, 0, 0, -78, 0, 0, -78, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78,
+        0, 0, 0, 0, 0, -80, 0, 0, -80, 0, 0, -80, 0, 0, -80, 0, 0, 0, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80,
         // State 98
-        0, 0, 0, 0, 0, -93, 0, 0, -93, 0, 0, -93, 0, 0, -93, 0, 0, 0, -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93,
+        0, 0, 0, 0, 0, -95, 0, 0, -95, 0, 0, -95, 0, 0, -95, 0, 0, 0, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95,
         // State 99
-        0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, -90, 0, 0, -90, 0, 0, 0, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90,
+        0, 0, 0, 0, 0, -92, 0, 0, -92, 0, 0, -92, 0, 0, -92, 0, 0, 0, -92, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92,
         // State 100
-        0, 0, 0, 0, 0, -77, 0, 0, -77, 0, 0, -77, 0, 0, -77, 0, 0, 0, -77, 0, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77,
+        0, 0, 0, 0, 0, -79, 0, 0, -79, 0, 0, -79, 0, 0, -79, 0, 0, 0, -79, 0, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79,
         // State 101
-        0, 0, 0, 0, 0, -80, 0, 0, -80, 0, 0, -80, 0, 0, -80, 0, 0, 0, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80,
+        0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, -82, 0, 0, -82, 0, 0, 0, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82,
         // State 102
-        0, 0, 0, 0, 0, -83, 0, 0, -83, 0, 0, -83, 0, 0, -83, 0, 0, 0, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83,
+        0, 0, 0, 0, 0, -85, 0, 0, -85, 0, 0, -85, 0, 0, -85, 0, 0, 0, -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85,
         // State 103
-        0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, -81, 0, 0, -81, 0, 0, 0, -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81,
+        0, 0, 0, 0, 0, -83, 0, 0, -83, 0, 0, -83, 0, 0, -83, 0, 0, 0, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83,
         // State 104
-        0, 0, 0, 0, 0, -89, 0, 0, -89, 0, 0, -89, 0, 0, -89, 0, 0, 0, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89,
+        0, 0, 0, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, 0, 0, -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91,
         // State 105
-        0, -21, 0, 0, -21, -21, 0, 0, -21, 0, 0, -21, -21, 0, -21, 0, 0, 0, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
+        0, -23, 0, 0, -23, -23, 0, 0, -23, 0, 0, -23, -23, 0, -23, 0, 0, 0, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
         // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, -73, 0, 0, -73, 0, 0, 0, -73, 0, 0, 0,
         // State 107
-        0, -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, 0, 0, 0, 0, 0, -1, 0, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, -3, 0, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3,
         // State 109
-        0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107,
+        0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109,
         // State 110
-        0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, -108, -108, 0, -108, 0, 0, 0, -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108,
+        0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, -110, -110, 0, -110, 0, 0, 0, -110, 0, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110,
         // State 111
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, -69, 0, 0, 0, -69, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0,
         // State 112
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, -70, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, 0, 0, 0, -72, 0, 0, 0,
         // State 113
-        0, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -141, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 115
         0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 116
-        0, -16, 0, 0, -16, -16, 0, 0, -16, 0, 0, -16, -16, 0, -16, 0, 0, 0, -16, 0, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16,
+        0, -18, 0, 0, -18, -18, 0, 0, -18, 0, 0, -18, -18, 0, -18, 0, 0, 0, -18, 0, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
         // State 117
-        0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, -101, 0, 0, -101, 0, 0, 0, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101,
+        0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, -103, 0, 0, -103, 0, 0, 0, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103,
         // State 118
-        0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, -7, 0, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, -9, 0, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
         // State 119
         0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        0, -9, 0, 0, -9, -9, 0, 0, -9, 0, 0, -9, -9, 0, -9, 0, 0, 0, -9, 0, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
+        0, -11, 0, 0, -11, -11, 0, 0, -11, 0, 0, -11, -11, 0, -11, 0, 0, 0, -11, 0, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
         // State 121
-        0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, -105, 0, 0, -105, 0, 0, 0, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105,
+        0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107,
         // State 122
-        0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, -103, 0, 0, -103, 0, 0, 0, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103,
+        0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, -105, 0, 0, -105, 0, 0, 0, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105,
         // State 123
-        -62, -62, 0, 0, -62, 0, 0, -62, -62, 0, -62, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -64, -64, 0, 0, -64, 0, 0, -64, -64, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, -68, 0, 0, 0, -68, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, -70, 0, 0, 0,
         // State 126
-        0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, -97, 0, 0, -97, 0, 0, 0, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97,
-        // State 127
         0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, -99, 0, 0, -99, 0, 0, 0, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99,
+        // State 127
+        0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, -101, 0, 0, -101, 0, 0, 0, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101,
         // State 128
-        0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, -8, 0, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, -10, 0, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
         // State 129
-        0, -10, 0, 0, -10, -10, 0, 0, -10, 0, 0, -10, -10, 0, -10, 0, 0, 0, -10, 0, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
+        0, -12, 0, 0, -12, -12, 0, 0, -12, 0, 0, -12, -12, 0, -12, 0, 0, 0, -12, 0, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
         // State 130
-        0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, -104, 0, 0, -104, 0, 0, 0, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104,
+        0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, -106, 0, 0, -106, 0, 0, 0, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106,
         // State 131
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, -37, 0, 0, -37, 0, 0, 0, -37, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, -39, 0, 0, -39, 0, 0, 0, -39, 0, 0, 0,
         // State 132
-        0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, -95, 0, 0, -95, 0, 0, 0, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95,
+        0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, -97, 0, 0, -97, 0, 0, 0, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97,
         // State 133
-        0, -131, 0, 0, -131, 0, 0, 0, -131, -131, -131, -131, -131, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131,
+        0, -133, 0, 0, -133, 0, 0, 0, -133, -133, -133, -133, -133, 0, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133,
         // State 134
-        0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,
+        0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, -6, 0, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
         // State 135
-        0, -123, 0, 0, -123, 0, 0, 0, -123, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123,
+        0, -125, 0, 0, -125, 0, 0, 0, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125,
         // State 136
-        0, -121, 0, 0, -121, 0, 0, 0, -121, -121, -121, -121, -121, 0, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121,
+        0, -123, 0, 0, -123, 0, 0, 0, -123, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123,
         // State 137
-        0, -120, 0, 0, -120, 0, 0, 0, -120, -120, -120, -120, -120, 0, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120,
+        0, -122, 0, 0, -122, 0, 0, 0, -122, -122, -122, -122, -122, 0, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122,
         // State 138
-        0, -24, 0, 0, -24, 0, 0, 0, -24, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
+        0, -26, 0, 0, -26, 0, 0, 0, -26, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
         // State 139
-        0, -122, 0, 0, -122, 0, 0, 0, -122, -122, -122, -122, -122, 0, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122,
+        0, -124, 0, 0, -124, 0, 0, 0, -124, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124,
         // State 140
-        0, -125, 0, 0, -125, 0, 0, 0, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125,
+        0, -127, 0, 0, -127, 0, 0, 0, -127, -127, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127,
         // State 141
-        0, -28, 0, 0, -28, 0, 0, 0, -28, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
+        0, -30, 0, 0, -30, 0, 0, 0, -30, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30,
         // State 142
-        0, -126, 0, 0, -126, 0, 0, 0, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126,
+        0, -128, 0, 0, -128, 0, 0, 0, -128, -128, -128, -128, -128, 0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128,
         // State 143
-        0, -26, 0, 0, -26, 0, 0, 0, -26, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        0, -28, 0, 0, -28, 0, 0, 0, -28, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
         // State 144
-        0, -25, 0, 0, -25, 0, 0, 0, -25, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        0, -27, 0, 0, -27, 0, 0, 0, -27, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
         // State 145
-        0, -124, 0, 0, -124, 0, 0, 0, -124, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124,
+        0, -126, 0, 0, -126, 0, 0, 0, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126,
         // State 146
-        0, -27, 0, 0, -27, 0, 0, 0, -27, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
+        0, -29, 0, 0, -29, 0, 0, 0, -29, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
         // State 147
-        -147, -147, 0, 0, -147, 0, 0, -147, -147, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -149, -149, 0, 0, -149, 0, 0, -149, -149, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 148
-        -143, -143, 0, 0, -143, 0, 0, -143, -143, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -145, -145, 0, 0, -145, 0, 0, -145, -145, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 149
-        -144, -144, 0, 0, -144, 0, 0, -144, -144, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -146, -146, 0, 0, -146, 0, 0, -146, -146, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 150
-        0, -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 151
-        0, -113, 0, 0, -113, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -115, 0, 0, -115, 0, 0, -115, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 152
-        0, -119, 0, 0, -119, 0, 0, 0, -119, -119, -119, -119, -119, 0, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119,
+        0, -121, 0, 0, -121, 0, 0, 0, -121, -121, -121, -121, -121, 0, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121,
         // State 153
-        0, -134, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, -134, 0, 0, -134, -134, -134, -134, -134, 0, 0, -134, 0, 0, -134, 0, 0, -134, 0, -134, 0, 0, -134, 0, -134, -134, 0,
+        0, -136, 0, 0, 0, 0, 0, 0, 0, -136, -136, 0, -136, 0, 0, -136, -136, -136, -136, -136, 0, 0, -136, 0, 0, -136, 0, 0, -136, 0, -136, 0, 0, -136, 0, -136, -136, 0,
         // State 154
-        0, -127, 0, 0, -127, 0, 0, 0, -127, -127, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127,
+        0, -129, 0, 0, -129, 0, 0, 0, -129, -129, -129, -129, -129, 0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129,
         // State 155
         0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 156
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, -66, 0, 0, 0, -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, -68, 0, 0, 0, -68, 0, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68,
         // State 157
-        0, -129, 0, 0, -129, 0, 0, 0, -129, -129, -129, -129, -129, 0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129,
+        0, -131, 0, 0, -131, 0, 0, 0, -131, -131, -131, -131, -131, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131,
         // State 158
-        -148, -148, 0, 0, -148, 0, 0, -148, -148, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -150, -150, 0, 0, -150, 0, 0, -150, -150, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 159
-        0, -136, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 160
-        0, -13, 0, 0, -13, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -15, 0, 0, -15, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 161
-        0, -135, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, -135, 0, 0, -135, -135, -135, -135, -135, 0, 0, -135, 0, 0, -135, 0, 0, -135, 0, -135, 0, 0, -135, 0, -135, -135, 0,
+        0, -137, 0, 0, 0, 0, 0, 0, 0, -137, -137, 0, -137, 0, 0, -137, -137, -137, -137, -137, 0, 0, -137, 0, 0, -137, 0, 0, -137, 0, -137, 0, 0, -137, 0, -137, -137, 0,
         // State 162
-        0, -128, 0, 0, -128, 0, 0, 0, -128, -128, -128, -128, -128, 0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128,
+        0, -130, 0, 0, -130, 0, 0, 0, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130,
         // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, 0, -67, 0, 0, 0, -67, 0, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, -69, 0, 0, 0, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69,
         // State 164
-        0, -130, 0, 0, -130, 0, 0, 0, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130,
+        0, -132, 0, 0, -132, 0, 0, 0, -132, -132, -132, -132, -132, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132,
         // State 165
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, -63, 0, 0, 0, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, -65, 0, 0, 0, -65, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
         __ACTION[(state as usize) * 38 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
-        -35,
+        -37,
         // State 1
-        -36,
+        -38,
         // State 2
         0,
         // State 3
@@ -509,15 +512,15 @@ mod __parse__ExecutableDocument {
         // State 52
         0,
         // State 53
-        -33,
+        -35,
         // State 54
-        -149,
+        -151,
         // State 55
-        -30,
+        -32,
         // State 56
-        -29,
+        -31,
         // State 57
-        -72,
+        -74,
         // State 58
         0,
         // State 59
@@ -525,7 +528,7 @@ mod __parse__ExecutableDocument {
         // State 60
         0,
         // State 61
-        -34,
+        -36,
         // State 62
         0,
         // State 63
@@ -615,7 +618,7 @@ mod __parse__ExecutableDocument {
         // State 105
         0,
         // State 106
-        -71,
+        -73,
         // State 107
         0,
         // State 108
@@ -623,11 +626,11 @@ mod __parse__ExecutableDocument {
         // State 109
         0,
         // State 110
-        -108,
+        -110,
         // State 111
-        -69,
+        -71,
         // State 112
-        -70,
+        -72,
         // State 113
         0,
         // State 114
@@ -653,7 +656,7 @@ mod __parse__ExecutableDocument {
         // State 124
         0,
         // State 125
-        -68,
+        -70,
         // State 126
         0,
         // State 127
@@ -665,7 +668,7 @@ mod __parse__ExecutableDocument {
         // State 130
         0,
         // State 131
-        -37,
+        -39,
         // State 132
         0,
         // State 133
@@ -737,24 +740,24 @@ mod __parse__ExecutableDocument {
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            0 => 11,
-            2 => match state {
+            2 => 11,
+            4 => match state {
                 35 => 128,
                 _ => 118,
             },
-            4 => 35,
-            5 => match state {
+            6 => 35,
+            7 => match state {
                 22 => 32,
                 21 => 116,
                 _ => 23,
             },
-            7 => 48,
-            9 => match state {
+            9 => 48,
+            11 => match state {
                 5 => 105,
                 _ => 62,
             },
-            11 => 5,
-            12 => match state {
+            13 => 5,
+            14 => match state {
                 7 => 16,
                 8 => 18,
                 12 => 24,
@@ -770,23 +773,23 @@ mod __parse__ExecutableDocument {
                 48 => 159,
                 _ => 6,
             },
-            13 => 133,
-            14 => match state {
+            15 => 133,
+            16 => match state {
                 1 => 61,
                 _ => 53,
             },
-            16 => 1,
-            17 => 54,
-            18 => 55,
-            19 => match state {
+            18 => 1,
+            19 => 54,
+            20 => 55,
+            21 => match state {
                 14 => 27,
                 _ => 15,
             },
-            20 => match state {
+            22 => match state {
                 29 | 38 | 43 => 123,
                 _ => 63,
             },
-            21 => match state {
+            23 => match state {
                 2 => 7,
                 10 => 21,
                 11 => 22,
@@ -796,24 +799,24 @@ mod __parse__ExecutableDocument {
                 46 | 51 => 155,
                 _ => 12,
             },
-            23 => match state {
+            25 => match state {
                 29 => 124,
                 _ => 41,
             },
-            24 => match state {
+            26 => match state {
                 51 => 163,
                 _ => 156,
             },
-            26 => 51,
-            27 => 56,
-            28 => 2,
-            29 => 85,
-            30 => match state {
+            28 => 51,
+            29 => 56,
+            30 => 2,
+            31 => 85,
+            32 => match state {
                 13 => 109,
                 _ => 84,
             },
-            31 => 13,
-            32 => match state {
+            33 => 13,
+            34 => match state {
                 6 => 106,
                 16 => 111,
                 18 => 112,
@@ -827,36 +830,36 @@ mod __parse__ExecutableDocument {
                 39 => 132,
                 _ => 57,
             },
-            35 => match state {
+            37 => match state {
                 43 => 151,
                 _ => 42,
             },
-            36 => match state {
+            38 => match state {
                 15 => 30,
                 _ => 28,
             },
-            38 => match state {
+            40 => match state {
                 45 => 153,
                 49 => 160,
                 50 => 161,
                 52 => 165,
                 _ => 134,
             },
-            40 => 50,
-            41 => match state {
+            42 => 50,
+            43 => match state {
                 19 => 113,
                 _ => 107,
             },
-            42 => 19,
-            43 => match state {
+            44 => 19,
+            45 => match state {
                 7 => 17,
                 _ => 8,
             },
-            45 => match state {
+            47 => match state {
                 47 => 158,
                 _ => 147,
             },
-            47 => 47,
+            49 => 47,
             _ => 0,
         }
     }
@@ -927,14 +930,14 @@ mod __parse__ExecutableDocument {
         }).collect()
     }
     pub(crate) struct __StateMachine<'input, '__1>
-    where
+    where 
     {
         input: &'input str,
         ast: &'__1 mut ExecutableAstWriter,
         __phantom: core::marker::PhantomData<(&'input ())>,
     }
     impl<'input, '__1> __state_machine::ParserDefinition for __StateMachine<'input, '__1>
-    where
+    where 
     {
         type Location = usize;
         type Error = lexer::LexicalError;
@@ -1106,26 +1109,26 @@ mod __parse__ExecutableDocument {
         match __reduce_index {
             0 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 0,
                     nonterminal_produced: 0,
                 }
             }
             1 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 1,
                 }
             }
             2 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 1,
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
                 }
             }
             3 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 2,
+                    states_to_pop: 1,
+                    nonterminal_produced: 3,
                 }
             }
             4 => {
@@ -1136,194 +1139,194 @@ mod __parse__ExecutableDocument {
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 3,
+                    states_to_pop: 3,
+                    nonterminal_produced: 4,
                 }
             }
             6 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 4,
+                    states_to_pop: 0,
+                    nonterminal_produced: 5,
                 }
             }
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 4,
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
                 }
             }
             8 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 5,
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
                 }
             }
             9 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 5,
+                    states_to_pop: 2,
+                    nonterminal_produced: 6,
                 }
             }
             10 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 6,
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
                 }
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 6,
+                    states_to_pop: 3,
+                    nonterminal_produced: 7,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 7,
+                    states_to_pop: 1,
+                    nonterminal_produced: 8,
                 }
             }
             13 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 8,
                 }
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 8,
+                    states_to_pop: 2,
+                    nonterminal_produced: 9,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 9,
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 9,
+                    states_to_pop: 0,
+                    nonterminal_produced: 10,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 10,
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
                 }
             }
             18 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 10,
+                    states_to_pop: 2,
+                    nonterminal_produced: 11,
                 }
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 11,
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 11,
+                    states_to_pop: 1,
+                    nonterminal_produced: 12,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 12,
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 12,
+                    states_to_pop: 2,
+                    nonterminal_produced: 13,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    states_to_pop: 0,
+                    nonterminal_produced: 14,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 14,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 15,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 15,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 15,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 15,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 15,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 15,
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 16,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 0,
+                    nonterminal_produced: 17,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 2,
+                    nonterminal_produced: 18,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 18,
+                    states_to_pop: 0,
+                    nonterminal_produced: 19,
                 }
             }
             37 => {
@@ -1334,128 +1337,128 @@ mod __parse__ExecutableDocument {
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 5,
                     nonterminal_produced: 20,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 21,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 22,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 22,
                 }
             }
             59 => {
@@ -1466,533 +1469,545 @@ mod __parse__ExecutableDocument {
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 22,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 24,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 0,
                     nonterminal_produced: 24,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 25,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    states_to_pop: 3,
+                    nonterminal_produced: 26,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 0,
+                    nonterminal_produced: 27,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 26,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 27,
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 27,
+                    states_to_pop: 2,
+                    nonterminal_produced: 28,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 27,
+                    states_to_pop: 5,
+                    nonterminal_produced: 29,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 27,
+                    states_to_pop: 4,
+                    nonterminal_produced: 29,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    states_to_pop: 4,
+                    nonterminal_produced: 29,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 29,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 30,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 30,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 30,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             85 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             87 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             92 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             93 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 31,
                 }
             }
             94 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 30,
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
                 }
             }
             95 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
                 }
             }
             96 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    states_to_pop: 5,
+                    nonterminal_produced: 32,
                 }
             }
             97 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    states_to_pop: 4,
+                    nonterminal_produced: 32,
                 }
             }
             98 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 32,
                 }
             }
             99 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 32,
                 }
             }
             100 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    states_to_pop: 4,
+                    nonterminal_produced: 32,
                 }
             }
             101 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 30,
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
                 }
             }
             102 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 32,
                 }
             }
             103 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    states_to_pop: 2,
+                    nonterminal_produced: 32,
                 }
             }
             104 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 32,
                 }
             }
             105 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 31,
+                    states_to_pop: 4,
+                    nonterminal_produced: 32,
                 }
             }
             106 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 31,
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
                 }
             }
             107 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 32,
+                    states_to_pop: 1,
+                    nonterminal_produced: 33,
                 }
             }
             108 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 33,
                 }
             }
             109 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 33,
+                    states_to_pop: 3,
+                    nonterminal_produced: 34,
                 }
             }
             110 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 34,
+                    nonterminal_produced: 35,
                 }
             }
             111 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 34,
+                    states_to_pop: 0,
+                    nonterminal_produced: 35,
                 }
             }
             112 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 35,
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
                 }
             }
             113 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 36,
                 }
             }
             114 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 37,
                 }
             }
             115 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 36,
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
                 }
             }
             116 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 37,
                 }
             }
             117 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 37,
+                    states_to_pop: 2,
+                    nonterminal_produced: 38,
                 }
             }
             118 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 38,
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
                 }
             }
             119 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    states_to_pop: 0,
+                    nonterminal_produced: 39,
                 }
             }
             120 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    states_to_pop: 2,
+                    nonterminal_produced: 40,
                 }
             }
             121 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             122 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             123 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             124 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             125 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             126 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 38,
+                    states_to_pop: 1,
+                    nonterminal_produced: 40,
                 }
             }
             127 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 38,
+                    states_to_pop: 1,
+                    nonterminal_produced: 40,
                 }
             }
             128 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             129 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 40,
                 }
             }
             130 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    states_to_pop: 2,
+                    nonterminal_produced: 40,
                 }
             }
             131 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 39,
+                    states_to_pop: 3,
+                    nonterminal_produced: 40,
                 }
             }
             132 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 39,
+                    nonterminal_produced: 40,
                 }
             }
             133 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    states_to_pop: 0,
+                    nonterminal_produced: 41,
                 }
             }
             134 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 40,
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
                 }
             }
             135 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 41,
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
                 }
             }
             136 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 41,
+                    states_to_pop: 2,
+                    nonterminal_produced: 42,
                 }
             }
             137 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    states_to_pop: 6,
+                    nonterminal_produced: 43,
                 }
             }
             138 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 42,
+                    states_to_pop: 5,
+                    nonterminal_produced: 43,
                 }
             }
             139 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 43,
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
                 }
             }
             140 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 44,
                 }
             }
             141 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 44,
+                    states_to_pop: 3,
+                    nonterminal_produced: 45,
                 }
             }
             142 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 45,
+                    nonterminal_produced: 46,
                 }
             }
             143 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 45,
+                    states_to_pop: 0,
+                    nonterminal_produced: 46,
                 }
             }
             144 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 46,
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
                 }
             }
             145 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 47,
                 }
             }
             146 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 47,
+                    states_to_pop: 0,
+                    nonterminal_produced: 48,
                 }
             }
             147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            149 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 47,
+                    nonterminal_produced: 49,
                 }
             }
-            148 => __state_machine::SimulatedReduce::Accept,
+            150 => __state_machine::SimulatedReduce::Accept,
             _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
@@ -2523,8 +2538,14 @@ mod __parse__ExecutableDocument {
                 __reduce147(input, ast, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             148 => {
+                __reduce148(input, ast, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            149 => {
+                __reduce149(input, ast, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            150 => {
                 // __ExecutableDocument = ExecutableDocument => ActionFn(0);
-                let __sym0 = __pop_Variant10(__symbols);
+                let __sym0 = __pop_Variant11(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
                 let __nt = super::__action0::<>(input, ast, __sym0);
@@ -2543,311 +2564,311 @@ mod __parse__ExecutableDocument {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant10<
+    fn __pop_Variant11<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant17<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (StringId, ValueId), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ArgumentRecord, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ExecutableDefinitionId, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant16<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FragmentDefinitionRecord, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, IdRange<DirectiveId>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, IdRange<SelectionId>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, OperationDefinitionRecord, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, OperationType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant21<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SelectionRecord, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, StringId, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant25<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, TypeId, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ValueId, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant26<
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<()>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<ArgumentRecord>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant28<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, WrappingType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<()>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<(StringId, ValueId)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant6<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<ArgumentRecord>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<ExecutableDefinitionId>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SelectionRecord>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant25<
+    fn __pop_Variant26<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<ValueId>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant29<
+    fn __pop_Variant30<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<WrappingType>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<IdRange<SelectionId>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant4<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<StringId>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant10<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<ValueId>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant28<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Vec<()>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant8<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Vec<ArgumentRecord>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2862,6 +2883,17 @@ mod __parse__ExecutableDocument {
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant1<
       'input,
     >(
@@ -2873,7 +2905,41 @@ mod __parse__ExecutableDocument {
             _ => __symbol_type_mismatch()
         }
     }
-    pub(crate) fn __reduce0<
+    pub(crate) fn __reduce0<
+        'input,
+    >(
+        input: &'input str,
+        ast: &mut ExecutableAstWriter,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(117);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action117::<>(input, ast, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 0)
+    }
+    pub(crate) fn __reduce1<
+        'input,
+    >(
+        input: &'input str,
+        ast: &mut ExecutableAstWriter,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(114);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action114::<>(input, ast, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 1)
+    }
+    pub(crate) fn __reduce2<
         'input,
     >(
         input: &'input str,
@@ -2886,14 +2952,14 @@ mod __parse__ExecutableDocument {
         // Alias = Name, ":" => ActionFn(17);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action17::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 0)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 2)
     }
-    pub(crate) fn __reduce1<
+    pub(crate) fn __reduce3<
         'input,
     >(
         input: &'input str,
@@ -2904,14 +2970,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Alias? = Alias => ActionFn(104);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action104::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 1)
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 3)
     }
-    pub(crate) fn __reduce2<
+    pub(crate) fn __reduce4<
         'input,
     >(
         input: &'input str,
@@ -2925,10 +2991,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action105::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 1)
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 3)
     }
-    pub(crate) fn __reduce3<
+    pub(crate) fn __reduce5<
         'input,
     >(
         input: &'input str,
@@ -2938,18 +3004,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Argument = Name, ":", Value => ActionFn(27);
+        // Argument = Name, ":", Value => ActionFn(141);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action27::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 2)
+        let __nt = super::__action141::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 4)
     }
-    pub(crate) fn __reduce4<
+    pub(crate) fn __reduce6<
         'input,
     >(
         input: &'input str,
@@ -2963,10 +3029,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action92::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 3)
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 5)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce7<
         'input,
     >(
         input: &'input str,
@@ -2977,14 +3043,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Argument* = Argument+ => ActionFn(93);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action93::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 3)
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 5)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce8<
         'input,
     >(
         input: &'input str,
@@ -2994,15 +3060,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Argument+ = Argument => ActionFn(124);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Argument+ = Argument => ActionFn(126);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action124::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 4)
+        let __nt = super::__action126::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 6)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce9<
         'input,
     >(
         input: &'input str,
@@ -3012,17 +3078,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Argument+ = Argument+, Argument => ActionFn(125);
+        // Argument+ = Argument+, Argument => ActionFn(127);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant4(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action125::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 4)
+        let __nt = super::__action127::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 6)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce10<
         'input,
     >(
         input: &'input str,
@@ -3032,17 +3098,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Arguments = "(", ")" => ActionFn(132);
+        // Arguments = "(", ")" => ActionFn(151);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action132::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 5)
+        let __nt = super::__action151::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 7)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce11<
         'input,
     >(
         input: &'input str,
@@ -3052,18 +3118,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Arguments = "(", Argument+, ")" => ActionFn(133);
+        // Arguments = "(", Argument+, ")" => ActionFn(152);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action133::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 5)
+        let __nt = super::__action152::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 7)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce12<
         'input,
     >(
         input: &'input str,
@@ -3074,14 +3140,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Arguments? = Arguments => ActionFn(102);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action102::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 6)
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 8)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce13<
         'input,
     >(
         input: &'input str,
@@ -3095,10 +3161,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action103::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 6)
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 8)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce14<
         'input,
     >(
         input: &'input str,
@@ -3110,15 +3176,15 @@ mod __parse__ExecutableDocument {
     {
         // DefaultValue = "=", Value => ActionFn(12);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action12::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 7)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 9)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce15<
         'input,
     >(
         input: &'input str,
@@ -3129,14 +3195,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // DefaultValue? = DefaultValue => ActionFn(108);
-        let __sym0 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action108::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 8)
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 10)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce16<
         'input,
     >(
         input: &'input str,
@@ -3150,10 +3216,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action109::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 8)
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 10)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce17<
         'input,
     >(
         input: &'input str,
@@ -3163,18 +3229,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Directive = "@", Name, Arguments => ActionFn(134);
+        // Directive = "@", Name, Arguments => ActionFn(153);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant6(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action134::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (3, 9)
+        let __nt = super::__action153::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 11)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce18<
         'input,
     >(
         input: &'input str,
@@ -3184,17 +3250,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Directive = "@", Name => ActionFn(135);
+        // Directive = "@", Name => ActionFn(154);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action135::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 9)
+        let __nt = super::__action154::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 11)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce19<
         'input,
     >(
         input: &'input str,
@@ -3208,10 +3274,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action94::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 10)
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 12)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce20<
         'input,
     >(
         input: &'input str,
@@ -3222,14 +3288,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Directive* = Directive+ => ActionFn(95);
-        let __sym0 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action95::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 10)
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 12)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce21<
         'input,
     >(
         input: &'input str,
@@ -3239,15 +3305,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Directive+ = Directive => ActionFn(122);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Directive+ = Directive => ActionFn(124);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action122::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 11)
+        let __nt = super::__action124::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 13)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce22<
         'input,
     >(
         input: &'input str,
@@ -3257,17 +3323,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Directive+ = Directive+, Directive => ActionFn(123);
+        // Directive+ = Directive+, Directive => ActionFn(125);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action123::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 11)
+        let __nt = super::__action125::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 13)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce23<
         'input,
     >(
         input: &'input str,
@@ -3277,14 +3343,14 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Directives =  => ActionFn(142);
+        // Directives =  => ActionFn(161);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action142::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 12)
+        let __nt = super::__action161::<>(input, ast, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 14)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce24<
         'input,
     >(
         input: &'input str,
@@ -3294,15 +3360,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Directives = Directive+ => ActionFn(143);
-        let __sym0 = __pop_Variant11(__symbols);
+        // Directives = Directive+ => ActionFn(162);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action143::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 12)
+        let __nt = super::__action162::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 14)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce25<
         'input,
     >(
         input: &'input str,
@@ -3317,10 +3383,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action42::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 13)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce26<
         'input,
     >(
         input: &'input str,
@@ -3335,10 +3401,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action43::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 13)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce27<
         'input,
     >(
         input: &'input str,
@@ -3353,10 +3419,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action44::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 13)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce28<
         'input,
     >(
         input: &'input str,
@@ -3371,10 +3437,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action45::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 13)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce29<
         'input,
     >(
         input: &'input str,
@@ -3389,10 +3455,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action46::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 13)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce30<
         'input,
     >(
         input: &'input str,
@@ -3403,14 +3469,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // ExecutableDefinition = OperationDefinition => ActionFn(2);
-        let __sym0 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action2::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 14)
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 16)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce31<
         'input,
     >(
         input: &'input str,
@@ -3421,14 +3487,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // ExecutableDefinition = FragmentDefinition => ActionFn(3);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action3::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 14)
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 16)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce32<
         'input,
     >(
         input: &'input str,
@@ -3438,14 +3504,14 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ExecutableDefinition* =  => ActionFn(116);
+        // ExecutableDefinition* =  => ActionFn(118);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action116::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 15)
+        let __nt = super::__action118::<>(input, ast, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 17)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce33<
         'input,
     >(
         input: &'input str,
@@ -3455,15 +3521,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ExecutableDefinition* = ExecutableDefinition+ => ActionFn(117);
-        let __sym0 = __pop_Variant14(__symbols);
+        // ExecutableDefinition* = ExecutableDefinition+ => ActionFn(119);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action117::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 15)
+        let __nt = super::__action119::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 17)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce34<
         'input,
     >(
         input: &'input str,
@@ -3473,15 +3539,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ExecutableDefinition+ = ExecutableDefinition => ActionFn(118);
-        let __sym0 = __pop_Variant13(__symbols);
+        // ExecutableDefinition+ = ExecutableDefinition => ActionFn(120);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action118::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 16)
+        let __nt = super::__action120::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 18)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce35<
         'input,
     >(
         input: &'input str,
@@ -3491,17 +3557,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ExecutableDefinition+ = ExecutableDefinition+, ExecutableDefinition => ActionFn(119);
+        // ExecutableDefinition+ = ExecutableDefinition+, ExecutableDefinition => ActionFn(121);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action119::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 16)
+        let __nt = super::__action121::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 18)
     }
-    pub(crate) fn __reduce34<
+    pub(crate) fn __reduce36<
         'input,
     >(
         input: &'input str,
@@ -3511,14 +3577,14 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ExecutableDocument =  => ActionFn(144);
+        // ExecutableDocument =  => ActionFn(163);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action144::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (0, 17)
+        let __nt = super::__action163::<>(input, ast, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 19)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce37<
         'input,
     >(
         input: &'input str,
@@ -3528,15 +3594,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ExecutableDocument = ExecutableDefinition+ => ActionFn(145);
-        let __sym0 = __pop_Variant14(__symbols);
+        // ExecutableDocument = ExecutableDefinition+ => ActionFn(164);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action145::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        let __nt = super::__action164::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 19)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce38<
         'input,
     >(
         input: &'input str,
@@ -3546,20 +3612,20 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FragmentDefinition = fragment, FragmentName, TypeCondition, Directives, SelectionSet => ActionFn(6);
+        // FragmentDefinition = fragment, FragmentName, TypeCondition, Directives, SelectionSet => ActionFn(143);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant22(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action6::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (5, 18)
+        let __nt = super::__action143::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (5, 20)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce39<
         'input,
     >(
         input: &'input str,
@@ -3574,10 +3640,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action48::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 19)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 21)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce40<
         'input,
     >(
         input: &'input str,
@@ -3593,9 +3659,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action68::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce41<
         'input,
     >(
         input: &'input str,
@@ -3611,9 +3677,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action69::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce42<
         'input,
     >(
         input: &'input str,
@@ -3629,9 +3695,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action70::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce43<
         'input,
     >(
         input: &'input str,
@@ -3647,9 +3713,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action71::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce44<
         'input,
     >(
         input: &'input str,
@@ -3665,9 +3731,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action72::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce45<
         'input,
     >(
         input: &'input str,
@@ -3683,9 +3749,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action73::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce46<
         'input,
     >(
         input: &'input str,
@@ -3701,9 +3767,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action74::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce47<
         'input,
     >(
         input: &'input str,
@@ -3719,9 +3785,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action75::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce48<
         'input,
     >(
         input: &'input str,
@@ -3737,9 +3803,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action76::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce47<
+    pub(crate) fn __reduce49<
         'input,
     >(
         input: &'input str,
@@ -3755,9 +3821,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action77::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce48<
+    pub(crate) fn __reduce50<
         'input,
     >(
         input: &'input str,
@@ -3773,9 +3839,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action78::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce51<
         'input,
     >(
         input: &'input str,
@@ -3791,9 +3857,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action79::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce50<
+    pub(crate) fn __reduce52<
         'input,
     >(
         input: &'input str,
@@ -3809,9 +3875,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action80::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce51<
+    pub(crate) fn __reduce53<
         'input,
     >(
         input: &'input str,
@@ -3827,9 +3893,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action81::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce52<
+    pub(crate) fn __reduce54<
         'input,
     >(
         input: &'input str,
@@ -3845,9 +3911,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action82::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce53<
+    pub(crate) fn __reduce55<
         'input,
     >(
         input: &'input str,
@@ -3863,9 +3929,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action83::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce54<
+    pub(crate) fn __reduce56<
         'input,
     >(
         input: &'input str,
@@ -3881,9 +3947,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action84::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce55<
+    pub(crate) fn __reduce57<
         'input,
     >(
         input: &'input str,
@@ -3899,9 +3965,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action85::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce56<
+    pub(crate) fn __reduce58<
         'input,
     >(
         input: &'input str,
@@ -3917,9 +3983,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action86::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce57<
+    pub(crate) fn __reduce59<
         'input,
     >(
         input: &'input str,
@@ -3935,9 +4001,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action87::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        (1, 22)
     }
-    pub(crate) fn __reduce58<
+    pub(crate) fn __reduce60<
         'input,
     >(
         input: &'input str,
@@ -3952,10 +4018,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action47::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 21)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 23)
     }
-    pub(crate) fn __reduce59<
+    pub(crate) fn __reduce61<
         'input,
     >(
         input: &'input str,
@@ -3965,15 +4031,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Name? = Name => ActionFn(114);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Name? = Name => ActionFn(115);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action114::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 22)
+        let __nt = super::__action115::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
-    pub(crate) fn __reduce60<
+    pub(crate) fn __reduce62<
         'input,
     >(
         input: &'input str,
@@ -3983,14 +4049,14 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Name? =  => ActionFn(115);
+        // Name? =  => ActionFn(116);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action115::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 22)
+        let __nt = super::__action116::<>(input, ast, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 24)
     }
-    pub(crate) fn __reduce61<
+    pub(crate) fn __reduce63<
         'input,
     >(
         input: &'input str,
@@ -4005,10 +4071,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action19::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 23)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 25)
     }
-    pub(crate) fn __reduce62<
+    pub(crate) fn __reduce64<
         'input,
     >(
         input: &'input str,
@@ -4020,16 +4086,16 @@ mod __parse__ExecutableDocument {
     {
         // ObjectField = Name, ":", Value => ActionFn(39);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
         let __nt = super::__action39::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (3, 24)
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (3, 26)
     }
-    pub(crate) fn __reduce63<
+    pub(crate) fn __reduce65<
         'input,
     >(
         input: &'input str,
@@ -4043,10 +4109,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action88::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (0, 25)
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 27)
     }
-    pub(crate) fn __reduce64<
+    pub(crate) fn __reduce66<
         'input,
     >(
         input: &'input str,
@@ -4057,14 +4123,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // ObjectField* = ObjectField+ => ActionFn(89);
-        let __sym0 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action89::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 25)
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 27)
     }
-    pub(crate) fn __reduce65<
+    pub(crate) fn __reduce67<
         'input,
     >(
         input: &'input str,
@@ -4074,15 +4140,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ObjectField+ = ObjectField => ActionFn(128);
-        let __sym0 = __pop_Variant16(__symbols);
+        // ObjectField+ = ObjectField => ActionFn(130);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action128::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 26)
+        let __nt = super::__action130::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce66<
+    pub(crate) fn __reduce68<
         'input,
     >(
         input: &'input str,
@@ -4092,17 +4158,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ObjectField+ = ObjectField+, ObjectField => ActionFn(129);
+        // ObjectField+ = ObjectField+, ObjectField => ActionFn(131);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant16(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action129::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (2, 26)
+        let __nt = super::__action131::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 28)
     }
-    pub(crate) fn __reduce67<
+    pub(crate) fn __reduce69<
         'input,
     >(
         input: &'input str,
@@ -4112,20 +4178,20 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OperationDefinition = OperationType, Name, VariableDefinitions, Directives, SelectionSet => ActionFn(162);
+        // OperationDefinition = OperationType, Name, VariableDefinitions, Directives, SelectionSet => ActionFn(181);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant22(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant26(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant27(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant20(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action162::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (5, 27)
+        let __nt = super::__action181::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (5, 29)
     }
-    pub(crate) fn __reduce68<
+    pub(crate) fn __reduce70<
         'input,
     >(
         input: &'input str,
@@ -4135,19 +4201,19 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OperationDefinition = OperationType, Name, Directives, SelectionSet => ActionFn(163);
+        // OperationDefinition = OperationType, Name, Directives, SelectionSet => ActionFn(182);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant22(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant20(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action163::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (4, 27)
+        let __nt = super::__action182::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (4, 29)
     }
-    pub(crate) fn __reduce69<
+    pub(crate) fn __reduce71<
         'input,
     >(
         input: &'input str,
@@ -4157,19 +4223,19 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OperationDefinition = OperationType, VariableDefinitions, Directives, SelectionSet => ActionFn(164);
+        // OperationDefinition = OperationType, VariableDefinitions, Directives, SelectionSet => ActionFn(183);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant22(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant26(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant20(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action164::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (4, 27)
+        let __nt = super::__action183::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (4, 29)
     }
-    pub(crate) fn __reduce70<
+    pub(crate) fn __reduce72<
         'input,
     >(
         input: &'input str,
@@ -4179,18 +4245,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OperationDefinition = OperationType, Directives, SelectionSet => ActionFn(165);
+        // OperationDefinition = OperationType, Directives, SelectionSet => ActionFn(184);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant22(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant20(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action165::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (3, 27)
+        let __nt = super::__action184::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (3, 29)
     }
-    pub(crate) fn __reduce71<
+    pub(crate) fn __reduce73<
         'input,
     >(
         input: &'input str,
@@ -4200,15 +4266,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OperationDefinition = SelectionSet => ActionFn(5);
-        let __sym0 = __pop_Variant22(__symbols);
+        // OperationDefinition = SelectionSet => ActionFn(136);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 27)
+        let __nt = super::__action136::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 29)
     }
-    pub(crate) fn __reduce72<
+    pub(crate) fn __reduce74<
         'input,
     >(
         input: &'input str,
@@ -4223,10 +4289,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action7::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 28)
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 30)
     }
-    pub(crate) fn __reduce73<
+    pub(crate) fn __reduce75<
         'input,
     >(
         input: &'input str,
@@ -4241,10 +4307,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action8::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 28)
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 30)
     }
-    pub(crate) fn __reduce74<
+    pub(crate) fn __reduce76<
         'input,
     >(
         input: &'input str,
@@ -4259,10 +4325,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action9::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 28)
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 30)
     }
-    pub(crate) fn __reduce75<
+    pub(crate) fn __reduce77<
         'input,
     >(
         input: &'input str,
@@ -4278,9 +4344,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action49::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce76<
+    pub(crate) fn __reduce78<
         'input,
     >(
         input: &'input str,
@@ -4296,9 +4362,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action50::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce77<
+    pub(crate) fn __reduce79<
         'input,
     >(
         input: &'input str,
@@ -4314,9 +4380,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action51::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce78<
+    pub(crate) fn __reduce80<
         'input,
     >(
         input: &'input str,
@@ -4332,9 +4398,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action52::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce79<
+    pub(crate) fn __reduce81<
         'input,
     >(
         input: &'input str,
@@ -4350,9 +4416,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action53::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce80<
+    pub(crate) fn __reduce82<
         'input,
     >(
         input: &'input str,
@@ -4368,9 +4434,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action54::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce81<
+    pub(crate) fn __reduce83<
         'input,
     >(
         input: &'input str,
@@ -4386,9 +4452,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action55::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce82<
+    pub(crate) fn __reduce84<
         'input,
     >(
         input: &'input str,
@@ -4404,9 +4470,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action56::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce83<
+    pub(crate) fn __reduce85<
         'input,
     >(
         input: &'input str,
@@ -4422,9 +4488,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action57::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce84<
+    pub(crate) fn __reduce86<
         'input,
     >(
         input: &'input str,
@@ -4440,9 +4506,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action58::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce85<
+    pub(crate) fn __reduce87<
         'input,
     >(
         input: &'input str,
@@ -4458,9 +4524,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action59::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce86<
+    pub(crate) fn __reduce88<
         'input,
     >(
         input: &'input str,
@@ -4476,9 +4542,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action60::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce87<
+    pub(crate) fn __reduce89<
         'input,
     >(
         input: &'input str,
@@ -4494,9 +4560,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action61::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce88<
+    pub(crate) fn __reduce90<
         'input,
     >(
         input: &'input str,
@@ -4512,9 +4578,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action62::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce89<
+    pub(crate) fn __reduce91<
         'input,
     >(
         input: &'input str,
@@ -4530,9 +4596,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action63::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce90<
+    pub(crate) fn __reduce92<
         'input,
     >(
         input: &'input str,
@@ -4548,9 +4614,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action64::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce91<
+    pub(crate) fn __reduce93<
         'input,
     >(
         input: &'input str,
@@ -4566,9 +4632,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action65::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce92<
+    pub(crate) fn __reduce94<
         'input,
     >(
         input: &'input str,
@@ -4584,9 +4650,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action66::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce93<
+    pub(crate) fn __reduce95<
         'input,
     >(
         input: &'input str,
@@ -4602,9 +4668,9 @@ mod __parse__ExecutableDocument {
         let __end = __sym0.2;
         let __nt = super::__action67::<>(input, ast, __sym0);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        (1, 31)
     }
-    pub(crate) fn __reduce94<
+    pub(crate) fn __reduce96<
         'input,
     >(
         input: &'input str,
@@ -4614,20 +4680,20 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Alias, Name, Arguments, Directives, SelectionSet => ActionFn(150);
+        // Selection = Alias, Name, Arguments, Directives, SelectionSet => ActionFn(169);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant22(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant6(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action150::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (5, 30)
+        let __nt = super::__action169::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (5, 32)
     }
-    pub(crate) fn __reduce95<
+    pub(crate) fn __reduce97<
         'input,
     >(
         input: &'input str,
@@ -4637,19 +4703,19 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Alias, Name, Arguments, Directives => ActionFn(151);
+        // Selection = Alias, Name, Arguments, Directives => ActionFn(170);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant6(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action151::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (4, 30)
+        let __nt = super::__action170::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (4, 32)
     }
-    pub(crate) fn __reduce96<
+    pub(crate) fn __reduce98<
         'input,
     >(
         input: &'input str,
@@ -4659,19 +4725,19 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Alias, Name, Directives, SelectionSet => ActionFn(152);
+        // Selection = Alias, Name, Directives, SelectionSet => ActionFn(171);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant22(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action152::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (4, 30)
+        let __nt = super::__action171::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (4, 32)
     }
-    pub(crate) fn __reduce97<
+    pub(crate) fn __reduce99<
         'input,
     >(
         input: &'input str,
@@ -4681,18 +4747,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Alias, Name, Directives => ActionFn(153);
+        // Selection = Alias, Name, Directives => ActionFn(172);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action153::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (3, 30)
+        let __nt = super::__action172::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (3, 32)
     }
-    pub(crate) fn __reduce98<
+    pub(crate) fn __reduce100<
         'input,
     >(
         input: &'input str,
@@ -4702,19 +4768,19 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Name, Arguments, Directives, SelectionSet => ActionFn(154);
+        // Selection = Name, Arguments, Directives, SelectionSet => ActionFn(173);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant22(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action154::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (4, 30)
+        let __nt = super::__action173::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (4, 32)
     }
-    pub(crate) fn __reduce99<
+    pub(crate) fn __reduce101<
         'input,
     >(
         input: &'input str,
@@ -4724,18 +4790,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Name, Arguments, Directives => ActionFn(155);
+        // Selection = Name, Arguments, Directives => ActionFn(174);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action155::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (3, 30)
+        let __nt = super::__action174::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (3, 32)
     }
-    pub(crate) fn __reduce100<
+    pub(crate) fn __reduce102<
         'input,
     >(
         input: &'input str,
@@ -4745,18 +4811,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Name, Directives, SelectionSet => ActionFn(156);
+        // Selection = Name, Directives, SelectionSet => ActionFn(175);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant22(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action156::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (3, 30)
+        let __nt = super::__action175::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (3, 32)
     }
-    pub(crate) fn __reduce101<
+    pub(crate) fn __reduce103<
         'input,
     >(
         input: &'input str,
@@ -4766,17 +4832,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = Name, Directives => ActionFn(157);
+        // Selection = Name, Directives => ActionFn(176);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant12(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action157::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (2, 30)
+        let __nt = super::__action176::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 32)
     }
-    pub(crate) fn __reduce102<
+    pub(crate) fn __reduce104<
         'input,
     >(
         input: &'input str,
@@ -4786,18 +4852,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = "...", FragmentName, Directives => ActionFn(15);
+        // Selection = "...", FragmentName, Directives => ActionFn(146);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action15::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (3, 30)
+        let __nt = super::__action146::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (3, 32)
     }
-    pub(crate) fn __reduce103<
+    pub(crate) fn __reduce105<
         'input,
     >(
         input: &'input str,
@@ -4807,19 +4873,19 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = "...", TypeCondition, Directives, SelectionSet => ActionFn(158);
+        // Selection = "...", TypeCondition, Directives, SelectionSet => ActionFn(177);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant22(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action158::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (4, 30)
+        let __nt = super::__action177::<>(input, ast, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (4, 32)
     }
-    pub(crate) fn __reduce104<
+    pub(crate) fn __reduce106<
         'input,
     >(
         input: &'input str,
@@ -4829,18 +4895,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Selection = "...", Directives, SelectionSet => ActionFn(159);
+        // Selection = "...", Directives, SelectionSet => ActionFn(178);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant22(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action159::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (3, 30)
+        let __nt = super::__action178::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (3, 32)
     }
-    pub(crate) fn __reduce105<
+    pub(crate) fn __reduce107<
         'input,
     >(
         input: &'input str,
@@ -4851,14 +4917,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Selection+ = Selection => ActionFn(106);
-        let __sym0 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action106::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 31)
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 33)
     }
-    pub(crate) fn __reduce106<
+    pub(crate) fn __reduce108<
         'input,
     >(
         input: &'input str,
@@ -4870,15 +4936,15 @@ mod __parse__ExecutableDocument {
     {
         // Selection+ = Selection+, Selection => ActionFn(107);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant20(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action107::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (2, 31)
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 33)
     }
-    pub(crate) fn __reduce107<
+    pub(crate) fn __reduce109<
         'input,
     >(
         input: &'input str,
@@ -4891,15 +4957,15 @@ mod __parse__ExecutableDocument {
         // SelectionSet = "{", Selection+, "}" => ActionFn(13);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
         let __nt = super::__action13::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (3, 32)
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (3, 34)
     }
-    pub(crate) fn __reduce108<
+    pub(crate) fn __reduce110<
         'input,
     >(
         input: &'input str,
@@ -4910,14 +4976,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // SelectionSet? = SelectionSet => ActionFn(100);
-        let __sym0 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action100::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 33)
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 35)
     }
-    pub(crate) fn __reduce109<
+    pub(crate) fn __reduce111<
         'input,
     >(
         input: &'input str,
@@ -4931,10 +4997,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action101::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (0, 33)
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (0, 35)
     }
-    pub(crate) fn __reduce110<
+    pub(crate) fn __reduce112<
         'input,
     >(
         input: &'input str,
@@ -4949,10 +5015,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action40::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 34)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 36)
     }
-    pub(crate) fn __reduce111<
+    pub(crate) fn __reduce113<
         'input,
     >(
         input: &'input str,
@@ -4967,10 +5033,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action41::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 34)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 36)
     }
-    pub(crate) fn __reduce112<
+    pub(crate) fn __reduce114<
         'input,
     >(
         input: &'input str,
@@ -4982,15 +5048,15 @@ mod __parse__ExecutableDocument {
     {
         // Type = "[", Type => ActionFn(20);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant24(__symbols);
+        let __sym1 = __pop_Variant25(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action20::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (2, 35)
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (2, 37)
     }
-    pub(crate) fn __reduce113<
+    pub(crate) fn __reduce115<
         'input,
     >(
         input: &'input str,
@@ -5000,15 +5066,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Type = NamedType => ActionFn(166);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Type = NamedType => ActionFn(185);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action166::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 35)
+        let __nt = super::__action185::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 37)
     }
-    pub(crate) fn __reduce114<
+    pub(crate) fn __reduce116<
         'input,
     >(
         input: &'input str,
@@ -5018,17 +5084,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Type = NamedType, WrappingType+ => ActionFn(167);
+        // Type = NamedType, WrappingType+ => ActionFn(186);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant30(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action167::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (2, 35)
+        let __nt = super::__action186::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (2, 37)
     }
-    pub(crate) fn __reduce115<
+    pub(crate) fn __reduce117<
         'input,
     >(
         input: &'input str,
@@ -5040,15 +5106,15 @@ mod __parse__ExecutableDocument {
     {
         // TypeCondition = on, NamedType => ActionFn(18);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action18::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 36)
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 38)
     }
-    pub(crate) fn __reduce116<
+    pub(crate) fn __reduce118<
         'input,
     >(
         input: &'input str,
@@ -5059,14 +5125,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // TypeCondition? = TypeCondition => ActionFn(98);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action98::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 37)
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 39)
     }
-    pub(crate) fn __reduce117<
+    pub(crate) fn __reduce119<
         'input,
     >(
         input: &'input str,
@@ -5080,10 +5146,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action99::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 37)
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 39)
     }
-    pub(crate) fn __reduce118<
+    pub(crate) fn __reduce120<
         'input,
     >(
         input: &'input str,
@@ -5095,15 +5161,15 @@ mod __parse__ExecutableDocument {
     {
         // Value = "$", Name => ActionFn(28);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action28::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 40)
     }
-    pub(crate) fn __reduce119<
+    pub(crate) fn __reduce121<
         'input,
     >(
         input: &'input str,
@@ -5118,10 +5184,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action29::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce120<
+    pub(crate) fn __reduce122<
         'input,
     >(
         input: &'input str,
@@ -5136,10 +5202,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action30::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce121<
+    pub(crate) fn __reduce123<
         'input,
     >(
         input: &'input str,
@@ -5154,10 +5220,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action31::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce122<
+    pub(crate) fn __reduce124<
         'input,
     >(
         input: &'input str,
@@ -5172,10 +5238,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action32::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce123<
+    pub(crate) fn __reduce125<
         'input,
     >(
         input: &'input str,
@@ -5190,10 +5256,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action33::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce124<
+    pub(crate) fn __reduce126<
         'input,
     >(
         input: &'input str,
@@ -5208,10 +5274,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action34::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce125<
+    pub(crate) fn __reduce127<
         'input,
     >(
         input: &'input str,
@@ -5226,10 +5292,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action35::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce126<
+    pub(crate) fn __reduce128<
         'input,
     >(
         input: &'input str,
@@ -5239,17 +5305,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Value = "[", "]" => ActionFn(160);
+        // Value = "[", "]" => ActionFn(179);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action160::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 38)
+        let __nt = super::__action179::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 40)
     }
-    pub(crate) fn __reduce127<
+    pub(crate) fn __reduce129<
         'input,
     >(
         input: &'input str,
@@ -5259,18 +5325,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Value = "[", Value+, "]" => ActionFn(161);
+        // Value = "[", Value+, "]" => ActionFn(180);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action161::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 38)
+        let __nt = super::__action180::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 40)
     }
-    pub(crate) fn __reduce128<
+    pub(crate) fn __reduce130<
         'input,
     >(
         input: &'input str,
@@ -5280,17 +5346,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Value = "{", "}" => ActionFn(148);
+        // Value = "{", "}" => ActionFn(167);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action148::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 38)
+        let __nt = super::__action167::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 40)
     }
-    pub(crate) fn __reduce129<
+    pub(crate) fn __reduce131<
         'input,
     >(
         input: &'input str,
@@ -5300,18 +5366,18 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Value = "{", ObjectField+, "}" => ActionFn(149);
+        // Value = "{", ObjectField+, "}" => ActionFn(168);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action149::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 38)
+        let __nt = super::__action168::<>(input, ast, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 40)
     }
-    pub(crate) fn __reduce130<
+    pub(crate) fn __reduce132<
         'input,
     >(
         input: &'input str,
@@ -5322,14 +5388,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Value = EnumValue => ActionFn(38);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action38::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 38)
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce131<
+    pub(crate) fn __reduce133<
         'input,
     >(
         input: &'input str,
@@ -5343,10 +5409,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action90::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (0, 39)
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (0, 41)
     }
-    pub(crate) fn __reduce132<
+    pub(crate) fn __reduce134<
         'input,
     >(
         input: &'input str,
@@ -5357,14 +5423,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // Value* = Value+ => ActionFn(91);
-        let __sym0 = __pop_Variant25(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action91::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 39)
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 41)
     }
-    pub(crate) fn __reduce133<
+    pub(crate) fn __reduce135<
         'input,
     >(
         input: &'input str,
@@ -5374,15 +5440,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Value+ = Value => ActionFn(126);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Value+ = Value => ActionFn(128);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action126::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 40)
+        let __nt = super::__action128::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 42)
     }
-    pub(crate) fn __reduce134<
+    pub(crate) fn __reduce136<
         'input,
     >(
         input: &'input str,
@@ -5392,17 +5458,17 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Value+ = Value+, Value => ActionFn(127);
+        // Value+ = Value+, Value => ActionFn(129);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action127::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (2, 40)
+        let __nt = super::__action129::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (2, 42)
     }
-    pub(crate) fn __reduce135<
+    pub(crate) fn __reduce137<
         'input,
     >(
         input: &'input str,
@@ -5412,21 +5478,21 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // VariableDefinition = "$", Name, ":", Type, DefaultValue, Directives => ActionFn(140);
+        // VariableDefinition = "$", Name, ":", Type, DefaultValue, Directives => ActionFn(159);
         assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant12(__symbols);
-        let __sym4 = __pop_Variant8(__symbols);
-        let __sym3 = __pop_Variant24(__symbols);
+        let __sym5 = __pop_Variant13(__symbols);
+        let __sym4 = __pop_Variant9(__symbols);
+        let __sym3 = __pop_Variant25(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action140::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (6, 41)
+        let __nt = super::__action159::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (6, 43)
     }
-    pub(crate) fn __reduce136<
+    pub(crate) fn __reduce138<
         'input,
     >(
         input: &'input str,
@@ -5436,20 +5502,20 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // VariableDefinition = "$", Name, ":", Type, Directives => ActionFn(141);
+        // VariableDefinition = "$", Name, ":", Type, Directives => ActionFn(160);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant24(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant25(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action141::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (5, 41)
+        let __nt = super::__action160::<>(input, ast, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (5, 43)
     }
-    pub(crate) fn __reduce137<
+    pub(crate) fn __reduce139<
         'input,
     >(
         input: &'input str,
@@ -5460,14 +5526,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // VariableDefinition+ = VariableDefinition => ActionFn(110);
-        let __sym0 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action110::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 42)
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 44)
     }
-    pub(crate) fn __reduce138<
+    pub(crate) fn __reduce140<
         'input,
     >(
         input: &'input str,
@@ -5479,15 +5545,15 @@ mod __parse__ExecutableDocument {
     {
         // VariableDefinition+ = VariableDefinition+, VariableDefinition => ActionFn(111);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
         let __nt = super::__action111::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 42)
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 44)
     }
-    pub(crate) fn __reduce139<
+    pub(crate) fn __reduce141<
         'input,
     >(
         input: &'input str,
@@ -5500,15 +5566,15 @@ mod __parse__ExecutableDocument {
         // VariableDefinitions = "(", VariableDefinition+, ")" => ActionFn(10);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
         let __nt = super::__action10::<>(input, ast, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (3, 43)
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 45)
     }
-    pub(crate) fn __reduce140<
+    pub(crate) fn __reduce142<
         'input,
     >(
         input: &'input str,
@@ -5519,14 +5585,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // VariableDefinitions? = VariableDefinitions => ActionFn(112);
-        let __sym0 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant27(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action112::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 44)
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce141<
+    pub(crate) fn __reduce143<
         'input,
     >(
         input: &'input str,
@@ -5540,10 +5606,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action113::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (0, 44)
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (0, 46)
     }
-    pub(crate) fn __reduce142<
+    pub(crate) fn __reduce144<
         'input,
     >(
         input: &'input str,
@@ -5558,10 +5624,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action22::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 45)
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce143<
+    pub(crate) fn __reduce145<
         'input,
     >(
         input: &'input str,
@@ -5576,10 +5642,10 @@ mod __parse__ExecutableDocument {
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action23::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 45)
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce144<
+    pub(crate) fn __reduce146<
         'input,
     >(
         input: &'input str,
@@ -5593,10 +5659,10 @@ mod __parse__ExecutableDocument {
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
         let __nt = super::__action96::<>(input, ast, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (0, 46)
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (0, 48)
     }
-    pub(crate) fn __reduce145<
+    pub(crate) fn __reduce147<
         'input,
     >(
         input: &'input str,
@@ -5607,14 +5673,14 @@ mod __parse__ExecutableDocument {
     ) -> (usize, usize)
     {
         // WrappingType* = WrappingType+ => ActionFn(97);
-        let __sym0 = __pop_Variant29(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action97::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (1, 46)
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce146<
+    pub(crate) fn __reduce148<
         'input,
     >(
         input: &'input str,
@@ -5624,15 +5690,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // WrappingType+ = WrappingType => ActionFn(120);
-        let __sym0 = __pop_Variant28(__symbols);
+        // WrappingType+ = WrappingType => ActionFn(122);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action120::<>(input, ast, __sym0);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (1, 47)
+        let __nt = super::__action122::<>(input, ast, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce147<
+    pub(crate) fn __reduce149<
         'input,
     >(
         input: &'input str,
@@ -5642,15 +5708,15 @@ mod __parse__ExecutableDocument {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // WrappingType+ = WrappingType+, WrappingType => ActionFn(121);
+        // WrappingType+ = WrappingType+, WrappingType => ActionFn(123);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant28(__symbols);
-        let __sym0 = __pop_Variant29(__symbols);
+        let __sym1 = __pop_Variant29(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action121::<>(input, ast, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (2, 47)
+        let __nt = super::__action123::<>(input, ast, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (2, 49)
     }
 }
 pub use self::__parse__ExecutableDocument::ExecutableDocumentParser;
@@ -5699,8 +5765,10 @@ fn __action3<'input>(
 fn __action4<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, operation_type, _): (usize, OperationType, usize),
     (_, name, _): (usize, core::option::Option<StringId>, usize),
+    (_, end, _): (usize, usize, usize),
     (_, variable_definitions, _): (usize, core::option::Option<Vec<()>>, usize),
     (_, directives, _): (usize, IdRange<DirectiveId>, usize),
     (_, selection_set, _): (usize, IdRange<SelectionId>, usize),
@@ -5714,6 +5782,7 @@ fn __action4<'input>(
             variable_definitions,
             directives,
             selection_set,
+            span: Span::new(start, end),
         }
     }
 }
@@ -5723,6 +5792,7 @@ fn __action4<'input>(
 fn __action5<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, selection_set, _): (usize, IdRange<SelectionId>, usize),
 ) -> OperationDefinitionRecord {
     {
@@ -5732,6 +5802,7 @@ fn __action5<'input>(
             selection_set,
             variable_definitions: Default::default(),
             directives: Default::default(),
+            span: Span::new(start, start),
         }
     }
 }
@@ -5741,9 +5812,11 @@ fn __action5<'input>(
 fn __action6<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, name, _): (usize, StringId, usize),
     (_, type_condition, _): (usize, StringId, usize),
+    (_, end, _): (usize, usize, usize),
     (_, directives, _): (usize, IdRange<DirectiveId>, usize),
     (_, selection_set, _): (usize, IdRange<SelectionId>, usize),
 ) -> FragmentDefinitionRecord {
@@ -5753,6 +5826,7 @@ fn __action6<'input>(
             type_condition,
             directives,
             selection_set,
+            span: Span::new(start, end),
         }
     }
 }
@@ -5806,8 +5880,10 @@ fn __action10<'input>(
 fn __action11<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, name, _): (usize, StringId, usize),
+    (_, end, _): (usize, usize, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, ty, _): (usize, TypeId, usize),
     (_, default_value, _): (usize, core::option::Option<ValueId>, usize),
@@ -5819,6 +5895,7 @@ fn __action11<'input>(
             ty,
             default_value,
             directives,
+            span: Span::new(start, end),
         });
     }
 }
@@ -5855,8 +5932,10 @@ fn __action13<'input>(
 fn __action14<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, alias, _): (usize, core::option::Option<StringId>, usize),
     (_, name, _): (usize, StringId, usize),
+    (_, end, _): (usize, usize, usize),
     (_, arguments, _): (usize, core::option::Option<Vec<ArgumentRecord>>, usize),
     (_, directives, _): (usize, IdRange<DirectiveId>, usize),
     (_, selection_set, _): (usize, core::option::Option<IdRange<SelectionId>>, usize),
@@ -5872,6 +5951,7 @@ fn __action14<'input>(
             arguments,
             directives,
             selection_set,
+            span: Span::new(start, end),
         }))
     }
 }
@@ -5881,14 +5961,17 @@ fn __action14<'input>(
 fn __action15<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, fragment_name, _): (usize, StringId, usize),
+    (_, end, _): (usize, usize, usize),
     (_, directives, _): (usize, IdRange<DirectiveId>, usize),
 ) -> SelectionRecord {
     {
         SelectionRecord::FragmentSpread(ast.fragment_spread(FragmentSpreadRecord {
             fragment_name,
             directives,
+            span: Span::new(start, end),
         }))
     }
 }
@@ -5898,8 +5981,10 @@ fn __action15<'input>(
 fn __action16<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, type_condition, _): (usize, core::option::Option<StringId>, usize),
+    (_, end, _): (usize, usize, usize),
     (_, directives, _): (usize, IdRange<DirectiveId>, usize),
     (_, selection_set, _): (usize, IdRange<SelectionId>, usize),
 ) -> SelectionRecord {
@@ -5908,6 +5993,7 @@ fn __action16<'input>(
             type_condition,
             directives,
             selection_set,
+            span: Span::new(start, end),
         }))
     }
 }
@@ -6010,15 +6096,21 @@ fn __action24<'input>(
 fn __action25<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, name, _): (usize, StringId, usize),
+    (_, end, _): (usize, usize, usize),
     (_, arguments, _): (usize, core::option::Option<Vec<ArgumentRecord>>, usize),
 ) {
     {
         let arguments = arguments
             .map(|args| ast.arguments(args))
             .unwrap_or_default();
-        ast.directive(DirectiveRecord { name, arguments });
+        ast.directive(DirectiveRecord {
+            name,
+            arguments,
+            span: Span::new(start, end),
+        });
     }
 }
 
@@ -6039,12 +6131,18 @@ fn __action26<'input>(
 fn __action27<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
+    (_, start, _): (usize, usize, usize),
     (_, name, _): (usize, StringId, usize),
     (_, _, _): (usize, lexer::Token<'input>, usize),
     (_, value, _): (usize, ValueId, usize),
+    (_, end, _): (usize, usize, usize),
 ) -> ArgumentRecord {
     {
-        ArgumentRecord { name, value }
+        ArgumentRecord {
+            name,
+            value,
+            span: Span::new(start, end),
+        }
     }
 }
 
@@ -6947,8 +7045,18 @@ fn __action113<'input>(
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments)]
 fn __action114<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize {
+    *__lookbehind
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action115<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     (_, __0, _): (usize, StringId, usize),
@@ -6958,7 +7066,7 @@ fn __action114<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action115<'input>(
+fn __action116<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __lookbehind: &usize,
@@ -6967,9 +7075,19 @@ fn __action115<'input>(
     None
 }
 
+#[allow(unused_variables)]
+fn __action117<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize {
+    *__lookahead
+}
+
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action116<'input>(
+fn __action118<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __lookbehind: &usize,
@@ -6980,167 +7098,465 @@ fn __action116<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action117<'input>(
+fn __action119<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<ExecutableDefinitionId>, usize),
+) -> alloc::vec::Vec<ExecutableDefinitionId> {
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action120<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, __0, _): (usize, ExecutableDefinitionId, usize),
+) -> alloc::vec::Vec<ExecutableDefinitionId> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action121<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<ExecutableDefinitionId>, usize),
+    (_, e, _): (usize, ExecutableDefinitionId, usize),
+) -> alloc::vec::Vec<ExecutableDefinitionId> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action122<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, __0, _): (usize, WrappingType, usize),
+) -> alloc::vec::Vec<WrappingType> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action123<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<WrappingType>, usize),
+    (_, e, _): (usize, WrappingType, usize),
+) -> alloc::vec::Vec<WrappingType> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action124<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, __0, _): (usize, (), usize),
+) -> alloc::vec::Vec<()> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action125<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<()>, usize),
+    (_, e, _): (usize, (), usize),
+) -> alloc::vec::Vec<()> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action126<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, __0, _): (usize, ArgumentRecord, usize),
+) -> alloc::vec::Vec<ArgumentRecord> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action127<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<ArgumentRecord>, usize),
+    (_, e, _): (usize, ArgumentRecord, usize),
+) -> alloc::vec::Vec<ArgumentRecord> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action128<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, __0, _): (usize, ValueId, usize),
+) -> alloc::vec::Vec<ValueId> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action129<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<ValueId>, usize),
+    (_, e, _): (usize, ValueId, usize),
+) -> alloc::vec::Vec<ValueId> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action130<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, __0, _): (usize, (StringId, ValueId), usize),
+) -> alloc::vec::Vec<(StringId, ValueId)> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action131<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    (_, v, _): (usize, alloc::vec::Vec<(StringId, ValueId)>, usize),
+    (_, e, _): (usize, (StringId, ValueId), usize),
+) -> alloc::vec::Vec<(StringId, ValueId)> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action132<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    __0: (usize, StringId, usize),
+    __1: (usize, lexer::Token<'input>, usize),
+    __2: (usize, ValueId, usize),
+    __3: (usize, usize, usize),
+) -> ArgumentRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(input, ast, __temp0, __0, __1, __2, __3)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action133<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, core::option::Option<Vec<ArgumentRecord>>, usize),
+) {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(input, ast, __temp0, __0, __1, __2, __3)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action134<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, StringId, usize),
+    __3: (usize, usize, usize),
+    __4: (usize, IdRange<DirectiveId>, usize),
+    __5: (usize, IdRange<SelectionId>, usize),
+) -> FragmentDefinitionRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(input, ast, __temp0, __0, __1, __2, __3, __4, __5)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action135<'input>(
+    input: &'input str,
+    ast: &mut ExecutableAstWriter,
+    __0: (usize, OperationType, usize),
+    __1: (usize, core::option::Option<StringId>, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, core::option::Option<Vec<()>>, usize),
+    __4: (usize, IdRange<DirectiveId>, usize),
+    __5: (usize, IdRange<SelectionId>, usize),
+) -> OperationDefinitionRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(input, ast, __temp0, __0, __1, __2, __3, __4, __5)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action136<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<ExecutableDefinitionId>, usize),
-) -> alloc::vec::Vec<ExecutableDefinitionId> {
-    v
+    __0: (usize, IdRange<SelectionId>, usize),
+) -> OperationDefinitionRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(input, ast, __temp0, __0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action118<'input>(
+fn __action137<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, __0, _): (usize, ExecutableDefinitionId, usize),
-) -> alloc::vec::Vec<ExecutableDefinitionId> {
-    alloc::vec![__0]
+    __0: (usize, core::option::Option<StringId>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, core::option::Option<Vec<ArgumentRecord>>, usize),
+    __4: (usize, IdRange<DirectiveId>, usize),
+    __5: (usize, core::option::Option<IdRange<SelectionId>>, usize),
+) -> SelectionRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(input, ast, __temp0, __0, __1, __2, __3, __4, __5)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action119<'input>(
+fn __action138<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<ExecutableDefinitionId>, usize),
-    (_, e, _): (usize, ExecutableDefinitionId, usize),
-) -> alloc::vec::Vec<ExecutableDefinitionId> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, IdRange<DirectiveId>, usize),
+) -> SelectionRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(input, ast, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action120<'input>(
+fn __action139<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, __0, _): (usize, WrappingType, usize),
-) -> alloc::vec::Vec<WrappingType> {
-    alloc::vec![__0]
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, core::option::Option<StringId>, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, IdRange<DirectiveId>, usize),
+    __4: (usize, IdRange<SelectionId>, usize),
+) -> SelectionRecord {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(input, ast, __temp0, __0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action121<'input>(
+fn __action140<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<WrappingType>, usize),
-    (_, e, _): (usize, WrappingType, usize),
-) -> alloc::vec::Vec<WrappingType> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, lexer::Token<'input>, usize),
+    __4: (usize, TypeId, usize),
+    __5: (usize, core::option::Option<ValueId>, usize),
+    __6: (usize, IdRange<DirectiveId>, usize),
+) {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action117(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(input, ast, __temp0, __0, __1, __2, __3, __4, __5, __6)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action122<'input>(
+fn __action141<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, __0, _): (usize, (), usize),
-) -> alloc::vec::Vec<()> {
-    alloc::vec![__0]
+    __0: (usize, StringId, usize),
+    __1: (usize, lexer::Token<'input>, usize),
+    __2: (usize, ValueId, usize),
+) -> ArgumentRecord {
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action132(input, ast, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action123<'input>(
+fn __action142<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<()>, usize),
-    (_, e, _): (usize, (), usize),
-) -> alloc::vec::Vec<()> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, core::option::Option<Vec<ArgumentRecord>>, usize),
+) {
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(input, ast, __0, __1, __temp0, __2)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action124<'input>(
+fn __action143<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, __0, _): (usize, ArgumentRecord, usize),
-) -> alloc::vec::Vec<ArgumentRecord> {
-    alloc::vec![__0]
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, StringId, usize),
+    __3: (usize, IdRange<DirectiveId>, usize),
+    __4: (usize, IdRange<SelectionId>, usize),
+) -> FragmentDefinitionRecord {
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(input, ast, __0, __1, __2, __temp0, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action125<'input>(
+fn __action144<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<ArgumentRecord>, usize),
-    (_, e, _): (usize, ArgumentRecord, usize),
-) -> alloc::vec::Vec<ArgumentRecord> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+    __0: (usize, OperationType, usize),
+    __1: (usize, core::option::Option<StringId>, usize),
+    __2: (usize, core::option::Option<Vec<()>>, usize),
+    __3: (usize, IdRange<DirectiveId>, usize),
+    __4: (usize, IdRange<SelectionId>, usize),
+) -> OperationDefinitionRecord {
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action135(input, ast, __0, __1, __temp0, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action126<'input>(
+fn __action145<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, __0, _): (usize, ValueId, usize),
-) -> alloc::vec::Vec<ValueId> {
-    alloc::vec![__0]
+    __0: (usize, core::option::Option<StringId>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, core::option::Option<Vec<ArgumentRecord>>, usize),
+    __3: (usize, IdRange<DirectiveId>, usize),
+    __4: (usize, core::option::Option<IdRange<SelectionId>>, usize),
+) -> SelectionRecord {
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action137(input, ast, __0, __1, __temp0, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action127<'input>(
+fn __action146<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<ValueId>, usize),
-    (_, e, _): (usize, ValueId, usize),
-) -> alloc::vec::Vec<ValueId> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, IdRange<DirectiveId>, usize),
+) -> SelectionRecord {
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(input, ast, __0, __1, __temp0, __2)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action128<'input>(
+fn __action147<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, __0, _): (usize, (StringId, ValueId), usize),
-) -> alloc::vec::Vec<(StringId, ValueId)> {
-    alloc::vec![__0]
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, core::option::Option<StringId>, usize),
+    __2: (usize, IdRange<DirectiveId>, usize),
+    __3: (usize, IdRange<SelectionId>, usize),
+) -> SelectionRecord {
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action139(input, ast, __0, __1, __temp0, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action129<'input>(
+fn __action148<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
-    (_, v, _): (usize, alloc::vec::Vec<(StringId, ValueId)>, usize),
-    (_, e, _): (usize, (StringId, ValueId), usize),
-) -> alloc::vec::Vec<(StringId, ValueId)> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+    __0: (usize, lexer::Token<'input>, usize),
+    __1: (usize, StringId, usize),
+    __2: (usize, lexer::Token<'input>, usize),
+    __3: (usize, TypeId, usize),
+    __4: (usize, core::option::Option<ValueId>, usize),
+    __5: (usize, IdRange<DirectiveId>, usize),
+) {
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action114(input, ast, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(input, ast, __0, __1, __temp0, __2, __3, __4, __5)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action130<'input>(
+fn __action149<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7153,12 +7569,12 @@ fn __action130<'input>(
     let __end0 = __0.2;
     let __temp0 = __action104(input, ast, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(input, ast, __temp0, __1, __2, __3, __4)
+    __action145(input, ast, __temp0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action131<'input>(
+fn __action150<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7170,12 +7586,12 @@ fn __action131<'input>(
     let __end0 = __0.0;
     let __temp0 = __action105(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(input, ast, __temp0, __0, __1, __2, __3)
+    __action145(input, ast, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action132<'input>(
+fn __action151<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7190,7 +7606,7 @@ fn __action132<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action133<'input>(
+fn __action152<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7206,7 +7622,7 @@ fn __action133<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action134<'input>(
+fn __action153<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7217,12 +7633,12 @@ fn __action134<'input>(
     let __end0 = __2.2;
     let __temp0 = __action102(input, ast, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action25(input, ast, __0, __1, __temp0)
+    __action142(input, ast, __0, __1, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action135<'input>(
+fn __action154<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7232,12 +7648,12 @@ fn __action135<'input>(
     let __end0 = __1.2;
     let __temp0 = __action103(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action25(input, ast, __0, __1, __temp0)
+    __action142(input, ast, __0, __1, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action136<'input>(
+fn __action155<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7250,12 +7666,12 @@ fn __action136<'input>(
     let __end0 = __2.2;
     let __temp0 = __action102(input, ast, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(input, ast, __0, __1, __temp0, __3, __4)
+    __action149(input, ast, __0, __1, __temp0, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action137<'input>(
+fn __action156<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7267,12 +7683,12 @@ fn __action137<'input>(
     let __end0 = __2.0;
     let __temp0 = __action103(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(input, ast, __0, __1, __temp0, __2, __3)
+    __action149(input, ast, __0, __1, __temp0, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action138<'input>(
+fn __action157<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7284,12 +7700,12 @@ fn __action138<'input>(
     let __end0 = __1.2;
     let __temp0 = __action102(input, ast, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(input, ast, __0, __temp0, __2, __3)
+    __action150(input, ast, __0, __temp0, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action139<'input>(
+fn __action158<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7300,12 +7716,12 @@ fn __action139<'input>(
     let __end0 = __1.0;
     let __temp0 = __action103(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(input, ast, __0, __temp0, __1, __2)
+    __action150(input, ast, __0, __temp0, __1, __2)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action140<'input>(
+fn __action159<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7319,12 +7735,12 @@ fn __action140<'input>(
     let __end0 = __4.2;
     let __temp0 = __action108(input, ast, __4);
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(input, ast, __0, __1, __2, __3, __temp0, __5)
+    __action148(input, ast, __0, __1, __2, __3, __temp0, __5)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action141<'input>(
+fn __action160<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7337,12 +7753,12 @@ fn __action141<'input>(
     let __end0 = __4.0;
     let __temp0 = __action109(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(input, ast, __0, __1, __2, __3, __temp0, __4)
+    __action148(input, ast, __0, __1, __2, __3, __temp0, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action142<'input>(
+fn __action161<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __lookbehind: &usize,
@@ -7357,7 +7773,7 @@ fn __action142<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action143<'input>(
+fn __action162<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, alloc::vec::Vec<()>, usize),
@@ -7371,7 +7787,7 @@ fn __action143<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action144<'input>(
+fn __action163<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __lookbehind: &usize,
@@ -7379,28 +7795,28 @@ fn __action144<'input>(
 ) {
     let __start0 = *__lookbehind;
     let __end0 = *__lookahead;
-    let __temp0 = __action116(input, ast, &__start0, &__end0);
+    let __temp0 = __action118(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
     __action1(input, ast, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action145<'input>(
+fn __action164<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, alloc::vec::Vec<ExecutableDefinitionId>, usize),
 ) {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action117(input, ast, __0);
+    let __temp0 = __action119(input, ast, __0);
     let __temp0 = (__start0, __temp0, __end0);
     __action1(input, ast, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action146<'input>(
+fn __action165<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, OperationType, usize),
@@ -7411,14 +7827,14 @@ fn __action146<'input>(
 ) -> OperationDefinitionRecord {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action114(input, ast, __1);
+    let __temp0 = __action115(input, ast, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(input, ast, __0, __temp0, __2, __3, __4)
+    __action144(input, ast, __0, __temp0, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action147<'input>(
+fn __action166<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, OperationType, usize),
@@ -7428,14 +7844,14 @@ fn __action147<'input>(
 ) -> OperationDefinitionRecord {
     let __start0 = __0.2;
     let __end0 = __1.0;
-    let __temp0 = __action115(input, ast, &__start0, &__end0);
+    let __temp0 = __action116(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(input, ast, __0, __temp0, __1, __2, __3)
+    __action144(input, ast, __0, __temp0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action148<'input>(
+fn __action167<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7450,7 +7866,7 @@ fn __action148<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action149<'input>(
+fn __action168<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7466,7 +7882,7 @@ fn __action149<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action150<'input>(
+fn __action169<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7479,12 +7895,12 @@ fn __action150<'input>(
     let __end0 = __4.2;
     let __temp0 = __action100(input, ast, __4);
     let __temp0 = (__start0, __temp0, __end0);
-    __action136(input, ast, __0, __1, __2, __3, __temp0)
+    __action155(input, ast, __0, __1, __2, __3, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action151<'input>(
+fn __action170<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7496,12 +7912,12 @@ fn __action151<'input>(
     let __end0 = __3.2;
     let __temp0 = __action101(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action136(input, ast, __0, __1, __2, __3, __temp0)
+    __action155(input, ast, __0, __1, __2, __3, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action152<'input>(
+fn __action171<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7513,12 +7929,12 @@ fn __action152<'input>(
     let __end0 = __3.2;
     let __temp0 = __action100(input, ast, __3);
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(input, ast, __0, __1, __2, __temp0)
+    __action156(input, ast, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action153<'input>(
+fn __action172<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7529,12 +7945,12 @@ fn __action153<'input>(
     let __end0 = __2.2;
     let __temp0 = __action101(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(input, ast, __0, __1, __2, __temp0)
+    __action156(input, ast, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action154<'input>(
+fn __action173<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7546,12 +7962,12 @@ fn __action154<'input>(
     let __end0 = __3.2;
     let __temp0 = __action100(input, ast, __3);
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(input, ast, __0, __1, __2, __temp0)
+    __action157(input, ast, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action155<'input>(
+fn __action174<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7562,12 +7978,12 @@ fn __action155<'input>(
     let __end0 = __2.2;
     let __temp0 = __action101(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(input, ast, __0, __1, __2, __temp0)
+    __action157(input, ast, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action156<'input>(
+fn __action175<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7578,12 +7994,12 @@ fn __action156<'input>(
     let __end0 = __2.2;
     let __temp0 = __action100(input, ast, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action139(input, ast, __0, __1, __temp0)
+    __action158(input, ast, __0, __1, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action157<'input>(
+fn __action176<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7593,12 +8009,12 @@ fn __action157<'input>(
     let __end0 = __1.2;
     let __temp0 = __action101(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action139(input, ast, __0, __1, __temp0)
+    __action158(input, ast, __0, __1, __temp0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action158<'input>(
+fn __action177<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7610,12 +8026,12 @@ fn __action158<'input>(
     let __end0 = __1.2;
     let __temp0 = __action98(input, ast, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(input, ast, __0, __temp0, __2, __3)
+    __action147(input, ast, __0, __temp0, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action159<'input>(
+fn __action178<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7626,12 +8042,12 @@ fn __action159<'input>(
     let __end0 = __1.0;
     let __temp0 = __action99(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(input, ast, __0, __temp0, __1, __2)
+    __action147(input, ast, __0, __temp0, __1, __2)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action160<'input>(
+fn __action179<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7646,7 +8062,7 @@ fn __action160<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action161<'input>(
+fn __action180<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, lexer::Token<'input>, usize),
@@ -7662,7 +8078,7 @@ fn __action161<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action162<'input>(
+fn __action181<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, OperationType, usize),
@@ -7675,12 +8091,12 @@ fn __action162<'input>(
     let __end0 = __2.2;
     let __temp0 = __action112(input, ast, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action146(input, ast, __0, __1, __temp0, __3, __4)
+    __action165(input, ast, __0, __1, __temp0, __3, __4)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action163<'input>(
+fn __action182<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, OperationType, usize),
@@ -7692,12 +8108,12 @@ fn __action163<'input>(
     let __end0 = __2.0;
     let __temp0 = __action113(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action146(input, ast, __0, __1, __temp0, __2, __3)
+    __action165(input, ast, __0, __1, __temp0, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action164<'input>(
+fn __action183<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, OperationType, usize),
@@ -7709,12 +8125,12 @@ fn __action164<'input>(
     let __end0 = __1.2;
     let __temp0 = __action112(input, ast, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action147(input, ast, __0, __temp0, __2, __3)
+    __action166(input, ast, __0, __temp0, __2, __3)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action165<'input>(
+fn __action184<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, OperationType, usize),
@@ -7725,12 +8141,12 @@ fn __action165<'input>(
     let __end0 = __1.0;
     let __temp0 = __action113(input, ast, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action147(input, ast, __0, __temp0, __1, __2)
+    __action166(input, ast, __0, __temp0, __1, __2)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action166<'input>(
+fn __action185<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
@@ -7744,7 +8160,7 @@ fn __action166<'input>(
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action167<'input>(
+fn __action186<'input>(
     input: &'input str,
     ast: &mut ExecutableAstWriter,
     __0: (usize, StringId, usize),
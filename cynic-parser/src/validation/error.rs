@@ -0,0 +1,247 @@
+use std::fmt;
+
+use crate::Span;
+
+/// An error found while validating an `ExecutableDocument` against a schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// An operation was provided for a root type that the schema doesn't have
+    UnknownRootType {
+        operation_type: crate::common::OperationType,
+        span: Span,
+    },
+
+    /// A selected field doesn't exist on its parent type
+    UnknownField {
+        type_name: String,
+        field_name: String,
+        span: Span,
+    },
+
+    /// A field of a leaf type was given a selection set
+    UnexpectedSelectionSet {
+        field_name: String,
+        type_name: String,
+        span: Span,
+    },
+
+    /// A field of a composite type was not given a selection set
+    MissingSelectionSet {
+        field_name: String,
+        type_name: String,
+        span: Span,
+    },
+
+    /// An argument was provided that the field or directive doesn't accept
+    UnknownArgument {
+        /// The name of the field or directive, with directive names prefixed
+        /// by an `@`
+        parent: String,
+        argument_name: String,
+        span: Span,
+    },
+
+    /// A required argument was not provided
+    MissingArgument {
+        /// The name of the field or directive, with directive names prefixed
+        /// by an `@`
+        parent: String,
+        argument_name: String,
+        span: Span,
+    },
+
+    /// The value provided for an argument is not valid for its type
+    InvalidArgumentValue {
+        argument_name: String,
+        expected_type: String,
+        span: Span,
+    },
+
+    /// A type condition or variable type refers to a type that doesn't exist
+    UnknownType { name: String, span: Span },
+
+    /// A fragment has a type condition that isn't an object, interface or
+    /// union
+    FragmentOnNonCompositeType { type_name: String, span: Span },
+
+    /// A fragment was spread into a selection set where it could never apply
+    ImpossibleFragmentSpread {
+        fragment_type: String,
+        parent_type: String,
+        span: Span,
+    },
+
+    /// A fragment spread refers to a fragment that doesn't exist
+    UnknownFragment { name: String, span: Span },
+
+    /// A variable was defined with a type that isn't an input type
+    NonInputVariableType {
+        name: String,
+        type_name: String,
+        span: Span,
+    },
+
+    /// A variable was used without being defined by its operation
+    UndefinedVariable { name: String, span: Span },
+
+    /// A variable was defined by an operation but never used
+    UnusedVariable { name: String, span: Span },
+
+    /// A variable was used in a position that doesn't accept its type
+    InvalidVariableUsage {
+        name: String,
+        variable_type: String,
+        expected_type: String,
+        span: Span,
+    },
+
+    /// Two fields with the same response key can't be merged
+    FieldsConflict {
+        response_key: String,
+        reason: String,
+        span: Span,
+        other_span: Span,
+    },
+}
+
+impl ValidationError {
+    pub fn span(&self) -> Span {
+        match self {
+            ValidationError::UnknownRootType { span, .. }
+            | ValidationError::UnknownField { span, .. }
+            | ValidationError::UnexpectedSelectionSet { span, .. }
+            | ValidationError::MissingSelectionSet { span, .. }
+            | ValidationError::UnknownArgument { span, .. }
+            | ValidationError::MissingArgument { span, .. }
+            | ValidationError::InvalidArgumentValue { span, .. }
+            | ValidationError::UnknownType { span, .. }
+            | ValidationError::FragmentOnNonCompositeType { span, .. }
+            | ValidationError::ImpossibleFragmentSpread { span, .. }
+            | ValidationError::UnknownFragment { span, .. }
+            | ValidationError::NonInputVariableType { span, .. }
+            | ValidationError::UndefinedVariable { span, .. }
+            | ValidationError::UnusedVariable { span, .. }
+            | ValidationError::InvalidVariableUsage { span, .. }
+            | ValidationError::FieldsConflict { span, .. } => *span,
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+#[cfg(feature = "report")]
+impl ValidationError {
+    pub fn to_report<'a>(&self, document: &'a str) -> crate::errors::Report<'a> {
+        use ariadne::{Config, Label, ReportKind};
+
+        let span = self.span();
+        let mut builder = ariadne::Report::build(ReportKind::Error, (), span.start)
+            .with_message(self)
+            .with_config(Config::default().with_color(false));
+
+        if let ValidationError::FieldsConflict { other_span, .. } = self {
+            builder.add_label(Label::new(span.start..span.end).with_message("this field"));
+            builder.add_label(
+                Label::new(other_span.start..other_span.end)
+                    .with_message("conflicts with this field"),
+            );
+        } else {
+            builder.add_label(Label::new(span.start..span.end));
+        }
+
+        crate::errors::Report::new(builder.finish(), document)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::UnknownRootType { operation_type, .. } => {
+                write!(f, "the schema does not support {operation_type} operations")
+            }
+            ValidationError::UnknownField {
+                type_name,
+                field_name,
+                ..
+            } => write!(f, "no field named {field_name} on {type_name}"),
+            ValidationError::UnexpectedSelectionSet {
+                field_name,
+                type_name,
+                ..
+            } => write!(
+                f,
+                "{field_name} is of type {type_name} and can't have a selection set"
+            ),
+            ValidationError::MissingSelectionSet {
+                field_name,
+                type_name,
+                ..
+            } => write!(
+                f,
+                "{field_name} is of type {type_name} and must have a selection set"
+            ),
+            ValidationError::UnknownArgument {
+                parent,
+                argument_name,
+                ..
+            } => write!(f, "{parent} has no argument named {argument_name}"),
+            ValidationError::MissingArgument {
+                parent,
+                argument_name,
+                ..
+            } => write!(f, "{parent} is missing the required argument {argument_name}"),
+            ValidationError::InvalidArgumentValue {
+                argument_name,
+                expected_type,
+                ..
+            } => write!(
+                f,
+                "the value of {argument_name} is not a valid {expected_type}"
+            ),
+            ValidationError::UnknownType { name, .. } => {
+                write!(f, "no type named {name} in the schema")
+            }
+            ValidationError::FragmentOnNonCompositeType { type_name, .. } => write!(
+                f,
+                "fragments can't be used on {type_name} as it is not an object, interface or union"
+            ),
+            ValidationError::ImpossibleFragmentSpread {
+                fragment_type,
+                parent_type,
+                ..
+            } => write!(
+                f,
+                "a fragment on {fragment_type} can never apply within {parent_type}"
+            ),
+            ValidationError::UnknownFragment { name, .. } => {
+                write!(f, "no fragment named {name}")
+            }
+            ValidationError::NonInputVariableType {
+                name, type_name, ..
+            } => write!(
+                f,
+                "variable ${name} is of type {type_name} which is not an input type"
+            ),
+            ValidationError::UndefinedVariable { name, .. } => {
+                write!(f, "variable ${name} is not defined by the operation")
+            }
+            ValidationError::UnusedVariable { name, .. } => {
+                write!(f, "variable ${name} is never used")
+            }
+            ValidationError::InvalidVariableUsage {
+                name,
+                variable_type,
+                expected_type,
+                ..
+            } => write!(
+                f,
+                "variable ${name} is of type {variable_type} but is used where {expected_type} is expected"
+            ),
+            ValidationError::FieldsConflict {
+                response_key,
+                reason,
+                ..
+            } => write!(f, "the fields selected as {response_key} conflict: {reason}"),
+        }
+    }
+}
@@ -0,0 +1,507 @@
+//! Validation of executable documents against a schema.
+//!
+//! This can be used to check any query before it's sent to a server - for
+//! example the `query` of an `Operation` built by `cynic`.
+//!
+//! This implements a subset of the [executable validation rules][rules] from
+//! the GraphQL spec:
+//!
+//! - Fields must exist on the type they're selected from, and leaf fields
+//!   can't have a selection set while other fields require one.
+//! - Arguments must exist, required arguments must be provided, and argument
+//!   values must be valid for their type.
+//! - Fragments must exist, have a type condition on an object, interface or
+//!   union, and be spread only where they could apply.
+//! - Variables must be defined by the operation that uses them, have an input
+//!   type, be used somewhere, and be used only where their type is allowed.
+//! - Fields with the same response key must be mergeable.
+//!
+//! [rules]: https://spec.graphql.org/October2021/#sec-Validation
+//!
+//! ```rust
+//! let schema = cynic_parser::parse_type_system_document(
+//!     "type Query { hello(name: String!): String }",
+//! )
+//! .unwrap();
+//! let query = cynic_parser::parse_executable_document("query { hello(name: 1) }").unwrap();
+//!
+//! let errors = cynic_parser::validation::validate(&schema, &query).unwrap_err();
+//!
+//! assert_eq!(
+//!     errors[0].to_string(),
+//!     "the value of name is not a valid String!"
+//! );
+//! assert_eq!(errors[0].span(), cynic_parser::Span::new(14, 21));
+//! ```
+
+mod error;
+mod overlapping;
+mod schema;
+mod values;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    executable::{
+        Argument, Directive, FieldSelection, FragmentDefinition, OperationDefinition, Selection,
+        Value,
+    },
+    ExecutableDocument, Span, TypeSystemDocument,
+};
+
+use self::{
+    schema::{InputValue, Schema, TypeRef},
+    values::{check_value, record_variables, VariableUsage},
+};
+
+pub use self::error::ValidationError;
+
+/// Validates `document` against `schema`, returning all the errors found.
+pub fn validate(
+    schema: &TypeSystemDocument,
+    document: &ExecutableDocument,
+) -> Result<(), Vec<ValidationError>> {
+    let mut validator = Validator {
+        schema: Schema::new(schema),
+        fragments: document
+            .fragments()
+            .map(|fragment| (fragment.name(), fragment))
+            .collect(),
+        errors: Vec::new(),
+        reported_conflicts: HashSet::new(),
+    };
+
+    validator.validate(document);
+
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors)
+    }
+}
+
+struct Validator<'a> {
+    schema: Schema<'a>,
+    fragments: HashMap<&'a str, FragmentDefinition<'a>>,
+    errors: Vec<ValidationError>,
+    reported_conflicts: HashSet<(Span, Span)>,
+}
+
+/// The variables used & fragments spread directly within a definition
+#[derive(Default)]
+struct Usages<'a> {
+    variables: Vec<VariableUsage<'a>>,
+    spreads: Vec<&'a str>,
+}
+
+impl<'a> Validator<'a> {
+    fn validate(&mut self, document: &'a ExecutableDocument) {
+        let mut fragment_usages = HashMap::new();
+        for fragment in document.fragments() {
+            let parent = self.type_condition(fragment.type_condition(), fragment.span());
+
+            let mut usages = Usages::default();
+            self.directives(fragment.directives(), &mut usages);
+            self.selection_set(parent, fragment.selection_set(), &mut usages);
+            self.overlapping_fields(parent, fragment.selection_set());
+
+            fragment_usages.insert(fragment.name(), usages);
+        }
+
+        for operation in document.operations() {
+            let parent = self.schema.root_type(operation.operation_type());
+            if parent.is_none() {
+                self.errors.push(ValidationError::UnknownRootType {
+                    operation_type: operation.operation_type(),
+                    span: operation.span(),
+                });
+            }
+
+            let mut usages = Usages::default();
+            self.directives(operation.directives(), &mut usages);
+            self.selection_set(parent, operation.selection_set(), &mut usages);
+            self.overlapping_fields(parent, operation.selection_set());
+
+            self.variables(operation, usages, &fragment_usages);
+        }
+    }
+
+    /// Validates a selection set of `parent`.
+    ///
+    /// If `parent` is `None` then the type is unknown and has already been
+    /// reported, so only variables & fragment spreads are recorded.
+    fn selection_set(
+        &mut self,
+        parent: Option<&'a str>,
+        selections: impl Iterator<Item = Selection<'a>>,
+        usages: &mut Usages<'a>,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => self.field(parent, field, usages),
+                Selection::InlineFragment(fragment) => {
+                    self.directives(fragment.directives(), usages);
+
+                    let parent = match fragment.type_condition() {
+                        Some(type_condition) => {
+                            let condition = self.type_condition(type_condition, fragment.span());
+                            if let (Some(parent), Some(condition)) = (parent, condition) {
+                                self.spread_possible(condition, parent, fragment.span());
+                            }
+                            condition
+                        }
+                        None => parent,
+                    };
+
+                    self.selection_set(parent, fragment.selection_set(), usages);
+                }
+                Selection::FragmentSpread(spread) => {
+                    self.directives(spread.directives(), usages);
+                    usages.spreads.push(spread.fragment_name());
+
+                    let Some(fragment) = self.fragments.get(spread.fragment_name()) else {
+                        self.errors.push(ValidationError::UnknownFragment {
+                            name: spread.fragment_name().to_string(),
+                            span: spread.span(),
+                        });
+                        continue;
+                    };
+
+                    let condition = fragment.type_condition();
+                    let condition_is_composite = self
+                        .schema
+                        .kind(condition)
+                        .is_some_and(|kind| kind.is_composite());
+
+                    if let (Some(parent), true) = (parent, condition_is_composite) {
+                        self.spread_possible(condition, parent, spread.span());
+                    }
+                }
+            }
+        }
+    }
+
+    fn field(
+        &mut self,
+        parent: Option<&'a str>,
+        field: FieldSelection<'a>,
+        usages: &mut Usages<'a>,
+    ) {
+        self.directives(field.directives(), usages);
+
+        let name = field.name();
+        let definition = match parent {
+            Some(_) if name == "__typename" => {
+                if field.selection_set().len() != 0 {
+                    self.errors.push(ValidationError::UnexpectedSelectionSet {
+                        field_name: name.to_string(),
+                        type_name: "String!".into(),
+                        span: field.span(),
+                    });
+                }
+                None
+            }
+            // We don't have the introspection types available so can't
+            // validate anything beneath these fields.
+            Some(_) if name == "__schema" || name == "__type" => None,
+            Some(parent) => {
+                let definition = self.schema.field(parent, name);
+                if definition.is_none() {
+                    self.errors.push(ValidationError::UnknownField {
+                        type_name: parent.to_string(),
+                        field_name: name.to_string(),
+                        span: field.span(),
+                    });
+                }
+                definition
+            }
+            None => None,
+        };
+
+        let Some(definition) = definition else {
+            for argument in field.arguments() {
+                record_variables(&argument.value(), argument.span(), &mut usages.variables);
+            }
+            self.selection_set(None, field.selection_set(), usages);
+            return;
+        };
+
+        self.arguments(
+            name,
+            &definition
+                .arguments()
+                .map(InputValue::from)
+                .collect::<Vec<_>>(),
+            field.arguments().collect(),
+            field.span(),
+            usages,
+        );
+
+        let ty = definition.ty();
+        let kind = self.schema.kind(ty.name());
+        let has_selection_set = field.selection_set().len() != 0;
+
+        match kind {
+            Some(kind) if kind.is_leaf() && has_selection_set => {
+                self.errors.push(ValidationError::UnexpectedSelectionSet {
+                    field_name: name.to_string(),
+                    type_name: ty.to_string(),
+                    span: field.span(),
+                });
+            }
+            Some(kind) if kind.is_composite() && !has_selection_set => {
+                self.errors.push(ValidationError::MissingSelectionSet {
+                    field_name: name.to_string(),
+                    type_name: ty.to_string(),
+                    span: field.span(),
+                });
+            }
+            _ => {}
+        }
+
+        let parent = kind.filter(|kind| kind.is_composite()).map(|_| ty.name());
+
+        self.selection_set(parent, field.selection_set(), usages);
+    }
+
+    fn directives(
+        &mut self,
+        directives: impl Iterator<Item = Directive<'a>>,
+        usages: &mut Usages<'a>,
+    ) {
+        for directive in directives {
+            let Some(definitions) = self.schema.directive_arguments(directive.name()) else {
+                for argument in directive.arguments() {
+                    record_variables(&argument.value(), argument.span(), &mut usages.variables);
+                }
+                continue;
+            };
+
+            self.arguments(
+                &format!("@{}", directive.name()),
+                &definitions,
+                directive.arguments().collect(),
+                directive.span(),
+                usages,
+            );
+        }
+    }
+
+    fn arguments(
+        &mut self,
+        parent: &str,
+        definitions: &[InputValue<'a>],
+        arguments: Vec<Argument<'a>>,
+        span: Span,
+        usages: &mut Usages<'a>,
+    ) {
+        for argument in &arguments {
+            let definition = definitions
+                .iter()
+                .find(|definition| definition.name == argument.name());
+
+            let Some(definition) = definition else {
+                self.errors.push(ValidationError::UnknownArgument {
+                    parent: parent.to_string(),
+                    argument_name: argument.name().to_string(),
+                    span: argument.span(),
+                });
+                record_variables(&argument.value(), argument.span(), &mut usages.variables);
+                continue;
+            };
+
+            let valid = check_value(
+                &self.schema,
+                &argument.value(),
+                Some(&definition.ty),
+                definition.has_default,
+                argument.span(),
+                &mut usages.variables,
+            );
+            if !valid {
+                self.errors.push(ValidationError::InvalidArgumentValue {
+                    argument_name: argument.name().to_string(),
+                    expected_type: definition.ty.to_string(),
+                    span: argument.span(),
+                });
+            }
+        }
+
+        for definition in definitions {
+            let provided = arguments
+                .iter()
+                .any(|argument| argument.name() == definition.name);
+
+            if definition.is_required() && !provided {
+                self.errors.push(ValidationError::MissingArgument {
+                    parent: parent.to_string(),
+                    argument_name: definition.name.to_string(),
+                    span,
+                });
+            }
+        }
+    }
+
+    /// Validates the type condition of a fragment, returning the type if it
+    /// can have fields selected on it.
+    fn type_condition(&mut self, type_condition: &'a str, span: Span) -> Option<&'a str> {
+        match self.schema.kind(type_condition) {
+            Some(kind) if kind.is_composite() => Some(type_condition),
+            Some(_) => {
+                self.errors
+                    .push(ValidationError::FragmentOnNonCompositeType {
+                        type_name: type_condition.to_string(),
+                        span,
+                    });
+                None
+            }
+            None => {
+                self.errors.push(ValidationError::UnknownType {
+                    name: type_condition.to_string(),
+                    span,
+                });
+                None
+            }
+        }
+    }
+
+    fn spread_possible(&mut self, fragment_type: &'a str, parent_type: &'a str, span: Span) {
+        let fragment_types = self.schema.possible_types(fragment_type);
+        let parent_types = self.schema.possible_types(parent_type);
+
+        if fragment_types.is_disjoint(&parent_types) {
+            self.errors.push(ValidationError::ImpossibleFragmentSpread {
+                fragment_type: fragment_type.to_string(),
+                parent_type: parent_type.to_string(),
+                span,
+            });
+        }
+    }
+
+    fn variables(
+        &mut self,
+        operation: OperationDefinition<'a>,
+        usages: Usages<'a>,
+        fragment_usages: &HashMap<&'a str, Usages<'a>>,
+    ) {
+        let definitions = operation.variable_definitions().collect::<Vec<_>>();
+
+        for definition in &definitions {
+            let type_name = definition.ty().name();
+            match self.schema.kind(type_name) {
+                Some(kind) if kind.is_input() => {}
+                Some(_) => self.errors.push(ValidationError::NonInputVariableType {
+                    name: definition.name().to_string(),
+                    type_name: definition.ty().to_string(),
+                    span: definition.span(),
+                }),
+                None => self.errors.push(ValidationError::UnknownType {
+                    name: type_name.to_string(),
+                    span: definition.span(),
+                }),
+            }
+        }
+
+        let mut variables = usages.variables;
+        let mut visited = HashSet::new();
+        let mut spreads = usages.spreads;
+        while let Some(fragment) = spreads.pop() {
+            if !visited.insert(fragment) {
+                continue;
+            }
+            if let Some(usages) = fragment_usages.get(fragment) {
+                variables.extend(usages.variables.iter().cloned());
+                spreads.extend(usages.spreads.iter().copied());
+            }
+        }
+
+        for usage in &variables {
+            let definition = definitions
+                .iter()
+                .find(|definition| definition.name() == usage.name);
+
+            let Some(definition) = definition else {
+                self.errors.push(ValidationError::UndefinedVariable {
+                    name: usage.name.to_string(),
+                    span: usage.span,
+                });
+                continue;
+            };
+
+            let Some(expected_type) = &usage.expected_type else {
+                continue;
+            };
+
+            let variable_type = TypeRef::from_executable(definition.ty());
+            let has_default = definition
+                .default_value()
+                .is_some_and(|value| value != Value::Null);
+
+            if !variable_usage_allowed(
+                &variable_type,
+                has_default,
+                expected_type,
+                usage.location_has_default,
+            ) {
+                self.errors.push(ValidationError::InvalidVariableUsage {
+                    name: usage.name.to_string(),
+                    variable_type: variable_type.to_string(),
+                    expected_type: expected_type.to_string(),
+                    span: usage.span,
+                });
+            }
+        }
+
+        for definition in &definitions {
+            if !variables
+                .iter()
+                .any(|usage| usage.name == definition.name())
+            {
+                self.errors.push(ValidationError::UnusedVariable {
+                    name: definition.name().to_string(),
+                    span: definition.span(),
+                });
+            }
+        }
+    }
+}
+
+/// Implements the IsVariableUsageAllowed algorithm from the spec
+fn variable_usage_allowed(
+    variable_type: &TypeRef<'_>,
+    variable_has_default: bool,
+    location_type: &TypeRef<'_>,
+    location_has_default: bool,
+) -> bool {
+    if location_type.is_non_null() && !variable_type.is_non_null() {
+        if !variable_has_default && !location_has_default {
+            return false;
+        }
+        let location_type = location_type.unwrap_once().expect("type to have a wrapper");
+        return types_compatible(variable_type, &location_type);
+    }
+
+    types_compatible(variable_type, location_type)
+}
+
+fn types_compatible(variable_type: &TypeRef<'_>, location_type: &TypeRef<'_>) -> bool {
+    use crate::common::WrappingType;
+
+    fn wrappers_compatible(variable: &[WrappingType], location: &[WrappingType]) -> bool {
+        match (variable.split_first(), location.split_first()) {
+            (Some((WrappingType::NonNull, variable)), Some((WrappingType::NonNull, location))) => {
+                wrappers_compatible(variable, location)
+            }
+            (_, Some((WrappingType::NonNull, _))) => false,
+            (Some((WrappingType::NonNull, variable)), _) => wrappers_compatible(variable, location),
+            (Some((WrappingType::List, variable)), Some((WrappingType::List, location))) => {
+                wrappers_compatible(variable, location)
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    variable_type.name == location_type.name
+        && wrappers_compatible(&variable_type.wrappers, &location_type.wrappers)
+}
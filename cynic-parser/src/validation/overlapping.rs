@@ -0,0 +1,260 @@
+//! The "Overlapping Fields Can Be Merged" validation rule
+
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+
+use crate::{
+    common::WrappingType,
+    executable::{FieldSelection, Selection},
+};
+
+use super::{
+    schema::{TypeKind, TypeRef},
+    ValidationError, Validator,
+};
+
+/// A field collected from a selection set, along with the type it was
+/// selected from
+struct CollectedField<'a> {
+    parent: Option<&'a str>,
+    field: FieldSelection<'a>,
+    ty: Option<TypeRef<'a>>,
+}
+
+type FieldsByResponseKey<'a> = IndexMap<&'a str, Vec<CollectedField<'a>>>;
+
+impl<'a> Validator<'a> {
+    /// Checks that fields with the same response key in this selection set
+    /// & any nested selection sets can be merged
+    pub(super) fn overlapping_fields(
+        &mut self,
+        parent: Option<&'a str>,
+        selections: impl Iterator<Item = Selection<'a>>,
+    ) {
+        let selections = selections.collect::<Vec<_>>();
+
+        let mut fields = FieldsByResponseKey::new();
+        self.collect_fields(
+            parent,
+            selections.iter().copied(),
+            &mut fields,
+            &mut HashSet::new(),
+        );
+
+        for (response_key, fields) in &fields {
+            for (index, field) in fields.iter().enumerate() {
+                for other in &fields[index + 1..] {
+                    let Some(reason) = self.fields_conflict(field, other, false) else {
+                        continue;
+                    };
+
+                    let span = field.field.span();
+                    let other_span = other.field.span();
+                    if self.reported_conflicts.insert((span, other_span)) {
+                        self.errors.push(ValidationError::FieldsConflict {
+                            response_key: response_key.to_string(),
+                            reason,
+                            span,
+                            other_span,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.nested_overlapping_fields(parent, selections.into_iter());
+    }
+
+    fn nested_overlapping_fields(
+        &mut self,
+        parent: Option<&'a str>,
+        selections: impl Iterator<Item = Selection<'a>>,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => {
+                    if field.selection_set().len() != 0 {
+                        let parent = self.field_type(parent, field).and_then(|ty| {
+                            self.schema
+                                .kind(ty.name)
+                                .filter(|kind| kind.is_composite())
+                                .map(|_| ty.name)
+                        });
+                        self.overlapping_fields(parent, field.selection_set());
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    let parent = fragment.type_condition().or(parent);
+                    self.nested_overlapping_fields(parent, fragment.selection_set());
+                }
+                // Fragment definitions are checked on their own
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    /// Collects all the fields in a selection set by their response key,
+    /// including those inside fragments
+    fn collect_fields(
+        &self,
+        parent: Option<&'a str>,
+        selections: impl Iterator<Item = Selection<'a>>,
+        fields: &mut FieldsByResponseKey<'a>,
+        visited_fragments: &mut HashSet<&'a str>,
+    ) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => {
+                    let response_key = field.alias().unwrap_or(field.name());
+                    fields
+                        .entry(response_key)
+                        .or_default()
+                        .push(CollectedField {
+                            parent,
+                            field,
+                            ty: self.field_type(parent, field),
+                        });
+                }
+                Selection::InlineFragment(fragment) => {
+                    let parent = fragment.type_condition().or(parent);
+                    self.collect_fields(
+                        parent,
+                        fragment.selection_set(),
+                        fields,
+                        visited_fragments,
+                    );
+                }
+                Selection::FragmentSpread(spread) => {
+                    if !visited_fragments.insert(spread.fragment_name()) {
+                        continue;
+                    }
+                    if let Some(fragment) = self.fragments.get(spread.fragment_name()) {
+                        self.collect_fields(
+                            Some(fragment.type_condition()),
+                            fragment.selection_set(),
+                            fields,
+                            visited_fragments,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks whether two fields with the same response key conflict,
+    /// returning the reason if they do
+    fn fields_conflict(
+        &self,
+        field: &CollectedField<'a>,
+        other: &CollectedField<'a>,
+        parents_mutually_exclusive: bool,
+    ) -> Option<String> {
+        if field.field.span() == other.field.span() {
+            return None;
+        }
+
+        let is_object = |name: Option<&str>| {
+            name.and_then(|name| self.schema.kind(name)) == Some(TypeKind::Object)
+        };
+
+        let mutually_exclusive = parents_mutually_exclusive
+            || (field.parent != other.parent && is_object(field.parent) && is_object(other.parent));
+
+        if !mutually_exclusive {
+            if field.field.name() != other.field.name() {
+                return Some(format!(
+                    "{} and {} are different fields",
+                    field.field.name(),
+                    other.field.name()
+                ));
+            }
+
+            if !same_arguments(field.field, other.field) {
+                return Some("they have differing arguments".into());
+            }
+        }
+
+        if let (Some(ty), Some(other_ty)) = (&field.ty, &other.ty) {
+            if !self.same_response_shape(ty, other_ty) {
+                return Some(format!("they return conflicting types {ty} and {other_ty}"));
+            }
+        }
+
+        if field.field.selection_set().len() == 0 || other.field.selection_set().len() == 0 {
+            return None;
+        }
+
+        let mut subfields = FieldsByResponseKey::new();
+        self.collect_fields(
+            field.ty.as_ref().map(|ty| ty.name),
+            field.field.selection_set(),
+            &mut subfields,
+            &mut HashSet::new(),
+        );
+
+        let mut other_subfields = FieldsByResponseKey::new();
+        self.collect_fields(
+            other.ty.as_ref().map(|ty| ty.name),
+            other.field.selection_set(),
+            &mut other_subfields,
+            &mut HashSet::new(),
+        );
+
+        for (response_key, subfields) in &subfields {
+            let Some(other_subfields) = other_subfields.get(response_key) else {
+                continue;
+            };
+            for subfield in subfields {
+                for other_subfield in other_subfields {
+                    if let Some(reason) =
+                        self.fields_conflict(subfield, other_subfield, mutually_exclusive)
+                    {
+                        return Some(format!(
+                            "subfields {response_key} conflict because {reason}"
+                        ));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn same_response_shape(&self, ty: &TypeRef<'a>, other: &TypeRef<'a>) -> bool {
+        if ty.wrappers != other.wrappers {
+            return false;
+        }
+
+        let is_leaf = |name| self.schema.kind(name).is_some_and(|kind| kind.is_leaf());
+        if is_leaf(ty.name) || is_leaf(other.name) {
+            return ty.name == other.name;
+        }
+
+        true
+    }
+
+    fn field_type(
+        &self,
+        parent: Option<&'a str>,
+        field: FieldSelection<'a>,
+    ) -> Option<TypeRef<'a>> {
+        if field.name() == "__typename" {
+            return Some(TypeRef::new("String", vec![WrappingType::NonNull]));
+        }
+
+        self.schema
+            .field(parent?, field.name())
+            .map(|definition| TypeRef::from_schema(definition.ty()))
+    }
+}
+
+fn same_arguments(field: FieldSelection<'_>, other: FieldSelection<'_>) -> bool {
+    field.arguments().len() == other.arguments().len()
+        && field.arguments().all(|argument| {
+            other.arguments().any(|other_argument| {
+                argument.name() == other_argument.name()
+                    && argument.value() == other_argument.value()
+            })
+        })
+}
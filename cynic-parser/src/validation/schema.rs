@@ -0,0 +1,285 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    common::{OperationType, WrappingType},
+    type_system::{
+        readers::{FieldDefinition, InputValueDefinition},
+        Definition, TypeDefinition,
+    },
+    TypeSystemDocument,
+};
+
+const BUILTIN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+
+/// The kinds of named type a schema can contain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+impl TypeKind {
+    pub fn is_composite(self) -> bool {
+        matches!(
+            self,
+            TypeKind::Object | TypeKind::Interface | TypeKind::Union
+        )
+    }
+
+    pub fn is_leaf(self) -> bool {
+        matches!(self, TypeKind::Scalar | TypeKind::Enum)
+    }
+
+    pub fn is_input(self) -> bool {
+        matches!(
+            self,
+            TypeKind::Scalar | TypeKind::Enum | TypeKind::InputObject
+        )
+    }
+}
+
+/// An index over a `TypeSystemDocument` that supports the lookups validation
+/// needs.
+///
+/// Type extensions are merged into the types they extend.
+pub(super) struct Schema<'a> {
+    types: HashMap<&'a str, Vec<TypeDefinition<'a>>>,
+    directives: HashMap<&'a str, Vec<InputValue<'a>>>,
+    implementations: HashMap<&'a str, HashSet<&'a str>>,
+    roots: HashMap<OperationType, &'a str>,
+}
+
+impl<'a> Schema<'a> {
+    pub fn new(document: &'a TypeSystemDocument) -> Self {
+        let mut types = HashMap::<_, Vec<_>>::new();
+        let mut directives = HashMap::new();
+        let mut implementations = HashMap::<_, HashSet<_>>::new();
+        let mut roots = HashMap::new();
+
+        for definition in document.definitions() {
+            match definition {
+                Definition::Schema(schema) | Definition::SchemaExtension(schema) => {
+                    roots.extend(schema.root_operations());
+                }
+                Definition::Type(ty) | Definition::TypeExtension(ty) => {
+                    if let TypeDefinition::Object(object) = ty {
+                        for interface in object.implements_interfaces() {
+                            implementations
+                                .entry(interface)
+                                .or_default()
+                                .insert(object.name());
+                        }
+                    }
+                    types.entry(type_name(ty)).or_default().push(ty);
+                }
+                Definition::Directive(directive) => {
+                    directives.insert(
+                        directive.name(),
+                        directive.arguments().map(InputValue::from).collect(),
+                    );
+                }
+            }
+        }
+
+        for (operation_type, default_name) in [
+            (OperationType::Query, "Query"),
+            (OperationType::Mutation, "Mutation"),
+            (OperationType::Subscription, "Subscription"),
+        ] {
+            if types.contains_key(default_name) && !roots.contains_key(&operation_type) {
+                roots.insert(operation_type, default_name);
+            }
+        }
+
+        for builtin in ["skip", "include"] {
+            directives.entry(builtin).or_insert_with(|| {
+                vec![InputValue {
+                    name: "if",
+                    ty: TypeRef::new("Boolean", vec![WrappingType::NonNull]),
+                    has_default: false,
+                }]
+            });
+        }
+
+        Schema {
+            types,
+            directives,
+            implementations,
+            roots,
+        }
+    }
+
+    pub fn root_type(&self, operation_type: OperationType) -> Option<&'a str> {
+        self.roots.get(&operation_type).copied()
+    }
+
+    pub fn kind(&self, name: &str) -> Option<TypeKind> {
+        if BUILTIN_SCALARS.contains(&name) {
+            return Some(TypeKind::Scalar);
+        }
+
+        Some(match self.types.get(name)?.first()? {
+            TypeDefinition::Scalar(_) => TypeKind::Scalar,
+            TypeDefinition::Object(_) => TypeKind::Object,
+            TypeDefinition::Interface(_) => TypeKind::Interface,
+            TypeDefinition::Union(_) => TypeKind::Union,
+            TypeDefinition::Enum(_) => TypeKind::Enum,
+            TypeDefinition::InputObject(_) => TypeKind::InputObject,
+        })
+    }
+
+    pub fn field(&self, type_name: &str, field_name: &str) -> Option<FieldDefinition<'a>> {
+        self.definitions(type_name)
+            .flat_map(
+                |definition| -> Box<dyn Iterator<Item = FieldDefinition<'a>> + 'a> {
+                    match definition {
+                        TypeDefinition::Object(object) => Box::new(object.fields()),
+                        TypeDefinition::Interface(interface) => Box::new(interface.fields()),
+                        _ => Box::new(std::iter::empty()),
+                    }
+                },
+            )
+            .find(|field| field.name() == field_name)
+    }
+
+    pub fn input_fields(&self, type_name: &str) -> Vec<InputValue<'a>> {
+        self.definitions(type_name)
+            .flat_map(|definition| match definition {
+                TypeDefinition::InputObject(input) => Some(input.fields()),
+                _ => None,
+            })
+            .flatten()
+            .map(InputValue::from)
+            .collect()
+    }
+
+    pub fn has_enum_value(&self, type_name: &str, value: &str) -> bool {
+        self.definitions(type_name)
+            .any(|definition| match definition {
+                TypeDefinition::Enum(enum_definition) => enum_definition
+                    .values()
+                    .any(|enum_value| enum_value.value() == value),
+                _ => false,
+            })
+    }
+
+    pub fn directive_arguments(&self, name: &str) -> Option<Vec<InputValue<'a>>> {
+        self.directives.get(name).cloned()
+    }
+
+    /// The set of object types that a value of the named type could be
+    pub fn possible_types(&self, name: &'a str) -> HashSet<&'a str> {
+        match self.kind(name) {
+            Some(TypeKind::Object) => HashSet::from([name]),
+            Some(TypeKind::Interface) => {
+                self.implementations.get(name).cloned().unwrap_or_default()
+            }
+            Some(TypeKind::Union) => self
+                .definitions(name)
+                .flat_map(|definition| match definition {
+                    TypeDefinition::Union(union) => Some(union.members()),
+                    _ => None,
+                })
+                .flatten()
+                .collect(),
+            _ => HashSet::new(),
+        }
+    }
+
+    fn definitions(&self, name: &str) -> impl Iterator<Item = TypeDefinition<'a>> + '_ {
+        self.types.get(name).into_iter().flatten().copied()
+    }
+}
+
+fn type_name(definition: TypeDefinition<'_>) -> &str {
+    match definition {
+        TypeDefinition::Scalar(scalar) => scalar.name(),
+        TypeDefinition::Object(object) => object.name(),
+        TypeDefinition::Interface(interface) => interface.name(),
+        TypeDefinition::Union(union) => union.name(),
+        TypeDefinition::Enum(enum_definition) => enum_definition.name(),
+        TypeDefinition::InputObject(input) => input.name(),
+    }
+}
+
+/// An argument or input object field
+#[derive(Clone)]
+pub(super) struct InputValue<'a> {
+    pub name: &'a str,
+    pub ty: TypeRef<'a>,
+    pub has_default: bool,
+}
+
+impl<'a> From<InputValueDefinition<'a>> for InputValue<'a> {
+    fn from(definition: InputValueDefinition<'a>) -> Self {
+        InputValue {
+            name: definition.name(),
+            ty: TypeRef::from_schema(definition.ty()),
+            has_default: definition.default_value().is_some(),
+        }
+    }
+}
+
+impl InputValue<'_> {
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && !self.has_default
+    }
+}
+
+/// A reference to a type from either a schema or an executable document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct TypeRef<'a> {
+    pub name: &'a str,
+
+    /// The wrapper types from the outermost to innermost
+    pub wrappers: Vec<WrappingType>,
+}
+
+impl<'a> TypeRef<'a> {
+    pub fn new(name: &'a str, wrappers: Vec<WrappingType>) -> Self {
+        TypeRef { name, wrappers }
+    }
+
+    pub fn from_schema(ty: crate::type_system::readers::Type<'a>) -> Self {
+        TypeRef::new(ty.name(), ty.wrappers().collect())
+    }
+
+    pub fn from_executable(ty: crate::executable::Type<'a>) -> Self {
+        TypeRef::new(ty.name(), ty.wrappers().collect())
+    }
+
+    pub fn is_non_null(&self) -> bool {
+        self.wrappers.first() == Some(&WrappingType::NonNull)
+    }
+
+    /// The type with its outermost wrapper removed, if it has one
+    pub fn unwrap_once(&self) -> Option<TypeRef<'a>> {
+        let (_, rest) = self.wrappers.split_first()?;
+        Some(TypeRef::new(self.name, rest.to_vec()))
+    }
+}
+
+impl fmt::Display for TypeRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for wrapper in &self.wrappers {
+            if let WrappingType::List = wrapper {
+                write!(f, "[")?;
+            }
+        }
+        write!(f, "{}", self.name)?;
+        for wrapper in self.wrappers.iter().rev() {
+            match wrapper {
+                WrappingType::NonNull => write!(f, "!")?,
+                WrappingType::List => write!(f, "]")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{common::WrappingType, executable::Value, Span};
+
+use super::schema::{Schema, TypeKind, TypeRef};
+
+/// A usage of a variable somewhere in a definition
+#[derive(Clone)]
+pub(super) struct VariableUsage<'a> {
+    pub name: &'a str,
+
+    /// The type expected where the variable was used, if it's known
+    pub expected_type: Option<TypeRef<'a>>,
+
+    /// Whether the argument or input field the variable was used in has a
+    /// default value
+    pub location_has_default: bool,
+
+    pub span: Span,
+}
+
+/// Checks whether `value` is valid for `ty`, recording any variables it uses
+/// in `usages`.
+///
+/// If `ty` is `None` the value is assumed to be valid and only variables are
+/// recorded.
+pub(super) fn check_value<'a>(
+    schema: &Schema<'a>,
+    value: &Value<'a>,
+    ty: Option<&TypeRef<'a>>,
+    has_default: bool,
+    span: Span,
+    usages: &mut Vec<VariableUsage<'a>>,
+) -> bool {
+    if let Value::Variable(name) = value {
+        usages.push(VariableUsage {
+            name,
+            expected_type: ty.cloned(),
+            location_has_default: has_default,
+            span,
+        });
+        return true;
+    }
+
+    let Some(ty) = ty else {
+        record_variables(value, span, usages);
+        return true;
+    };
+
+    match ty.wrappers.first() {
+        Some(WrappingType::NonNull) => {
+            let inner = ty.unwrap_once().expect("type to have a wrapper");
+            !matches!(value, Value::Null)
+                && check_value(schema, value, Some(&inner), false, span, usages)
+        }
+        Some(WrappingType::List) => {
+            let inner = ty.unwrap_once().expect("type to have a wrapper");
+            match value {
+                Value::Null => true,
+                Value::List(items) => items.iter().fold(true, |valid, item| {
+                    check_value(schema, item, Some(&inner), false, span, usages) && valid
+                }),
+                // Input coercion allows a single item to be provided for a list
+                _ => check_value(schema, value, Some(&inner), false, span, usages),
+            }
+        }
+        None => check_named_value(schema, value, ty.name, span, usages),
+    }
+}
+
+fn check_named_value<'a>(
+    schema: &Schema<'a>,
+    value: &Value<'a>,
+    type_name: &str,
+    span: Span,
+    usages: &mut Vec<VariableUsage<'a>>,
+) -> bool {
+    if let Value::Null = value {
+        return true;
+    }
+
+    match (schema.kind(type_name), value) {
+        (Some(TypeKind::Scalar), _) => match type_name {
+            "Int" => matches!(value, Value::Int(_)),
+            "Float" => matches!(value, Value::Int(_) | Value::Float(_)),
+            "String" => matches!(value, Value::String(_)),
+            "Boolean" => matches!(value, Value::Boolean(_)),
+            "ID" => matches!(value, Value::Int(_) | Value::String(_)),
+            _ => {
+                // We can't know what custom scalars accept, so assume the
+                // value is fine
+                record_variables(value, span, usages);
+                true
+            }
+        },
+        (Some(TypeKind::Enum), Value::Enum(enum_value)) => {
+            schema.has_enum_value(type_name, enum_value)
+        }
+        (Some(TypeKind::InputObject), Value::Object(fields)) => {
+            let definitions = schema.input_fields(type_name);
+
+            let mut valid = definitions
+                .iter()
+                .filter(|definition| definition.is_required())
+                .all(|definition| fields.iter().any(|(name, _)| *name == definition.name));
+
+            for (name, value) in fields {
+                let definition = definitions
+                    .iter()
+                    .find(|definition| definition.name == *name);
+
+                valid = check_value(
+                    schema,
+                    value,
+                    definition.map(|definition| &definition.ty),
+                    definition.is_some_and(|definition| definition.has_default),
+                    span,
+                    usages,
+                ) && definition.is_some()
+                    && valid;
+            }
+
+            valid
+        }
+        (kind, _) => {
+            record_variables(value, span, usages);
+            kind.is_none()
+        }
+    }
+}
+
+/// Records any variables used in a value whose type is unknown
+pub(super) fn record_variables<'a>(
+    value: &Value<'a>,
+    span: Span,
+    usages: &mut Vec<VariableUsage<'a>>,
+) {
+    match value {
+        Value::Variable(name) => usages.push(VariableUsage {
+            name,
+            expected_type: None,
+            location_has_default: false,
+            span,
+        }),
+        Value::List(items) => {
+            for item in items {
+                record_variables(item, span, usages);
+            }
+        }
+        Value::Object(fields) => {
+            for (_, value) in fields {
+                record_variables(value, span, usages);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,287 @@
+use cynic_parser::{parse_executable_document, parse_type_system_document, validation::validate};
+
+#[test]
+fn test_valid_document() {
+    let errors = errors(
+        r#"
+        query FilmQuery($id: ID!, $episode: Episode, $skip: Boolean = false) {
+          film(id: $id) {
+            ...FilmFields
+            director @skip(if: $skip) {
+              name
+            }
+          }
+          films(first: 10, filter: { episode: $episode, years: 1977 }) {
+            id
+            title: title
+          }
+          node(id: "123") {
+            __typename
+            ... on Person {
+              name
+            }
+          }
+          search(term: "hope") {
+            ... on Node {
+              id
+            }
+            ...PersonFields
+          }
+        }
+
+        fragment FilmFields on Film {
+          id
+          title
+          characters {
+            ...PersonFields
+          }
+        }
+
+        fragment PersonFields on Person {
+          name
+          height
+        }
+
+        mutation RateFilm($rating: Int) {
+          rateFilm(id: "1", rating: $rating) {
+            id
+          }
+        }
+        "#,
+    );
+
+    assert_eq!(errors, Vec::<String>::new());
+}
+
+#[test]
+fn test_field_selections() {
+    insta::assert_debug_snapshot!(errors(
+        r#"
+        query {
+          film(id: "1") {
+            title {
+              length
+            }
+            producer
+            director
+          }
+          search(term: "") {
+            id
+            __typename
+          }
+        }
+        "#,
+    ), @r###"
+    [
+        "title is of type String and can't have a selection set at `title`",
+        "no field named producer on Film at `producer`",
+        "director is of type Person and must have a selection set at `director`",
+        "no field named id on SearchResult at `id`",
+    ]
+    "###);
+}
+
+#[test]
+fn test_arguments() {
+    insta::assert_debug_snapshot!(errors(
+        r#"
+        query {
+          film {
+            id
+          }
+          films(first: "ten", last: 10, filter: { episode: PHANTOM }) {
+            id
+          }
+          search(term: null) {
+            __typename
+          }
+          node(id: 1) @include(if: "true") {
+            id
+          }
+        }
+
+        mutation {
+          rateFilm(id: "1") {
+            id
+          }
+        }
+        "#,
+    ), @r###"
+    [
+        "film is missing the required argument id at `film`",
+        "the value of first is not a valid Int at `first: \"ten\"`",
+        "films has no argument named last at `last: 10`",
+        "the value of filter is not a valid FilmFilter at `filter: { episode: PHANTOM }`",
+        "the value of term is not a valid String! at `term: null`",
+        "the value of if is not a valid Boolean! at `if: \"true\"`",
+    ]
+    "###);
+}
+
+#[test]
+fn test_fragments() {
+    insta::assert_debug_snapshot!(errors(
+        r#"
+        query {
+          film(id: "1") {
+            ... on Person {
+              name
+            }
+            ... on Starship {
+              name
+            }
+            ...PlanetFields
+            ...MissingFields
+          }
+        }
+
+        fragment PlanetFields on Planet {
+          name
+        }
+
+        fragment EpisodeFields on Episode {
+          name
+        }
+        "#,
+    ), @r###"
+    [
+        "fragments can't be used on Episode as it is not an object, interface or union at `fragment EpisodeFields on Episode`",
+        "a fragment on Person can never apply within Film at `... on Person`",
+        "no type named Starship in the schema at `... on Starship`",
+        "a fragment on Planet can never apply within Film at `...PlanetFields`",
+        "no fragment named MissingFields at `...MissingFields`",
+        "the fields selected as name conflict: they return conflicting types String! and String at `name`",
+    ]
+    "###);
+}
+
+#[test]
+fn test_variables() {
+    insta::assert_debug_snapshot!(errors(
+        r#"
+        query FilmQuery($id: ID, $first: Int!, $unused: String, $film: Film) {
+          film(id: $id) {
+            ...FilmFields
+          }
+          films(first: $first, filter: { titleContains: $title }) {
+            id
+          }
+        }
+
+        fragment FilmFields on Film {
+          characters(first: $count) {
+            name
+          }
+        }
+
+        mutation RateFilm($rating: Int, $id: ID! = "1") {
+          rateFilm(id: $id, rating: $rating) {
+            id
+          }
+        }
+        "#,
+    ), @r###"
+    [
+        "variable $film is of type Film which is not an input type at `$film`",
+        "variable $id is of type ID but is used where ID! is expected at `id: $id`",
+        "variable $title is not defined by the operation at `filter: { titleContains: $title }`",
+        "variable $count is not defined by the operation at `first: $count`",
+        "variable $unused is never used at `$unused`",
+        "variable $film is never used at `$film`",
+    ]
+    "###);
+}
+
+#[test]
+fn test_overlapping_fields() {
+    insta::assert_debug_snapshot!(errors(
+        r#"
+        query {
+          film(id: "1") {
+            title: releaseYear
+            title
+            characters(first: 1) {
+              name
+            }
+            characters(first: 2) {
+              name
+            }
+            ...DirectorFields
+          }
+          search(term: "") {
+            ... on Film {
+              id: title
+            }
+            ... on Person {
+              id: height
+            }
+          }
+        }
+
+        fragment DirectorFields on Film {
+          director {
+            name: id
+          }
+          director {
+            name
+          }
+        }
+        "#,
+    ), @r###"
+    [
+        "the fields selected as director conflict: subfields name conflict because id and name are different fields at `director`",
+        "the fields selected as title conflict: releaseYear and title are different fields at `title: releaseYear`",
+        "the fields selected as characters conflict: they have differing arguments at `characters`",
+        "the fields selected as id conflict: they return conflicting types String and Float at `id: title`",
+    ]
+    "###);
+}
+
+#[test]
+fn test_error_report() {
+    let document = r#"query { film(id: "1") { title: releaseYear title } }"#;
+    let schema = schema();
+    let query = parse_executable_document(document).unwrap();
+
+    let errors = validate(&schema, &query).unwrap_err();
+
+    insta::assert_display_snapshot!(errors[0].to_report(document), @r###"
+    Error: the fields selected as title conflict: releaseYear and title are different fields
+       ╭─[<unknown>:1:25]
+       │
+     1 │ query { film(id: "1") { title: releaseYear title } }
+       │                         ─────────┬──────── ──┬──  
+       │                                  ╰──────────────── this field
+       │                                              │    
+       │                                              ╰──── conflicts with this field
+    ───╯
+    "###);
+}
+
+#[test]
+fn test_unknown_root_type() {
+    insta::assert_debug_snapshot!(errors("subscription OnFilm { film(id: 1) { id } }"), @r###"
+    [
+        "the schema does not support subscription operations at `subscription OnFilm`",
+    ]
+    "###);
+}
+
+fn schema() -> cynic_parser::TypeSystemDocument {
+    parse_type_system_document(include_str!("validation/schema.graphql")).unwrap()
+}
+
+/// Validates `document`, returning the errors along with the text of their spans
+fn errors(document: &str) -> Vec<String> {
+    let query = parse_executable_document(document).unwrap();
+
+    validate(&schema(), &query)
+        .err()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|error| {
+            let span = error.span();
+            format!("{error} at `{}`", &document[span.start..span.end])
+        })
+        .collect()
+}
@@ -35,10 +35,41 @@ pub fn use_schema(input: TokenStream) -> TokenStream {
     rv
 }
 
+/// Generates the structs for a GraphQL document at compile time.
+///
+/// This runs the same generator as the `cynic querygen` command against a
+/// schema registered in your build.rs, outputting `QueryFragment`,
+/// `QueryVariables`, `InlineFragments`, `Enum`, `InputObject` & `Scalar`
+/// types for the document.  The path is relative to your `Cargo.toml`.
+///
+/// ```rust,ignore
+/// #[cynic::schema("github")]
+/// mod schema {}
+///
+/// cynic::query_document!("queries/user.graphql", schema = "github");
+/// ```
+///
+/// See [the book for usage details](https://cynic-rs.dev/query-documents.html)
+#[cfg(feature = "query-document")]
+#[proc_macro]
+pub fn query_document(input: TokenStream) -> TokenStream {
+    let input =
+        syn::parse_macro_input!(input as cynic_codegen::query_document::QueryDocumentParams);
+
+    let rv = match cynic_codegen::query_document::query_document(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    };
+
+    //eprintln!("{}", rv);
+
+    rv
+}
+
 /// Derives `cynic::QueryFragment`
 ///
 /// See [the book for usage details](https://cynic-rs.dev/derives/query-fragments.html)
-#[proc_macro_derive(QueryFragment, attributes(cynic, arguments))]
+#[proc_macro_derive(QueryFragment, attributes(cynic, arguments, directives))]
 pub fn query_fragment_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse_macro_input!(input as syn::DeriveInput);
 
@@ -70,7 +101,7 @@ pub fn query_variables_derive(input: TokenStream) -> TokenStream {
 /// Derives `cynic::InlineFragments`
 ///
 /// See [the book for usage details](https://cynic-rs.dev/derives/inline-fragments.html)
-#[proc_macro_derive(InlineFragments, attributes(cynic))]
+#[proc_macro_derive(InlineFragments, attributes(cynic, directives))]
 pub fn inline_fragments_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse_macro_input!(input as syn::DeriveInput);
 
@@ -0,0 +1,252 @@
+//! A normalized cache of GraphQL responses.
+//!
+//! A [`Cache`] splits the objects in responses out into entities, keyed by
+//! their `__typename` and `id` (or some other set of key fields configured
+//! with [`Cache::with_key_fields`]).  Each entity is stored once no matter how
+//! many operations or fields it appears in, so updating it in one place (e.g.
+//! in the response to a mutation) updates it everywhere.
+//!
+//! Operations can then be answered from the cache whenever every field they
+//! select is present.
+//!
+//! Caches are usually used via a [`Client`](crate::client::Client), which can
+//! run queries with a [`FetchPolicy`]:
+//!
+//! ```rust
+//! # mod schema {
+//! #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+//! # }
+//! #
+//! # #[derive(cynic::QueryFragment)]
+//! # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+//! # struct Film {
+//! #    title: Option<String>,
+//! # }
+//! #
+//! # #[derive(cynic::QueryFragment)]
+//! # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+//! # struct FilmQuery {
+//! #     #[arguments(id: "ZmlsbXM6MQ==")]
+//! #     film: Option<Film>,
+//! # }
+//! use cynic::{
+//!     cache::{Cache, FetchPolicy},
+//!     client::{Client, MockTransport},
+//!     QueryBuilder,
+//! };
+//! use futures::StreamExt;
+//!
+//! # futures::executor::block_on(async {
+//! let transport = MockTransport::new();
+//! transport.push_json(serde_json::json!({
+//!     "data": {"film": {"__typename": "Film", "id": "ZmlsbXM6MQ==", "title": "A New Hope"}}
+//! }));
+//!
+//! let client = Client::new(transport.clone()).with_cache(Cache::new());
+//!
+//! for _ in 0..2 {
+//!     let response = client
+//!         .run_cached(FilmQuery::build(()), FetchPolicy::CacheFirst)
+//!         .next()
+//!         .await
+//!         .unwrap()
+//!         .unwrap();
+//!
+//!     assert_eq!(
+//!         response.data.unwrap().film.unwrap().title.unwrap(),
+//!         "A New Hope"
+//!     );
+//! }
+//!
+//! // The second query was answered from the cache
+//! assert_eq!(transport.requests().len(), 1);
+//! # });
+//! ```
+
+mod normalize;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use serde_json::{Map, Value};
+
+use crate::{operation::build_selection, Operation, QueryFragment};
+
+use self::normalize::{Context, Entities};
+
+/// The feature that should be enabled on an
+/// [`OperationBuilder`](crate::OperationBuilder) to select the fields a
+/// [`Cache`] needs to identify objects.
+///
+/// This adds `__typename` to every object in the operation, and `id` to any
+/// objects that have an `id` field.  It can be enabled with
+/// [`OperationBuilder::with_cache_keys`](crate::OperationBuilder::with_cache_keys),
+/// and [`Client::run_cached`] enables it automatically.
+///
+/// [`Client::run_cached`]: crate::client::Client::run_cached
+pub const CACHE_KEYS_FEATURE: &str = crate::queries::CACHE_KEYS_FEATURE;
+
+/// How a [`Client`](crate::client::Client) should use its cache when running a
+/// query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FetchPolicy {
+    /// Returns the data from the cache if every field of the query is present,
+    /// and only sends a request if it isn't.
+    #[default]
+    CacheFirst,
+
+    /// Always sends a request, updating the cache with the response.
+    NetworkOnly,
+
+    /// Returns the data from the cache if every field of the query is
+    /// present, and then sends a request and returns that response too.
+    CacheAndNetwork,
+}
+
+/// A normalized cache of GraphQL responses.
+///
+/// Clones of a `Cache` share the same entities.
+#[derive(Clone, Default)]
+pub struct Cache {
+    inner: Arc<Mutex<CacheState>>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entities: Entities,
+    key_fields: HashMap<String, Vec<String>>,
+}
+
+impl Cache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Cache::default()
+    }
+
+    /// Sets the fields that identify objects of the type `typename`.
+    ///
+    /// By default objects are identified by their `id` field.  Note that only
+    /// `id` is selected automatically - any other key fields need to be
+    /// selected by your `QueryFragment`s.
+    pub fn with_key_fields(
+        self,
+        typename: impl Into<String>,
+        fields: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.inner.lock().unwrap().key_fields.insert(
+            typename.into(),
+            fields.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Writes the `data` from a response to `operation` into the cache.
+    ///
+    /// The operation should have been built with [`CACHE_KEYS_FEATURE`]
+    /// enabled, otherwise objects in the response can't be identified.
+    pub fn write<Fragment, Variables>(
+        &self,
+        operation: &Operation<Fragment, Variables>,
+        data: &Value,
+    ) -> Result<(), serde_json::Error>
+    where
+        Fragment: QueryFragment,
+        Variables: serde::Serialize,
+    {
+        let Value::Object(data) = data else {
+            return Ok(());
+        };
+
+        let variables = variables_map(&operation.variables)?;
+        let (selection_set, fragments, _) = build_selection::<Fragment>(operation.features());
+
+        let mut state = self.inner.lock().unwrap();
+        let CacheState {
+            entities,
+            key_fields,
+        } = &mut *state;
+
+        let context = Context {
+            variables: &variables,
+            fragments: &fragments,
+            key_fields,
+        };
+
+        normalize::write(
+            entities,
+            &context,
+            root_key::<Fragment>(),
+            &selection_set,
+            data,
+        );
+
+        Ok(())
+    }
+
+    /// Reads the data for `operation` from the cache.
+    ///
+    /// Returns `None` if any of the fields the operation selects are missing
+    /// from the cache.
+    pub fn read<Fragment, Variables>(
+        &self,
+        operation: &Operation<Fragment, Variables>,
+    ) -> Option<Fragment>
+    where
+        Fragment: QueryFragment + serde::de::DeserializeOwned,
+        Variables: serde::Serialize,
+    {
+        let variables = variables_map(&operation.variables).ok()?;
+        let (selection_set, fragments, _) = build_selection::<Fragment>(operation.features());
+
+        let state = self.inner.lock().unwrap();
+        let context = Context {
+            variables: &variables,
+            fragments: &fragments,
+            key_fields: &state.key_fields,
+        };
+
+        let data = normalize::read(
+            &state.entities,
+            &context,
+            root_key::<Fragment>(),
+            &selection_set,
+        )?;
+
+        serde_json::from_value(data).ok()
+    }
+
+    /// Returns the normalized fields of the entity with the given key, e.g.
+    /// `Film:ZmlsbXM6MQ==`.
+    ///
+    /// Any references to other entities are stored as `{"__ref": "<key>"}`.
+    pub fn entity(&self, key: &str) -> Option<Value> {
+        self.inner
+            .lock()
+            .unwrap()
+            .entities
+            .get(key)
+            .cloned()
+            .map(Value::Object)
+    }
+
+    /// Removes everything from the cache.
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().entities.clear();
+    }
+}
+
+/// The key that the root fields of operations for `Fragment` are stored under
+fn root_key<Fragment: QueryFragment>() -> &'static str {
+    Fragment::TYPE.unwrap_or("ROOT")
+}
+
+fn variables_map(
+    variables: &impl serde::Serialize,
+) -> Result<Map<String, Value>, serde_json::Error> {
+    match serde_json::to_value(variables)? {
+        Value::Object(variables) => Ok(variables),
+        _ => Ok(Map::new()),
+    }
+}
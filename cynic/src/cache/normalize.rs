@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::queries::{
+    Directive, FieldSelection, FragmentDefinitions, InputLiteral, Selection, SelectionSet,
+};
+
+/// The key that references to other entities are stored under.
+const REFERENCE_KEY: &str = "__ref";
+
+/// The fields that identify a type if no key fields have been configured.
+const DEFAULT_KEY_FIELDS: &[&str] = &["id"];
+
+pub(super) type Entities = HashMap<String, Map<String, Value>>;
+
+/// The details of an operation that are needed to normalize its responses.
+pub(super) struct Context<'a> {
+    pub variables: &'a Map<String, Value>,
+    pub fragments: &'a FragmentDefinitions,
+    pub key_fields: &'a HashMap<String, Vec<String>>,
+}
+
+impl Context<'_> {
+    /// The key an object is stored under, if it can be identified.
+    fn entity_key(&self, object: &Map<String, Value>) -> Option<String> {
+        let typename = object.get("__typename")?.as_str()?;
+
+        let values = match self.key_fields.get(typename) {
+            Some(fields) => fields
+                .iter()
+                .map(|field| key_value(object, field))
+                .collect::<Option<Vec<_>>>()?,
+            None => DEFAULT_KEY_FIELDS
+                .iter()
+                .map(|field| key_value(object, field))
+                .collect::<Option<Vec<_>>>()?,
+        };
+
+        match values.as_slice() {
+            [] => None,
+            [Value::String(id)] => Some(format!("{typename}:{id}")),
+            [value] => Some(format!("{typename}:{value}")),
+            values => Some(format!(
+                "{typename}:{}",
+                Value::Array(values.iter().map(|&value| value.clone()).collect())
+            )),
+        }
+    }
+
+    /// The key a field is stored under in its parent.
+    ///
+    /// This is the name of the field along with any arguments it has, so the
+    /// same field with different arguments is stored separately.
+    fn storage_key(&self, field: &FieldSelection) -> String {
+        if field.arguments.is_empty() {
+            return field.name.to_string();
+        }
+
+        let arguments = field
+            .arguments
+            .iter()
+            .map(|argument| (argument.name.to_string(), self.literal(&argument.value)))
+            .collect::<Map<_, _>>();
+
+        format!("{}({})", field.name, Value::Object(arguments))
+    }
+
+    fn literal(&self, literal: &InputLiteral) -> Value {
+        match literal {
+            InputLiteral::Int(value) => Value::from(*value),
+            InputLiteral::Float(value) => Value::from(*value),
+            InputLiteral::Bool(value) => Value::from(*value),
+            InputLiteral::String(value) => Value::from(value.as_ref()),
+            InputLiteral::Id(value) => Value::from(value.as_str()),
+            InputLiteral::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|field| (field.name.to_string(), self.literal(&field.value)))
+                    .collect(),
+            ),
+            InputLiteral::List(values) => {
+                Value::Array(values.iter().map(|value| self.literal(value)).collect())
+            }
+            InputLiteral::Variable(name) => {
+                self.variables.get(*name).cloned().unwrap_or(Value::Null)
+            }
+            InputLiteral::Null => Value::Null,
+            InputLiteral::EnumValue(value) => Value::from(*value),
+        }
+    }
+
+    /// Whether a `@skip` or `@include` directive means a selection won't be
+    /// in the response.
+    fn is_skipped(&self, directives: &[Directive]) -> bool {
+        directives.iter().any(|directive| {
+            let condition = directive
+                .arguments
+                .iter()
+                .find(|argument| argument.name == "if")
+                .map(|argument| self.literal(&argument.value));
+
+            match (directive.name, condition) {
+                ("skip", Some(Value::Bool(condition))) => condition,
+                ("include", Some(Value::Bool(condition))) => !condition,
+                _ => false,
+            }
+        })
+    }
+}
+
+fn key_value<'a>(object: &'a Map<String, Value>, field: &str) -> Option<&'a Value> {
+    object.get(field).filter(|value| !value.is_null())
+}
+
+fn response_key(field: &FieldSelection) -> &str {
+    field.alias.as_deref().unwrap_or(field.name)
+}
+
+/// Writes the response `data` for `selection_set` into the entity stored
+/// under `root_key`, normalizing any identifiable objects into their own
+/// entities.
+pub(super) fn write(
+    entities: &mut Entities,
+    context: &Context<'_>,
+    root_key: &str,
+    selection_set: &SelectionSet,
+    data: &Map<String, Value>,
+) {
+    let mut writer = Writer { entities, context };
+
+    let mut fields = Map::new();
+    writer.write_selections(&mut fields, selection_set, data);
+    writer
+        .entities
+        .entry(root_key.to_string())
+        .or_default()
+        .extend(fields);
+}
+
+struct Writer<'a, 'b> {
+    entities: &'a mut Entities,
+    context: &'a Context<'b>,
+}
+
+impl Writer<'_, '_> {
+    fn write_selections(
+        &mut self,
+        fields: &mut Map<String, Value>,
+        selection_set: &SelectionSet,
+        data: &Map<String, Value>,
+    ) {
+        for selection in &selection_set.selections {
+            match selection {
+                Selection::Field(field) => {
+                    let Some(value) = data.get(response_key(field)) else {
+                        continue;
+                    };
+                    let value = self.write_value(&field.children, value);
+                    fields.insert(self.context.storage_key(field), value);
+                }
+                Selection::InlineFragment(fragment) => {
+                    self.write_selections(fields, &fragment.children, data)
+                }
+                Selection::FragmentSpread(spread) => {
+                    if let Some((_, selection_set)) = self.context.fragments.get(&spread.name) {
+                        self.write_selections(fields, selection_set, data)
+                    }
+                }
+            }
+        }
+    }
+
+    fn write_value(&mut self, selection_set: &SelectionSet, value: &Value) -> Value {
+        if selection_set.selections.is_empty() {
+            return value.clone();
+        }
+
+        match value {
+            Value::Array(values) => Value::Array(
+                values
+                    .iter()
+                    .map(|value| self.write_value(selection_set, value))
+                    .collect(),
+            ),
+            Value::Object(object) => {
+                let mut fields = Map::new();
+                self.write_selections(&mut fields, selection_set, object);
+
+                let Some(key) = self.context.entity_key(object) else {
+                    return Value::Object(fields);
+                };
+
+                self.entities.entry(key.clone()).or_default().extend(fields);
+
+                let mut reference = Map::new();
+                reference.insert(REFERENCE_KEY.to_string(), Value::String(key));
+                Value::Object(reference)
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+/// Reads the data for `selection_set` from the entity stored under
+/// `root_key`.
+///
+/// Returns `None` if any of the selected fields are missing from the cache.
+pub(super) fn read(
+    entities: &Entities,
+    context: &Context<'_>,
+    root_key: &str,
+    selection_set: &SelectionSet,
+) -> Option<Value> {
+    let reader = Reader { entities, context };
+
+    let mut output = Map::new();
+    reader.read_selections(entities.get(root_key)?, selection_set, &mut output)?;
+
+    Some(Value::Object(output))
+}
+
+struct Reader<'a, 'b> {
+    entities: &'a Entities,
+    context: &'a Context<'b>,
+}
+
+impl Reader<'_, '_> {
+    fn read_selections(
+        &self,
+        fields: &Map<String, Value>,
+        selection_set: &SelectionSet,
+        output: &mut Map<String, Value>,
+    ) -> Option<()> {
+        for selection in &selection_set.selections {
+            match selection {
+                Selection::Field(field) => {
+                    if self.context.is_skipped(&field.directives) {
+                        continue;
+                    }
+                    let value = fields.get(&self.context.storage_key(field))?;
+                    output.insert(
+                        response_key(field).to_string(),
+                        self.read_value(&field.children, value)?,
+                    );
+                }
+                Selection::InlineFragment(fragment) => {
+                    if self.context.is_skipped(&fragment.directives) {
+                        continue;
+                    }
+                    // Deferred fields are always optional, so it's fine if
+                    // they're missing
+                    let required = !fragment
+                        .directives
+                        .iter()
+                        .any(|directive| directive.name == "defer");
+
+                    self.read_fragment(
+                        fields,
+                        fragment.on_clause,
+                        &fragment.children,
+                        required,
+                        output,
+                    )?;
+                }
+                Selection::FragmentSpread(spread) => {
+                    let (type_condition, selection_set) =
+                        self.context.fragments.get(&spread.name)?;
+
+                    self.read_fragment(fields, Some(type_condition), selection_set, true, output)?;
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    fn read_fragment(
+        &self,
+        fields: &Map<String, Value>,
+        type_condition: Option<&str>,
+        selection_set: &SelectionSet,
+        required: bool,
+        output: &mut Map<String, Value>,
+    ) -> Option<()> {
+        let mut fragment_output = Map::new();
+        if self
+            .read_selections(fields, selection_set, &mut fragment_output)
+            .is_some()
+        {
+            output.extend(fragment_output);
+            return Some(());
+        }
+
+        // We don't know the possible types of interfaces & unions, so a
+        // fragment that doesn't name the type of the object exactly is
+        // assumed not to apply if we can't read it.
+        let typename = fields.get("__typename").and_then(Value::as_str);
+        let applies = match (type_condition, typename) {
+            (Some(type_condition), Some(typename)) => type_condition == typename,
+            _ => true,
+        };
+
+        if required && applies {
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn read_value(&self, selection_set: &SelectionSet, value: &Value) -> Option<Value> {
+        if selection_set.selections.is_empty() {
+            return Some(value.clone());
+        }
+
+        match value {
+            Value::Array(values) => values
+                .iter()
+                .map(|value| self.read_value(selection_set, value))
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Array),
+            Value::Object(object) => {
+                let fields = match object.get(REFERENCE_KEY) {
+                    Some(Value::String(key)) => self.entities.get(key)?,
+                    _ => object,
+                };
+
+                let mut output = Map::new();
+                self.read_selections(fields, selection_set, &mut output)?;
+
+                Some(Value::Object(output))
+            }
+            other => Some(other.clone()),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Middleware for a [`Client`](super::Client), and some common
+//! implementations of it.
+
+use std::sync::{Arc, RwLock};
+
+use super::{BoxFuture, ClientError, Request, Response, Transport};
+use crate::OperationKind;
+
+/// A layer that sits between a [`Client`](super::Client) and its
+/// [`Transport`], with the chance to modify each request and response.
+pub trait Middleware: Send + Sync {
+    /// Handles a request.
+    ///
+    /// Implementations should usually call `next.run` to pass the request on
+    /// to the rest of the stack.  They are free to modify the request before
+    /// doing so, modify the response afterwards, or call `next.run` several
+    /// times.
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, ClientError>>;
+}
+
+/// The remainder of a middleware stack.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    transport: &'a dyn Transport,
+}
+
+impl<'a> Next<'a> {
+    pub(super) fn new(middleware: &'a [Arc<dyn Middleware>], transport: &'a dyn Transport) -> Self {
+        Next {
+            middleware,
+            transport,
+        }
+    }
+
+    /// Passes the request on to the rest of the stack.
+    pub fn run(self, request: Request) -> BoxFuture<'a, Result<Response, ClientError>> {
+        match self.middleware.split_first() {
+            Some((current, rest)) => current.handle(
+                request,
+                Next {
+                    middleware: rest,
+                    transport: self.transport,
+                },
+            ),
+            None => self.transport.send(request),
+        }
+    }
+}
+
+/// Adds a fixed set of headers to every request.
+#[derive(Clone, Debug, Default)]
+pub struct Headers {
+    headers: Vec<(String, String)>,
+}
+
+impl Headers {
+    /// Creates an empty `Headers` middleware.
+    pub fn new() -> Self {
+        Headers::default()
+    }
+
+    /// Adds a header that will be sent with every request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Middleware for Headers {
+    fn handle<'a>(
+        &'a self,
+        mut request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, ClientError>> {
+        for (name, value) in &self.headers {
+            request.set_header(name, value);
+        }
+        next.run(request)
+    }
+}
+
+type TokenFn = dyn Fn() -> BoxFuture<'static, Result<String, ClientError>> + Send + Sync;
+
+/// Adds a bearer token to each request, fetching a new token when the
+/// server responds with a 401.
+///
+/// The `fetch_token` function will be called before the first request and
+/// whenever a request is rejected as unauthorized.  Requests that are rejected
+/// are retried once with the new token.
+pub struct AuthRefresh {
+    token: RwLock<Option<String>>,
+    fetch_token: Box<TokenFn>,
+}
+
+impl AuthRefresh {
+    /// Creates an `AuthRefresh` middleware that gets tokens from `fetch_token`
+    pub fn new<F>(fetch_token: F) -> Self
+    where
+        F: Fn() -> BoxFuture<'static, Result<String, ClientError>> + Send + Sync + 'static,
+    {
+        AuthRefresh {
+            token: RwLock::new(None),
+            fetch_token: Box::new(fetch_token),
+        }
+    }
+
+    async fn refresh(&self) -> Result<String, ClientError> {
+        let token = (self.fetch_token)().await?;
+        *self.token.write().unwrap() = Some(token.clone());
+        Ok(token)
+    }
+}
+
+impl Middleware for AuthRefresh {
+    fn handle<'a>(
+        &'a self,
+        mut request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, ClientError>> {
+        Box::pin(async move {
+            let current_token = self.token.read().unwrap().clone();
+            let token = match current_token {
+                Some(token) => token,
+                None => self.refresh().await?,
+            };
+
+            request.set_header("Authorization", format!("Bearer {token}"));
+            let response = next.run(request.clone()).await?;
+            if response.status != 401 {
+                return Ok(response);
+            }
+
+            let token = self.refresh().await?;
+            request.set_header("Authorization", format!("Bearer {token}"));
+            next.run(request).await
+        })
+    }
+}
+
+type DelayFn = dyn Fn(u32) -> BoxFuture<'static, ()> + Send + Sync;
+
+/// Retries requests that fail with a transport error or a 5xx status.
+///
+/// Only queries are retried by default: a mutation that failed may still have
+/// been applied on the server, so retrying it isn't safe unless the mutation is
+/// idempotent.  Use [`Retry::retry_mutations`] to retry everything.
+pub struct Retry {
+    max_retries: u32,
+    retry_mutations: bool,
+    delay: Option<Box<DelayFn>>,
+}
+
+impl Retry {
+    /// Creates a `Retry` middleware that will retry each request up to
+    /// `max_retries` times.
+    pub fn new(max_retries: u32) -> Self {
+        Retry {
+            max_retries,
+            retry_mutations: false,
+            delay: None,
+        }
+    }
+
+    /// Retries mutations (and any other request that isn't known to be a
+    /// query) as well as queries.
+    ///
+    /// Only enable this if all of your mutations are safe to run twice.
+    pub fn retry_mutations(mut self) -> Self {
+        self.retry_mutations = true;
+        self
+    }
+
+    /// Sets a function to wait between retries.
+    ///
+    /// This is called with the number of the upcoming retry (starting at 1),
+    /// and should return a future that completes when it's time to retry. By
+    /// default retries happen immediately - cynic doesn't depend on any
+    /// particular async runtime, so you'll need to provide a delay function
+    /// using the timers of your runtime of choice.
+    pub fn with_delay<F>(mut self, delay: F) -> Self
+    where
+        F: Fn(u32) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+    {
+        self.delay = Some(Box::new(delay));
+        self
+    }
+}
+
+impl Middleware for Retry {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, ClientError>> {
+        let can_retry =
+            self.retry_mutations || request.operation_kind == Some(OperationKind::Query);
+
+        Box::pin(async move {
+            let mut attempt = 0;
+            loop {
+                let result = next.run(request.clone()).await;
+                let should_retry = match &result {
+                    Ok(response) => response.status >= 500,
+                    Err(ClientError::Transport(_)) => true,
+                    Err(_) => false,
+                };
+
+                if !can_retry || !should_retry || attempt >= self.max_retries {
+                    return result;
+                }
+
+                attempt += 1;
+                if let Some(delay) = &self.delay {
+                    delay(attempt).await;
+                }
+            }
+        })
+    }
+}
+
+/// The events reported by the [`Logging`] middleware.
+#[derive(Debug)]
+pub enum LogEvent<'a> {
+    /// A request is about to be sent
+    Request(&'a Request),
+    /// A response has been received
+    Response(&'a Request, &'a Response),
+    /// A request failed
+    Error(&'a Request, &'a ClientError),
+}
+
+type LogFn = dyn Fn(LogEvent<'_>) + Send + Sync;
+
+/// Calls a function with each request & response, so they can be logged
+/// with the logging library of your choice.
+pub struct Logging {
+    log: Box<LogFn>,
+}
+
+impl Logging {
+    /// Creates a `Logging` middleware that calls `log` with each event.
+    pub fn new<F>(log: F) -> Self
+    where
+        F: Fn(LogEvent<'_>) + Send + Sync + 'static,
+    {
+        Logging { log: Box::new(log) }
+    }
+}
+
+impl Middleware for Logging {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, ClientError>> {
+        Box::pin(async move {
+            (self.log)(LogEvent::Request(&request));
+            let result = next.run(request.clone()).await;
+            match &result {
+                Ok(response) => (self.log)(LogEvent::Response(&request, response)),
+                Err(error) => (self.log)(LogEvent::Error(&request, error)),
+            }
+            result
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type TimingFn = dyn Fn(Option<&str>, std::time::Duration) + Send + Sync;
+
+/// Measures how long each request takes, and reports it to a function
+/// along with the name of the operation.
+///
+/// This isn't available on wasm, where `std::time::Instant` isn't supported.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Timing {
+    report: Box<TimingFn>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Timing {
+    /// Creates a `Timing` middleware that calls `report` with the duration
+    /// of each request.
+    pub fn new<F>(report: F) -> Self
+    where
+        F: Fn(Option<&str>, std::time::Duration) + Send + Sync + 'static,
+    {
+        Timing {
+            report: Box::new(report),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Middleware for Timing {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, ClientError>> {
+        Box::pin(async move {
+            let operation_name = request.operation_name.clone();
+            let start = std::time::Instant::now();
+            let result = next.run(request).await;
+            (self.report)(operation_name.as_deref(), start.elapsed());
+            result
+        })
+    }
+}
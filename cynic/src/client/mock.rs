@@ -0,0 +1,71 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use super::{BoxFuture, ClientError, Request, Response, Transport};
+
+/// A [`Transport`] that returns canned responses, for use in tests.
+///
+/// Responses are returned in the order they were pushed, and each request is
+/// recorded so tests can make assertions about it.  Clones of a
+/// `MockTransport` share their responses & requests, so you can keep a clone
+/// around after giving one to a `Client`.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    inner: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    responses: VecDeque<Result<Response, String>>,
+    requests: Vec<Request>,
+}
+
+impl MockTransport {
+    /// Creates a `MockTransport` with no responses.
+    pub fn new() -> Self {
+        MockTransport::default()
+    }
+
+    /// Queues a response to be returned.
+    pub fn push_response(&self, response: Response) {
+        self.inner.lock().unwrap().responses.push_back(Ok(response));
+    }
+
+    /// Queues a successful response containing `body` as JSON.
+    pub fn push_json(&self, body: serde_json::Value) {
+        self.push_response(Response {
+            status: 200,
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: body.to_string().into_bytes(),
+        })
+    }
+
+    /// Queues a transport error with the given message.
+    pub fn push_error(&self, message: impl Into<String>) {
+        self.inner
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(Err(message.into()));
+    }
+
+    /// Returns all the requests this transport has received.
+    pub fn requests(&self) -> Vec<Request> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, ClientError>> {
+        let mut state = self.inner.lock().unwrap();
+        state.requests.push(request);
+        let response = state
+            .responses
+            .pop_front()
+            .unwrap_or_else(|| Err("MockTransport has no more responses".into()));
+
+        Box::pin(async move { response.map_err(ClientError::transport) })
+    }
+}
@@ -0,0 +1,497 @@
+//! A transport agnostic GraphQL client.
+//!
+//! [`Client`] runs [`Operation`]s over any [`Transport`] - an HTTP library or
+//! anything else that can send some bytes and return some bytes.  Requests &
+//! responses pass through a stack of [`Middleware`], which can be used to add
+//! headers, refresh authentication, retry failures, log etc.
+//!
+//! ```rust
+//! # mod schema {
+//! #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+//! # }
+//! #
+//! # #[derive(cynic::QueryFragment)]
+//! # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+//! # struct Film {
+//! #    title: Option<String>,
+//! #    director: Option<String>
+//! # }
+//! #
+//! # #[derive(cynic::QueryFragment)]
+//! # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+//! # struct FilmDirectorQuery {
+//! #     #[arguments(id = cynic::Id::new("ZmlsbXM6MQ=="))]
+//! #     film: Option<Film>,
+//! # }
+//! use cynic::{
+//!     client::{middleware, Client, MockTransport},
+//!     QueryBuilder,
+//! };
+//!
+//! # futures::executor::block_on(async {
+//! let transport = MockTransport::new();
+//! transport.push_json(serde_json::json!({
+//!     "data": {"film": {"title": "A New Hope", "director": "George Lucas"}}
+//! }));
+//!
+//! let client = Client::new(transport.clone())
+//!     .with(middleware::Headers::new().header("Authorization", "Bearer abcd"))
+//!     .with(middleware::Retry::new(3));
+//!
+//! let response = client.run(FilmDirectorQuery::build(())).await.unwrap();
+//!
+//! assert_eq!(
+//!     response.data.unwrap().film.unwrap().director.unwrap(),
+//!     "George Lucas"
+//! );
+//! assert_eq!(
+//!     transport.requests()[0].header("Authorization"),
+//!     Some("Bearer abcd")
+//! );
+//! # });
+//! ```
+
+pub mod middleware;
+
+mod mock;
+mod transports;
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use crate::{GraphQlResponse, Operation, OperationKind};
+
+pub use self::{middleware::Middleware, mock::MockTransport};
+
+#[cfg(all(feature = "http-reqwest", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "http-reqwest")))]
+pub use self::transports::ReqwestTransport;
+
+#[cfg(feature = "http-surf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http-surf")))]
+pub use self::transports::SurfTransport;
+
+/// A boxed future, as returned by [`Transport`] & [`Middleware`]
+/// implementations.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Something that can send a serialized operation to a server and return
+/// the response.
+pub trait Transport: Send + Sync {
+    /// Sends a request, returning the response.
+    ///
+    /// Implementations should return the response regardless of its status
+    /// code - only failures to send the request or read the response should be
+    /// returned as errors.
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, ClientError>>;
+}
+
+impl<T> Transport for Arc<T>
+where
+    T: Transport + ?Sized,
+{
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, ClientError>> {
+        (**self).send(request)
+    }
+}
+
+/// A request to be sent by a [`Transport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// The name of the operation in this request, if it has one.
+    pub operation_name: Option<String>,
+
+    /// The kind of the operation in this request, if it's known.
+    pub operation_kind: Option<OperationKind>,
+
+    /// Any headers that should be sent along with the request.
+    pub headers: Vec<(String, String)>,
+
+    /// The JSON encoded operation.
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Creates a request with the given JSON body.
+    pub fn new(body: Vec<u8>) -> Self {
+        Request {
+            operation_name: None,
+            operation_kind: None,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Gets the value of a header on this request, if it's present.
+    ///
+    /// Header names are matched case insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Sets a header, replacing any existing value for it.
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.headers
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value.into()));
+    }
+}
+
+/// A response returned from a [`Transport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// The HTTP status code of the response (or something equivalent for
+    /// non-HTTP transports).
+    pub status: u16,
+
+    /// The headers of the response.
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates a response with the given status & body.
+    pub fn new(status: u16, body: Vec<u8>) -> Self {
+        Response {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Whether the status of this response indicates success
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Gets the value of a header on this response, if it's present.
+    ///
+    /// Header names are matched case insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// The errors that can be returned by a [`Client`].
+#[derive(thiserror::Error, Debug)]
+pub enum ClientError {
+    /// The transport failed to send a request or receive a response.
+    #[error("Transport error: {0}")]
+    Transport(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// The server returned an error response that didn't contain a GraphQL
+    /// response.
+    #[error("Server returned {0}: {1}")]
+    ErrorResponse(u16, String),
+
+    /// The operation couldn't be serialized.
+    #[error("Couldn't serialize the operation: {0}")]
+    Serialization(#[source] serde_json::Error),
+
+    /// The response couldn't be deserialized.
+    #[error("Couldn't decode the response: {0}")]
+    Decode(#[source] serde_json::Error),
+
+    /// A middleware failed the request.
+    #[error("{0}")]
+    Middleware(String),
+
+    /// The operation couldn't be built.
+    #[error("Couldn't build the operation: {0}")]
+    Build(#[source] crate::OperationBuildError),
+}
+
+impl ClientError {
+    /// Creates a `ClientError::Transport` from any error.
+    pub fn transport(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        ClientError::Transport(error.into())
+    }
+}
+
+/// A GraphQL client that sends operations over a [`Transport`], via a stack
+/// of [`Middleware`].
+#[derive(Clone)]
+pub struct Client {
+    transport: Arc<dyn Transport>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    #[cfg(feature = "cache")]
+    cache: Option<crate::cache::Cache>,
+}
+
+impl Client {
+    /// Creates a new client using the given transport.
+    pub fn new(transport: impl Transport + 'static) -> Self {
+        Client {
+            transport: Arc::new(transport),
+            middleware: Vec::new(),
+            #[cfg(feature = "cache")]
+            cache: None,
+        }
+    }
+
+    /// Adds a middleware to this client.
+    ///
+    /// Middleware runs in the order it was added: the first middleware added
+    /// sees the request first and the response last.
+    pub fn with(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the cache used by [`Client::run_cached`] &
+    /// [`Client::run_mutation_cached`].
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub fn with_cache(mut self, cache: crate::cache::Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The cache used by this client, if it has one.
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub fn cache(&self) -> Option<&crate::cache::Cache> {
+        self.cache.as_ref()
+    }
+
+    /// Runs an operation, returning the response.
+    pub async fn run<ResponseData, Vars>(
+        &self,
+        operation: Operation<ResponseData, Vars>,
+    ) -> Result<GraphQlResponse<ResponseData>, ClientError>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+    {
+        self.send_json(&operation, OperationInfo::of(&operation))
+            .await
+    }
+
+    /// Runs an operation as an automatic persisted query.
+    ///
+    /// This first sends only the hash of the query.  If the server responds
+    /// with a `PersistedQueryNotFound` error (or an error with a
+    /// `PERSISTED_QUERY_NOT_FOUND` code) the request is re-sent with the full
+    /// query document, which the server should then remember for future
+    /// requests.
+    pub async fn run_persisted<ResponseData, Vars>(
+        &self,
+        operation: Operation<ResponseData, Vars>,
+    ) -> Result<GraphQlResponse<ResponseData>, ClientError>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+    {
+        let info = OperationInfo::of(&operation);
+        let persisted_query = operation.persisted_query();
+
+        let response = self
+            .send_json::<ResponseData, serde_json::Value>(&persisted_query, info)
+            .await?;
+        if !response.is_persisted_query_not_found() {
+            return Ok(response.without_error_extensions());
+        }
+
+        self.send_json(&persisted_query.with_document(), info).await
+    }
+
+    /// Runs a query using the cache of this client according to `policy`.
+    ///
+    /// This returns a stream that yields the data from the cache and/or the
+    /// response from the server - see [`FetchPolicy`] for details.  Any
+    /// response from the server is written into the cache.
+    ///
+    /// Unless it was built with [`CACHE_KEYS_FEATURE`] enabled the operation
+    /// is rebuilt with it, along with any other features it was built with,
+    /// so that objects in the response can be identified.  If this client has
+    /// no cache every query is sent to the server.
+    ///
+    /// [`FetchPolicy`]: crate::cache::FetchPolicy
+    /// [`CACHE_KEYS_FEATURE`]: crate::cache::CACHE_KEYS_FEATURE
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub fn run_cached<'a, ResponseData, Vars>(
+        &'a self,
+        operation: Operation<ResponseData, Vars>,
+        policy: crate::cache::FetchPolicy,
+    ) -> futures_util::stream::BoxStream<'a, Result<GraphQlResponse<ResponseData>, ClientError>>
+    where
+        ResponseData: crate::QueryFragment + serde::de::DeserializeOwned + Send + 'a,
+        ResponseData::SchemaType: crate::schema::QueryRoot,
+        Vars: crate::QueryVariables + serde::Serialize + Send + Sync + 'a,
+    {
+        use crate::cache::FetchPolicy;
+        use futures_util::{stream, StreamExt};
+
+        let operation = match with_cache_keys(crate::OperationBuilder::query(), operation) {
+            Ok(operation) => operation,
+            Err(error) => return stream::once(async move { Err(error) }).boxed(),
+        };
+
+        let cached = match (&self.cache, policy) {
+            (_, FetchPolicy::NetworkOnly) | (None, _) => None,
+            (Some(cache), _) => cache.read(&operation).map(|data| {
+                Ok(GraphQlResponse {
+                    data: Some(data),
+                    errors: None,
+                    extensions: None,
+                })
+            }),
+        };
+
+        if policy == FetchPolicy::CacheFirst && cached.is_some() {
+            return stream::iter(cached).boxed();
+        }
+
+        stream::iter(cached)
+            .chain(stream::once(self.run_and_cache(operation)))
+            .boxed()
+    }
+
+    /// Runs a mutation, updating any objects in the response in the cache of
+    /// this client.
+    ///
+    /// Unless it was built with [`CACHE_KEYS_FEATURE`] enabled the operation
+    /// is rebuilt with it, along with any other features it was built with,
+    /// so that objects in the response can be identified.
+    ///
+    /// [`CACHE_KEYS_FEATURE`]: crate::cache::CACHE_KEYS_FEATURE
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub async fn run_mutation_cached<ResponseData, Vars>(
+        &self,
+        operation: Operation<ResponseData, Vars>,
+    ) -> Result<GraphQlResponse<ResponseData>, ClientError>
+    where
+        ResponseData: crate::QueryFragment + serde::de::DeserializeOwned,
+        ResponseData::SchemaType: crate::schema::MutationRoot,
+        Vars: crate::QueryVariables + serde::Serialize,
+    {
+        let operation = with_cache_keys(crate::OperationBuilder::mutation(), operation)?;
+
+        self.run_and_cache(operation).await
+    }
+
+    #[cfg(feature = "cache")]
+    async fn run_and_cache<ResponseData, Vars>(
+        &self,
+        operation: Operation<ResponseData, Vars>,
+    ) -> Result<GraphQlResponse<ResponseData>, ClientError>
+    where
+        ResponseData: crate::QueryFragment + serde::de::DeserializeOwned,
+        Vars: serde::Serialize,
+    {
+        let response = self
+            .send_json::<serde_json::Value, serde::de::IgnoredAny>(
+                &operation,
+                OperationInfo::of(&operation),
+            )
+            .await?;
+
+        if let (Some(cache), Some(data)) = (&self.cache, &response.data) {
+            cache
+                .write(&operation, data)
+                .map_err(ClientError::Serialization)?;
+        }
+
+        Ok(GraphQlResponse {
+            data: response
+                .data
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(ClientError::Decode)?,
+            errors: response.errors,
+            extensions: response.extensions,
+        })
+    }
+
+    async fn send_json<ResponseData, ErrorExtensions>(
+        &self,
+        body: &impl serde::Serialize,
+        info: OperationInfo<'_>,
+    ) -> Result<GraphQlResponse<ResponseData, ErrorExtensions>, ClientError>
+    where
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+        ErrorExtensions: serde::de::DeserializeOwned,
+    {
+        let mut request =
+            Request::new(serde_json::to_vec(body).map_err(ClientError::Serialization)?);
+        request.operation_name = info.name.map(Into::into);
+        request.operation_kind = info.kind;
+        request.set_header("Content-Type", "application/json");
+        request.set_header("Accept", "application/json");
+
+        let response = self.send(request).await?;
+
+        if !response.is_success() {
+            return serde_json::from_slice(&response.body).map_err(|_| {
+                ClientError::ErrorResponse(
+                    response.status,
+                    String::from_utf8_lossy(&response.body).into_owned(),
+                )
+            });
+        }
+
+        serde_json::from_slice(&response.body).map_err(ClientError::Decode)
+    }
+
+    /// Sends a request through the middleware stack & transport of this
+    /// client, without any GraphQL specific handling.
+    pub fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, ClientError>> {
+        middleware::Next::new(&self.middleware, self.transport.as_ref()).run(request)
+    }
+}
+
+/// The details of an operation that are copied onto a [`Request`].
+#[derive(Clone, Copy)]
+struct OperationInfo<'a> {
+    name: Option<&'a str>,
+    kind: Option<OperationKind>,
+}
+
+impl<'a> OperationInfo<'a> {
+    fn of<ResponseData, Vars>(operation: &'a Operation<ResponseData, Vars>) -> Self {
+        OperationInfo {
+            name: operation.operation_name.as_deref(),
+            kind: operation.kind(),
+        }
+    }
+}
+
+/// Rebuilds `operation` with the fields needed to identify objects in a
+/// normalized cache, keeping any other features it was built with.
+#[cfg(feature = "cache")]
+fn with_cache_keys<ResponseData, Vars>(
+    mut builder: crate::OperationBuilder<ResponseData, Vars>,
+    operation: Operation<ResponseData, Vars>,
+) -> Result<Operation<ResponseData, Vars>, ClientError>
+where
+    ResponseData: crate::QueryFragment,
+    Vars: crate::QueryVariables,
+{
+    if operation.is_feature_enabled(crate::cache::CACHE_KEYS_FEATURE) {
+        return Ok(operation);
+    }
+
+    if let Some(name) = &operation.operation_name {
+        builder.set_operation_name(name);
+    }
+    for feature in operation.features() {
+        builder.enable_feature(feature);
+    }
+
+    builder
+        .with_variables(operation.variables)
+        .with_cache_keys()
+        .build()
+        .map_err(ClientError::Build)
+}
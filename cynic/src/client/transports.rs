@@ -0,0 +1,103 @@
+#[cfg(all(feature = "http-reqwest", not(target_arch = "wasm32")))]
+pub use self::reqwest::ReqwestTransport;
+
+#[cfg(feature = "http-surf")]
+pub use self::surf::SurfTransport;
+
+#[cfg(all(feature = "http-reqwest", not(target_arch = "wasm32")))]
+mod reqwest {
+    use super::super::{BoxFuture, ClientError, Request, Response, Transport};
+
+    /// A [`Transport`] that sends requests to a URL using [`reqwest`].
+    #[derive(Clone, Debug)]
+    pub struct ReqwestTransport {
+        client: reqwest::Client,
+        url: reqwest::Url,
+    }
+
+    impl ReqwestTransport {
+        /// Creates a `ReqwestTransport` that sends requests to `url` with
+        /// `client`.
+        pub fn new(client: reqwest::Client, url: reqwest::Url) -> Self {
+            ReqwestTransport { client, url }
+        }
+    }
+
+    impl Transport for ReqwestTransport {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, ClientError>> {
+            let mut builder = self.client.post(self.url.clone()).body(request.body);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+
+            Box::pin(async move {
+                let response = builder.send().await.map_err(ClientError::transport)?;
+                let status = response.status().as_u16();
+                let headers = response
+                    .headers()
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect();
+                let body = response.bytes().await.map_err(ClientError::transport)?;
+
+                Ok(Response {
+                    status,
+                    headers,
+                    body: body.to_vec(),
+                })
+            })
+        }
+    }
+}
+
+#[cfg(feature = "http-surf")]
+mod surf {
+    use super::super::{BoxFuture, ClientError, Request, Response, Transport};
+
+    /// A [`Transport`] that sends requests to a URL using [`surf`].
+    #[derive(Clone, Debug)]
+    pub struct SurfTransport {
+        client: surf::Client,
+        url: surf::Url,
+    }
+
+    impl SurfTransport {
+        /// Creates a `SurfTransport` that sends requests to `url` with
+        /// `client`.
+        pub fn new(client: surf::Client, url: surf::Url) -> Self {
+            SurfTransport { client, url }
+        }
+    }
+
+    impl Transport for SurfTransport {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, ClientError>> {
+            Box::pin(async move {
+                let mut builder = self.client.post(self.url.clone()).body(request.body);
+                for (name, value) in &request.headers {
+                    builder = builder.header(name.as_str(), value.as_str());
+                }
+
+                let mut response = builder
+                    .await
+                    .map_err(|error| ClientError::transport(error.into_inner()))?;
+                let status = response.status().into();
+                let headers = response
+                    .iter()
+                    .map(|(name, values)| (name.to_string(), values.last().to_string()))
+                    .collect();
+                let body = response
+                    .body_bytes()
+                    .await
+                    .map_err(|error| ClientError::transport(error.into_inner()))?;
+
+                Ok(Response {
+                    status,
+                    headers,
+                    body,
+                })
+            })
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::borrow::Cow;
 
-use crate::{queries::SelectionBuilder, QueryVariablesFields};
+use crate::{
+    queries::{SelectionBuilder, StaticSelection},
+    QueryVariablesFields, StaticOperation,
+};
 
 /// A trait that marks a type as part of a GraphQL query.
 ///
@@ -15,16 +18,83 @@ pub trait QueryFragment: Sized {
     /// The name of the type in the GraphQL schema
     const TYPE: Option<&'static str> = None;
 
+    /// The selection set of this fragment, if it could be built at compile
+    /// time.
+    ///
+    /// The derive provides this for structs without generic parameters that
+    /// only select fields with no arguments or arguments made up of
+    /// variables, `null`, enum values, booleans, integers & strings.  Fields
+    /// using `feature`, `flatten`, `recurse`, `spread`, `defer`, `stream` or
+    /// directives can't be built at compile time, and neither can
+    /// `InlineFragments` or named fragments.
+    const STATIC_SELECTION: Option<StaticSelection> = None;
+
+    /// The document of an operation built from this fragment, if it could be
+    /// built at compile time.
+    ///
+    /// The derive provides this for fragments on the root types of a schema
+    /// that have a `STATIC_SELECTION`.  It's used when building operations
+    /// with no features enabled and the default operation name.
+    const STATIC_OPERATION: Option<StaticOperation> = None;
+
+    /// Whether this fragment contains any [`FieldResult`](crate::FieldResult)s.
+    ///
+    /// Responses are buffered when this is true, so that the errors are
+    /// available while the data is deserialized.  The derive provides this,
+    /// assuming that fields using `recurse` might contain `FieldResult`s.
+    const USES_FIELD_RESULTS: bool = false;
+
     /// Adds this fragment to the query being built by `builder`
     fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>);
 
-    /// The name of this fragment, useful for operations, maybe fragments if we ever support them...
+    /// The name of this fragment, used as the name of operations and named
+    /// fragments.
     fn name() -> Option<Cow<'static, str>> {
         // Most QueryFragments don't need a name so return None
         None
     }
 }
 
+/// A fragment that can be selected on fields with the schema type
+/// `SchemaType`.
+///
+/// Every `QueryFragment` implements this for its own `SchemaType`.  Generic
+/// structs that derive `QueryFragment` with more than one `graphql_type`
+/// implement it once for each of those types instead, so that the field a
+/// fragment is selected on can decide which type is used.
+pub trait QueryFragmentFor<SchemaType>: Sized {
+    /// The variables that are required to execute this fragment
+    type VariablesFields: QueryVariablesFields;
+
+    /// The name of the type in the GraphQL schema
+    const TYPE: Option<&'static str> = None;
+
+    /// The selection set of this fragment, if it could be built at compile
+    /// time.
+    const STATIC_SELECTION: Option<StaticSelection> = None;
+
+    /// Whether this fragment contains any [`FieldResult`](crate::FieldResult)s.
+    const USES_FIELD_RESULTS: bool = false;
+
+    /// Adds this fragment to the query being built by `builder`
+    fn query_for(builder: SelectionBuilder<'_, SchemaType, Self::VariablesFields>);
+}
+
+impl<T> QueryFragmentFor<T::SchemaType> for T
+where
+    T: QueryFragment,
+{
+    type VariablesFields = T::VariablesFields;
+
+    const TYPE: Option<&'static str> = T::TYPE;
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
+
+    fn query_for(builder: SelectionBuilder<'_, T::SchemaType, Self::VariablesFields>) {
+        T::query(builder)
+    }
+}
+
 impl<T> QueryFragment for Option<T>
 where
     T: QueryFragment,
@@ -32,6 +102,9 @@ where
     type SchemaType = Option<T::SchemaType>;
     type VariablesFields = T::VariablesFields;
 
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
+
     fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
         T::query(builder.into_inner())
     }
@@ -44,6 +117,9 @@ where
     type SchemaType = Vec<T::SchemaType>;
     type VariablesFields = T::VariablesFields;
 
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
+
     fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
         T::query(builder.into_inner())
     }
@@ -57,6 +133,8 @@ where
     type VariablesFields = T::VariablesFields;
 
     const TYPE: Option<&'static str> = T::TYPE;
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
 
     fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
         T::query(builder)
@@ -71,6 +149,8 @@ where
     type VariablesFields = T::VariablesFields;
 
     const TYPE: Option<&'static str> = T::TYPE;
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
 
     fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
         T::query(builder)
@@ -85,6 +165,24 @@ where
     type VariablesFields = T::VariablesFields;
 
     const TYPE: Option<&'static str> = T::TYPE;
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
+
+    fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
+        T::query(builder)
+    }
+}
+
+impl<T, ErrorExtensions> QueryFragment for crate::FieldResult<T, ErrorExtensions>
+where
+    T: QueryFragment,
+{
+    type SchemaType = T::SchemaType;
+    type VariablesFields = T::VariablesFields;
+
+    const TYPE: Option<&'static str> = T::TYPE;
+    const STATIC_SELECTION: Option<StaticSelection> = T::STATIC_SELECTION;
+    const USES_FIELD_RESULTS: bool = true;
 
     fn query(builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
         T::query(builder)
@@ -125,6 +223,9 @@ pub trait InlineFragments<'de>: QueryFragment + serde::de::Deserialize<'de> {
 pub trait Enum: serde::de::DeserializeOwned + serde::Serialize {
     /// The enum in the schema that this type represents.
     type SchemaType;
+
+    /// Whether this type contains a [`FieldResult`](crate::FieldResult)
+    const USES_FIELD_RESULTS: bool = false;
 }
 
 impl<T> Enum for Option<T>
@@ -132,6 +233,8 @@ where
     T: Enum,
 {
     type SchemaType = Option<T::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
 }
 
 impl<T> Enum for Vec<T>
@@ -139,6 +242,8 @@ where
     T: Enum,
 {
     type SchemaType = Vec<T::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
 }
 
 impl<T> Enum for Box<T>
@@ -146,6 +251,18 @@ where
     T: Enum,
 {
     type SchemaType = T::SchemaType;
+
+    const USES_FIELD_RESULTS: bool = T::USES_FIELD_RESULTS;
+}
+
+impl<T, ErrorExtensions> Enum for crate::FieldResult<T, ErrorExtensions>
+where
+    T: Enum,
+    ErrorExtensions: serde::de::DeserializeOwned,
+{
+    type SchemaType = T::SchemaType;
+
+    const USES_FIELD_RESULTS: bool = true;
 }
 
 /// A GraphQL input object.
@@ -0,0 +1,220 @@
+//! Support for sending several operations to a server in a single request.
+//!
+//! Some servers accept a JSON array of operations in place of a single
+//! operation, and respond with an array of responses in the same order.  The
+//! [`Batch`] trait is implemented for tuples of [`Operation`]s, which
+//! decode into a tuple of [`GraphQlResponse`]s, and for a `Vec` of operations
+//! that all have the same type.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{GraphQlResponse, Operation, UsesFieldResults};
+
+/// A batch of operations that can be sent to a server in a single request.
+///
+/// ```rust
+/// # mod schema {
+/// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Film {
+/// #    title: Option<String>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+/// # struct FilmQuery {
+/// #     #[arguments(id: "ZmlsbXM6MQ==")]
+/// #     film: Option<Film>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Planet {
+/// #    name: Option<String>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+/// # struct PlanetQuery {
+/// #     #[arguments(id: "cGxhbmV0czox")]
+/// #     planet: Option<Planet>,
+/// # }
+/// use cynic::{http::batch::Batch, QueryBuilder};
+///
+/// let batch = (FilmQuery::build(()), PlanetQuery::build(()));
+///
+/// assert_eq!(batch.len(), 2);
+/// assert!(serde_json::to_value(&batch).unwrap().is_array());
+/// ```
+pub trait Batch: serde::Serialize {
+    /// The type that the responses to this batch are decoded into.
+    type Responses;
+
+    /// The number of operations in this batch.
+    fn len(&self) -> usize;
+
+    /// Whether this batch contains no operations.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decodes the responses to this batch.
+    ///
+    /// `responses` should contain one entry for each operation in the batch,
+    /// in the same order as the operations.  The HTTP integrations check the
+    /// number of responses before calling this, so implementations don't
+    /// need to.
+    fn decode_responses(responses: Vec<Value>) -> Result<Self::Responses, BatchError>;
+}
+
+/// The errors that can occur when decoding the responses to a batch.
+#[derive(thiserror::Error, Debug)]
+pub enum BatchError {
+    /// The server returned a different number of responses than there were
+    /// operations in the batch.
+    #[error("Expected {expected} responses to a batch but the server returned {received}")]
+    MismatchedResponseCount {
+        /// The number of operations in the batch
+        expected: usize,
+        /// The number of responses the server returned
+        received: usize,
+    },
+
+    /// One of the responses couldn't be decoded.
+    #[error("Couldn't decode a response in a batch: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+/// Decodes the responses to a batch, checking that there's one response for
+/// each of the `expected` operations.
+pub(crate) fn decode_responses<B>(
+    expected: usize,
+    responses: Vec<Value>,
+) -> Result<B::Responses, BatchError>
+where
+    B: Batch,
+{
+    if responses.len() != expected {
+        return Err(BatchError::MismatchedResponseCount {
+            expected,
+            received: responses.len(),
+        });
+    }
+
+    B::decode_responses(responses)
+}
+
+impl<ResponseData, Variables> Batch for Vec<Operation<ResponseData, Variables>>
+where
+    ResponseData: DeserializeOwned + UsesFieldResults,
+    Variables: serde::Serialize,
+{
+    type Responses = Vec<GraphQlResponse<ResponseData>>;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn decode_responses(responses: Vec<Value>) -> Result<Self::Responses, BatchError> {
+        Ok(responses
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+macro_rules! impl_batch_for_tuple {
+    ($($response_data:ident, $variables:ident);+) => {
+        impl<$($response_data, $variables),+> Batch for ($(Operation<$response_data, $variables>,)+)
+        where
+            $(
+                $response_data: DeserializeOwned + UsesFieldResults,
+                $variables: serde::Serialize,
+            )+
+        {
+            type Responses = ($(GraphQlResponse<$response_data>,)+);
+
+            fn len(&self) -> usize {
+                [$(stringify!($response_data)),+].len()
+            }
+
+            fn decode_responses(responses: Vec<Value>) -> Result<Self::Responses, BatchError> {
+                let mut responses = responses.into_iter();
+                Ok(($(
+                    serde_json::from_value::<GraphQlResponse<$response_data>>(
+                        responses.next().unwrap_or_default()
+                    )?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_batch_for_tuple!(A, VA);
+impl_batch_for_tuple!(A, VA; B, VB);
+impl_batch_for_tuple!(A, VA; B, VB; C, VC);
+impl_batch_for_tuple!(A, VA; B, VB; C, VC; D, VD);
+impl_batch_for_tuple!(A, VA; B, VB; C, VC; D, VD; E, VE);
+impl_batch_for_tuple!(A, VA; B, VB; C, VC; D, VD; E, VE; F, VF);
+impl_batch_for_tuple!(A, VA; B, VB; C, VC; D, VD; E, VE; F, VF; G, VG);
+impl_batch_for_tuple!(A, VA; B, VB; C, VC; D, VD; E, VE; F, VF; G, VG; H, VH);
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_tuple_batch_decoding() {
+        type TestBatch = (Operation<Value, Value>, Operation<String, Value>);
+
+        let (one, two) = decode_responses::<TestBatch>(
+            2,
+            vec![
+                json!({"data": 1}),
+                json!({"data": null, "errors": [{"message": "Oops"}]}),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(one.data, Some(json!(1)));
+        assert_eq!(two.data, None);
+        assert_eq!(two.errors.unwrap()[0].message, "Oops");
+    }
+
+    #[test]
+    fn test_vec_batch_decoding() {
+        let responses = decode_responses::<Vec<Operation<Value, Value>>>(
+            3,
+            vec![json!({"data": 1}), json!({"data": 2}), json!({"data": 3})],
+        )
+        .unwrap();
+
+        assert_eq!(
+            responses
+                .into_iter()
+                .map(|response| response.data.unwrap())
+                .collect::<Vec<_>>(),
+            vec![json!(1), json!(2), json!(3)]
+        );
+    }
+
+    #[test]
+    fn test_mismatched_response_count() {
+        type TestBatch = (Operation<Value, Value>, Operation<String, Value>);
+
+        assert_matches!(
+            decode_responses::<TestBatch>(2, vec![json!({"data": 1})]),
+            Err(BatchError::MismatchedResponseCount {
+                expected: 2,
+                received: 1
+            })
+        );
+    }
+}
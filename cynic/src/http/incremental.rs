@@ -0,0 +1,298 @@
+//! Support for assembling incremental delivery responses.
+//!
+//! When a query uses `@defer` or `@stream` a server may send its response in
+//! several payloads: an initial payload with everything that wasn't deferred,
+//! followed by payloads with an `incremental` list describing data that
+//! should be added to the response at a given `path`.  The
+//! [`IncrementalResponse`] type in this module merges these payloads back
+//! into a single response.
+
+use std::marker::PhantomData;
+
+use serde::de::Error as _;
+use serde_json::{Map, Value};
+
+use crate::GraphQlResponse;
+
+/// A GraphQL response that is being assembled from a series of incremental
+/// delivery payloads.
+///
+/// ```rust
+/// # use cynic::http::incremental::IncrementalResponse;
+/// # use serde_json::json;
+/// let mut response = IncrementalResponse::<serde_json::Value>::new();
+///
+/// response.apply(json!({"data": {"film": {"title": "A New Hope"}}, "hasNext": true})).unwrap();
+/// response.apply(json!({
+///     "incremental": [{"data": {"director": "George Lucas"}, "path": ["film"]}],
+///     "hasNext": false
+/// })).unwrap();
+///
+/// assert!(!response.has_next());
+/// assert_eq!(
+///     response.into_response().unwrap().data.unwrap(),
+///     json!({"film": {"title": "A New Hope", "director": "George Lucas"}})
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalResponse<ResponseData> {
+    data: Value,
+    errors: Vec<Value>,
+    has_next: bool,
+    phantom: PhantomData<fn() -> ResponseData>,
+}
+
+impl<ResponseData> Default for IncrementalResponse<ResponseData> {
+    fn default() -> Self {
+        IncrementalResponse {
+            data: Value::Null,
+            errors: Vec::new(),
+            has_next: true,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<ResponseData> IncrementalResponse<ResponseData> {
+    /// Creates an `IncrementalResponse` that hasn't received any payloads.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges a payload from the server into this response.
+    ///
+    /// This accepts the initial payload of a response, subsequent payloads
+    /// with an `incremental` list, or subsequent payloads in the older format
+    /// that have `data` or `items` alongside a `path`.
+    pub fn apply(&mut self, payload: Value) -> Result<(), serde_json::Error> {
+        let Value::Object(mut payload) = payload else {
+            return Err(serde_json::Error::custom(
+                "expected an incremental delivery payload to be an object",
+            ));
+        };
+
+        self.has_next = payload
+            .remove("hasNext")
+            .and_then(|has_next| has_next.as_bool())
+            .unwrap_or_default();
+
+        if let Some(incremental) = payload.remove("incremental") {
+            let Value::Array(items) = incremental else {
+                return Err(serde_json::Error::custom(
+                    "expected incremental in an incremental delivery payload to be a list",
+                ));
+            };
+            for item in items {
+                let Value::Object(item) = item else {
+                    return Err(serde_json::Error::custom(
+                        "expected the items of incremental to be objects",
+                    ));
+                };
+                self.apply_incremental(item)?;
+            }
+            return Ok(());
+        }
+
+        if payload.contains_key("path") {
+            return self.apply_incremental(payload);
+        }
+
+        self.take_errors(&mut payload);
+        if let Some(data) = payload.remove("data") {
+            merge(&mut self.data, data);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the server has indicated that there are more payloads to come.
+    pub fn has_next(&self) -> bool {
+        self.has_next
+    }
+
+    /// Returns the response as it stands, with any data that hasn't arrived
+    /// yet missing.
+    pub fn current(&self) -> Result<GraphQlResponse<ResponseData>, serde_json::Error>
+    where
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+    {
+        serde_json::from_value(self.to_json())
+    }
+
+    /// Converts this into a `GraphQlResponse`.
+    ///
+    /// This is usually called once `has_next` returns false, but can be
+    /// called earlier if the remaining payloads aren't needed.
+    pub fn into_response(self) -> Result<GraphQlResponse<ResponseData>, serde_json::Error>
+    where
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+    {
+        self.current()
+    }
+
+    fn apply_incremental(&mut self, mut item: Map<String, Value>) -> Result<(), serde_json::Error> {
+        let Some(Value::Array(path)) = item.remove("path") else {
+            return Err(serde_json::Error::custom(
+                "expected an incremental delivery result to have a path",
+            ));
+        };
+
+        self.take_errors(&mut item);
+
+        if let Some(data) = item.remove("data") {
+            if let Some(target @ Value::Object(_)) = lookup_mut(&mut self.data, &path) {
+                merge(target, data);
+            }
+        }
+
+        if let Some(items) = item.remove("items") {
+            let Value::Array(items) = items else {
+                return Err(serde_json::Error::custom(
+                    "expected items in an incremental delivery result to be a list",
+                ));
+            };
+            // The path of a stream result points at the index of its first
+            // item, so the list itself lives at the parent path.
+            let list_path = path.split_last().map(|(_, rest)| rest).unwrap_or_default();
+            if let Some(Value::Array(list)) = lookup_mut(&mut self.data, list_path) {
+                list.extend(items);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn take_errors(&mut self, payload: &mut Map<String, Value>) {
+        if let Some(Value::Array(errors)) = payload.remove("errors") {
+            self.errors.extend(errors);
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut response = Map::new();
+        response.insert("data".into(), self.data.clone());
+        if !self.errors.is_empty() {
+            response.insert("errors".into(), Value::Array(self.errors.clone()));
+        }
+        Value::Object(response)
+    }
+}
+
+/// Finds the value at `path`, returning None if some part of the path is
+/// missing (e.g. because it was nulled out by an error).
+fn lookup_mut<'a>(mut value: &'a mut Value, path: &[Value]) -> Option<&'a mut Value> {
+    for segment in path {
+        value = match segment {
+            Value::String(field) => value.as_object_mut()?.get_mut(field)?,
+            Value::Number(index) => value
+                .as_array_mut()?
+                .get_mut(usize::try_from(index.as_u64()?).ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+fn merge(target: &mut Value, value: Value) {
+    match (target, value) {
+        (Value::Object(target), Value::Object(value)) => {
+            for (key, value) in value {
+                merge(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_merging_deferred_data() {
+        let mut response = IncrementalResponse::<Value>::new();
+
+        response
+            .apply(json!({
+                "data": {"films": [{"id": "1", "title": "A New Hope"}, {"id": "2", "title": null}]},
+                "hasNext": true
+            }))
+            .unwrap();
+        assert!(response.has_next());
+
+        response
+            .apply(json!({
+                "incremental": [
+                    {"data": {"director": "George Lucas"}, "path": ["films", 0]},
+                    {
+                        "data": {"director": null},
+                        "path": ["films", 1],
+                        "errors": [{"message": "Oops"}]
+                    }
+                ],
+                "hasNext": false
+            }))
+            .unwrap();
+        assert!(!response.has_next());
+
+        let response = response.into_response().unwrap();
+        assert_eq!(
+            response.data.unwrap(),
+            json!({"films": [
+                {"id": "1", "title": "A New Hope", "director": "George Lucas"},
+                {"id": "2", "title": null, "director": null}
+            ]})
+        );
+        assert_eq!(response.errors.unwrap()[0].message, "Oops");
+    }
+
+    #[test]
+    fn test_merging_streamed_items() {
+        let mut response = IncrementalResponse::<Value>::new();
+
+        response
+            .apply(json!({"data": {"films": [{"id": "1"}]}, "hasNext": true}))
+            .unwrap();
+        response
+            .apply(json!({"items": [{"id": "2"}], "path": ["films", 1], "hasNext": true}))
+            .unwrap();
+        assert_eq!(
+            response.current().unwrap().data.unwrap(),
+            json!({"films": [{"id": "1"}, {"id": "2"}]})
+        );
+
+        response
+            .apply(json!({
+                "incremental": [{"items": [{"id": "3"}, {"id": "4"}], "path": ["films", 2]}],
+                "hasNext": false
+            }))
+            .unwrap();
+
+        assert_eq!(
+            response.into_response().unwrap().data.unwrap(),
+            json!({"films": [{"id": "1"}, {"id": "2"}, {"id": "3"}, {"id": "4"}]})
+        );
+    }
+
+    #[test]
+    fn test_ignores_results_for_missing_paths() {
+        let mut response = IncrementalResponse::<Value>::new();
+
+        response
+            .apply(json!({"data": {"film": null}, "hasNext": true}))
+            .unwrap();
+        response
+            .apply(json!({
+                "incremental": [{"data": {"director": "George Lucas"}, "path": ["film"]}],
+                "hasNext": false
+            }))
+            .unwrap();
+
+        assert_eq!(
+            response.into_response().unwrap().data.unwrap(),
+            json!({"film": null})
+        );
+    }
+}
@@ -0,0 +1,925 @@
+//! HTTP client support for cynic.
+//!
+//! These are hidden behind feature flags by default as HTTP clients are quite
+//! heavy dependencies, and there's several options to choose from.
+
+#[cfg(feature = "http-streaming")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http-streaming")))]
+pub mod streaming;
+
+#[cfg(any(feature = "http-surf", feature = "http-reqwest"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "http-surf", feature = "http-reqwest"))))]
+pub mod batch;
+
+#[cfg(feature = "http-streaming")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http-streaming")))]
+pub mod incremental;
+
+#[cfg(all(feature = "http-reqwest", feature = "http-multipart"))]
+mod multipart;
+
+#[cfg(feature = "http-surf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surf")))]
+pub use self::surf_ext::SurfExt;
+
+#[cfg(feature = "http-reqwest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+pub use reqwest_ext::ReqwestExt;
+
+#[cfg(feature = "http-reqwest-blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reqwest-blocking")))]
+pub use reqwest_blocking_ext::ReqwestBlockingExt;
+
+#[cfg(feature = "http-surf")]
+mod surf_ext {
+    use serde_json::json;
+    use std::{future::Future, pin::Pin};
+
+    use super::batch::{decode_responses, Batch};
+    use crate::{GraphQlResponse, Operation};
+
+    type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+    /// An extension trait for surf::RequestBuilder.
+    ///
+    /// ```rust,no_run
+    /// # mod schema {
+    /// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+    /// # }
+    /// #
+    /// # #[derive(cynic::QueryFragment)]
+    /// # #[cynic(
+    /// #    schema_path = "../schemas/starwars.schema.graphql",
+    /// #    schema_module = "schema",
+    /// # )]
+    /// # struct Film {
+    /// #    title: Option<String>,
+    /// #    director: Option<String>
+    /// # }
+    /// #
+    /// # #[derive(cynic::QueryFragment)]
+    /// # #[cynic(
+    /// #     schema_path = "../schemas/starwars.schema.graphql",
+    /// #     schema_module = "schema",
+    /// #     graphql_type = "Root"
+    /// # )]
+    /// # struct FilmDirectorQuery {
+    /// #     #[arguments(id = cynic::Id::new("ZmlsbXM6MQ=="))]
+    /// #     film: Option<Film>,
+    /// # }
+    /// use cynic::{http::SurfExt, QueryBuilder};
+    ///
+    /// # async move {
+    /// let operation = FilmDirectorQuery::build(());
+    ///
+    /// let response = surf::post("https://swapi-graphql.netlify.app/.netlify/functions/index")
+    ///     .run_graphql(operation)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!(
+    ///     "The director is {}",
+    ///     response.data
+    ///         .and_then(|d| d.film)
+    ///         .and_then(|f| f.director)
+    ///         .unwrap()
+    /// );
+    /// # };
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "http-surf")))]
+    pub trait SurfExt {
+        /// Runs a GraphQL query with the parameters in RequestBuilder, deserializes
+        /// the response and returns the result.
+        fn run_graphql<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, surf::Error>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a GraphQL query like `run_graphql`, but deserializes the
+        /// `extensions` of any errors into `ErrorExtensions` and the top-level
+        /// `extensions` of the response into `ResponseExtensions`.
+        fn run_graphql_with_extensions<ResponseData, Vars, ErrorExtensions, ResponseExtensions>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<
+            'static,
+            Result<GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>, surf::Error>,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+            ErrorExtensions: serde::de::DeserializeOwned + 'static,
+            ResponseExtensions: serde::de::DeserializeOwned + 'static;
+
+        /// Runs a GraphQL query as an automatic persisted query.
+        ///
+        /// This first sends only the hash of the query.  If the server
+        /// responds with a `PersistedQueryNotFound` error (or an error with a
+        /// `PERSISTED_QUERY_NOT_FOUND` code) the request is re-sent with the
+        /// full query document, which the server should then remember for
+        /// future requests.
+        fn run_graphql_persisted<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, surf::Error>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a batch of GraphQL operations in a single request, returning
+        /// the responses in the same order as the operations.
+        ///
+        /// If the server returns the wrong number of responses this fails
+        /// with a [`BatchError`](super::batch::BatchError) that can be
+        /// retrieved with `surf::Error::downcast_ref`.
+        fn run_graphql_batch<B>(
+            self,
+            batch: B,
+        ) -> BoxFuture<'static, Result<B::Responses, surf::Error>>
+        where
+            B: Batch + 'static;
+    }
+
+    impl SurfExt for surf::RequestBuilder {
+        fn run_graphql<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, surf::Error>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            self.run_graphql_with_extensions(operation)
+        }
+
+        fn run_graphql_with_extensions<ResponseData, Vars, ErrorExtensions, ResponseExtensions>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<
+            'static,
+            Result<GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>, surf::Error>,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+            ErrorExtensions: serde::de::DeserializeOwned + 'static,
+            ResponseExtensions: serde::de::DeserializeOwned + 'static,
+        {
+            let builder = self.body(json!(&operation));
+            Box::pin(decode_response(builder))
+        }
+
+        fn run_graphql_persisted<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, surf::Error>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            let persisted_query = operation.persisted_query();
+            // A RequestBuilder can't be cloned, so the retry happens in a
+            // middleware that has access to the client.
+            let builder = self
+                .body(json!(&persisted_query))
+                .middleware(PersistedQueryRetry {
+                    body_with_document: json!(&persisted_query.with_document()),
+                });
+            Box::pin(decode_response(builder))
+        }
+
+        fn run_graphql_batch<B>(
+            self,
+            batch: B,
+        ) -> BoxFuture<'static, Result<B::Responses, surf::Error>>
+        where
+            B: Batch + 'static,
+        {
+            let expected = batch.len();
+            let batch = json!(&batch);
+            Box::pin(async move {
+                let mut response = self.body(batch).await?;
+                let status = response.status();
+
+                let responses = if !status.is_success() {
+                    let body_string = response.body_string().await?;
+                    match serde_json::from_str::<Vec<serde_json::Value>>(&body_string) {
+                        Ok(responses) => responses,
+                        Err(_) => {
+                            return Err(surf::Error::from_str(
+                                status,
+                                format!("Server returned {}: {}", status, body_string),
+                            ))
+                        }
+                    }
+                } else {
+                    response.body_json::<Vec<serde_json::Value>>().await?
+                };
+
+                decode_responses::<B>(expected, responses)
+                    .map_err(|error| surf::Error::new(status, error))
+            })
+        }
+    }
+
+    async fn decode_response<ResponseData, ErrorExtensions, ResponseExtensions>(
+        builder: surf::RequestBuilder,
+    ) -> Result<GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>, surf::Error>
+    where
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+        ErrorExtensions: serde::de::DeserializeOwned,
+        ResponseExtensions: serde::de::DeserializeOwned,
+    {
+        let mut response = builder.await?;
+
+        if !response.status().is_success() {
+            let body_string = response.body_string().await?;
+            return serde_json::from_str(&body_string).map_err(|_| {
+                surf::Error::from_str(
+                    response.status(),
+                    format!("Server returned {}: {}", response.status(), body_string),
+                )
+            });
+        }
+
+        response.body_json().await
+    }
+
+    /// A middleware that re-sends a persisted query with its full document if
+    /// the server doesn't recognise the hash.
+    struct PersistedQueryRetry {
+        body_with_document: serde_json::Value,
+    }
+
+    #[surf::utils::async_trait]
+    impl surf::middleware::Middleware for PersistedQueryRetry {
+        async fn handle(
+            &self,
+            request: surf::Request,
+            client: surf::Client,
+            next: surf::middleware::Next<'_>,
+        ) -> surf::Result<surf::Response> {
+            // Cloning a request doesn't clone its body, so the retry gets a
+            // new one below
+            let mut retry_request = request.clone();
+
+            let mut response = next.run(request, client.clone()).await?;
+            let body = response.body_bytes().await?;
+            let not_found = serde_json::from_slice::<
+                GraphQlResponse<serde::de::IgnoredAny, serde_json::Value>,
+            >(&body)
+            .map(|response| response.is_persisted_query_not_found())
+            .unwrap_or(false);
+
+            if !not_found {
+                response.set_body(body);
+                return Ok(response);
+            }
+
+            retry_request.body_json(&self.body_with_document)?;
+            next.run(retry_request, client).await
+        }
+    }
+}
+
+/// The error type returned by `ReqwestExt` & `ReqwestBlockingExt`
+#[cfg(any(feature = "http-reqwest", feature = "http-reqwest-blocking"))]
+#[derive(thiserror::Error, Debug)]
+pub enum CynicReqwestError {
+    /// An error from reqwest when making an HTTP request.
+    #[error("Error making HTTP request: {0}")]
+    ReqwestError(#[from] reqwest::Error),
+
+    /// An error response from the server with the given status code and body.
+    #[error("Server returned {0}: {1}")]
+    ErrorResponse(reqwest::StatusCode, String),
+
+    /// The server responded with a `Content-Type` that couldn't be read as a
+    /// stream of responses.
+    #[cfg(feature = "http-streaming")]
+    #[error("Unsupported content type: {0}")]
+    UnsupportedContentType(String),
+
+    /// The responses to a batch of operations couldn't be decoded.
+    #[error("{0}")]
+    BatchError(#[from] batch::BatchError),
+
+    /// An operation couldn't be encoded as a multipart request.
+    #[cfg(feature = "http-multipart")]
+    #[error("Couldn't encode a multipart request: {0}")]
+    MultipartError(#[source] serde_json::Error),
+}
+
+#[cfg(feature = "http-reqwest")]
+mod reqwest_ext {
+    use super::{
+        batch::{decode_responses, Batch},
+        CynicReqwestError,
+    };
+    use std::{future::Future, pin::Pin};
+
+    #[cfg(feature = "http-multipart")]
+    use super::multipart::MultipartRequest;
+
+    use crate::{GraphQlResponse, Operation};
+
+    #[cfg(all(feature = "http-streaming", not(target_arch = "wasm32")))]
+    use crate::{
+        http::streaming::{ResponseStream, ACCEPT_HEADER},
+        StreamingOperation,
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+    #[cfg(target_arch = "wasm32")]
+    type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+    /// An extension trait for reqwest::RequestBuilder.
+    ///
+    /// ```rust,no_run
+    /// # mod schema {
+    /// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+    /// # }
+    /// #
+    /// # #[derive(cynic::QueryFragment)]
+    /// # #[cynic(
+    /// #    schema_path = "../schemas/starwars.schema.graphql",
+    /// #    schema_module = "schema",
+    /// # )]
+    /// # struct Film {
+    /// #    title: Option<String>,
+    /// #    director: Option<String>
+    /// # }
+    /// #
+    /// # #[derive(cynic::QueryFragment)]
+    /// # #[cynic(
+    /// #     schema_path = "../schemas/starwars.schema.graphql",
+    /// #     schema_module = "schema",
+    /// #     graphql_type = "Root"
+    /// # )]
+    /// # struct FilmDirectorQuery {
+    /// #     #[arguments(id = cynic::Id::new("ZmlsbXM6MQ=="))]
+    /// #     film: Option<Film>,
+    /// # }
+    /// use cynic::{http::ReqwestExt, QueryBuilder};
+    ///
+    /// # async move {
+    /// let operation = FilmDirectorQuery::build(());
+    ///
+    /// let client = reqwest::Client::new();
+    /// let response = client.post("https://swapi-graphql.netlify.app/.netlify/functions/index")
+    ///     .run_graphql(operation)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!(
+    ///     "The director is {}",
+    ///     response.data
+    ///         .and_then(|d| d.film)
+    ///         .and_then(|f| f.director)
+    ///         .unwrap()
+    /// );
+    /// # };
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "http-reqwest")))]
+    pub trait ReqwestExt {
+        /// Runs a GraphQL query with the parameters in RequestBuilder, deserializes
+        /// the and returns the result.
+        fn run_graphql<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, CynicReqwestError>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a GraphQL query like `run_graphql`, but deserializes the
+        /// `extensions` of any errors into `ErrorExtensions` and the top-level
+        /// `extensions` of the response into `ResponseExtensions`.
+        fn run_graphql_with_extensions<ResponseData, Vars, ErrorExtensions, ResponseExtensions>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<
+            'static,
+            Result<
+                GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>,
+                CynicReqwestError,
+            >,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+            ErrorExtensions: serde::de::DeserializeOwned + 'static,
+            ResponseExtensions: serde::de::DeserializeOwned + 'static;
+
+        /// Runs a GraphQL query as an automatic persisted query.
+        ///
+        /// This first sends only the hash of the query.  If the server
+        /// responds with a `PersistedQueryNotFound` error (or an error with a
+        /// `PERSISTED_QUERY_NOT_FOUND` code) the request is re-sent with the
+        /// full query document, which the server should then remember for
+        /// future requests.
+        fn run_graphql_persisted<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, CynicReqwestError>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a GraphQL operation that contains [`Upload`](crate::Upload)s
+        /// using the [GraphQL multipart request spec][spec].
+        ///
+        /// The operation is sent in the `operations` part of the request with
+        /// `null` in place of each file, followed by a `map` part and a part
+        /// for each file.
+        ///
+        /// [spec]: https://github.com/jaydenseric/graphql-multipart-request-spec
+        #[cfg(feature = "http-multipart")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "http-multipart")))]
+        fn run_graphql_multipart<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, CynicReqwestError>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a batch of GraphQL operations in a single request, returning
+        /// the responses in the same order as the operations.
+        fn run_graphql_batch<B>(
+            self,
+            batch: B,
+        ) -> BoxFuture<'static, Result<B::Responses, CynicReqwestError>>
+        where
+            B: Batch + 'static;
+
+        /// Runs a GraphQL subscription (or a query using `@defer`) with the
+        /// parameters in RequestBuilder, returning a stream of responses.
+        ///
+        /// This supports servers that respond with server-sent events or
+        /// `multipart/mixed` bodies, as well as plain JSON responses.
+        #[cfg(all(feature = "http-streaming", not(target_arch = "wasm32")))]
+        #[cfg_attr(docsrs, doc(cfg(feature = "http-streaming")))]
+        fn run_graphql_streaming<ResponseData, Vars>(
+            self,
+            operation: StreamingOperation<ResponseData, Vars>,
+        ) -> BoxFuture<
+            'static,
+            Result<ResponseStream<ResponseData, reqwest::Error>, CynicReqwestError>,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + Send + 'static;
+    }
+
+    impl ReqwestExt for reqwest::RequestBuilder {
+        fn run_graphql<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, CynicReqwestError>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            self.run_graphql_with_extensions(operation)
+        }
+
+        fn run_graphql_with_extensions<ResponseData, Vars, ErrorExtensions, ResponseExtensions>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<
+            'static,
+            Result<
+                GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>,
+                CynicReqwestError,
+            >,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+            ErrorExtensions: serde::de::DeserializeOwned + 'static,
+            ResponseExtensions: serde::de::DeserializeOwned + 'static,
+        {
+            let builder = self.json(&operation);
+            Box::pin(async move { decode_response(builder.send().await?).await })
+        }
+
+        fn run_graphql_persisted<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, CynicReqwestError>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            let persisted_query = operation.persisted_query();
+            let Some(retry_builder) = self.try_clone() else {
+                // We can't retry this request, so send the full document up front
+                let builder = self.json(&persisted_query.with_document());
+                return Box::pin(async move { decode_response(builder.send().await?).await });
+            };
+
+            let builder = self.json(&persisted_query);
+            let retry_builder = retry_builder.json(&persisted_query.with_document());
+            Box::pin(async move {
+                {
+                    let response: GraphQlResponse<ResponseData, serde_json::Value> =
+                        decode_response(builder.send().await?).await?;
+                    if !response.is_persisted_query_not_found() {
+                        return Ok(response.without_error_extensions());
+                    }
+                }
+
+                decode_response(retry_builder.send().await?).await
+            })
+        }
+
+        #[cfg(feature = "http-multipart")]
+        fn run_graphql_multipart<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> BoxFuture<'static, Result<GraphQlResponse<ResponseData>, CynicReqwestError>>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            let form = match multipart_form(&operation) {
+                Ok(form) => form,
+                Err(error) => return Box::pin(async move { Err(error) }),
+            };
+            let builder = self.multipart(form);
+            Box::pin(async move { decode_response(builder.send().await?).await })
+        }
+
+        fn run_graphql_batch<B>(
+            self,
+            batch: B,
+        ) -> BoxFuture<'static, Result<B::Responses, CynicReqwestError>>
+        where
+            B: Batch + 'static,
+        {
+            let expected = batch.len();
+            let builder = self.json(&batch);
+            Box::pin(async move {
+                let response = builder.send().await?;
+
+                let status = response.status();
+                let responses = if !status.is_success() {
+                    let body_string = response.text().await?;
+                    serde_json::from_str::<Vec<serde_json::Value>>(&body_string)
+                        .map_err(|_| CynicReqwestError::ErrorResponse(status, body_string))?
+                } else {
+                    response.json::<Vec<serde_json::Value>>().await?
+                };
+
+                Ok(decode_responses::<B>(expected, responses)?)
+            })
+        }
+
+        #[cfg(all(feature = "http-streaming", not(target_arch = "wasm32")))]
+        fn run_graphql_streaming<ResponseData, Vars>(
+            self,
+            operation: StreamingOperation<ResponseData, Vars>,
+        ) -> BoxFuture<
+            'static,
+            Result<ResponseStream<ResponseData, reqwest::Error>, CynicReqwestError>,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + Send + 'static,
+        {
+            let builder = self
+                .header(reqwest::header::ACCEPT, ACCEPT_HEADER)
+                .json(&operation);
+            Box::pin(async move {
+                let response = builder.send().await?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    let body_string = response.text().await?;
+                    return Err(CynicReqwestError::ErrorResponse(status, body_string));
+                }
+
+                let content_type = response
+                    .headers()
+                    .get(reqwest::header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or("application/json")
+                    .to_string();
+
+                ResponseStream::from_content_type(&content_type, response.bytes_stream())
+                    .map_err(|_| CynicReqwestError::UnsupportedContentType(content_type))
+            })
+        }
+    }
+
+    #[cfg(feature = "http-multipart")]
+    fn multipart_form(
+        operation: &impl serde::Serialize,
+    ) -> Result<reqwest::multipart::Form, CynicReqwestError> {
+        let request =
+            MultipartRequest::new(operation).map_err(CynicReqwestError::MultipartError)?;
+
+        let mut form = reqwest::multipart::Form::new()
+            .text("operations", request.operations)
+            .text("map", request.map);
+
+        for (name, upload) in request.files {
+            let file_name = upload.file_name().to_string();
+            let mime_type = upload.mime_type().map(str::to_string);
+
+            let mut part =
+                reqwest::multipart::Part::bytes(upload.into_content()).file_name(file_name);
+            if let Some(mime_type) = mime_type {
+                part = part.mime_str(&mime_type)?;
+            }
+            form = form.part(name, part);
+        }
+
+        Ok(form)
+    }
+
+    async fn decode_response<ResponseData, ErrorExtensions, ResponseExtensions>(
+        response: reqwest::Response,
+    ) -> Result<GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>, CynicReqwestError>
+    where
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+        ErrorExtensions: serde::de::DeserializeOwned,
+        ResponseExtensions: serde::de::DeserializeOwned,
+    {
+        let status = response.status();
+        if !status.is_success() {
+            let body_string = response.text().await?;
+
+            return serde_json::from_str(&body_string)
+                .map_err(|_| CynicReqwestError::ErrorResponse(status, body_string));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(CynicReqwestError::ReqwestError)
+    }
+}
+
+#[cfg(feature = "http-reqwest-blocking")]
+mod reqwest_blocking_ext {
+    use super::{
+        batch::{decode_responses, Batch},
+        CynicReqwestError,
+    };
+
+    #[cfg(feature = "http-multipart")]
+    use super::multipart::MultipartRequest;
+
+    use crate::{GraphQlResponse, Operation};
+
+    /// An extension trait for reqwest::blocking::RequestBuilder.
+    ///
+    /// ```rust,no_run
+    /// # mod schema {
+    /// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+    /// # }
+    /// #
+    /// # #[derive(cynic::QueryFragment)]
+    /// # #[cynic(
+    /// #    schema_path = "../schemas/starwars.schema.graphql",
+    /// #    schema_module = "schema",
+    /// # )]
+    /// # struct Film {
+    /// #    title: Option<String>,
+    /// #    director: Option<String>
+    /// # }
+    /// #
+    /// # #[derive(cynic::QueryFragment)]
+    /// # #[cynic(
+    /// #     schema_path = "../schemas/starwars.schema.graphql",
+    /// #     schema_module = "schema",
+    /// #     graphql_type = "Root"
+    /// # )]
+    /// # struct FilmDirectorQuery {
+    /// #     #[arguments(id = cynic::Id::new("ZmlsbXM6MQ=="))]
+    /// #     film: Option<Film>,
+    /// # }
+    /// use cynic::{http::ReqwestBlockingExt, QueryBuilder};
+    ///
+    /// let operation = FilmDirectorQuery::build(());
+    ///
+    /// let client = reqwest::blocking::Client::new();
+    /// let response = client.post("https://swapi-graphql.netlify.app/.netlify/functions/index")
+    ///     .run_graphql(operation)
+    ///     .unwrap();
+    ///
+    /// println!(
+    ///     "The director is {}",
+    ///     response.data
+    ///         .and_then(|d| d.film)
+    ///         .and_then(|f| f.director)
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "http-reqwest-blocking")))]
+    pub trait ReqwestBlockingExt {
+        /// Runs a GraphQL query with the parameters in RequestBuilder, deserializes
+        /// the and returns the result.
+        fn run_graphql<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a GraphQL query like `run_graphql`, but deserializes the
+        /// `extensions` of any errors into `ErrorExtensions` and the top-level
+        /// `extensions` of the response into `ResponseExtensions`.
+        fn run_graphql_with_extensions<ResponseData, Vars, ErrorExtensions, ResponseExtensions>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<
+            GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>,
+            CynicReqwestError,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+            ErrorExtensions: serde::de::DeserializeOwned + 'static,
+            ResponseExtensions: serde::de::DeserializeOwned + 'static;
+
+        /// Runs a GraphQL query as an automatic persisted query.
+        ///
+        /// This first sends only the hash of the query.  If the server
+        /// responds with a `PersistedQueryNotFound` error (or an error with a
+        /// `PERSISTED_QUERY_NOT_FOUND` code) the request is re-sent with the
+        /// full query document, which the server should then remember for
+        /// future requests.
+        fn run_graphql_persisted<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a GraphQL operation that contains [`Upload`](crate::Upload)s
+        /// using the [GraphQL multipart request spec][spec].
+        ///
+        /// The operation is sent in the `operations` part of the request with
+        /// `null` in place of each file, followed by a `map` part and a part
+        /// for each file.
+        ///
+        /// [spec]: https://github.com/jaydenseric/graphql-multipart-request-spec
+        #[cfg(feature = "http-multipart")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "http-multipart")))]
+        fn run_graphql_multipart<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static;
+
+        /// Runs a batch of GraphQL operations in a single request, returning
+        /// the responses in the same order as the operations.
+        fn run_graphql_batch<B>(self, batch: B) -> Result<B::Responses, CynicReqwestError>
+        where
+            B: Batch;
+    }
+
+    impl ReqwestBlockingExt for reqwest::blocking::RequestBuilder {
+        fn run_graphql<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            self.run_graphql_with_extensions(operation)
+        }
+
+        fn run_graphql_with_extensions<ResponseData, Vars, ErrorExtensions, ResponseExtensions>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<
+            GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>,
+            CynicReqwestError,
+        >
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+            ErrorExtensions: serde::de::DeserializeOwned + 'static,
+            ResponseExtensions: serde::de::DeserializeOwned + 'static,
+        {
+            decode_response(self.json(&operation).send()?)
+        }
+
+        fn run_graphql_persisted<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            let persisted_query = operation.persisted_query();
+            let Some(retry_builder) = self.try_clone() else {
+                // We can't retry this request, so send the full document up front
+                return decode_response(self.json(&persisted_query.with_document()).send()?);
+            };
+
+            let response: GraphQlResponse<ResponseData, serde_json::Value> =
+                decode_response(self.json(&persisted_query).send()?)?;
+            if !response.is_persisted_query_not_found() {
+                return Ok(response.without_error_extensions());
+            }
+
+            decode_response(
+                retry_builder
+                    .json(&persisted_query.with_document())
+                    .send()?,
+            )
+        }
+
+        #[cfg(feature = "http-multipart")]
+        fn run_graphql_multipart<ResponseData, Vars>(
+            self,
+            operation: Operation<ResponseData, Vars>,
+        ) -> Result<GraphQlResponse<ResponseData>, CynicReqwestError>
+        where
+            Vars: serde::Serialize,
+            ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + 'static,
+        {
+            decode_response(self.multipart(multipart_form(&operation)?).send()?)
+        }
+
+        fn run_graphql_batch<B>(self, batch: B) -> Result<B::Responses, CynicReqwestError>
+        where
+            B: Batch,
+        {
+            let expected = batch.len();
+            let response = self.json(&batch).send()?;
+
+            let status = response.status();
+            let responses = if !status.is_success() {
+                let body_string = response.text()?;
+                serde_json::from_str::<Vec<serde_json::Value>>(&body_string)
+                    .map_err(|_| CynicReqwestError::ErrorResponse(status, body_string))?
+            } else {
+                response.json::<Vec<serde_json::Value>>()?
+            };
+
+            Ok(decode_responses::<B>(expected, responses)?)
+        }
+    }
+
+    #[cfg(feature = "http-multipart")]
+    fn multipart_form(
+        operation: &impl serde::Serialize,
+    ) -> Result<reqwest::blocking::multipart::Form, CynicReqwestError> {
+        let request =
+            MultipartRequest::new(operation).map_err(CynicReqwestError::MultipartError)?;
+
+        let mut form = reqwest::blocking::multipart::Form::new()
+            .text("operations", request.operations)
+            .text("map", request.map);
+
+        for (name, upload) in request.files {
+            let file_name = upload.file_name().to_string();
+            let mime_type = upload.mime_type().map(str::to_string);
+
+            let mut part = reqwest::blocking::multipart::Part::bytes(upload.into_content())
+                .file_name(file_name);
+            if let Some(mime_type) = mime_type {
+                part = part.mime_str(&mime_type)?;
+            }
+            form = form.part(name, part);
+        }
+
+        Ok(form)
+    }
+
+    fn decode_response<ResponseData, ErrorExtensions, ResponseExtensions>(
+        response: reqwest::blocking::Response,
+    ) -> Result<GraphQlResponse<ResponseData, ErrorExtensions, ResponseExtensions>, CynicReqwestError>
+    where
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+        ErrorExtensions: serde::de::DeserializeOwned,
+        ResponseExtensions: serde::de::DeserializeOwned,
+    {
+        let status = response.status();
+        if !status.is_success() {
+            let body_string = response.text().map_err(CynicReqwestError::ReqwestError)?;
+
+            return serde_json::from_str(&body_string)
+                .map_err(|_| CynicReqwestError::ErrorResponse(status, body_string));
+        }
+
+        Ok(response.json()?)
+    }
+}
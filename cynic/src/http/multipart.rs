@@ -0,0 +1,149 @@
+//! Support for the [GraphQL multipart request spec][spec], which is used to
+//! send [`Upload`]s to a server alongside an operation.
+//!
+//! [spec]: https://github.com/jaydenseric/graphql-multipart-request-spec
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::{
+    upload::{collect_uploads, placeholder_index},
+    Upload,
+};
+
+/// The parts of a multipart request for an operation.
+pub(crate) struct MultipartRequest {
+    /// The operation as JSON, with `null` in place of every file
+    pub operations: String,
+
+    /// A JSON object mapping the name of each file part to the paths of the
+    /// variables it should be used for
+    pub map: String,
+
+    /// The files to send, along with the names of their parts
+    pub files: Vec<(String, Upload)>,
+}
+
+impl MultipartRequest {
+    /// Builds the multipart request for `operation`.
+    pub fn new(operation: &impl serde::Serialize) -> Result<Self, serde_json::Error> {
+        let (operations, uploads) = collect_uploads(|| serde_json::to_value(operation));
+        let mut operations = operations?;
+
+        let mut paths = vec![Vec::new(); uploads.len()];
+        replace_uploads(&mut operations, &mut String::new(), &mut paths);
+
+        let map = paths
+            .iter()
+            .enumerate()
+            .filter(|(_, paths)| !paths.is_empty())
+            .map(|(index, paths)| (index.to_string(), paths))
+            .collect::<BTreeMap<_, _>>();
+
+        let files = uploads
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !paths[*index].is_empty())
+            .map(|(index, upload)| (index.to_string(), upload))
+            .collect();
+
+        Ok(MultipartRequest {
+            operations: operations.to_string(),
+            map: serde_json::to_string(&map)?,
+            files,
+        })
+    }
+}
+
+/// Replaces the upload placeholders in `value` with nulls, recording the path
+/// of each one in `paths`.
+fn replace_uploads(value: &mut Value, path: &mut String, paths: &mut [Vec<String>]) {
+    match value {
+        Value::String(string) => {
+            let Some(index) = placeholder_index(string) else {
+                return;
+            };
+            if let Some(paths) = paths.get_mut(index) {
+                paths.push(path.clone());
+                *value = Value::Null;
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                with_segment(path, &index.to_string(), |path| {
+                    replace_uploads(value, path, paths)
+                });
+            }
+        }
+        Value::Object(fields) => {
+            for (key, value) in fields.iter_mut() {
+                with_segment(path, key, |path| replace_uploads(value, path, paths));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn with_segment(path: &mut String, segment: &str, f: impl FnOnce(&mut String)) {
+    let len = path.len();
+    if !path.is_empty() {
+        path.push('.');
+    }
+    path.push_str(segment);
+    f(path);
+    path.truncate(len);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipart_request_paths() {
+        #[derive(serde::Serialize)]
+        struct Input {
+            folder: &'static str,
+            files: Vec<Upload>,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Variables {
+            file: Upload,
+            input: Input,
+        }
+
+        let request = MultipartRequest::new(&json_operation(Variables {
+            file: Upload::new("a.txt", "a"),
+            input: Input {
+                folder: "docs",
+                files: vec![Upload::new("b.txt", "b"), Upload::new("c.txt", "c")],
+            },
+        }))
+        .unwrap();
+
+        insta::assert_snapshot!(request.operations, @r###"{"query":"mutation { ... }","variables":{"file":null,"input":{"files":[null,null],"folder":"docs"}}}"###);
+        insta::assert_snapshot!(request.map, @r###"{"0":["variables.file"],"1":["variables.input.files.0"],"2":["variables.input.files.1"]}"###);
+        assert_eq!(
+            request
+                .files
+                .iter()
+                .map(|(name, upload)| (name.as_str(), upload.file_name()))
+                .collect::<Vec<_>>(),
+            vec![("0", "a.txt"), ("1", "b.txt"), ("2", "c.txt")]
+        );
+    }
+
+    fn json_operation<V: serde::Serialize>(variables: V) -> impl serde::Serialize {
+        #[derive(serde::Serialize)]
+        struct Operation<V> {
+            query: &'static str,
+            variables: V,
+        }
+
+        Operation {
+            query: "mutation { ... }",
+            variables,
+        }
+    }
+}
@@ -0,0 +1,794 @@
+//! Support for GraphQL responses that are delivered incrementally over HTTP.
+//!
+//! Some servers & gateways deliver subscriptions (and `@defer`ed results) as
+//! a stream of responses in a single HTTP response, using either
+//! [server-sent events][sse] (`text/event-stream`) or a `multipart/mixed`
+//! body.  The functions in this module turn a stream of bytes in either of
+//! these formats into a stream of `GraphQlResponse`s.
+//!
+//! Responses to queries that use `@defer` or `@stream` can be read with
+//! [`ResponseStream::incremental`], which merges each payload into the
+//! response using [`IncrementalResponse`].
+//!
+//! [sse]: https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md
+
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures_util::{stream::BoxStream, Stream, StreamExt};
+use serde_json::Value;
+
+use super::incremental::IncrementalResponse;
+use crate::GraphQlResponse;
+
+/// The value that should be sent in the `Accept` header of a request that
+/// expects a streaming response.
+pub const ACCEPT_HEADER: &str = "text/event-stream, multipart/mixed;subscriptionSpec=\"1.0\", multipart/mixed;deferSpec=20220824, application/graphql-response+json, application/json";
+
+/// The errors that can occur when reading a streaming response.
+#[derive(thiserror::Error, Debug)]
+pub enum StreamingError<TransportError> {
+    /// The underlying byte stream returned an error
+    #[error("Error reading response: {0}")]
+    Transport(TransportError),
+
+    /// One of the responses in the stream could not be decoded.
+    #[error("Couldn't decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+
+    /// The response had a `Content-Type` that we don't know how to read.
+    #[error("Unsupported content type: {0}")]
+    UnsupportedContentType(String),
+}
+
+/// A stream of `GraphQlResponse`s read from a HTTP response.
+pub struct ResponseStream<ResponseData, TransportError> {
+    payloads: BoxStream<'static, Result<Value, StreamingError<TransportError>>>,
+    phantom: PhantomData<fn() -> ResponseData>,
+}
+
+impl<ResponseData, TransportError> Stream for ResponseStream<ResponseData, TransportError>
+where
+    ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+{
+    type Item = Result<GraphQlResponse<ResponseData>, StreamingError<TransportError>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match ready!(self.payloads.poll_next_unpin(cx)) {
+                // Payloads without data or errors are just telling us
+                // there's nothing else to come, we don't need to
+                // return those
+                Some(Ok(payload)) if !is_response(&payload) => continue,
+                Some(Ok(payload)) => {
+                    Poll::Ready(Some(serde_json::from_value(payload).map_err(Into::into)))
+                }
+                Some(Err(error)) => Poll::Ready(Some(Err(error))),
+                None => Poll::Ready(None),
+            };
+        }
+    }
+}
+
+impl<ResponseData, TransportError> ResponseStream<ResponseData, TransportError>
+where
+    ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults + Send + 'static,
+    TransportError: Send + 'static,
+{
+    /// Reads a stream of `text/event-stream` bytes as a stream of responses.
+    pub fn from_sse<S, B>(bytes: S) -> Self
+    where
+        S: Stream<Item = Result<B, TransportError>> + Send + 'static,
+        B: AsRef<[u8]>,
+    {
+        Self::from_payloads(payload_stream(bytes, Decoder::Sse(SseDecoder::default())))
+    }
+
+    /// Reads a stream of `multipart/mixed` bytes as a stream of responses.
+    ///
+    /// `boundary` should be the boundary parameter from the `Content-Type`
+    /// header of the response.
+    pub fn from_multipart<S, B>(bytes: S, boundary: &str) -> Self
+    where
+        S: Stream<Item = Result<B, TransportError>> + Send + 'static,
+        B: AsRef<[u8]>,
+    {
+        Self::from_payloads(payload_stream(
+            bytes,
+            Decoder::Multipart(MultipartDecoder::new(boundary)),
+        ))
+    }
+
+    /// Reads a stream of bytes as a stream of responses, using the
+    /// `Content-Type` of the response to determine the format.
+    ///
+    /// As well as the streaming formats, this supports plain JSON responses,
+    /// which are returned as a stream with a single response.
+    pub fn from_content_type<S, B>(
+        content_type: &str,
+        bytes: S,
+    ) -> Result<Self, StreamingError<TransportError>>
+    where
+        S: Stream<Item = Result<B, TransportError>> + Send + 'static,
+        B: AsRef<[u8]>,
+    {
+        let mime_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        match mime_type.as_str() {
+            "text/event-stream" => Ok(Self::from_sse(bytes)),
+            "multipart/mixed" => Ok(Self::from_multipart(
+                bytes,
+                &multipart_boundary(content_type).unwrap_or_else(|| "-".into()),
+            )),
+            "application/json" | "application/graphql-response+json" => {
+                Ok(Self::from_payloads(payload_stream(bytes, Decoder::Json)))
+            }
+            _ => Err(StreamingError::UnsupportedContentType(
+                content_type.to_string(),
+            )),
+        }
+    }
+
+    /// Converts this into a stream that assembles the payloads of an
+    /// incremental delivery response, as sent by servers for queries that
+    /// use `@defer` or `@stream`.
+    pub fn incremental(self) -> IncrementalStream<ResponseData, TransportError> {
+        IncrementalStream {
+            payloads: self.payloads,
+            response: IncrementalResponse::new(),
+        }
+    }
+
+    fn from_payloads<S>(payloads: S) -> Self
+    where
+        S: Stream<Item = Result<Value, StreamingError<TransportError>>> + Send + 'static,
+    {
+        ResponseStream {
+            payloads: payloads.boxed(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// A stream that assembles the payloads of an incremental delivery
+/// response.
+///
+/// Each item of the stream is the response as it stands after a payload
+/// has been received, with any deferred data that hasn't arrived yet
+/// missing.  The last item is the complete response.
+pub struct IncrementalStream<ResponseData, TransportError> {
+    payloads: BoxStream<'static, Result<Value, StreamingError<TransportError>>>,
+    response: IncrementalResponse<ResponseData>,
+}
+
+impl<ResponseData, TransportError> IncrementalStream<ResponseData, TransportError>
+where
+    ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+{
+    /// Reads all of the remaining payloads and returns the complete
+    /// response.
+    pub async fn final_response(
+        mut self,
+    ) -> Result<GraphQlResponse<ResponseData>, StreamingError<TransportError>> {
+        while let Some(payload) = self.payloads.next().await {
+            self.response.apply(payload?)?;
+        }
+        Ok(self.response.into_response()?)
+    }
+}
+
+impl<ResponseData, TransportError> Stream for IncrementalStream<ResponseData, TransportError>
+where
+    ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+{
+    type Item = Result<GraphQlResponse<ResponseData>, StreamingError<TransportError>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let payload = match ready!(self.payloads.poll_next_unpin(cx)) {
+                Some(Ok(payload)) => payload,
+                Some(Err(error)) => return Poll::Ready(Some(Err(error))),
+                None => return Poll::Ready(None),
+            };
+
+            // Payloads that only contain hasNext are just telling us there's
+            // nothing else to come, so there's no new state to return
+            let is_update = is_response(&payload)
+                || payload.get("incremental").is_some()
+                || payload.get("path").is_some();
+
+            if let Err(error) = self.response.apply(payload) {
+                return Poll::Ready(Some(Err(error.into())));
+            }
+
+            if is_update {
+                return Poll::Ready(Some(self.response.current().map_err(Into::into)));
+            }
+        }
+    }
+}
+
+fn is_response(payload: &Value) -> bool {
+    payload.get("data").is_some() || payload.get("errors").is_some()
+}
+
+/// Extracts the boundary parameter from a multipart `Content-Type` header
+fn multipart_boundary(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("boundary") {
+            return None;
+        }
+        Some(value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Decodes a byte stream into a stream of JSON payloads.
+///
+/// Heartbeats are skipped, and any subscription payloads in Apollos
+/// multipart subscription format are unwrapped.
+pub(crate) fn payload_stream<S, B, E>(
+    bytes: S,
+    decoder: Decoder,
+) -> impl Stream<Item = Result<Value, StreamingError<E>>> + Send + 'static
+where
+    S: Stream<Item = Result<B, E>> + Send + 'static,
+    B: AsRef<[u8]>,
+    E: Send + 'static,
+{
+    struct State<E> {
+        bytes: BoxStream<'static, Result<Vec<u8>, E>>,
+        buffer: Vec<u8>,
+        decoder: Decoder,
+        finished: bool,
+    }
+
+    let state = State {
+        bytes: bytes
+            .map(|chunk| chunk.map(|chunk| chunk.as_ref().to_vec()))
+            .boxed(),
+        buffer: Vec::new(),
+        decoder,
+        finished: false,
+    };
+
+    futures_util::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.finished {
+                return None;
+            }
+
+            match state.decoder.decode(&mut state.buffer) {
+                Frame::Payload(payload) => match parse_payload(&payload) {
+                    Ok(Some(payload)) => return Some((Ok(payload), state)),
+                    Ok(None) => continue,
+                    Err(error) => {
+                        state.finished = true;
+                        return Some((Err(error), state));
+                    }
+                },
+                Frame::Done => {
+                    state.finished = true;
+                    return None;
+                }
+                Frame::NeedMoreData => {}
+            }
+
+            match state.bytes.next().await {
+                Some(Ok(chunk)) => state.buffer.extend_from_slice(&chunk),
+                Some(Err(error)) => {
+                    state.finished = true;
+                    return Some((Err(StreamingError::Transport(error)), state));
+                }
+                None => {
+                    state.finished = true;
+                    return state
+                        .decoder
+                        .finish(&mut state.buffer)
+                        .and_then(|payload| parse_payload(&payload).transpose())
+                        .map(|payload| (payload, state));
+                }
+            }
+        }
+    })
+}
+
+fn parse_payload<E>(payload: &[u8]) -> Result<Option<Value>, StreamingError<E>> {
+    if payload.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+
+    let mut value = serde_json::from_slice::<Value>(payload)?;
+
+    if let Some(object) = value.as_object_mut() {
+        if object.is_empty() {
+            // Heartbeat
+            return Ok(None);
+        }
+        if let Some(payload) = object.remove("payload") {
+            // Apollos multipart subscription format wraps the response in
+            // a payload field, with transport level errors alongside it.
+            if payload.is_null() {
+                return Ok(object
+                    .remove("errors")
+                    .map(|errors| serde_json::json!({ "data": null, "errors": errors })));
+            }
+            return Ok(Some(payload));
+        }
+    }
+
+    Ok(Some(value))
+}
+
+pub(crate) enum Decoder {
+    Sse(SseDecoder),
+    Multipart(MultipartDecoder),
+    Json,
+}
+
+enum Frame {
+    Payload(Vec<u8>),
+    Done,
+    NeedMoreData,
+}
+
+impl Decoder {
+    fn decode(&mut self, buffer: &mut Vec<u8>) -> Frame {
+        match self {
+            Decoder::Sse(decoder) => decoder.decode(buffer),
+            Decoder::Multipart(decoder) => decoder.decode(buffer),
+            // JSON responses are only complete once the stream ends.
+            Decoder::Json => Frame::NeedMoreData,
+        }
+    }
+
+    /// Called when the byte stream is finished to get any trailing payload
+    fn finish(&mut self, buffer: &mut Vec<u8>) -> Option<Vec<u8>> {
+        match self {
+            // Incomplete events & parts should be discarded
+            Decoder::Sse(_) | Decoder::Multipart(_) => None,
+            Decoder::Json => Some(std::mem::take(buffer)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct SseDecoder {
+    event: Option<String>,
+    data: Vec<u8>,
+}
+
+impl SseDecoder {
+    fn decode(&mut self, buffer: &mut Vec<u8>) -> Frame {
+        while let Some(newline) = buffer.iter().position(|&b| b == b'\n') {
+            let mut line = buffer.drain(..=newline).collect::<Vec<_>>();
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+
+            if line.is_empty() {
+                let event = self.event.take();
+                let data = std::mem::take(&mut self.data);
+                match event.as_deref() {
+                    Some("complete") => return Frame::Done,
+                    _ if data.is_empty() => continue,
+                    None | Some("next") | Some("message") => return Frame::Payload(data),
+                    // Unknown events are ignored
+                    Some(_) => continue,
+                }
+            }
+
+            let (field, value) = match line.iter().position(|&b| b == b':') {
+                Some(0) => continue, // A comment
+                Some(colon) => {
+                    let value = &line[colon + 1..];
+                    (&line[..colon], value.strip_prefix(b" ").unwrap_or(value))
+                }
+                None => (line.as_slice(), &[][..]),
+            };
+
+            match field {
+                b"event" => self.event = Some(String::from_utf8_lossy(value).into_owned()),
+                b"data" => {
+                    if !self.data.is_empty() {
+                        self.data.push(b'\n');
+                    }
+                    self.data.extend_from_slice(value);
+                }
+                _ => {}
+            }
+        }
+
+        Frame::NeedMoreData
+    }
+}
+
+pub(crate) struct MultipartDecoder {
+    /// `--` followed by the boundary, which starts every delimiter line
+    dash_boundary: Vec<u8>,
+    /// Where to start looking for the next delimiter, so that each chunk of
+    /// a large part is only scanned once
+    scan_offset: usize,
+    in_preamble: bool,
+    done: bool,
+}
+
+impl MultipartDecoder {
+    fn new(boundary: &str) -> Self {
+        MultipartDecoder {
+            dash_boundary: format!("--{boundary}").into_bytes(),
+            scan_offset: 0,
+            in_preamble: true,
+            done: false,
+        }
+    }
+
+    fn decode(&mut self, buffer: &mut Vec<u8>) -> Frame {
+        loop {
+            if self.done {
+                return Frame::Done;
+            }
+
+            let Some(delimiter_start) = self.find_delimiter(buffer) else {
+                return Frame::NeedMoreData;
+            };
+
+            // The closing delimiter has `--` after the boundary, any other
+            // delimiter runs to the end of its line.
+            let boundary_end = delimiter_start + self.dash_boundary.len();
+            let after_boundary = &buffer[boundary_end..];
+            let delimiter_end = if after_boundary.starts_with(b"--") {
+                self.done = true;
+                boundary_end + 2
+            } else if b"--".starts_with(after_boundary) {
+                return Frame::NeedMoreData;
+            } else {
+                match after_boundary.iter().position(|&b| b == b'\n') {
+                    Some(newline) => boundary_end + newline + 1,
+                    None => return Frame::NeedMoreData,
+                }
+            };
+
+            let part = buffer.drain(..delimiter_end).collect::<Vec<_>>();
+            self.scan_offset = 0;
+            let was_preamble = std::mem::replace(&mut self.in_preamble, false);
+
+            if !was_preamble {
+                // The line break before a delimiter is part of the delimiter
+                let part = &part[..delimiter_start];
+                let part = part.strip_suffix(b"\n").unwrap_or(part);
+                let part = part.strip_suffix(b"\r").unwrap_or(part);
+
+                return Frame::Payload(part_body(part));
+            }
+        }
+    }
+
+    /// Finds the start of the next delimiter in `buffer`.
+    ///
+    /// Delimiters are the boundary at the start of a line.  The boundary is
+    /// chosen by the server so that it never appears in the parts, so this
+    /// doesn't depend on what the parts contain.
+    fn find_delimiter(&mut self, buffer: &[u8]) -> Option<usize> {
+        // The first delimiter doesn't need a line break before it
+        if self.in_preamble && buffer.starts_with(&self.dash_boundary) {
+            return Some(0);
+        }
+
+        let scan_offset = self.scan_offset.min(buffer.len());
+        match find(&buffer[scan_offset..], b"\n", &self.dash_boundary) {
+            Some(pos) => {
+                // The rest of the delimiter line might not have arrived yet,
+                // in which case we'll be back here with more data.
+                self.scan_offset = scan_offset + pos;
+                Some(scan_offset + pos + 1)
+            }
+            None => {
+                // A delimiter could start in the last few bytes, so they
+                // need scanning again once there's more data.
+                let delimiter_len = 1 + self.dash_boundary.len();
+                self.scan_offset = buffer.len().saturating_sub(delimiter_len - 1);
+                None
+            }
+        }
+    }
+}
+
+/// Extracts the body of a multipart part, skipping any headers.
+fn part_body(part: &[u8]) -> Vec<u8> {
+    // The headers are separated from the body by an empty line.  If there
+    // aren't any headers, the part starts with that empty line.
+    let body = if let Some(body) = part.strip_prefix(b"\r\n") {
+        body
+    } else if let Some(body) = part.strip_prefix(b"\n") {
+        body
+    } else if let Some(pos) = find(part, b"\r\n\r\n", b"") {
+        &part[pos + 4..]
+    } else if let Some(pos) = find(part, b"\n\n", b"") {
+        &part[pos + 2..]
+    } else {
+        &[]
+    };
+
+    body.to_vec()
+}
+
+/// Finds `prefix` followed by `needle` in `haystack`.
+fn find(haystack: &[u8], prefix: &[u8], needle: &[u8]) -> Option<usize> {
+    let len = prefix.len() + needle.len();
+    haystack
+        .windows(len)
+        .position(|window| window.starts_with(prefix) && window.ends_with(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream, StreamExt};
+    use serde_json::json;
+
+    use super::*;
+
+    fn chunks(body: &str, chunk_size: usize) -> Vec<Result<Vec<u8>, ()>> {
+        body.as_bytes()
+            .chunks(chunk_size)
+            .map(|chunk| Ok(chunk.to_vec()))
+            .collect()
+    }
+
+    fn collect(stream: ResponseStream<Value, ()>) -> Vec<Value> {
+        block_on(stream.collect::<Vec<_>>())
+            .into_iter()
+            .map(|response| response.unwrap().data.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sse_decoding() {
+        let body = concat!(
+            ": a comment\n\n",
+            "event: next\r\n",
+            "data: {\"data\": {\"count\": 1}}\r\n",
+            "\r\n",
+            "event: next\n",
+            "data: {\"data\":\n",
+            "data: {\"count\": 2}}\n",
+            "\n",
+            "event: complete\n",
+            "data:\n",
+            "\n",
+            "event: next\n",
+            "data: {\"data\": {\"count\": 3}}\n",
+            "\n",
+        );
+
+        for chunk_size in [1, 7, body.len()] {
+            let responses = collect(ResponseStream::from_sse(stream::iter(chunks(
+                body, chunk_size,
+            ))));
+
+            assert_eq!(responses, vec![json!({"count": 1}), json!({"count": 2})]);
+        }
+    }
+
+    #[test]
+    fn test_multipart_decoding() {
+        let body = concat!(
+            "\r\n---\r\n",
+            "Content-Type: application/json; charset=utf-8\r\n",
+            "\r\n",
+            "{\"data\": {\"count\": 1}, \"hasNext\": true}\r\n",
+            "---\r\n",
+            "Content-Type: application/json; charset=utf-8\r\n",
+            "\r\n",
+            "{}\r\n",
+            "---\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"payload\": {\"data\": {\"count\": 2}}}\r\n",
+            "---\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"hasNext\": false}\r\n",
+            "-----\r\n"
+        );
+
+        for chunk_size in [1, 7, body.len()] {
+            let responses = collect(ResponseStream::from_multipart(
+                stream::iter(chunks(body, chunk_size)),
+                "-",
+            ));
+
+            assert_eq!(responses, vec![json!({"count": 1}), json!({"count": 2})]);
+        }
+    }
+
+    #[test]
+    fn test_multipart_parts_are_split_on_the_boundary() {
+        let body = concat!(
+            "preamble\r\n",
+            "--graphql\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\n",
+            "  \"data\": {\n",
+            "    \"text\": \"--graphql\"\n",
+            "  }\n",
+            "}\r\n",
+            "--graphql\r\n",
+            "\r\n",
+            "{\"data\": {\"text\": \"no headers\"}}\r\n",
+            "--graphql--"
+        );
+
+        for chunk_size in [1, 7, body.len()] {
+            let responses = collect(ResponseStream::from_multipart(
+                stream::iter(chunks(body, chunk_size)),
+                "graphql",
+            ));
+
+            assert_eq!(
+                responses,
+                vec![json!({"text": "--graphql"}), json!({"text": "no headers"})]
+            );
+        }
+    }
+
+    #[test]
+    fn test_multipart_decoder_doesnt_rescan_parts() {
+        let mut decoder = MultipartDecoder::new("graphql");
+        let mut buffer = b"--graphql\r\n\r\n{\"data\": ".to_vec();
+
+        assert!(matches!(decoder.decode(&mut buffer), Frame::NeedMoreData));
+        assert_eq!(decoder.scan_offset, buffer.len() - "\n--graphql".len() + 1);
+
+        buffer.extend_from_slice(b"null}\r\n--gra");
+        assert!(matches!(decoder.decode(&mut buffer), Frame::NeedMoreData));
+        assert_eq!(decoder.scan_offset, buffer.len() - "\n--graphql".len() + 1);
+
+        buffer.extend_from_slice(b"phql--");
+        assert!(matches!(
+            decoder.decode(&mut buffer),
+            Frame::Payload(payload) if payload == b"{\"data\": null}"
+        ));
+        assert_eq!(decoder.scan_offset, 0);
+    }
+
+    #[test]
+    fn test_unsupported_content_types() {
+        let result = ResponseStream::<Value, ()>::from_content_type(
+            "text/html; charset=utf-8",
+            stream::iter(chunks("<html></html>", 5)),
+        );
+
+        assert!(matches!(
+            result,
+            Err(StreamingError::UnsupportedContentType(content_type))
+                if content_type == "text/html; charset=utf-8"
+        ));
+    }
+
+    #[test]
+    fn test_incremental_multipart_decoding() {
+        let body = concat!(
+            "\r\n---\r\n",
+            "Content-Type: application/json; charset=utf-8\r\n",
+            "\r\n",
+            "{\"data\": {\"film\": {\"title\": \"A New Hope\"}}, \"hasNext\": true}\r\n",
+            "---\r\n",
+            "Content-Type: application/json; charset=utf-8\r\n",
+            "\r\n",
+            "{\"incremental\": [{\"data\": {\"director\": \"George Lucas\"}, \"path\": [\"film\"]}], \"hasNext\": true}\r\n",
+            "---\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"hasNext\": false}\r\n",
+            "-----\r\n"
+        );
+
+        let responses = block_on(
+            ResponseStream::<Value, ()>::from_multipart(stream::iter(chunks(body, 7)), "-")
+                .incremental()
+                .collect::<Vec<_>>(),
+        )
+        .into_iter()
+        .map(|response| response.unwrap().data.unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            responses,
+            vec![
+                json!({"film": {"title": "A New Hope"}}),
+                json!({"film": {"title": "A New Hope", "director": "George Lucas"}})
+            ]
+        );
+
+        let response = block_on(
+            ResponseStream::<Value, ()>::from_multipart(stream::iter(chunks(body, 7)), "-")
+                .incremental()
+                .final_response(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.data.unwrap(),
+            json!({"film": {"title": "A New Hope", "director": "George Lucas"}})
+        );
+    }
+
+    #[test]
+    fn test_multipart_transport_errors() {
+        let body = concat!(
+            "--graphql\r\n",
+            "Content-Type: application/json\r\n",
+            "\r\n",
+            "{\"payload\": null, \"errors\": [{\"message\": \"Oh no\"}]}\r\n",
+            "--graphql--\r\n"
+        );
+
+        let responses = block_on(
+            ResponseStream::<Value, ()>::from_content_type(
+                "multipart/mixed; boundary=\"graphql\"; subscriptionSpec=1.0",
+                stream::iter(chunks(body, 5)),
+            )
+            .unwrap()
+            .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(responses.len(), 1);
+        let response = responses.into_iter().next().unwrap().unwrap();
+        assert_eq!(response.data, None);
+        assert_eq!(response.errors.unwrap()[0].message, "Oh no");
+    }
+
+    #[test]
+    fn test_json_decoding() {
+        let responses = collect(
+            ResponseStream::from_content_type(
+                "application/json",
+                stream::iter(chunks(r#"{"data": {"count": 1}}"#, 3)),
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(responses, vec![json!({"count": 1})]);
+    }
+
+    #[test]
+    fn test_transport_errors() {
+        let responses = block_on(
+            ResponseStream::<Value, &'static str>::from_sse(stream::iter(vec![
+                Ok(b"data: {\"data\": {}}\n\n".to_vec()),
+                Err("oops"),
+            ]))
+            .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(responses.len(), 2);
+        assert!(matches!(
+            responses[1],
+            Err(StreamingError::Transport("oops"))
+        ));
+    }
+
+    #[test]
+    fn test_multipart_boundary() {
+        assert_eq!(
+            multipart_boundary("multipart/mixed; boundary=\"graphql\""),
+            Some("graphql".into())
+        );
+        assert_eq!(
+            multipart_boundary("multipart/mixed;deferSpec=20220824;boundary=-"),
+            Some("-".into())
+        );
+        assert_eq!(multipart_boundary("multipart/mixed"), None);
+    }
+}
@@ -152,13 +152,29 @@
 //!
 //! Cynic has a few features that are controlled by feature flags.
 //!
+//! - `cache` adds a normalized response cache, which can be used by the
+//!   client.  This enables the `client` feature.
+//! - `client` adds a transport agnostic GraphQL client with support for
+//!   middleware.
 //! - `http-surf` adds integration with the [`surf`](https://github.com/http-rs/surf)
 //!   http client.
 //! - `http-reqwest` adds async integration with the [`reqwest`](https://github.com/seanmonstar/reqwest)
 //!   http client.
 //! - `http-reqwest-blocking` adds blocking integration with the [`reqwest`](https://github.com/seanmonstar/reqwest)
 //!   http client.
+//! - `http-streaming` adds support for reading subscriptions & other streaming
+//!   responses delivered as server-sent events or `multipart/mixed` bodies.
+//!   When used with `http-reqwest` this adds `ReqwestExt::run_graphql_streaming`.
+//! - `http-multipart` adds support for sending `Upload`s with the GraphQL
+//!   multipart request spec, via the `run_graphql_multipart` functions of the
+//!   reqwest integrations.
+//! - `pagination` adds helpers for fetching every page of a paginated field.
+//! - `query-document` adds the `query_document!` macro, which generates
+//!   cynic structs from a `.graphql` file at compile time.
 //! - `rkyv` can be used to speed up compiles when working with large schemas.
+//! - `testing` adds helpers for generating mock responses to queries in tests.
+//! - `ws` adds a websocket client for running subscriptions, which can be
+//!   used with any websocket library.
 //!
 //! It's worth noting that each of these features pulls in extra
 //! dependencies, which may impact your build size.  Particularly
@@ -172,8 +188,10 @@
 mod builders;
 mod core;
 mod id;
+mod merged;
 mod operation;
 mod result;
+mod upload;
 
 pub mod coercions;
 pub mod queries;
@@ -182,15 +200,41 @@ pub mod variables;
 pub mod http;
 pub mod schema;
 
+#[cfg(feature = "cache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+pub mod cache;
+
+#[cfg(feature = "client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub mod client;
+
+#[cfg(feature = "pagination")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pagination")))]
+pub mod pagination;
+
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
+#[cfg(feature = "ws")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ws")))]
+pub mod ws;
+
 #[path = "private/mod.rs"]
 pub mod __private;
 
 pub use {
-    self::core::{Enum, InlineFragments, InputObject, QueryFragment},
+    self::core::{Enum, InlineFragments, InputObject, QueryFragment, QueryFragmentFor},
     builders::{MutationBuilder, QueryBuilder, SubscriptionBuilder},
     id::Id,
-    operation::{Operation, OperationBuildError, OperationBuilder, StreamingOperation},
+    merged::Merged,
+    operation::{
+        ApolloManifest, ErrorCode, ManifestEntry, Operation, OperationBuildError, OperationBuilder,
+        OperationKind, OperationManifest, PersistedDocument, PersistedQuery, QueryHash,
+        RelayManifest, StaticOperation, StreamingOperation,
+    },
     result::*,
+    upload::Upload,
     variables::{QueryVariables, QueryVariablesFields},
 };
 
@@ -199,6 +243,10 @@ pub use cynic_proc_macros::{
     QueryVariables, Scalar,
 };
 
+#[cfg(feature = "query-document")]
+#[cfg_attr(docsrs, doc(cfg(feature = "query-document")))]
+pub use cynic_proc_macros::query_document;
+
 pub use static_assertions::assert_type_eq_all;
 
 // We re-export serde as the output from a lot of our derive macros require it,
@@ -0,0 +1,176 @@
+//! Support for merging several root `QueryFragment`s into a single operation.
+
+use std::{borrow::Cow, marker::PhantomData};
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    __private::Spreadable,
+    queries::{SelectionBuilder, VariableMatch},
+    QueryFragment, QueryVariables,
+};
+
+/// Merges several root `QueryFragment`s into a single operation.
+///
+/// This is implemented for tuples of `QueryFragment`s that share a
+/// `SchemaType`.  The fields of each fragment are aliased with a prefix
+/// based on the fragments position in the tuple, so fragments that select
+/// the same fields with different arguments won't conflict.  When the
+/// response arrives it's split back into the individual fragments.
+///
+/// The fragments must either use no variables, the `QueryVariables` provided
+/// as `Variables`, or a `QueryVariables` struct that is a `#[cynic(flatten)]`
+/// field of `Variables`:
+///
+/// ```rust,ignore
+/// #[derive(cynic::QueryVariables)]
+/// struct MergedVariables {
+///     #[cynic(flatten)]
+///     film: FilmVariables,
+///     #[cynic(flatten)]
+///     planet: PlanetVariables,
+/// }
+///
+/// type MergedQuery = Merged<(FilmQuery, PlanetQuery), MergedVariables>;
+/// ```
+///
+/// ```rust
+/// # mod schema {
+/// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Film {
+/// #    title: Option<String>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+/// # struct FilmQuery {
+/// #     #[arguments(id: "ZmlsbXM6MQ==")]
+/// #     film: Option<Film>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Planet {
+/// #    name: Option<String>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+/// # struct PlanetQuery {
+/// #     #[arguments(id: "cGxhbmV0czox")]
+/// #     planet: Option<Planet>,
+/// # }
+/// use cynic::{Merged, QueryBuilder};
+///
+/// let operation = Merged::<(FilmQuery, PlanetQuery)>::build(());
+///
+/// assert!(operation.query.contains("_0_film: film"));
+/// assert!(operation.query.contains("_1_planet: planet"));
+/// ```
+///
+/// Note that only the fields selected directly at the root of each fragment
+/// are aliased - the fields of any `named_fragment`s spread onto the root type
+/// are left as they are, so those fragments can't be merged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Merged<Fragments, Variables = ()> {
+    fragments: Fragments,
+    phantom: PhantomData<fn() -> Variables>,
+}
+
+impl<Fragments, Variables> Merged<Fragments, Variables> {
+    /// Returns a reference to the merged fragments
+    pub fn inner(&self) -> &Fragments {
+        &self.fragments
+    }
+
+    /// Returns the merged fragments
+    pub fn into_inner(self) -> Fragments {
+        self.fragments
+    }
+}
+
+macro_rules! impl_merged_for_tuple {
+    ($first:ident $first_index:tt $(, $fragment:ident $index:tt)*) => {
+        impl<$first, $($fragment,)* Variables> QueryFragment for Merged<($first, $($fragment,)*), Variables>
+        where
+            Variables: QueryVariables,
+            $first: QueryFragment,
+            Variables::Fields: VariableMatch<$first::VariablesFields>,
+            $(
+                $fragment: QueryFragment<SchemaType = $first::SchemaType>,
+                Variables::Fields: VariableMatch<$fragment::VariablesFields>,
+            )*
+        {
+            type SchemaType = $first::SchemaType;
+            type VariablesFields = Variables::Fields;
+
+            const USES_FIELD_RESULTS: bool =
+                $first::USES_FIELD_RESULTS $(|| $fragment::USES_FIELD_RESULTS)*;
+
+            fn query(mut builder: SelectionBuilder<'_, Self::SchemaType, Self::VariablesFields>) {
+                builder.select_with_alias_prefix::<$first::VariablesFields>(
+                    concat!("_", stringify!($first_index), "_"),
+                    $first::query,
+                );
+                $(
+                    builder.select_with_alias_prefix::<$fragment::VariablesFields>(
+                        concat!("_", stringify!($index), "_"),
+                        $fragment::query,
+                    );
+                )*
+            }
+
+            fn name() -> Option<Cow<'static, str>> {
+                let names = [$first::name(), $($fragment::name()),*]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+
+                if names.is_empty() {
+                    return None;
+                }
+
+                Some(Cow::Owned(names.join("And")))
+            }
+        }
+
+        impl<'de, $first, $($fragment,)* Variables> Deserialize<'de> for Merged<($first, $($fragment,)*), Variables>
+        where
+            $first: Deserialize<'de>,
+            $($fragment: Deserialize<'de>,)*
+        {
+            fn deserialize<__D>(deserializer: __D) -> Result<Self, __D::Error>
+            where
+                __D: Deserializer<'de>,
+            {
+                let spreadable = Spreadable::<__D::Error>::deserialize(deserializer)?;
+
+                Ok(Merged {
+                    fragments: (
+                        $first::deserialize(
+                            spreadable.prefixed_deserializer(concat!("_", stringify!($first_index), "_"))
+                        )?,
+                        $(
+                            $fragment::deserialize(
+                                spreadable.prefixed_deserializer(concat!("_", stringify!($index), "_"))
+                            )?,
+                        )*
+                    ),
+                    phantom: PhantomData,
+                })
+            }
+        }
+    };
+}
+
+impl_merged_for_tuple!(A 0, B 1);
+impl_merged_for_tuple!(A 0, B 1, C 2);
+impl_merged_for_tuple!(A 0, B 1, C 2, D 3);
+impl_merged_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_merged_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_merged_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_merged_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
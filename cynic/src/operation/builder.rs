@@ -1,7 +1,7 @@
-use std::{borrow::Cow, collections::HashSet, marker::PhantomData, rc::Rc, sync::mpsc};
+use std::{borrow::Cow, cell::RefCell, collections::HashSet, marker::PhantomData, sync::mpsc};
 
 use crate::{
-    queries::{SelectionBuilder, SelectionSet},
+    queries::{FragmentDefinitions, SelectionBuilder, SelectionSet, CACHE_KEYS_FEATURE},
     schema::{MutationRoot, QueryRoot, SubscriptionRoot},
     QueryFragment, QueryVariables,
 };
@@ -84,6 +84,15 @@ where
         self.features.insert(feature.to_string());
     }
 
+    /// Enables [`CACHE_KEYS_FEATURE`](crate::cache::CACHE_KEYS_FEATURE) for
+    /// the operation, so that the objects in its response can be identified
+    /// by a [`Cache`](crate::cache::Cache).
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub fn with_cache_keys(self) -> Self {
+        self.with_feature_enabled(CACHE_KEYS_FEATURE)
+    }
+
     /// Adds a name to the operation
     pub fn with_operation_name(self, name: &str) -> Self {
         OperationBuilder {
@@ -98,45 +107,107 @@ where
     }
 
     /// Tries to builds an [Operation]
+    ///
+    /// If `Fragment` has a [`StaticOperation`](super::StaticOperation) that
+    /// matches the settings of this builder the query will borrow its
+    /// document rather than building a new one.
     pub fn build(self) -> Result<super::Operation<Fragment, Variables>, OperationBuildError> {
-        use std::fmt::Write;
+        let static_operation = self.static_operation();
+        let query = self.document()?;
 
-        let features_enabled = Rc::new(self.features);
-        let mut selection_set = SelectionSet::default();
-        let (variable_tx, variable_rx) = mpsc::channel();
-        let builder = SelectionBuilder::<_, Fragment::VariablesFields>::new(
-            &mut selection_set,
-            &variable_tx,
-            &features_enabled,
-        );
+        Ok(Operation {
+            query,
+            variables: self.variables.ok_or(OperationBuildError::VariablesNotSet)?,
+            operation_name: self.operation_name,
+            kind: Some(self.operation_kind),
+            features: self.features,
+            static_operation,
+            phantom: PhantomData,
+        })
+    }
 
-        Fragment::query(builder);
+    /// The [`StaticOperation`](super::StaticOperation) of `Fragment`, if it
+    /// matches the settings of this builder.
+    pub(super) fn static_operation(&self) -> Option<super::StaticOperation> {
+        Fragment::STATIC_OPERATION.filter(|operation| {
+            self.features.is_empty()
+                && operation
+                    .matches::<Variables>(self.operation_kind, self.operation_name.as_deref())
+        })
+    }
 
-        let vars = VariableDefinitions::new::<Variables>(variable_rx.try_iter().collect());
+    /// Builds the query document, without needing the variables to be set.
+    pub(super) fn document(&self) -> Result<Cow<'static, str>, OperationBuildError> {
+        match self.static_operation() {
+            Some(operation) => Ok(Cow::Borrowed(operation.document())),
+            None => Ok(Cow::Owned(self.build_query()?)),
+        }
+    }
+
+    /// The kind of operation this builder builds
+    pub(super) fn operation_kind(&self) -> OperationKind {
+        self.operation_kind
+    }
+
+    /// The name of the operation this builder builds
+    pub(super) fn operation_name(&self) -> Option<&str> {
+        self.operation_name.as_deref()
+    }
+
+    fn build_query(&self) -> Result<String, std::fmt::Error> {
+        use std::fmt::Write;
+
+        let (selection_set, fragments, variables_used) =
+            build_selection::<Fragment>(&self.features);
+
+        let vars = VariableDefinitions::new::<Variables>(variables_used);
 
         let name_str = self.operation_name.as_deref().unwrap_or("");
 
-        let declaration_str = match self.operation_kind {
-            OperationKind::Query => "query",
-            OperationKind::Mutation => "mutation",
-            OperationKind::Subscription => "subscription",
-        };
+        let declaration_str = self.operation_kind.as_str();
+
+        let operation_directives = selection_set.operation_directives();
 
         let mut query = String::new();
         writeln!(
             &mut query,
-            "{declaration_str} {name_str}{vars}{selection_set}"
+            "{declaration_str} {name_str}{vars}{operation_directives}{selection_set}"
         )?;
+        write!(&mut query, "{fragments}")?;
 
-        Ok(Operation {
-            query,
-            variables: self.variables.ok_or(OperationBuildError::VariablesNotSet)?,
-            operation_name: self.operation_name,
-            phantom: PhantomData,
-        })
+        Ok(query)
     }
 }
 
+/// Builds the selection set of `Fragment`, returning it along with any named
+/// fragments it uses and the names of the variables it uses.
+pub(crate) fn build_selection<Fragment>(
+    features: &HashSet<String>,
+) -> (SelectionSet, FragmentDefinitions, Vec<&'static str>)
+where
+    Fragment: QueryFragment,
+{
+    let mut selection_set = SelectionSet::default();
+    let (variable_tx, variable_rx) = mpsc::channel();
+    let fragments = RefCell::new(FragmentDefinitions::default());
+    let builder = SelectionBuilder::<_, Fragment::VariablesFields>::new(
+        &mut selection_set,
+        &variable_tx,
+        features,
+        &fragments,
+    );
+
+    Fragment::query(builder);
+
+    let mut fragments = fragments.into_inner();
+    if features.contains(CACHE_KEYS_FEATURE) {
+        selection_set.add_typenames();
+        fragments.add_typenames();
+    }
+
+    (selection_set, fragments, variable_rx.try_iter().collect())
+}
+
 #[derive(thiserror::Error, Debug)]
 /// Errors that can occur when building the operation
 pub enum OperationBuildError {
@@ -148,8 +219,23 @@ pub enum OperationBuildError {
     CouldntBuildQueryString(#[from] std::fmt::Error),
 }
 
-enum OperationKind {
+/// The kind of an operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperationKind {
+    /// A query
     Query,
+    /// A mutation
     Mutation,
+    /// A subscription
     Subscription,
 }
+
+impl OperationKind {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            OperationKind::Query => "query",
+            OperationKind::Mutation => "mutation",
+            OperationKind::Subscription => "subscription",
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use crate::{
+    schema::{MutationRoot, QueryRoot, SubscriptionRoot},
+    QueryFragment, QueryVariables,
+};
+
+use super::{Operation, OperationBuilder, OperationKind, QueryHash, StreamingOperation};
+
+/// A manifest of persisted operations, for registering the operations a
+/// service can send with a server or gateway.
+///
+/// Operations are added by the type of their `QueryFragment`, and get the
+/// same document that `QueryBuilder::build`, `MutationBuilder::build` or
+/// `SubscriptionBuilder::build` would produce.  A manifest is usually
+/// generated by a test or a small binary that writes it to disk with
+/// `serde_json`, so CI can upload it.  Once the manifest has been uploaded,
+/// operations can be sent by id alone:
+///
+/// ```rust
+/// # mod schema {
+/// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Film {
+/// #    title: Option<String>,
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+/// # struct FilmQuery {
+/// #     #[arguments(id = cynic::Id::new("ZmlsbXM6MQ=="))]
+/// #     film: Option<Film>,
+/// # }
+/// use cynic::{OperationManifest, QueryBuilder};
+///
+/// let mut manifest = OperationManifest::new();
+/// manifest.add_query::<FilmQuery, ()>();
+///
+/// let apollo_manifest = serde_json::to_string_pretty(&manifest.apollo()).unwrap();
+/// let relay_manifest = serde_json::to_string_pretty(&manifest.relay()).unwrap();
+///
+/// let operation = FilmQuery::build(());
+/// let document_id = operation.document_id();
+/// let request = operation.persisted_document(&document_id);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OperationManifest {
+    operations: BTreeMap<String, ManifestEntry>,
+}
+
+/// An operation in an [`OperationManifest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// The id of the operation - the SHA-256 hash of its document
+    pub id: QueryHash,
+
+    /// The name of the operation, if it has one
+    pub name: Option<String>,
+
+    /// The kind of operation
+    pub kind: OperationKind,
+
+    /// The GraphQL document for the operation
+    pub document: Cow<'static, str>,
+}
+
+impl OperationManifest {
+    /// Creates an empty manifest
+    pub fn new() -> Self {
+        OperationManifest::default()
+    }
+
+    /// Adds the query built from `Fragment` to the manifest.
+    pub fn add_query<Fragment, Variables>(&mut self) -> &mut Self
+    where
+        Variables: QueryVariables,
+        Fragment: QueryFragment<VariablesFields = Variables::Fields>,
+        Fragment::SchemaType: QueryRoot,
+    {
+        self.add_builder(OperationBuilder::<Fragment, Variables>::query())
+    }
+
+    /// Adds the mutation built from `Fragment` to the manifest.
+    pub fn add_mutation<Fragment, Variables>(&mut self) -> &mut Self
+    where
+        Variables: QueryVariables,
+        Fragment: QueryFragment<VariablesFields = Variables::Fields>,
+        Fragment::SchemaType: MutationRoot,
+    {
+        self.add_builder(OperationBuilder::<Fragment, Variables>::mutation())
+    }
+
+    /// Adds the subscription built from `Fragment` to the manifest.
+    pub fn add_subscription<Fragment, Variables>(&mut self) -> &mut Self
+    where
+        Variables: QueryVariables,
+        Fragment: QueryFragment<VariablesFields = Variables::Fields>,
+        Fragment::SchemaType: SubscriptionRoot,
+    {
+        self.add_builder(OperationBuilder::<Fragment, Variables>::subscription())
+    }
+
+    /// Adds an operation that has already been built to the manifest.
+    ///
+    /// This is useful for operations that were built with features enabled
+    /// or a custom name.  The variables of the operation are ignored.
+    pub fn add_operation<ResponseData, Variables>(
+        &mut self,
+        operation: &Operation<ResponseData, Variables>,
+    ) -> &mut Self {
+        let kind = operation.kind().unwrap_or_else(|| {
+            [OperationKind::Mutation, OperationKind::Subscription]
+                .into_iter()
+                .find(|kind| operation.query.starts_with(kind.as_str()))
+                .unwrap_or(OperationKind::Query)
+        });
+
+        self.insert(ManifestEntry {
+            id: operation.query_hash(),
+            name: operation.operation_name.as_deref().map(Into::into),
+            kind,
+            document: operation.query.clone(),
+        })
+    }
+
+    /// Adds a subscription that has already been built to the manifest.
+    pub fn add_streaming_operation<ResponseData, Variables>(
+        &mut self,
+        operation: &StreamingOperation<ResponseData, Variables>,
+    ) -> &mut Self {
+        self.add_operation(&operation.inner)
+    }
+
+    /// The operations in this manifest, ordered by id
+    pub fn operations(&self) -> impl Iterator<Item = &ManifestEntry> + '_ {
+        self.operations.values()
+    }
+
+    /// Returns something that serializes this manifest in the format used
+    /// by Apollo's persisted query lists.
+    pub fn apollo(&self) -> ApolloManifest<'_> {
+        ApolloManifest(self)
+    }
+
+    /// Returns something that serializes this manifest as a Relay style
+    /// JSON object of `id -> document`.
+    pub fn relay(&self) -> RelayManifest<'_> {
+        RelayManifest(self)
+    }
+
+    fn add_builder<Fragment, Variables>(
+        &mut self,
+        builder: OperationBuilder<Fragment, Variables>,
+    ) -> &mut Self
+    where
+        Fragment: QueryFragment,
+        Variables: QueryVariables,
+    {
+        let document = builder
+            .document()
+            .expect("to be able to build the operation");
+        let id = match builder.static_operation() {
+            Some(operation) => operation.hash(),
+            None => QueryHash::new(&document),
+        };
+
+        self.insert(ManifestEntry {
+            id,
+            name: builder.operation_name().map(Into::into),
+            kind: builder.operation_kind(),
+            document,
+        })
+    }
+
+    fn insert(&mut self, entry: ManifestEntry) -> &mut Self {
+        self.operations.insert(entry.id.to_string(), entry);
+        self
+    }
+}
+
+/// Serializes an [`OperationManifest`] in Apollo's persisted query list
+/// format.
+///
+/// Created by [`OperationManifest::apollo`]
+pub struct ApolloManifest<'a>(&'a OperationManifest);
+
+impl serde::Serialize for ApolloManifest<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        struct Manifest<'a> {
+            format: &'static str,
+            version: u32,
+            operations: Vec<ManifestOperation<'a>>,
+        }
+
+        #[derive(serde::Serialize)]
+        struct ManifestOperation<'a> {
+            id: QueryHash,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<&'a str>,
+            #[serde(rename = "type")]
+            kind: &'static str,
+            body: &'a str,
+        }
+
+        serde::Serialize::serialize(
+            &Manifest {
+                format: "apollo-persisted-query-manifest",
+                version: 1,
+                operations: self
+                    .0
+                    .operations()
+                    .map(|entry| ManifestOperation {
+                        id: entry.id,
+                        name: entry.name.as_deref(),
+                        kind: entry.kind.as_str(),
+                        body: &entry.document,
+                    })
+                    .collect(),
+            },
+            serializer,
+        )
+    }
+}
+
+/// Serializes an [`OperationManifest`] as a Relay style JSON object of
+/// `id -> document`.
+///
+/// Created by [`OperationManifest::relay`]
+pub struct RelayManifest<'a>(&'a OperationManifest);
+
+impl serde::Serialize for RelayManifest<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(
+            self.0
+                .operations
+                .iter()
+                .map(|(id, entry)| (id, &entry.document)),
+        )
+    }
+}
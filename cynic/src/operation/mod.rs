@@ -1,4 +1,4 @@
-use std::{borrow::Cow, marker::PhantomData};
+use std::{borrow::Cow, collections::HashSet, marker::PhantomData};
 
 use crate::{
     core::QueryFragment,
@@ -7,9 +7,18 @@ use crate::{
 };
 
 mod builder;
+mod manifest;
+mod persisted;
+mod static_operation;
 mod variables;
 
-pub use builder::{OperationBuildError, OperationBuilder};
+pub use builder::{OperationBuildError, OperationBuilder, OperationKind};
+
+#[cfg(any(feature = "cache", feature = "testing"))]
+pub(crate) use builder::build_selection;
+pub use manifest::{ApolloManifest, ManifestEntry, OperationManifest, RelayManifest};
+pub use persisted::{ErrorCode, PersistedDocument, PersistedQuery, QueryHash};
+pub use static_operation::StaticOperation;
 
 /// An Operation that can be sent to a remote GraphQL server.
 ///
@@ -17,7 +26,10 @@ pub use builder::{OperationBuildError, OperationBuilder};
 /// serialized into JSON with `serde::Serialize` and sent to a remote server.
 pub struct Operation<QueryFragment, Variables = ()> {
     /// The graphql query string that will be sent to the server
-    pub query: String,
+    ///
+    /// This borrows a static document when the operation could be built at
+    /// compile time - see [`StaticOperation`].
+    pub query: Cow<'static, str>,
 
     /// The variables that will be sent to the server as part of this operation
     pub variables: Variables,
@@ -25,6 +37,12 @@ pub struct Operation<QueryFragment, Variables = ()> {
     /// The name of the operation in query that we should run
     pub operation_name: Option<Cow<'static, str>>,
 
+    kind: Option<OperationKind>,
+
+    features: HashSet<String>,
+
+    static_operation: Option<StaticOperation>,
+
     phantom: PhantomData<fn() -> QueryFragment>,
 }
 
@@ -48,6 +66,46 @@ where
     }
 }
 
+impl<QueryFragment, Variables> Clone for Operation<QueryFragment, Variables>
+where
+    Variables: Clone,
+{
+    fn clone(&self) -> Self {
+        Operation {
+            query: self.query.clone(),
+            variables: self.variables.clone(),
+            operation_name: self.operation_name.clone(),
+            kind: self.kind,
+            features: self.features.clone(),
+            static_operation: self.static_operation,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<ResponseData, Variables> Operation<ResponseData, Variables> {
+    /// The kind of this operation, if it's known.
+    ///
+    /// This is `None` for operations created with [`Operation::new`].
+    pub fn kind(&self) -> Option<OperationKind> {
+        self.kind
+    }
+
+    /// Whether `feature` was enabled when this operation was built.
+    ///
+    /// This is always `false` for operations created with
+    /// [`Operation::new`].
+    pub fn is_feature_enabled(&self, feature: &str) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// The features that were enabled when this operation was built.
+    #[cfg(feature = "cache")]
+    pub(crate) fn features(&self) -> &HashSet<String> {
+        &self.features
+    }
+}
+
 impl<Fragment, Variables> Operation<Fragment, Variables>
 where
     Fragment: QueryFragment,
@@ -58,11 +116,14 @@ where
     /// This is useful for certain testing cirumstances, but offers no typesafety.
     /// [crate::QueryBuilder], [crate::MutationBuilder] and [crate::SubscriptionBuilder]
     /// should be preferered.
-    pub fn new(query: String, variables: Variables) -> Self {
+    pub fn new(query: impl Into<Cow<'static, str>>, variables: Variables) -> Self {
         Operation {
-            query,
+            query: query.into(),
             variables,
             operation_name: None,
+            kind: None,
+            features: HashSet::new(),
+            static_operation: None,
             phantom: PhantomData,
         }
     }
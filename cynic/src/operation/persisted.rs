@@ -0,0 +1,555 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{GraphQlError, GraphQlResponse};
+
+use super::Operation;
+
+/// The SHA-256 hash of a GraphQL document, as used by Apollo style
+/// automatic persisted queries.
+///
+/// `QueryHash::new` is a `const fn` so hashes of documents that are known at
+/// compile time can be computed at compile time:
+///
+/// ```rust
+/// use cynic::QueryHash;
+///
+/// const HASH: QueryHash = QueryHash::new("{ __typename }");
+///
+/// assert_eq!(
+///     HASH.to_string(),
+///     "7f56e67dd21ab3f30d1ff8b7bed08893f0a0db86449836189b361dd1e56ddb4b"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueryHash([u8; 32]);
+
+impl QueryHash {
+    /// Computes the hash of a GraphQL document.
+    pub const fn new(document: &str) -> Self {
+        QueryHash(sha256(document.as_bytes()))
+    }
+
+    /// The raw bytes of the hash
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for QueryHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for QueryHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "QueryHash({self})")
+    }
+}
+
+impl serde::Serialize for QueryHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// An [`Operation`] that will be sent as an automatic persisted query.
+///
+/// This serializes to the body of an Apollo style persisted query request.
+/// By default it contains only the hash of the query - if the server responds
+/// with a `PersistedQueryNotFound` error the request should be re-sent with
+/// the full document, using [`PersistedQuery::with_document`].
+///
+/// Created by [`Operation::persisted_query`].
+pub struct PersistedQuery<'a, ResponseData, Variables> {
+    operation: &'a Operation<ResponseData, Variables>,
+    hash: QueryHash,
+    include_document: bool,
+}
+
+impl<'a, ResponseData, Variables> PersistedQuery<'a, ResponseData, Variables> {
+    /// The hash of the query document
+    pub fn hash(&self) -> QueryHash {
+        self.hash
+    }
+
+    /// Includes the full query document in this request, for registering
+    /// the query with the server.
+    pub fn with_document(self) -> Self {
+        PersistedQuery {
+            include_document: true,
+            ..self
+        }
+    }
+}
+
+impl<ResponseData, Variables> Clone for PersistedQuery<'_, ResponseData, Variables> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<ResponseData, Variables> Copy for PersistedQuery<'_, ResponseData, Variables> {}
+
+impl<ResponseData, Variables> serde::Serialize for PersistedQuery<'_, ResponseData, Variables>
+where
+    Variables: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(serde::Serialize)]
+        struct Extensions {
+            #[serde(rename = "persistedQuery")]
+            persisted_query: PersistedQueryExtension,
+        }
+
+        #[derive(serde::Serialize)]
+        struct PersistedQueryExtension {
+            version: u32,
+            #[serde(rename = "sha256Hash")]
+            sha256_hash: QueryHash,
+        }
+
+        let mut map_serializer = serializer.serialize_map(None)?;
+        if self.include_document {
+            map_serializer.serialize_entry("query", &self.operation.query)?;
+        }
+        map_serializer.serialize_entry("variables", &self.operation.variables)?;
+        if let Some(operation_name) = &self.operation.operation_name {
+            map_serializer.serialize_entry("operationName", &operation_name)?;
+        }
+        map_serializer.serialize_entry(
+            "extensions",
+            &Extensions {
+                persisted_query: PersistedQueryExtension {
+                    version: 1,
+                    sha256_hash: self.hash,
+                },
+            },
+        )?;
+        map_serializer.end()
+    }
+}
+
+/// An [`Operation`] that will be sent as a Relay style persisted document.
+///
+/// This serializes to a request body containing the `doc_id` of the
+/// operation, its variables & its name, but not the document itself.  The
+/// server is expected to already know about the document, usually from a
+/// manifest generated at build time with `cynic_codegen::operation_manifest`.
+///
+/// Created by [`Operation::persisted_document`].
+pub struct PersistedDocument<'a, ResponseData, Variables> {
+    operation: &'a Operation<ResponseData, Variables>,
+    doc_id: &'a str,
+}
+
+impl<ResponseData, Variables> PersistedDocument<'_, ResponseData, Variables> {
+    /// The id of the document
+    pub fn doc_id(&self) -> &str {
+        self.doc_id
+    }
+}
+
+impl<ResponseData, Variables> Clone for PersistedDocument<'_, ResponseData, Variables> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<ResponseData, Variables> Copy for PersistedDocument<'_, ResponseData, Variables> {}
+
+impl<ResponseData, Variables> serde::Serialize for PersistedDocument<'_, ResponseData, Variables>
+where
+    Variables: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map_serializer = serializer.serialize_map(None)?;
+        map_serializer.serialize_entry("doc_id", self.doc_id)?;
+        map_serializer.serialize_entry("variables", &self.operation.variables)?;
+        if let Some(operation_name) = &self.operation.operation_name {
+            map_serializer.serialize_entry("operationName", &operation_name)?;
+        }
+        map_serializer.end()
+    }
+}
+
+impl<ResponseData, Variables> Operation<ResponseData, Variables> {
+    /// Computes the hash of this operations query document.
+    ///
+    /// Operations that borrow the document of a
+    /// [`StaticOperation`](super::StaticOperation) return the hash that was
+    /// computed at compile time rather than hashing the document again.
+    pub fn query_hash(&self) -> QueryHash {
+        match (&self.query, self.static_operation) {
+            (Cow::Borrowed(query), Some(operation))
+                if std::ptr::eq(*query, operation.document()) =>
+            {
+                operation.hash()
+            }
+            _ => QueryHash::new(&self.query),
+        }
+    }
+
+    /// Returns a [`PersistedQuery`] that can be used to send this operation
+    /// as an automatic persisted query.
+    pub fn persisted_query(&self) -> PersistedQuery<'_, ResponseData, Variables> {
+        PersistedQuery {
+            operation: self,
+            hash: self.query_hash(),
+            include_document: false,
+        }
+    }
+
+    /// The id of this operation in an
+    /// [`OperationManifest`](super::OperationManifest) - the hex encoded
+    /// SHA-256 hash of its query document.
+    pub fn document_id(&self) -> String {
+        self.query_hash().to_string()
+    }
+
+    /// Returns a [`PersistedDocument`] that sends this operation by its
+    /// `doc_id` alone, for servers that only accept operations from a
+    /// persisted operation manifest.
+    ///
+    /// Operations built from a `QueryFragment` can use
+    /// [`Operation::document_id`], which matches their id in an
+    /// [`OperationManifest`](super::OperationManifest).  Operations generated
+    /// by `query_document!` have a `DOCUMENT_ID` constant that matches the id
+    /// in any manifest generated from the same document by
+    /// `cynic_codegen::operation_manifest`.
+    pub fn persisted_document<'a>(
+        &'a self,
+        doc_id: &'a str,
+    ) -> PersistedDocument<'a, ResponseData, Variables> {
+        PersistedDocument {
+            operation: self,
+            doc_id,
+        }
+    }
+}
+
+/// Error extensions that can report the `code` of an error.
+///
+/// This lets [`GraphQlError::is_persisted_query_not_found`] recognise servers
+/// that report a missing persisted query with a
+/// `PERSISTED_QUERY_NOT_FOUND` code rather than the error message.
+pub trait ErrorCode {
+    /// The `code` of this error, if it has one.
+    fn error_code(&self) -> Option<&str>;
+}
+
+impl ErrorCode for serde::de::IgnoredAny {
+    fn error_code(&self) -> Option<&str> {
+        None
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl ErrorCode for serde_json::Value {
+    fn error_code(&self) -> Option<&str> {
+        self.get("code")?.as_str()
+    }
+}
+
+impl<Extensions> GraphQlError<Extensions>
+where
+    Extensions: ErrorCode,
+{
+    /// Whether this error indicates that the server doesn't know about a
+    /// persisted query, and the full document should be sent.
+    pub fn is_persisted_query_not_found(&self) -> bool {
+        self.message == "PersistedQueryNotFound"
+            || self.extensions.as_ref().and_then(ErrorCode::error_code)
+                == Some("PERSISTED_QUERY_NOT_FOUND")
+    }
+}
+
+impl<T, ErrorExtensions, ResponseExtensions> GraphQlResponse<T, ErrorExtensions, ResponseExtensions>
+where
+    ErrorExtensions: ErrorCode,
+{
+    /// Whether this response indicates that the server doesn't know about a
+    /// persisted query, and the full document should be sent.
+    pub fn is_persisted_query_not_found(&self) -> bool {
+        self.errors
+            .iter()
+            .flatten()
+            .any(GraphQlError::is_persisted_query_not_found)
+    }
+}
+
+#[cfg(any(feature = "client", feature = "http-reqwest"))]
+impl<T> GraphQlResponse<T, serde_json::Value> {
+    /// Discards the error extensions of this response.
+    ///
+    /// The first request of a persisted query is decoded with its error
+    /// extensions so that any error code can be checked, but callers get the
+    /// default response type back.
+    pub(crate) fn without_error_extensions(self) -> GraphQlResponse<T> {
+        GraphQlResponse {
+            data: self.data,
+            errors: self.errors.map(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| {
+                        GraphQlError::new(
+                            error.message,
+                            error.locations,
+                            error.path,
+                            error.extensions.map(|_| serde::de::IgnoredAny),
+                        )
+                    })
+                    .collect()
+            }),
+            extensions: self.extensions,
+        }
+    }
+}
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+// Rotations are written out by hand, as calls to `rotate_right` count
+// against the compilers const evaluation limits.
+macro_rules! rotr {
+    ($x:expr, $n:literal) => {
+        ($x >> $n) | ($x << (32 - $n))
+    };
+}
+
+/// A `const` implementation of SHA-256, so that hashes of static documents
+/// can be computed at compile time.
+const fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    // The message is padded with a 1 bit, zeros & the length in bits so that
+    // it's a multiple of 64 bytes long.
+    let padded_len = (input.len() + 9 + 63) / 64 * 64;
+    let bit_len = (input.len() as u64).wrapping_mul(8);
+
+    let mut offset = 0;
+    while offset < padded_len {
+        let mut w = [0u32; 64];
+        if offset + 64 <= input.len() {
+            let mut i = 0;
+            while i < 16 {
+                let j = offset + i * 4;
+                w[i] = (input[j] as u32) << 24
+                    | (input[j + 1] as u32) << 16
+                    | (input[j + 2] as u32) << 8
+                    | input[j + 3] as u32;
+                i += 1;
+            }
+        } else {
+            // Only the last block or two contain any padding
+            let mut i = 0;
+            while i < 64 {
+                let index = offset + i;
+                let byte = if index < input.len() {
+                    input[index]
+                } else if index == input.len() {
+                    0x80
+                } else if index >= padded_len - 8 {
+                    (bit_len >> ((padded_len - 1 - index) * 8)) as u8
+                } else {
+                    0
+                };
+                w[i / 4] |= (byte as u32) << ((3 - i % 4) * 8);
+                i += 1;
+            }
+        }
+        state = compress(state, w);
+        offset += 64;
+    }
+
+    let mut output = [0u8; 32];
+    let mut i = 0;
+    while i < 8 {
+        let bytes = state[i].to_be_bytes();
+        output[i * 4] = bytes[0];
+        output[i * 4 + 1] = bytes[1];
+        output[i * 4 + 2] = bytes[2];
+        output[i * 4 + 3] = bytes[3];
+        i += 1;
+    }
+    output
+}
+
+/// Compresses a block, given its first 16 words in `w`
+const fn compress(state: [u32; 8], mut w: [u32; 64]) -> [u32; 8] {
+    let mut i = 16;
+    while i < 64 {
+        let s0 = rotr!(w[i - 15], 7) ^ rotr!(w[i - 15], 18) ^ (w[i - 15] >> 3);
+        let s1 = rotr!(w[i - 2], 17) ^ rotr!(w[i - 2], 19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+    let mut i = 0;
+    while i < 64 {
+        let s1 = rotr!(e, 6) ^ rotr!(e, 11) ^ rotr!(e, 25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = rotr!(a, 2) ^ rotr!(a, 13) ^ rotr!(a, 22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+        i += 1;
+    }
+
+    [
+        state[0].wrapping_add(a),
+        state[1].wrapping_add(b),
+        state[2].wrapping_add(c),
+        state[3].wrapping_add(d),
+        state[4].wrapping_add(e),
+        state[5].wrapping_add(f),
+        state[6].wrapping_add(g),
+        state[7].wrapping_add(h),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_known_values() {
+        assert_eq!(
+            QueryHash::new("").to_string(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            QueryHash::new("abc").to_string(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // 56 bytes, so the padding spills into a second block
+        assert_eq!(
+            QueryHash::new("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").to_string(),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        // 112 bytes, so the first block has no padding
+        assert_eq!(
+            QueryHash::new(concat!(
+                "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn",
+                "hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu"
+            ))
+            .to_string(),
+            "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_persisted_query_not_found_detection() {
+        let by_message: GraphQlResponse<serde_json::Value, serde_json::Value> =
+            serde_json::from_value(serde_json::json!({
+                "errors": [{"message": "PersistedQueryNotFound"}]
+            }))
+            .unwrap();
+        assert!(by_message.is_persisted_query_not_found());
+
+        let by_code: GraphQlResponse<serde_json::Value, serde_json::Value> =
+            serde_json::from_value(serde_json::json!({
+                "errors": [{
+                    "message": "Persisted query not found",
+                    "extensions": {"code": "PERSISTED_QUERY_NOT_FOUND"}
+                }]
+            }))
+            .unwrap();
+        assert!(by_code.is_persisted_query_not_found());
+        #[cfg(any(feature = "client", feature = "http-reqwest"))]
+        assert!(!by_code
+            .without_error_extensions()
+            .is_persisted_query_not_found());
+
+        let other_error: GraphQlResponse<serde_json::Value, serde_json::Value> =
+            serde_json::from_value(serde_json::json!({
+                "errors": [{
+                    "message": "Something went wrong",
+                    "extensions": {"code": "INTERNAL_SERVER_ERROR"}
+                }]
+            }))
+            .unwrap();
+        assert!(!other_error.is_persisted_query_not_found());
+    }
+
+    #[test]
+    fn test_static_operations_are_not_rehashed() {
+        use std::{collections::HashSet, marker::PhantomData};
+
+        use crate::{OperationKind, StaticOperation};
+
+        const DOCUMENT: &str = "query Test {\n  __typename\n}\n\n";
+
+        // A hash that doesn't match the document, so we can tell whether
+        // `query_hash` used it or hashed the document again.
+        let recorded_hash = QueryHash::new("not the document");
+
+        let mut operation = Operation::<(), ()> {
+            query: Cow::Borrowed(DOCUMENT),
+            variables: (),
+            operation_name: Some(Cow::Borrowed("Test")),
+            kind: Some(OperationKind::Query),
+            features: HashSet::new(),
+            static_operation: Some(StaticOperation {
+                kind: OperationKind::Query,
+                name: "Test",
+                document: DOCUMENT,
+                hash: recorded_hash,
+                variables: &[],
+            }),
+            phantom: PhantomData,
+        };
+
+        assert_eq!(operation.query_hash(), recorded_hash);
+        assert_eq!(operation.persisted_query().hash(), recorded_hash);
+
+        // If the query is replaced the recorded hash no longer applies
+        operation.query = Cow::Owned(DOCUMENT.to_string());
+        assert_eq!(operation.query_hash(), QueryHash::new(DOCUMENT));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{variables::VariableType, QueryHash, QueryVariables};
+
+use super::OperationKind;
+
+/// The document of an operation that was built at compile time.
+///
+/// The `QueryFragment` derive generates one of these for fragments on the
+/// root types of a schema, provided the fragment doesn't use any features
+/// that need to be resolved at runtime.  Operations built from these
+/// fragments borrow the static document rather than building a new one, and
+/// the hash of the document is available as a constant:
+///
+/// ```rust
+/// # mod schema {
+/// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Film {
+/// #    title: Option<String>,
+/// # }
+/// #
+/// #[derive(cynic::QueryVariables)]
+/// struct FilmVariables {
+///     id: cynic::Id,
+/// }
+///
+/// #[derive(cynic::QueryFragment)]
+/// #[cynic(
+///     schema_path = "../schemas/starwars.schema.graphql",
+///     graphql_type = "Root",
+///     variables = "FilmVariables"
+/// )]
+/// struct FilmQuery {
+///     #[arguments(id: $id)]
+///     film: Option<Film>,
+/// }
+///
+/// use cynic::{QueryBuilder, QueryFragment, QueryHash};
+///
+/// const HASH: QueryHash = match FilmQuery::STATIC_OPERATION {
+///     Some(operation) => operation.hash(),
+///     None => panic!("FilmQuery should be built at compile time"),
+/// };
+///
+/// let operation = FilmQuery::build(FilmVariables { id: "ZmlsbXM6MQ==".into() });
+///
+/// assert_eq!(HASH, QueryHash::new(&operation.query));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StaticOperation {
+    pub(crate) kind: OperationKind,
+    pub(crate) name: &'static str,
+    pub(crate) document: &'static str,
+    pub(crate) hash: QueryHash,
+    pub(crate) variables: &'static [(&'static str, VariableType)],
+}
+
+impl StaticOperation {
+    /// The GraphQL document of the operation
+    pub const fn document(&self) -> &'static str {
+        self.document
+    }
+
+    /// The SHA-256 hash of the document, as used by automatic persisted
+    /// queries
+    pub const fn hash(&self) -> QueryHash {
+        self.hash
+    }
+
+    /// Whether this is the document an `OperationBuilder` with the given
+    /// settings & no features enabled would build.
+    pub(crate) fn matches<Variables>(&self, kind: OperationKind, name: Option<&str>) -> bool
+    where
+        Variables: QueryVariables,
+    {
+        self.kind == kind && name == Some(self.name) && self.variables == Variables::VARIABLES
+    }
+}
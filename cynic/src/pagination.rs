@@ -0,0 +1,432 @@
+//! Helpers for fetching every page of a paginated field.
+//!
+//! A [`Paginator`] repeatedly runs an [`Operation`], updating its variables
+//! after each response to fetch the next page, and yields the nodes from
+//! every page as a `Stream` (with [`Paginator::into_stream`]) or a blocking
+//! `Iterator` (with [`Paginator::into_blocking_iter`]).
+//!
+//! Relay style cursor connections can be paginated forwards (with `first` &
+//! `after`) or backwards (with `last` & `before`) by implementing
+//! [`Connection`] for the connection type and [`ForwardVariables`] or
+//! [`BackwardVariables`] for the variables of the query.  APIs that use
+//! offsets are supported via [`OffsetVariables`].
+//!
+//! ```rust
+//! # mod schema {
+//! #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+//! # }
+//! #
+//! #[derive(cynic::QueryVariables, Clone)]
+//! struct FilmsVariables {
+//!     after: Option<String>,
+//! }
+//!
+//! #[derive(cynic::QueryFragment)]
+//! #[cynic(
+//!     schema_path = "../schemas/starwars.schema.graphql",
+//!     graphql_type = "Root",
+//!     variables = "FilmsVariables"
+//! )]
+//! struct FilmsQuery {
+//!     #[arguments(first: 2, after: $after)]
+//!     all_films: Option<FilmsConnection>,
+//! }
+//!
+//! #[derive(cynic::QueryFragment)]
+//! #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+//! struct FilmsConnection {
+//!     films: Option<Vec<Option<Film>>>,
+//!     page_info: PageInfo,
+//! }
+//!
+//! #[derive(cynic::QueryFragment)]
+//! #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+//! struct PageInfo {
+//!     has_next_page: bool,
+//!     end_cursor: Option<String>,
+//! }
+//!
+//! #[derive(cynic::QueryFragment)]
+//! #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+//! struct Film {
+//!     title: Option<String>,
+//! }
+//!
+//! impl cynic::pagination::Connection for FilmsConnection {
+//!     type Node = Film;
+//!
+//!     fn page_info(&self) -> cynic::pagination::PageInfo {
+//!         cynic::pagination::PageInfo {
+//!             has_next_page: self.page_info.has_next_page,
+//!             end_cursor: self.page_info.end_cursor.clone(),
+//!             ..Default::default()
+//!         }
+//!     }
+//!
+//!     fn into_nodes(self) -> Vec<Film> {
+//!         self.films.unwrap_or_default().into_iter().flatten().collect()
+//!     }
+//! }
+//!
+//! impl cynic::pagination::ForwardVariables for FilmsVariables {
+//!     fn set_after(&mut self, cursor: String) {
+//!         self.after = Some(cursor);
+//!     }
+//! }
+//!
+//! # fn send(
+//! #     operation: cynic::Operation<FilmsQuery, FilmsVariables>,
+//! # ) -> Result<cynic::GraphQlResponse<FilmsQuery>, std::convert::Infallible> {
+//! #     let response = match operation.variables.after.as_deref() {
+//! #         None => serde_json::json!({"data": {"allFilms": {
+//! #             "films": [{"title": "A New Hope"}, {"title": "The Empire Strikes Back"}],
+//! #             "pageInfo": {"hasNextPage": true, "endCursor": "YXJyYXljb25uZWN0aW9uOjE="}
+//! #         }}}),
+//! #         Some(_) => serde_json::json!({"data": {"allFilms": {
+//! #             "films": [{"title": "Return of the Jedi"}],
+//! #             "pageInfo": {"hasNextPage": false, "endCursor": "YXJyYXljb25uZWN0aW9uOjI="}
+//! #         }}}),
+//! #     };
+//! #     Ok(serde_json::from_value(response).unwrap())
+//! # }
+//! use cynic::{pagination::Paginator, QueryBuilder};
+//!
+//! let paginator = Paginator::forward(
+//!     FilmsQuery::build(FilmsVariables { after: None }),
+//!     |data: FilmsQuery| data.all_films,
+//! );
+//!
+//! // `send` could be e.g. `|operation| client.post(url).run_graphql(operation)`
+//! // with a `reqwest::blocking::Client`
+//! let titles = paginator
+//!     .into_blocking_iter(send)
+//!     .map(|film| film.unwrap().title.unwrap())
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     titles,
+//!     vec!["A New Hope", "The Empire Strikes Back", "Return of the Jedi"]
+//! );
+//! ```
+
+use std::future::Future;
+
+use futures_util::{stream, Stream, StreamExt};
+
+use crate::{GraphQlError, GraphQlResponse, Operation};
+
+/// The pagination details of a page of a Relay style connection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Whether there are more nodes after this page
+    pub has_next_page: bool,
+    /// Whether there are more nodes before this page
+    pub has_previous_page: bool,
+    /// The cursor of the first node in this page
+    pub start_cursor: Option<String>,
+    /// The cursor of the last node in this page
+    pub end_cursor: Option<String>,
+}
+
+/// A page of a Relay style connection.
+///
+/// This should be implemented on a `QueryFragment` for a connection type.
+pub trait Connection {
+    /// The type of the nodes in this connection
+    type Node;
+
+    /// The pagination details of this page.
+    ///
+    /// Only `has_next_page` & `end_cursor` are needed when paginating forwards,
+    /// and only `has_previous_page` & `start_cursor` when paginating backwards.
+    fn page_info(&self) -> PageInfo;
+
+    /// The nodes in this page.
+    fn into_nodes(self) -> Vec<Self::Node>;
+}
+
+/// Variables for a query that can be paginated forwards with an `after`
+/// cursor.
+pub trait ForwardVariables {
+    /// Sets the cursor the next page should start after.
+    fn set_after(&mut self, cursor: String);
+}
+
+/// Variables for a query that can be paginated backwards with a `before`
+/// cursor.
+pub trait BackwardVariables {
+    /// Sets the cursor the next page should end before.
+    fn set_before(&mut self, cursor: String);
+}
+
+/// Variables for a query that's paginated with an offset.
+pub trait OffsetVariables {
+    /// Sets the offset of the first node in the next page.
+    fn set_offset(&mut self, offset: usize);
+}
+
+/// The errors that can occur while paginating.
+#[derive(thiserror::Error, Debug)]
+pub enum PaginationError<E> {
+    /// Running the operation for a page failed.
+    #[error("Couldn't fetch a page: {0}")]
+    Run(#[source] E),
+
+    /// The server returned errors instead of the data for a page.
+    #[error("The server returned errors when fetching a page: {}", first_message(.0))]
+    GraphQl(Vec<GraphQlError>),
+}
+
+fn first_message(errors: &[GraphQlError]) -> &str {
+    errors
+        .first()
+        .map(|error| error.message.as_str())
+        .unwrap_or_default()
+}
+
+type NextPage<ResponseData, Variables, Node> =
+    Box<dyn FnMut(ResponseData, &mut Variables) -> Page<Node> + Send>;
+
+struct Page<Node> {
+    nodes: Vec<Node>,
+    has_more: bool,
+}
+
+/// Fetches every page of a paginated field.
+///
+/// See the [module level documentation](self) for an example.
+pub struct Paginator<ResponseData, Variables, Node> {
+    operation: Operation<ResponseData, Variables>,
+    next_page: NextPage<ResponseData, Variables, Node>,
+    finished: bool,
+}
+
+impl<ResponseData, Variables, Node> Paginator<ResponseData, Variables, Node>
+where
+    Variables: Clone,
+{
+    /// Paginates forwards through a connection, using its `end_cursor` as the
+    /// `after` cursor for the next page.
+    ///
+    /// `connection` should return the connection from the response data.
+    /// Pagination stops when `has_next_page` is false, or a page is empty.
+    pub fn forward<C>(
+        operation: Operation<ResponseData, Variables>,
+        mut connection: impl FnMut(ResponseData) -> Option<C> + Send + 'static,
+    ) -> Self
+    where
+        C: Connection<Node = Node>,
+        Variables: ForwardVariables,
+    {
+        Self::new(
+            operation,
+            Box::new(move |data, variables| {
+                let Some(connection) = connection(data) else {
+                    return Page::last(Vec::new());
+                };
+                let page_info = connection.page_info();
+                let nodes = connection.into_nodes();
+
+                match page_info.end_cursor {
+                    Some(cursor) if page_info.has_next_page && !nodes.is_empty() => {
+                        variables.set_after(cursor);
+                        Page::more(nodes)
+                    }
+                    _ => Page::last(nodes),
+                }
+            }),
+        )
+    }
+
+    /// Paginates backwards through a connection, using its `start_cursor` as
+    /// the `before` cursor for the next page.
+    ///
+    /// `connection` should return the connection from the response data.
+    /// Pagination stops when `has_previous_page` is false, or a page is empty.
+    ///
+    /// Pages are fetched from the end of the connection, so the last page is
+    /// yielded first - but the nodes within each page are yielded in the
+    /// order the server returned them.
+    pub fn backward<C>(
+        operation: Operation<ResponseData, Variables>,
+        mut connection: impl FnMut(ResponseData) -> Option<C> + Send + 'static,
+    ) -> Self
+    where
+        C: Connection<Node = Node>,
+        Variables: BackwardVariables,
+    {
+        Self::new(
+            operation,
+            Box::new(move |data, variables| {
+                let Some(connection) = connection(data) else {
+                    return Page::last(Vec::new());
+                };
+                let page_info = connection.page_info();
+                let nodes = connection.into_nodes();
+
+                match page_info.start_cursor {
+                    Some(cursor) if page_info.has_previous_page && !nodes.is_empty() => {
+                        variables.set_before(cursor);
+                        Page::more(nodes)
+                    }
+                    _ => Page::last(nodes),
+                }
+            }),
+        )
+    }
+
+    /// Paginates through a list using offsets, adding the number of nodes in
+    /// each page to the offset for the next page.
+    ///
+    /// `nodes` should return the nodes of the page from the response data.
+    /// Pagination stops when a page contains fewer than `page_size` nodes.
+    pub fn offset(
+        operation: Operation<ResponseData, Variables>,
+        page_size: usize,
+        mut nodes: impl FnMut(ResponseData) -> Option<Vec<Node>> + Send + 'static,
+    ) -> Self
+    where
+        Variables: OffsetVariables,
+    {
+        let mut offset = 0;
+
+        Self::new(
+            operation,
+            Box::new(move |data, variables| {
+                let nodes = nodes(data).unwrap_or_default();
+                if nodes.is_empty() || nodes.len() < page_size {
+                    return Page::last(nodes);
+                }
+
+                offset += nodes.len();
+                variables.set_offset(offset);
+                Page::more(nodes)
+            }),
+        )
+    }
+
+    fn new(
+        operation: Operation<ResponseData, Variables>,
+        next_page: NextPage<ResponseData, Variables, Node>,
+    ) -> Self {
+        Paginator {
+            operation,
+            next_page,
+            finished: false,
+        }
+    }
+
+    /// Returns the operation that fetches the next page, or `None` if every
+    /// page has been fetched.
+    pub fn next_operation(&self) -> Option<Operation<ResponseData, Variables>> {
+        if self.finished {
+            return None;
+        }
+
+        Some(self.operation.clone())
+    }
+
+    /// Handles the response for the operation returned by
+    /// [`next_operation`](Self::next_operation), returning the nodes it
+    /// contains.
+    pub fn receive<E>(
+        &mut self,
+        response: GraphQlResponse<ResponseData>,
+    ) -> Result<Vec<Node>, PaginationError<E>> {
+        let Some(data) = response.data else {
+            self.finished = true;
+            return Err(PaginationError::GraphQl(
+                response.errors.unwrap_or_default(),
+            ));
+        };
+
+        let page = (self.next_page)(data, &mut self.operation.variables);
+        self.finished = !page.has_more;
+
+        Ok(page.nodes)
+    }
+
+    /// Fetches every page using `run`, returning a stream of their nodes.
+    ///
+    /// `run` should run the operation it's given, e.g. with
+    /// [`ReqwestExt::run_graphql`](crate::http::ReqwestExt::run_graphql) or
+    /// [`Client::run`](crate::client::Client::run).  The stream ends after the
+    /// first error.
+    pub fn into_stream<'a, Run, Fut, E>(
+        self,
+        run: Run,
+    ) -> impl Stream<Item = Result<Node, PaginationError<E>>> + 'a
+    where
+        Run: FnMut(Operation<ResponseData, Variables>) -> Fut + 'a,
+        Fut: Future<Output = Result<GraphQlResponse<ResponseData>, E>> + 'a,
+        ResponseData: 'a,
+        Variables: 'a,
+        Node: 'a,
+        E: 'a,
+    {
+        stream::unfold((self, run), |(mut paginator, mut run)| async move {
+            let operation = paginator.next_operation()?;
+            let page = match run(operation).await {
+                Ok(response) => paginator.receive(response),
+                Err(error) => {
+                    paginator.finished = true;
+                    Err(PaginationError::Run(error))
+                }
+            };
+
+            Some((page, (paginator, run)))
+        })
+        .flat_map(|page| stream::iter(flatten_page(page)))
+    }
+
+    /// Fetches every page using `run`, returning an iterator over their
+    /// nodes.
+    ///
+    /// `run` should run the operation it's given, e.g. with
+    /// [`ReqwestBlockingExt::run_graphql`](crate::http::ReqwestBlockingExt::run_graphql).
+    /// The iterator ends after the first error.
+    pub fn into_blocking_iter<Run, E>(
+        mut self,
+        mut run: Run,
+    ) -> impl Iterator<Item = Result<Node, PaginationError<E>>>
+    where
+        Run: FnMut(Operation<ResponseData, Variables>) -> Result<GraphQlResponse<ResponseData>, E>,
+    {
+        std::iter::from_fn(move || {
+            let operation = self.next_operation()?;
+            Some(match run(operation) {
+                Ok(response) => self.receive(response),
+                Err(error) => {
+                    self.finished = true;
+                    Err(PaginationError::Run(error))
+                }
+            })
+        })
+        .flat_map(flatten_page)
+    }
+}
+
+impl<Node> Page<Node> {
+    fn more(nodes: Vec<Node>) -> Self {
+        Page {
+            nodes,
+            has_more: true,
+        }
+    }
+
+    fn last(nodes: Vec<Node>) -> Self {
+        Page {
+            nodes,
+            has_more: false,
+        }
+    }
+}
+
+fn flatten_page<Node, E>(
+    page: Result<Vec<Node>, PaginationError<E>>,
+) -> Vec<Result<Node, PaginationError<E>>> {
+    match page {
+        Ok(nodes) => nodes.into_iter().map(Ok).collect(),
+        Err(error) => vec![Err(error)],
+    }
+}
@@ -9,7 +9,7 @@ use serde::{
     Deserialize, Deserializer,
 };
 
-use super::key_de::KeyDeserializer;
+use super::field_errors::{TrackedMapAccess, TrackedSeqAccess};
 
 /// Used from generated code to buffer the contents of the Deserializer when
 /// deserializing inline fragments or spreads
@@ -48,6 +48,38 @@ pub enum Content<'de> {
 }
 
 impl<'de> Content<'de> {
+    /// Copies any borrowed data so this content can outlive the deserializer
+    pub fn into_owned(self) -> Content<'static> {
+        match self {
+            Content::Bool(v) => Content::Bool(v),
+            Content::U8(v) => Content::U8(v),
+            Content::U16(v) => Content::U16(v),
+            Content::U32(v) => Content::U32(v),
+            Content::U64(v) => Content::U64(v),
+            Content::I8(v) => Content::I8(v),
+            Content::I16(v) => Content::I16(v),
+            Content::I32(v) => Content::I32(v),
+            Content::I64(v) => Content::I64(v),
+            Content::F32(v) => Content::F32(v),
+            Content::F64(v) => Content::F64(v),
+            Content::Char(v) => Content::Char(v),
+            Content::String(v) => Content::String(v),
+            Content::Str(v) => Content::String(v.to_owned()),
+            Content::ByteBuf(v) => Content::ByteBuf(v),
+            Content::Bytes(v) => Content::ByteBuf(v.to_owned()),
+            Content::None => Content::None,
+            Content::Some(v) => Content::Some(Box::new(v.into_owned())),
+            Content::Unit => Content::Unit,
+            Content::Newtype(v) => Content::Newtype(Box::new(v.into_owned())),
+            Content::Seq(v) => Content::Seq(v.into_iter().map(Content::into_owned).collect()),
+            Content::Map(v) => Content::Map(
+                v.into_iter()
+                    .map(|(k, v)| (Cow::Owned(k.into_owned()), v.into_owned()))
+                    .collect(),
+            ),
+        }
+    }
+
     #[cold]
     fn unexpected(&self) -> Unexpected<'_> {
         match *self {
@@ -347,7 +379,7 @@ where
     E: de::Error,
 {
     let seq = content.into_iter().map(ContentDeserializer::new);
-    let mut seq_visitor = de::value::SeqDeserializer::new(seq);
+    let mut seq_visitor = TrackedSeqAccess::new(seq);
     let value = visitor.visit_seq(&mut seq_visitor)?;
     seq_visitor.end()?;
     Ok(value)
@@ -363,8 +395,8 @@ where
 {
     let map = content
         .into_iter()
-        .map(|(k, v)| (KeyDeserializer::new(k), ContentDeserializer::new(v)));
-    let mut map_visitor = de::value::MapDeserializer::new(map);
+        .map(|(k, v)| (k, ContentDeserializer::new(v)));
+    let mut map_visitor = TrackedMapAccess::new(map);
     let value = visitor.visit_map(&mut map_visitor)?;
     map_visitor.end()?;
     Ok(value)
@@ -761,7 +793,7 @@ where
     E: de::Error,
 {
     let seq = content.iter().map(ContentRefDeserializer::new);
-    let mut seq_visitor = de::value::SeqDeserializer::new(seq);
+    let mut seq_visitor = TrackedSeqAccess::new(seq);
     let value = visitor.visit_seq(&mut seq_visitor)?;
     seq_visitor.end()?;
     Ok(value)
@@ -775,13 +807,10 @@ where
     V: Visitor<'de>,
     E: de::Error,
 {
-    let map = content.iter().map(|(k, v)| {
-        (
-            KeyDeserializer::new(k.clone()),
-            ContentRefDeserializer::new(v),
-        )
-    });
-    let mut map_visitor = de::value::MapDeserializer::new(map);
+    let map = content
+        .iter()
+        .map(|(k, v)| (k.clone(), ContentRefDeserializer::new(v)));
+    let mut map_visitor = TrackedMapAccess::new(map);
     let value = visitor.visit_map(&mut map_visitor)?;
     map_visitor.end()?;
     Ok(value)
@@ -0,0 +1,226 @@
+//! Tracks where in a response we are while deserializing, so that
+//! `FieldResult`s can pick up any errors the server reported for them.
+
+use std::{borrow::Cow, cell::RefCell};
+
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess};
+
+use super::{content::Content, key_de::KeyDeserializer};
+use crate::GraphQlErrorPathSegment;
+
+thread_local! {
+    static CONTEXT: RefCell<Option<Context>> = RefCell::new(None);
+}
+
+struct Context {
+    /// The errors in the response along with their paths
+    errors: Vec<(Vec<GraphQlErrorPathSegment>, Content<'static>)>,
+
+    /// The path to the value currently being deserialized
+    path: Vec<GraphQlErrorPathSegment>,
+}
+
+/// Runs `f` with the given errors available to any `FieldResult`s that are
+/// deserialized inside it.
+pub(crate) fn with_errors<R>(
+    errors: Vec<(Vec<GraphQlErrorPathSegment>, Content<'static>)>,
+    f: impl FnOnce() -> R,
+) -> R {
+    struct Guard(Option<Context>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CONTEXT.with(|context| *context.borrow_mut() = previous);
+        }
+    }
+
+    let previous = CONTEXT.with(|context| {
+        context.borrow_mut().replace(Context {
+            errors,
+            path: Vec::new(),
+        })
+    });
+    let _guard = Guard(previous);
+
+    f()
+}
+
+/// Runs `f` with `segment` added to the current path, if any errors are being
+/// tracked.
+pub(crate) fn with_segment<R>(
+    segment: impl FnOnce() -> GraphQlErrorPathSegment,
+    f: impl FnOnce() -> R,
+) -> R {
+    let pushed = CONTEXT.with(|context| match context.borrow_mut().as_mut() {
+        Some(context) => {
+            context.path.push(segment());
+            true
+        }
+        None => false,
+    });
+
+    if !pushed {
+        return f();
+    }
+
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            CONTEXT.with(|context| {
+                if let Some(context) = context.borrow_mut().as_mut() {
+                    context.path.pop();
+                }
+            });
+        }
+    }
+
+    let _guard = Guard;
+
+    f()
+}
+
+/// Finds the error that caused the value at the current path to be null.
+///
+/// The server nulls out the nearest nullable parent of a field that errors,
+/// so this is the first error whose path starts with the current path.
+pub(crate) fn current_error() -> Option<Content<'static>> {
+    CONTEXT.with(|context| {
+        let context = context.borrow();
+        let context = context.as_ref()?;
+
+        context
+            .errors
+            .iter()
+            .find(|(path, _)| path.starts_with(&context.path))
+            .map(|(_, error)| error.clone())
+    })
+}
+
+/// A `MapAccess` over buffered map entries that tracks the key of each value
+/// as it's deserialized.
+pub(super) struct TrackedMapAccess<'de, I, D> {
+    iter: I,
+    next_value: Option<(Cow<'de, str>, D)>,
+}
+
+impl<'de, I, D> TrackedMapAccess<'de, I, D>
+where
+    I: ExactSizeIterator<Item = (Cow<'de, str>, D)>,
+    D: de::Deserializer<'de>,
+{
+    pub fn new(iter: I) -> Self {
+        TrackedMapAccess {
+            iter,
+            next_value: None,
+        }
+    }
+
+    /// Checks that the visitor consumed every entry in the map.
+    pub fn end(self) -> Result<(), D::Error> {
+        match self.iter.len() {
+            0 => Ok(()),
+            remaining => Err(de::Error::invalid_length(
+                remaining,
+                &"fewer elements in map",
+            )),
+        }
+    }
+}
+
+impl<'de, I, D> MapAccess<'de> for TrackedMapAccess<'de, I, D>
+where
+    I: ExactSizeIterator<Item = (Cow<'de, str>, D)>,
+    D: de::Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+        let result = seed.deserialize(KeyDeserializer::new(key.clone()));
+        self.next_value = Some((key, value));
+
+        result.map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (key, value) = self
+            .next_value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+
+        with_segment(
+            || GraphQlErrorPathSegment::Field(key.into_owned()),
+            || seed.deserialize(value),
+        )
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+/// A `SeqAccess` over buffered list elements that tracks the index of each
+/// element as it's deserialized.
+pub(super) struct TrackedSeqAccess<I> {
+    iter: std::iter::Enumerate<I>,
+}
+
+impl<'de, I, D> TrackedSeqAccess<I>
+where
+    I: ExactSizeIterator<Item = D>,
+    D: de::Deserializer<'de>,
+{
+    pub fn new(iter: I) -> Self {
+        TrackedSeqAccess {
+            iter: iter.enumerate(),
+        }
+    }
+
+    /// Checks that the visitor consumed every element in the list.
+    pub fn end(self) -> Result<(), D::Error> {
+        match self.iter.len() {
+            0 => Ok(()),
+            remaining => Err(de::Error::invalid_length(
+                remaining,
+                &"fewer elements in seq",
+            )),
+        }
+    }
+}
+
+impl<'de, I, D> SeqAccess<'de> for TrackedSeqAccess<I>
+where
+    I: ExactSizeIterator<Item = D>,
+    D: de::Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some((index, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+
+        with_segment(
+            || GraphQlErrorPathSegment::Index(index as i32),
+            || seed.deserialize(value),
+        )
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
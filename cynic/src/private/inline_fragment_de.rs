@@ -5,8 +5,10 @@ use serde::de::{Error, MapAccess, Visitor};
 use super::{
     content::{Content, ContentDeserializer},
     cow_str::CowStr,
+    field_errors::with_segment,
     key_de::KeyDeserializer,
 };
+use crate::GraphQlErrorPathSegment;
 
 pub struct InlineFragmentVisitor<T> {
     phantom: PhantomData<fn() -> T>,
@@ -92,7 +94,7 @@ where
 struct BufferMapAccess<'de, M: MapAccess<'de>> {
     access: M,
     buffer: Vec<(Cow<'de, str>, Content<'de>)>,
-    next_content: Option<Content<'de>>,
+    next_content: Option<(Cow<'de, str>, Content<'de>)>,
 }
 
 impl<'de, M> serde::de::MapAccess<'de> for BufferMapAccess<'de, M>
@@ -106,8 +108,9 @@ where
         K: serde::de::DeserializeSeed<'de>,
     {
         if let Some((key, content)) = self.buffer.pop() {
-            self.next_content = Some(content);
-            return seed.deserialize(KeyDeserializer::new(key)).map(Some);
+            let result = seed.deserialize(KeyDeserializer::new(key.clone()));
+            self.next_content = Some((key, content));
+            return result.map(Some);
         }
 
         self.access.next_key_seed(seed)
@@ -117,8 +120,11 @@ where
     where
         V: serde::de::DeserializeSeed<'de>,
     {
-        if let Some(content) = self.next_content.take() {
-            return seed.deserialize(ContentDeserializer::new(content));
+        if let Some((key, content)) = self.next_content.take() {
+            return with_segment(
+                || GraphQlErrorPathSegment::Field(key.into_owned()),
+                || seed.deserialize(ContentDeserializer::new(content)),
+            );
         }
 
         self.access.next_value_seed(seed)
@@ -5,11 +5,17 @@
 
 mod content;
 mod cow_str;
+mod field_errors;
 mod flatten_de;
 mod inline_fragment_de;
 mod key_de;
 mod spread_de;
 
+pub mod query_variables;
+pub mod static_query;
+
+pub(crate) use content::{Content, ContentDeserializer, ContentRefDeserializer};
+pub(crate) use field_errors::{current_error, with_errors};
 pub use flatten_de::Flattened;
 pub use inline_fragment_de::InlineFragmentVisitor;
 pub use spread_de::Spreadable;
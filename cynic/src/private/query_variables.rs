@@ -0,0 +1,285 @@
+//! Helpers used by the `QueryVariables` derive to support `flatten`ed fields.
+
+use serde::ser::{Error, Impossible, Serialize, SerializeMap, SerializeStruct, Serializer};
+
+use crate::variables::VariableType;
+
+type Variables = &'static [(&'static str, VariableType)];
+
+/// The total number of variables in `parts`
+pub const fn variables_len(parts: &[Variables]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Concatenates the variables in `parts`.
+///
+/// This panics (and therefore fails to compile when used in a `const`) if the
+/// same variable name appears more than once.
+pub const fn concat_variables<const N: usize>(
+    parts: &[Variables],
+) -> [(&'static str, VariableType); N] {
+    let mut output = [("", VariableType::Named("")); N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            let mut existing = 0;
+            while existing < len {
+                if str_eq(output[existing].0, parts[i][j].0) {
+                    panic!(
+                        "A variable with the same name appears in more than one flattened field"
+                    );
+                }
+                existing += 1;
+            }
+            output[len] = parts[i][j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    output
+}
+
+const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let lhs = lhs.as_bytes();
+    let rhs = rhs.as_bytes();
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A `Serializer` that writes the entries of a struct or map into the
+/// `SerializeMap` of its parent.
+pub struct FlattenSerializer<'a, M> {
+    map: &'a mut M,
+}
+
+impl<'a, M> FlattenSerializer<'a, M> {
+    pub fn new(map: &'a mut M) -> Self {
+        FlattenSerializer { map }
+    }
+}
+
+fn unsupported<E: Error>() -> E {
+    E::custom("flattened variables must be a struct")
+}
+
+impl<'a, M> Serializer for FlattenSerializer<'a, M>
+where
+    M: SerializeMap,
+{
+    type Ok = ();
+    type Error = M::Error;
+
+    type SerializeSeq = Impossible<(), M::Error>;
+    type SerializeTuple = Impossible<(), M::Error>;
+    type SerializeTupleStruct = Impossible<(), M::Error>;
+    type SerializeTupleVariant = Impossible<(), M::Error>;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), M::Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_none(self) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    // `()` has no variables, so there's nothing to write
+    fn serialize_unit(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), M::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, M::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, M::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, M::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, M::Error> {
+        Err(unsupported())
+    }
+}
+
+impl<'a, M> SerializeMap for FlattenSerializer<'a, M>
+where
+    M: SerializeMap,
+{
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), M::Error> {
+        self.map.serialize_key(key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), M::Error> {
+        self.map.serialize_value(value)
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
+
+impl<'a, M> SerializeStruct for FlattenSerializer<'a, M>
+where
+    M: SerializeMap,
+{
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        self.map.serialize_entry(key, value)
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
@@ -5,8 +5,10 @@ use serde::de::{self, Deserialize, Deserializer, MapAccess};
 use super::{
     content::{Content, ContentRefDeserializer},
     cow_str::CowStr,
+    field_errors::with_segment,
     key_de::KeyDeserializer,
 };
+use crate::GraphQlErrorPathSegment;
 
 pub struct Spreadable<'de, E> {
     fields: HashMap<Cow<'de, str>, Content<'de>>,
@@ -39,16 +41,59 @@ where
         T: serde::de::Deserialize<'de>,
     {
         if let Some(content) = self.fields.get(field) {
-            return T::deserialize(ContentRefDeserializer::new(content));
+            return deserialize_tracked(field, content);
         }
 
         Err(E::missing_field(field))
     }
 
+    /// Deserializes a field that the server may omit from its response,
+    /// e.g. one with `@skip`, `@include` or `@defer` applied.
+    pub fn deserialize_optional_field<T>(&self, field: &'static str) -> Result<T, E>
+    where
+        T: serde::de::Deserialize<'de> + Default,
+    {
+        match self.fields.get(field) {
+            Some(content) => deserialize_tracked(field, content),
+            None => Ok(T::default()),
+        }
+    }
+
+    /// Deserializes a spread that was wrapped in `@defer`.
+    ///
+    /// The deferred fields may not have arrived yet, in which case this
+    /// returns `None`.
+    pub fn deserialize_deferred_spread<T>(&self) -> Result<Option<T>, E>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        let deserializer = SpreadDeserializer::<DeferredError> {
+            iter: self.fields.iter(),
+            prefix: "",
+            next_field: None,
+            error: PhantomData,
+        };
+        match T::deserialize(deserializer) {
+            Ok(value) => Ok(Some(value)),
+            Err(DeferredError::MissingField) => Ok(None),
+            Err(DeferredError::Other(message)) => Err(E::custom(message)),
+        }
+    }
+
     pub fn spread_deserializer(&'_ self) -> impl Deserializer<'de, Error = E> + '_ {
+        self.prefixed_deserializer("")
+    }
+
+    /// Returns a deserializer for the fields that start with `prefix`, with
+    /// the prefix removed from each of their names.
+    pub fn prefixed_deserializer<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Deserializer<'de, Error = E> + 'a {
         SpreadDeserializer::<E> {
             iter: self.fields.iter(),
-            next_content: None,
+            prefix,
+            next_field: None,
             error: PhantomData,
         }
     }
@@ -56,7 +101,8 @@ where
 
 struct SpreadDeserializer<'a, 'de, E> {
     iter: std::collections::hash_map::Iter<'a, Cow<'de, str>, Content<'de>>,
-    next_content: Option<&'a Content<'de>>,
+    prefix: &'a str,
+    next_field: Option<(&'a str, &'a Content<'de>)>,
     error: PhantomData<fn() -> E>,
 }
 
@@ -90,11 +136,19 @@ where
     where
         K: de::DeserializeSeed<'de>,
     {
-        if let Some((key, content)) = self.iter.next() {
-            self.next_content = Some(content);
-            return seed
-                .deserialize(KeyDeserializer::new(key.clone()))
-                .map(Some);
+        for (original_key, content) in self.iter.by_ref() {
+            let key = match original_key {
+                Cow::Borrowed(key) => match key.strip_prefix(self.prefix) {
+                    Some(key) => Cow::Borrowed(key),
+                    None => continue,
+                },
+                Cow::Owned(key) => match key.strip_prefix(self.prefix) {
+                    Some(key) => Cow::Owned(key.to_string()),
+                    None => continue,
+                },
+            };
+            self.next_field = Some((original_key, content));
+            return seed.deserialize(KeyDeserializer::new(key)).map(Some);
         }
 
         Ok(None)
@@ -104,11 +158,59 @@ where
     where
         V: de::DeserializeSeed<'de>,
     {
-        let content = self
-            .next_content
+        let (key, content) = self
+            .next_field
             .take()
             .expect("next_value_seed called before next_key_seed");
 
-        seed.deserialize(ContentRefDeserializer::new(content))
+        with_segment(
+            || GraphQlErrorPathSegment::Field(key.to_string()),
+            || seed.deserialize(ContentRefDeserializer::new(content)),
+        )
+    }
+}
+
+/// Deserializes the buffered value of `field`, tracking its path in case it
+/// contains any `FieldResult`s.
+fn deserialize_tracked<'de, T, E>(field: &str, content: &Content<'de>) -> Result<T, E>
+where
+    T: Deserialize<'de>,
+    E: de::Error,
+{
+    with_segment(
+        || GraphQlErrorPathSegment::Field(field.to_string()),
+        || T::deserialize(ContentRefDeserializer::new(content)),
+    )
+}
+
+/// An error type used when deserializing deferred spreads, which lets us
+/// tell missing fields apart from other errors.
+#[derive(Debug)]
+enum DeferredError {
+    MissingField,
+    Other(String),
+}
+
+impl std::fmt::Display for DeferredError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeferredError::MissingField => write!(f, "missing field"),
+            DeferredError::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for DeferredError {}
+
+impl de::Error for DeferredError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        DeferredError::Other(msg.to_string())
+    }
+
+    fn missing_field(_field: &'static str) -> Self {
+        DeferredError::MissingField
     }
 }
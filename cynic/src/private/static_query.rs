@@ -0,0 +1,368 @@
+//! Const functions used by the `QueryFragment` derive to build queries at
+//! compile time.
+//!
+//! The output of these functions must exactly match the output of the
+//! `Display` impls in `queries::ast` & `operation::variables`, otherwise
+//! operations built at compile time would differ from those built at runtime.
+
+pub use crate::operation::OperationKind;
+
+use crate::{
+    operation::StaticOperation, queries::StaticSelection, variables::VariableType, QueryHash,
+};
+
+/// Part of the selection set of a `QueryFragment`.
+pub enum Part {
+    /// Some text that should be written as is
+    Text(&'static str),
+    /// A variable used in an argument
+    Variable(&'static str),
+    /// The selection set of a composite field, or `None` if that couldn't be
+    /// built at compile time.
+    SelectionSet(Option<StaticSelection>),
+}
+
+/// A buffer that a query is written into.
+///
+/// A `Writer` with a capacity of 0 only counts the length of its output,
+/// which can be used to create a `Writer` with the right capacity.
+pub struct Writer<const N: usize> {
+    /// The contents of the buffer
+    pub bytes: [u8; N],
+    /// The length of the output
+    pub len: usize,
+}
+
+// These are macros rather than functions because `const fn`s can't take
+// `&mut` references on our MSRV, and passing the buffer by value would copy
+// it for every byte that's written.
+
+/// Writes the bytes of `$s` into `$bytes`, or just counts them if the
+/// capacity `N` of the calling function is 0
+macro_rules! push {
+    ($bytes:ident, $len:ident, $s:expr) => {{
+        let s: &[u8] = $s.as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            if N != 0 {
+                $bytes[$len] = s[i];
+            }
+            $len += 1;
+            i += 1;
+        }
+    }};
+}
+
+/// Writes a selection set, indenting its selections by 2 spaces
+macro_rules! push_selection_set {
+    ($bytes:ident, $len:ident, $selection:expr) => {{
+        let selections = $selection.selections.as_bytes();
+        if selections.is_empty() {
+            push!($bytes, $len, "\n");
+        } else {
+            push!($bytes, $len, " {\n");
+            let mut line_start = true;
+            let mut i = 0;
+            while i < selections.len() {
+                if line_start && selections[i] != b'\n' {
+                    push!($bytes, $len, "  ");
+                }
+                line_start = selections[i] == b'\n';
+                if N != 0 {
+                    $bytes[$len] = selections[i];
+                }
+                $len += 1;
+                i += 1;
+            }
+            push!($bytes, $len, "}\n");
+        }
+    }};
+}
+
+/// Writes out the selections in `parts`
+pub const fn selections<const N: usize>(parts: &[Part]) -> Writer<N> {
+    let mut bytes = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        match &parts[i] {
+            Part::Text(text) => push!(bytes, len, text),
+            Part::Variable(name) => {
+                push!(bytes, len, "$");
+                push!(bytes, len, name);
+            }
+            Part::SelectionSet(Some(selection)) => push_selection_set!(bytes, len, selection),
+            Part::SelectionSet(None) => {}
+        }
+        i += 1;
+    }
+    Writer { bytes, len }
+}
+
+/// The number of variables used in `parts`, including duplicates
+pub const fn variables_len(parts: &[Part]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += match &parts[i] {
+            Part::Variable(_) => 1,
+            Part::SelectionSet(Some(selection)) => selection.variables.len(),
+            _ => 0,
+        };
+        i += 1;
+    }
+    len
+}
+
+/// The names of the variables used in `parts`
+pub const fn variables<const N: usize>(parts: &[Part]) -> [&'static str; N] {
+    let mut variables = [""; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        match &parts[i] {
+            Part::Variable(name) => {
+                variables[len] = *name;
+                len += 1;
+            }
+            Part::SelectionSet(Some(selection)) => {
+                let mut j = 0;
+                while j < selection.variables.len() {
+                    variables[len] = selection.variables[j];
+                    len += 1;
+                    j += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    variables
+}
+
+/// Creates a `StaticSelection` from the output of `selections` & `variables`,
+/// if all of the nested selection sets in `parts` could be built at compile
+/// time.
+pub const fn selection(
+    parts: &[Part],
+    selections: &'static [u8],
+    variables: &'static [&'static str],
+) -> Option<StaticSelection> {
+    let mut i = 0;
+    while i < parts.len() {
+        if let Part::SelectionSet(None) = &parts[i] {
+            return None;
+        }
+        i += 1;
+    }
+
+    Some(StaticSelection {
+        selections: utf8(selections),
+        variables,
+    })
+}
+
+/// Writes out the document of an operation
+pub const fn operation<const N: usize>(
+    kind: OperationKind,
+    name: &'static str,
+    selection: Option<StaticSelection>,
+    variables: &'static [(&'static str, VariableType)],
+) -> Writer<N> {
+    let mut bytes = [0; N];
+    let mut len = 0;
+
+    let Some(selection) = selection else {
+        return Writer { bytes, len };
+    };
+
+    push!(bytes, len, kind.as_str());
+    push!(bytes, len, " ");
+    push!(bytes, len, name);
+
+    let mut first = true;
+    let mut i = 0;
+    while i < variables.len() {
+        let (variable_name, ty) = &variables[i];
+        if contains(selection.variables, variable_name) {
+            push!(bytes, len, if first { "($" } else { ", $" });
+            push!(bytes, len, variable_name);
+            push!(bytes, len, ": ");
+
+            // Lists & nullability nest, so the opening brackets & name are
+            // written on the way in, then the closing brackets are written
+            // from the innermost list out.
+            let mut list_depth = 0;
+            let mut current = ty;
+            let mut required = true;
+            loop {
+                match current {
+                    VariableType::Nullable(inner) => {
+                        required = false;
+                        current = inner;
+                    }
+                    VariableType::List(inner) => {
+                        push!(bytes, len, "[");
+                        list_depth += 1;
+                        required = true;
+                        current = inner;
+                    }
+                    VariableType::Named(name) => {
+                        push!(bytes, len, name);
+                        if required {
+                            push!(bytes, len, "!");
+                        }
+                        break;
+                    }
+                }
+            }
+            while list_depth > 0 {
+                list_depth -= 1;
+                push!(bytes, len, "]");
+                if list_required(ty, list_depth) {
+                    push!(bytes, len, "!");
+                }
+            }
+
+            first = false;
+        }
+        i += 1;
+    }
+    if !first {
+        push!(bytes, len, ")");
+    }
+
+    push_selection_set!(bytes, len, selection);
+    push!(bytes, len, "\n");
+
+    Writer { bytes, len }
+}
+
+/// Whether the list that's nested `depth` lists deep in `ty` is required
+const fn list_required(ty: &VariableType, depth: usize) -> bool {
+    let mut current = ty;
+    let mut required = true;
+    let mut depth = depth;
+    loop {
+        match current {
+            VariableType::Nullable(inner) => {
+                required = false;
+                current = inner;
+            }
+            VariableType::List(inner) if depth > 0 => {
+                depth -= 1;
+                required = true;
+                current = inner;
+            }
+            VariableType::List(_) | VariableType::Named(_) => return required,
+        }
+    }
+}
+
+/// Creates a `StaticOperation` from the output of `operation`
+pub const fn static_operation(
+    kind: OperationKind,
+    name: &'static str,
+    selection: Option<StaticSelection>,
+    document: &'static [u8],
+    variables: &'static [(&'static str, VariableType)],
+) -> Option<StaticOperation> {
+    if selection.is_none() {
+        return None;
+    }
+
+    let document = utf8(document);
+
+    Some(StaticOperation {
+        kind,
+        name,
+        document,
+        hash: QueryHash::new(document),
+        variables,
+    })
+}
+
+const fn contains(haystack: &[&str], needle: &str) -> bool {
+    let mut i = 0;
+    while i < haystack.len() {
+        if str_eq(haystack[i], needle) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn utf8(bytes: &'static [u8]) -> &'static str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("a static query should be valid UTF-8"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_variable_types() {
+        const VARIABLES: &[(&str, VariableType)] = &[
+            ("foo", VariableType::List(&VariableType::Named("Foo"))),
+            ("bar", VariableType::Named("Bar")),
+            (
+                "nullable_bar",
+                VariableType::Nullable(&VariableType::Named("Bar")),
+            ),
+            (
+                "nullable_list_foo",
+                VariableType::Nullable(&VariableType::List(&VariableType::Named("Foo"))),
+            ),
+            (
+                "nested_lists",
+                VariableType::List(&VariableType::Nullable(&VariableType::List(
+                    &VariableType::Nullable(&VariableType::Named("Foo")),
+                ))),
+            ),
+            ("unused", VariableType::Named("Unused")),
+        ];
+        const SELECTION: StaticSelection = StaticSelection {
+            selections: "a\nb {\n  c\n}\n",
+            variables: &[
+                "foo",
+                "bar",
+                "nullable_bar",
+                "nullable_list_foo",
+                "nested_lists",
+            ],
+        };
+        const LEN: usize =
+            operation::<0>(OperationKind::Query, "Test", Some(SELECTION), VARIABLES).len;
+
+        let writer = operation::<LEN>(OperationKind::Query, "Test", Some(SELECTION), VARIABLES);
+
+        insta::assert_snapshot!(std::str::from_utf8(&writer.bytes).unwrap(), @r###"
+        query Test($foo: [Foo!]!, $bar: Bar!, $nullable_bar: Bar, $nullable_list_foo: [Foo!], $nested_lists: [[Foo]]!) {
+          a
+          b {
+            c
+          }
+        }
+
+        "###);
+    }
+}
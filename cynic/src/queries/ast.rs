@@ -1,11 +1,14 @@
 use std::{borrow::Cow, fmt::Write};
 
 use super::indent::indented;
+#[cfg(feature = "testing")]
+use crate::schema::TypeInfo;
 
 #[derive(Debug, Default)]
 /// A set of field selections that form part of a graphql query.
 pub struct SelectionSet {
-    pub(super) selections: Vec<Selection>,
+    pub(crate) selections: Vec<Selection>,
+    pub(crate) operation_directives: Vec<Directive>,
 }
 
 #[derive(Debug)]
@@ -15,22 +18,34 @@ pub enum Selection {
     Field(FieldSelection),
     /// Selects an inline fragment
     InlineFragment(InlineFragment),
+    /// Spreads a named fragment
+    FragmentSpread(FragmentSpread),
 }
 
 #[derive(Debug)]
 /// The details of a particular field selection
 pub struct FieldSelection {
-    pub(super) name: &'static str,
-    pub(super) alias: Option<Cow<'static, str>>,
-    pub(super) arguments: Vec<Argument>,
-    pub(super) children: SelectionSet,
+    pub(crate) name: &'static str,
+    pub(crate) alias: Option<Cow<'static, str>>,
+    pub(crate) arguments: Vec<Argument>,
+    pub(crate) directives: Vec<Directive>,
+    pub(crate) children: SelectionSet,
+    #[cfg(feature = "testing")]
+    pub(crate) type_info: Option<&'static TypeInfo>,
+}
+
+#[derive(Debug, PartialEq)]
+/// A directive applied to part of a query
+pub struct Directive {
+    pub(crate) name: &'static str,
+    pub(crate) arguments: Vec<Argument>,
 }
 
 #[derive(Debug, PartialEq)]
 /// An argument
 pub struct Argument {
-    pub(super) name: Cow<'static, str>,
-    pub(super) value: InputLiteral,
+    pub(crate) name: Cow<'static, str>,
+    pub(crate) value: InputLiteral,
 }
 
 impl Argument {
@@ -78,8 +93,109 @@ pub enum InputLiteral {
 #[derive(Debug, Default)]
 /// An inline fragment that selects fields from one possible type
 pub struct InlineFragment {
-    pub(super) on_clause: Option<&'static str>,
-    pub(super) children: SelectionSet,
+    pub(crate) on_clause: Option<&'static str>,
+    pub(crate) directives: Vec<Directive>,
+    pub(crate) children: SelectionSet,
+}
+
+#[derive(Debug)]
+/// A spread of a named fragment
+pub struct FragmentSpread {
+    pub(crate) name: Cow<'static, str>,
+}
+
+/// The named fragments that have been defined while building an operation
+#[derive(Debug, Default)]
+pub(crate) struct FragmentDefinitions {
+    fragments: Vec<FragmentDefinition>,
+}
+
+#[derive(Debug)]
+struct FragmentDefinition {
+    name: Cow<'static, str>,
+    rust_type: &'static str,
+    type_condition: &'static str,
+    selection_set: Option<SelectionSet>,
+}
+
+/// The result of looking up a fragment in `FragmentDefinitions`
+pub(super) enum FragmentLookup {
+    /// The fragment has already been defined with the given name
+    Defined {
+        name: Cow<'static, str>,
+        is_empty: bool,
+    },
+    /// The fragment is still being built, i.e. it contains itself
+    InProgress,
+    /// The fragment hasn't been seen before, and should be defined with the
+    /// given name
+    New(Cow<'static, str>),
+}
+
+impl FragmentDefinitions {
+    /// Looks up the fragment for `rust_type`, reserving a name for it if it
+    /// hasn't been seen before.
+    pub(super) fn reserve(
+        &mut self,
+        name: Cow<'static, str>,
+        rust_type: &'static str,
+        type_condition: &'static str,
+    ) -> FragmentLookup {
+        if let Some(existing) = self.fragments.iter().find(|f| f.rust_type == rust_type) {
+            return match &existing.selection_set {
+                Some(selection_set) => FragmentLookup::Defined {
+                    name: existing.name.clone(),
+                    is_empty: selection_set.selections.is_empty(),
+                },
+                None => FragmentLookup::InProgress,
+            };
+        }
+
+        // Different types can have the same name, so we need to make sure
+        // each fragment gets a unique one
+        let mut unique_name = name.clone();
+        let mut suffix = 1;
+        while self.fragments.iter().any(|f| f.name == unique_name) {
+            suffix += 1;
+            unique_name = Cow::Owned(format!("{name}{suffix}"));
+        }
+
+        self.fragments.push(FragmentDefinition {
+            name: unique_name.clone(),
+            rust_type,
+            type_condition,
+            selection_set: None,
+        });
+
+        FragmentLookup::New(unique_name)
+    }
+
+    /// Defines the fragment with the given name
+    pub(super) fn define(&mut self, name: &str, selection_set: SelectionSet) {
+        if let Some(fragment) = self.fragments.iter_mut().find(|f| f.name == name) {
+            fragment.selection_set = Some(selection_set);
+        }
+    }
+
+    /// Gets the type condition & selection set of the fragment with the given
+    /// name
+    #[cfg(any(feature = "cache", feature = "testing"))]
+    pub(crate) fn get(&self, name: &str) -> Option<(&'static str, &SelectionSet)> {
+        self.fragments
+            .iter()
+            .find(|f| f.name == name)
+            .and_then(|f| Some((f.type_condition, f.selection_set.as_ref()?)))
+    }
+
+    /// Adds a `__typename` selection to every composite field in these
+    /// fragments
+    pub(crate) fn add_typenames(&mut self) {
+        for fragment in &mut self.fragments {
+            if let Some(selection_set) = &mut fragment.selection_set {
+                selection_set.add_typenames();
+            }
+        }
+    }
 }
 
 impl FieldSelection {
@@ -89,7 +205,19 @@ impl FieldSelection {
             name,
             alias: None,
             arguments: Vec::new(),
+            directives: Vec::new(),
             children: SelectionSet::default(),
+            #[cfg(feature = "testing")]
+            type_info: None,
+        }
+    }
+
+    /// Creates a new FieldSelection for a field of the given type
+    #[cfg(feature = "testing")]
+    pub(crate) fn with_type_info(name: &'static str, type_info: &'static TypeInfo) -> Self {
+        FieldSelection {
+            type_info: Some(type_info),
+            ..FieldSelection::new(name)
         }
     }
 }
@@ -129,6 +257,9 @@ impl std::fmt::Display for Selection {
                     }
                     write!(f, ")")?;
                 }
+                for directive in &field_selection.directives {
+                    write!(f, " {directive}")?;
+                }
                 write!(f, "{}", field_selection.children)
             }
             Selection::InlineFragment(inline_fragment) => {
@@ -138,11 +269,120 @@ impl std::fmt::Display for Selection {
                     if let Some(on_type) = inline_fragment.on_clause {
                         write!(f, " on {}", on_type)?;
                     }
+                    for directive in &inline_fragment.directives {
+                        write!(f, " {directive}")?;
+                    }
                     write!(f, "{}", inline_fragment.children)?;
                 }
                 Ok(())
             }
+            Selection::FragmentSpread(fragment_spread) => {
+                writeln!(f, "...{}", fragment_spread.name)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for FragmentDefinitions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for fragment in &self.fragments {
+            let Some(selection_set) = &fragment.selection_set else {
+                continue;
+            };
+            // Don't print any empty fragments - these are never spread
+            if selection_set.selections.is_empty() {
+                continue;
+            }
+            writeln!(
+                f,
+                "fragment {} on {}{}",
+                fragment.name, fragment.type_condition, selection_set
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl SelectionSet {
+    /// Adds `prefix` to the alias of every field at the root of this
+    /// SelectionSet
+    pub(super) fn prefix_aliases(&mut self, prefix: &str) {
+        for selection in &mut self.selections {
+            match selection {
+                Selection::Field(field) => {
+                    let name = field.alias.as_deref().unwrap_or(field.name);
+                    field.alias = Some(Cow::Owned(format!("{prefix}{name}")));
+                }
+                Selection::InlineFragment(fragment) => fragment.children.prefix_aliases(prefix),
+                // The fields of a named fragment are shared by all of its
+                // spreads so we can't alias them
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    /// Adds a `__typename` selection to every composite field nested inside
+    /// this SelectionSet, if it doesn't already have one.
+    pub(crate) fn add_typenames(&mut self) {
+        for selection in &mut self.selections {
+            match selection {
+                Selection::Field(field) if !field.children.selections.is_empty() => {
+                    field.children.add_typenames();
+                    if !field.children.contains_field("__typename") {
+                        field
+                            .children
+                            .selections
+                            .insert(0, Selection::Field(FieldSelection::new("__typename")));
+                    }
+                }
+                Selection::InlineFragment(fragment) => fragment.children.add_typenames(),
+                _ => {}
+            }
+        }
+    }
+
+    /// Checks whether this SelectionSet selects the named field without an
+    /// alias
+    pub(crate) fn contains_field(&self, name: &str) -> bool {
+        self.selections.iter().any(|selection| {
+            matches!(selection, Selection::Field(field) if field.name == name && field.alias.is_none())
+        })
+    }
+
+    /// Returns something that displays the directives that should be applied
+    /// to an operation with this as its root selection set.
+    pub(crate) fn operation_directives(&self) -> impl std::fmt::Display + '_ {
+        OperationDirectives(&self.operation_directives)
+    }
+}
+
+struct OperationDirectives<'a>(&'a [Directive]);
+
+impl std::fmt::Display for OperationDirectives<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for directive in self.0 {
+            write!(f, " {directive}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Directive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}", self.name)?;
+        if !self.arguments.is_empty() {
+            write!(f, "(")?;
+            let mut first = true;
+            for arg in &self.arguments {
+                if !first {
+                    write!(f, ", ")?;
+                }
+                first = false;
+                write!(f, "{}", arg)?;
+            }
+            write!(f, ")")?;
         }
+        Ok(())
     }
 }
 
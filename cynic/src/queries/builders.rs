@@ -1,6 +1,8 @@
-use std::{borrow::Cow, collections::HashSet, marker::PhantomData, sync::mpsc::Sender};
+use std::{
+    borrow::Cow, cell::RefCell, collections::HashSet, marker::PhantomData, sync::mpsc::Sender,
+};
 
-use crate::{coercions::CoercesTo, schema, variables::VariableDefinition};
+use crate::{coercions::CoercesTo, schema, variables::VariableDefinition, QueryFragment};
 
 use super::{ast::*, to_input_literal, FlattensInto, IsFieldType, Recursable};
 
@@ -8,6 +10,10 @@ use super::{ast::*, to_input_literal, FlattensInto, IsFieldType, Recursable};
 // and giving up
 const MAX_DEPTH: u16 = 4096;
 
+// The feature that enables selection of the fields a normalized cache uses
+// to identify objects
+pub(crate) const CACHE_KEYS_FEATURE: &str = "cynic-cache-keys";
+
 /// Builds a SelectionSet for the given `SchemaType` and `VariablesFields`
 pub struct SelectionBuilder<'a, SchemaType, VariablesFields> {
     phantom: PhantomData<fn() -> (SchemaType, VariablesFields)>,
@@ -17,7 +23,8 @@ pub struct SelectionBuilder<'a, SchemaType, VariablesFields> {
 }
 
 impl<'a, T, U> SelectionBuilder<'a, Vec<T>, U> {
-    pub(crate) fn into_inner(self) -> SelectionBuilder<'a, T, U> {
+    #[doc(hidden)]
+    pub fn into_inner(self) -> SelectionBuilder<'a, T, U> {
         SelectionBuilder {
             selection_set: self.selection_set,
             has_typename: self.has_typename,
@@ -28,7 +35,8 @@ impl<'a, T, U> SelectionBuilder<'a, Vec<T>, U> {
 }
 
 impl<'a, T, U> SelectionBuilder<'a, Option<T>, U> {
-    pub(crate) fn into_inner(self) -> SelectionBuilder<'a, T, U> {
+    #[doc(hidden)]
+    pub fn into_inner(self) -> SelectionBuilder<'a, T, U> {
         SelectionBuilder {
             selection_set: self.selection_set,
             has_typename: self.has_typename,
@@ -43,6 +51,7 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
         selection_set: &'a mut SelectionSet,
         variables_used: &'a Sender<&'static str>,
         features_enabled: &'a HashSet<String>,
+        fragments: &'a RefCell<FragmentDefinitions>,
     ) -> Self {
         SelectionBuilder::private_new(
             selection_set,
@@ -51,6 +60,7 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
                 overall_depth: 0,
                 features_enabled,
                 variables_used,
+                fragments,
             },
         )
     }
@@ -76,10 +86,11 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
         FieldType: FlattensInto<Flattened>,
         SchemaType: schema::HasField<FieldMarker>,
         FieldType: IsFieldType<SchemaType::Type>,
+        SchemaType::Type: schema::MaybeHasTypeInfo,
     {
         FieldSelectionBuilder {
             context: self.context,
-            field: self.push_selection(FieldMarker::NAME),
+            field: self.push_selection::<SchemaType::Type>(FieldMarker::NAME),
             phantom: PhantomData,
         }
     }
@@ -97,10 +108,11 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
         FieldMarker: schema::Field,
         SchemaType: schema::HasField<FieldMarker>,
         FieldType: IsFieldType<SchemaType::Type>,
+        SchemaType::Type: schema::MaybeHasTypeInfo,
     {
         FieldSelectionBuilder {
             context: self.context,
-            field: self.push_selection(FieldMarker::NAME),
+            field: self.push_selection::<SchemaType::Type>(FieldMarker::NAME),
             phantom: PhantomData,
         }
     }
@@ -117,6 +129,7 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
         FieldMarker: schema::Field,
         SchemaType: schema::HasField<FieldMarker>,
         FieldType: Recursable<FieldMarker::Type>,
+        FieldMarker::Type: schema::MaybeHasTypeInfo,
     {
         let context = self.context.recurse();
         let new_depth = context.recurse_depth.unwrap();
@@ -126,15 +139,23 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
 
         Some(FieldSelectionBuilder {
             context,
-            field: self.push_selection(FieldMarker::NAME),
+            field: self.push_selection::<FieldMarker::Type>(FieldMarker::NAME),
             phantom: PhantomData,
         })
     }
 
-    fn push_selection(&'_ mut self, name: &'static str) -> &mut FieldSelection {
-        self.selection_set
-            .selections
-            .push(Selection::Field(FieldSelection::new(name)));
+    // FieldType is only used to record type info for the testing feature
+    #[cfg_attr(not(feature = "testing"), allow(clippy::extra_unused_type_parameters))]
+    fn push_selection<FieldType: schema::MaybeHasTypeInfo>(
+        &'_ mut self,
+        name: &'static str,
+    ) -> &mut FieldSelection {
+        #[cfg(feature = "testing")]
+        let field = FieldSelection::with_type_info(name, FieldType::TYPE_INFO_REF);
+        #[cfg(not(feature = "testing"))]
+        let field = FieldSelection::new(name);
+
+        self.selection_set.selections.push(Selection::Field(field));
 
         match self.selection_set.selections.last_mut() {
             Some(Selection::Field(field_selection)) => field_selection,
@@ -142,6 +163,26 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
         }
     }
 
+    /// Selects the `FieldMarker` field if normalized cache keys are enabled
+    /// for this operation and the field hasn't already been selected.
+    ///
+    /// This is used to select the `id` field of types, so that a normalized
+    /// cache can identify them.
+    pub fn select_cache_key<FieldMarker>(&'_ mut self)
+    where
+        FieldMarker: schema::Field,
+        SchemaType: schema::HasField<FieldMarker>,
+        SchemaType::Type: schema::MaybeHasTypeInfo,
+    {
+        if !self.is_feature_enabled(CACHE_KEYS_FEATURE)
+            || self.selection_set.contains_field(FieldMarker::NAME)
+        {
+            return;
+        }
+
+        self.push_selection::<SchemaType::Type>(FieldMarker::NAME);
+    }
+
     /// Adds an inline fragment to the SelectionSet
     pub fn inline_fragment(&'_ mut self) -> InlineFragmentBuilder<'_, SchemaType, VariablesFields> {
         if !self.has_typename {
@@ -167,6 +208,142 @@ impl<'a, SchemaType, VariablesFields> SelectionBuilder<'a, SchemaType, Variables
         }
     }
 
+    /// Adds a fragment with the `@defer` directive applied to the
+    /// SelectionSet.
+    ///
+    /// Any fields selected inside this fragment may be delivered by the
+    /// server in a later payload of an incremental response.
+    pub fn defer(&'_ mut self) -> InlineFragmentBuilder<'_, SchemaType, VariablesFields> {
+        self.selection_set
+            .selections
+            .push(Selection::InlineFragment(InlineFragment {
+                directives: vec![Directive {
+                    name: "defer",
+                    arguments: Vec::new(),
+                }],
+                ..InlineFragment::default()
+            }));
+
+        let inline_fragment = match self.selection_set.selections.last_mut() {
+            Some(Selection::InlineFragment(inline_fragment)) => inline_fragment,
+            _ => panic!("This should not be possible"),
+        };
+
+        InlineFragmentBuilder {
+            inline_fragment,
+            phantom: PhantomData,
+            context: self.context,
+        }
+    }
+
+    /// Adds a spread of the named fragment for `Fragment` to the SelectionSet.
+    ///
+    /// The first time a `Fragment` is used in an operation `build` is called
+    /// to select the fields of the fragment, and the fragment is defined
+    /// alongside the operation.  Subsequent uses of the same `Fragment` just
+    /// add another spread.
+    ///
+    /// If `Fragment` doesn't have a name & type, or is used inside itself,
+    /// its fields are selected directly into this SelectionSet instead.
+    pub fn named_fragment<Fragment>(
+        &'_ mut self,
+        build: impl FnOnce(SelectionBuilder<'_, SchemaType, VariablesFields>),
+    ) where
+        Fragment: QueryFragment<SchemaType = SchemaType, VariablesFields = VariablesFields>,
+    {
+        let (Some(name), Some(type_condition)) = (Fragment::name(), Fragment::TYPE) else {
+            return build(SelectionBuilder::private_new(
+                self.selection_set,
+                self.context,
+            ));
+        };
+
+        let lookup = self.context.fragments.borrow_mut().reserve(
+            name,
+            std::any::type_name::<Fragment>(),
+            type_condition,
+        );
+
+        let name = match lookup {
+            FragmentLookup::Defined { is_empty: true, .. } => return,
+            FragmentLookup::Defined { name, .. } => name,
+            FragmentLookup::InProgress => {
+                // Fragments can't contain themselves, so we have to inline
+                // the selection
+                return build(SelectionBuilder::private_new(
+                    self.selection_set,
+                    self.context,
+                ));
+            }
+            FragmentLookup::New(name) => {
+                let mut selection_set = SelectionSet::default();
+                build(SelectionBuilder::private_new(
+                    &mut selection_set,
+                    self.context.descend(),
+                ));
+                let is_empty = selection_set.selections.is_empty();
+                self.context
+                    .fragments
+                    .borrow_mut()
+                    .define(&name, selection_set);
+                if is_empty {
+                    return;
+                }
+                name
+            }
+        };
+
+        self.selection_set
+            .selections
+            .push(Selection::FragmentSpread(FragmentSpread { name }));
+    }
+
+    /// Selects fields into this SelectionSet with `build`, adding `prefix` to
+    /// the alias of each of the fields.
+    ///
+    /// This allows several root `QueryFragment`s to be merged into a single
+    /// operation without their fields conflicting - see [`crate::Merged`].
+    pub fn select_with_alias_prefix<InnerVariablesFields>(
+        &'_ mut self,
+        prefix: &str,
+        build: impl FnOnce(SelectionBuilder<'_, SchemaType, InnerVariablesFields>),
+    ) where
+        VariablesFields: VariableMatch<InnerVariablesFields>,
+    {
+        let mut selection_set = SelectionSet::default();
+        build(SelectionBuilder::private_new(
+            &mut selection_set,
+            self.context,
+        ));
+        selection_set.prefix_aliases(prefix);
+
+        self.selection_set
+            .selections
+            .extend(selection_set.selections);
+        for directive in selection_set.operation_directives {
+            if !self.selection_set.operation_directives.contains(&directive) {
+                self.selection_set.operation_directives.push(directive);
+            }
+        }
+    }
+
+    /// Adds a directive to the operation this selection is the root of.
+    ///
+    /// Accepts `DirectiveMarker` - the schema marker struct for the directive
+    /// you wish to add.  The directive is ignored if this builder isn't
+    /// building the root selection of an operation.
+    pub fn operation_directive<DirectiveMarker>(
+        &'_ mut self,
+    ) -> DirectiveBuilder<'_, DirectiveMarker, VariablesFields>
+    where
+        DirectiveMarker: schema::OperationDirective<SchemaType>,
+    {
+        DirectiveBuilder::new::<DirectiveMarker>(
+            &mut self.selection_set.operation_directives,
+            self.context,
+        )
+    }
+
     /// Checks if a feature has been enabled for this operation.
     ///
     /// QueryFragment implementations can use this to avoid sending parts of
@@ -211,6 +388,32 @@ impl<'a, Field, FieldSchemaType, VariablesFields>
         }
     }
 
+    /// Adds a directive to this field.
+    ///
+    /// Accepts `DirectiveMarker` - the schema marker struct for the directive
+    /// you wish to add.  The returned `DirectiveBuilder` can be used to
+    /// provide any arguments to the directive.
+    pub fn directive<DirectiveMarker>(
+        &'_ mut self,
+    ) -> DirectiveBuilder<'_, DirectiveMarker, VariablesFields>
+    where
+        DirectiveMarker: schema::FieldDirective,
+    {
+        DirectiveBuilder::new::<DirectiveMarker>(&mut self.field.directives, self.context)
+    }
+
+    /// Adds the `@stream` directive to this field.
+    ///
+    /// This should only be used on list fields, and allows the server to
+    /// deliver the items of the list in later payloads of an incremental
+    /// response.
+    pub fn stream(&mut self) {
+        self.field.directives.push(Directive {
+            name: "stream",
+            arguments: Vec::new(),
+        });
+    }
+
     /// Returns a SelectionBuilder that can be used to select fields
     /// within this field.
     pub fn select_children<InnerVariables>(
@@ -223,6 +426,51 @@ impl<'a, Field, FieldSchemaType, VariablesFields>
     }
 }
 
+/// Builds the arguments of a directive
+pub struct DirectiveBuilder<'a, DirectiveMarker, VariablesFields> {
+    arguments: &'a mut Vec<Argument>,
+    context: BuilderContext<'a>,
+    phantom: PhantomData<fn() -> (DirectiveMarker, VariablesFields)>,
+}
+
+impl<'a, DirectiveMarker, VariablesFields> DirectiveBuilder<'a, DirectiveMarker, VariablesFields> {
+    fn new<Marker>(directives: &'a mut Vec<Directive>, context: BuilderContext<'a>) -> Self
+    where
+        Marker: schema::Directive,
+    {
+        directives.push(Directive {
+            name: Marker::NAME,
+            arguments: Vec::new(),
+        });
+
+        DirectiveBuilder {
+            arguments: &mut directives.last_mut().unwrap().arguments,
+            context,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Adds an argument to this directive.
+    ///
+    /// Accepts `ArgumentName` - the schema marker struct for the argument you
+    /// wish to add.
+    pub fn argument<ArgumentName>(
+        &'_ mut self,
+    ) -> InputBuilder<'_, DirectiveMarker::ArgumentType, VariablesFields>
+    where
+        DirectiveMarker: schema::HasArgument<ArgumentName>,
+    {
+        InputBuilder {
+            destination: InputLiteralContainer::object(
+                <DirectiveMarker as schema::HasArgument<ArgumentName>>::NAME,
+                self.arguments,
+            ),
+            context: self.context,
+            phantom: PhantomData,
+        }
+    }
+}
+
 /// Builds an inline fragment in a selection
 pub struct InlineFragmentBuilder<'a, SchemaType, VariablesFields> {
     phantom: PhantomData<fn() -> (SchemaType, VariablesFields)>,
@@ -248,6 +496,20 @@ impl<'a, SchemaType, VariablesFields> InlineFragmentBuilder<'a, SchemaType, Vari
         }
     }
 
+    /// Adds a directive to this inline fragment.
+    ///
+    /// Accepts `DirectiveMarker` - the schema marker struct for the directive
+    /// you wish to add.  The returned `DirectiveBuilder` can be used to
+    /// provide any arguments to the directive.
+    pub fn directive<DirectiveMarker>(
+        &'_ mut self,
+    ) -> DirectiveBuilder<'_, DirectiveMarker, VariablesFields>
+    where
+        DirectiveMarker: schema::InlineFragmentDirective,
+    {
+        DirectiveBuilder::new::<DirectiveMarker>(&mut self.inline_fragment.directives, self.context)
+    }
+
     /// Returns a SelectionBuilder that can be used to select the fields
     /// of this fragment.
     pub fn select_children<InnerVariablesFields>(
@@ -441,6 +703,7 @@ impl<T> VariableMatch<()> for T where T: crate::QueryVariablesFields {}
 struct BuilderContext<'a> {
     features_enabled: &'a HashSet<String>,
     variables_used: &'a Sender<&'static str>,
+    fragments: &'a RefCell<FragmentDefinitions>,
     recurse_depth: Option<u8>,
     overall_depth: u16,
 }
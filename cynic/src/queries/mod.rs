@@ -6,13 +6,20 @@ mod flatten;
 mod indent;
 mod input_literal_ser;
 mod recurse;
+mod static_selection;
 mod type_eq;
 
+pub(crate) use self::{ast::FragmentDefinitions, builders::CACHE_KEYS_FEATURE};
+
+#[cfg(any(feature = "cache", feature = "testing"))]
+pub(crate) use self::ast::{FieldSelection, Selection};
+
 pub use self::{
-    ast::{Argument, InputLiteral, SelectionSet},
+    ast::{Argument, Directive, InputLiteral, SelectionSet},
     builders::{SelectionBuilder, VariableMatch},
     flatten::FlattensInto,
     input_literal_ser::to_input_literal,
     recurse::Recursable,
+    static_selection::StaticSelection,
     type_eq::IsFieldType,
 };
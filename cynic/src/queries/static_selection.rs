@@ -0,0 +1,14 @@
+/// A selection set that was built at compile time.
+///
+/// This is generated by the `QueryFragment` derive for fragments that don't
+/// use any features that need to be resolved at runtime - see
+/// [`QueryFragment::STATIC_SELECTION`](crate::QueryFragment::STATIC_SELECTION)
+/// for details.
+#[derive(Clone, Copy, Debug)]
+pub struct StaticSelection {
+    /// The selections of the selection set, without the surrounding braces
+    pub(crate) selections: &'static str,
+
+    /// The names of the variables used in the selection set
+    pub(crate) variables: &'static [&'static str],
+}
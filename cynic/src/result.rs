@@ -1,11 +1,25 @@
+use std::marker::PhantomData;
+
+use serde::Deserialize;
+
+use crate::__private::{self, Content, ContentDeserializer, ContentRefDeserializer};
+
 /// The response to a GraphQl operation
 #[derive(Debug, Clone)]
-pub struct GraphQlResponse<T, ErrorExtensions = serde::de::IgnoredAny> {
+pub struct GraphQlResponse<
+    T,
+    ErrorExtensions = serde::de::IgnoredAny,
+    ResponseExtensions = serde::de::IgnoredAny,
+> {
     /// The operation data (if the operation was successful)
     pub data: Option<T>,
 
     /// Any errors that occurred as part of this operation
     pub errors: Option<Vec<GraphQlError<ErrorExtensions>>>,
+
+    /// Any extra data the server included alongside the response, e.g. tracing
+    /// or rate limiting information
+    pub extensions: Option<ResponseExtensions>,
 }
 
 /// A model describing an error which has taken place during execution.
@@ -58,10 +72,46 @@ pub enum GraphQlErrorPathSegment {
     Index(i32),
 }
 
-impl<'de, T, ErrorExtensions> serde::Deserialize<'de> for GraphQlResponse<T, ErrorExtensions>
+/// The data of a [`GraphQlResponse`].
+///
+/// This tells `GraphQlResponse` whether the data contains any
+/// [`FieldResult`]s, which need the errors of the response while the data is
+/// deserialized.  Responses without them are deserialized as they're read,
+/// rather than being buffered.
+///
+/// This is implemented for every `QueryFragment`, as well as `()`,
+/// `serde_json::Value` & `serde::de::IgnoredAny`.
+pub trait UsesFieldResults {
+    /// Whether this type contains any `FieldResult`s
+    const USES_FIELD_RESULTS: bool;
+}
+
+impl<T> UsesFieldResults for T
 where
-    T: serde::Deserialize<'de>,
+    T: crate::QueryFragment,
+{
+    const USES_FIELD_RESULTS: bool = <T as crate::QueryFragment>::USES_FIELD_RESULTS;
+}
+
+impl UsesFieldResults for () {
+    const USES_FIELD_RESULTS: bool = false;
+}
+
+impl UsesFieldResults for serde::de::IgnoredAny {
+    const USES_FIELD_RESULTS: bool = false;
+}
+
+#[cfg(any(feature = "serde_json", test))]
+impl UsesFieldResults for serde_json::Value {
+    const USES_FIELD_RESULTS: bool = false;
+}
+
+impl<'de, T, ErrorExtensions, ResponseExtensions> serde::Deserialize<'de>
+    for GraphQlResponse<T, ErrorExtensions, ResponseExtensions>
+where
+    T: serde::Deserialize<'de> + UsesFieldResults,
     ErrorExtensions: serde::Deserialize<'de>,
+    ResponseExtensions: serde::Deserialize<'de>,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -70,15 +120,51 @@ where
         use serde::de::Error;
 
         #[derive(serde::Deserialize)]
-        struct ResponseDeser<T, ErrorExtensions> {
+        struct ResponseDeser<Data, Error, Extensions> {
             /// The operation data (if the operation was successful)
-            data: Option<T>,
+            data: Option<Data>,
 
             /// Any errors that occurred as part of this operation
-            errors: Option<Vec<GraphQlError<ErrorExtensions>>>,
+            errors: Option<Vec<Error>>,
+
+            /// Any extra data the server included alongside the response
+            extensions: Option<Extensions>,
         }
 
-        let ResponseDeser { data, errors } = ResponseDeser::deserialize(deserializer)?;
+        if !T::USES_FIELD_RESULTS {
+            // Nothing in the data needs the errors, so everything can be
+            // deserialized as it's read.
+            let ResponseDeser {
+                data,
+                errors,
+                extensions,
+            } = ResponseDeser::<T, GraphQlError<ErrorExtensions>, ResponseExtensions>::deserialize(
+                deserializer,
+            )?;
+
+            if data.is_none() && errors.is_none() {
+                return Err(D::Error::custom(
+                    "Either data or errors must be present in a GraphQL response",
+                ));
+            }
+
+            return Ok(GraphQlResponse {
+                data,
+                errors,
+                extensions,
+            });
+        }
+
+        // The data contains `FieldResult`s, so the response is buffered to
+        // make any errors available when deserializing the data, regardless
+        // of which comes first.
+        let ResponseDeser {
+            data,
+            errors,
+            extensions,
+        } = ResponseDeser::<Content<'de>, Content<'de>, ResponseExtensions>::deserialize(
+            deserializer,
+        )?;
 
         if data.is_none() && errors.is_none() {
             return Err(D::Error::custom(
@@ -86,12 +172,201 @@ where
             ));
         }
 
-        Ok(GraphQlResponse { data, errors })
+        let Some(errors) = errors else {
+            return Ok(GraphQlResponse {
+                data: data
+                    .map(|data| T::deserialize(ContentDeserializer::new(data)))
+                    .transpose()?,
+                errors: None,
+                extensions,
+            });
+        };
+
+        let parsed_errors = errors
+            .iter()
+            .map(|error| GraphQlError::deserialize(ContentRefDeserializer::new(error)))
+            .collect::<Result<Vec<_>, D::Error>>()?;
+
+        // Any errors with paths are made available to the `FieldResult`s in
+        // the data while it's deserialized.
+        let field_errors = parsed_errors
+            .iter()
+            .zip(errors)
+            .filter_map(|(parsed, error)| Some((parsed.path.clone()?, error.into_owned())))
+            .collect::<Vec<_>>();
+
+        let data = __private::with_errors(field_errors, || {
+            data.map(|data| T::deserialize(ContentDeserializer::new(data)))
+                .transpose()
+        })?;
+
+        Ok(GraphQlResponse {
+            data,
+            errors: Some(parsed_errors),
+            extensions,
+        })
+    }
+}
+
+/// The result of a single field in a `QueryFragment`.
+///
+/// When a field fails to resolve the server sets it to `null` (or, if the
+/// field is non-nullable, the nearest nullable field above it) and reports
+/// the error in the `errors` of the response.  Wrapping a field in a
+/// `FieldResult` captures that error, letting the rest of the response be
+/// used as normal:
+///
+/// ```rust
+/// # mod schema {
+/// #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+/// # }
+/// #
+/// # #[derive(cynic::QueryFragment, Debug)]
+/// # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+/// # struct Film {
+/// #    title: Option<String>,
+/// # }
+/// #
+/// #[derive(cynic::QueryFragment)]
+/// #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+/// struct FilmQuery {
+///     #[arguments(id: "ZmlsbXM6MQ==")]
+///     film: cynic::FieldResult<Option<Film>>,
+/// }
+///
+/// let response = serde_json::from_value::<cynic::GraphQlResponse<FilmQuery>>(
+///     serde_json::json!({
+///         "data": {"film": null},
+///         "errors": [{"message": "Film not found", "path": ["film"]}]
+///     }),
+/// )
+/// .unwrap();
+///
+/// let error = response.data.unwrap().film.into_result().unwrap_err();
+/// assert_eq!(error.message, "Film not found");
+/// ```
+///
+/// A field that's `null` without any errors deserializes as `Ok`.  Errors are
+/// only captured when the `FieldResult` is deserialized as part of a
+/// [`GraphQlResponse`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldResult<T, ErrorExtensions = serde::de::IgnoredAny>(
+    Result<T, GraphQlError<ErrorExtensions>>,
+);
+
+impl<T, ErrorExtensions> FieldResult<T, ErrorExtensions> {
+    /// Returns true if the field resolved successfully
+    pub fn is_ok(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    /// Returns true if the server reported an error for this field
+    pub fn is_err(&self) -> bool {
+        self.0.is_err()
+    }
+
+    /// Returns a reference to the contained result
+    pub fn as_result(&self) -> Result<&T, &GraphQlError<ErrorExtensions>> {
+        self.0.as_ref()
+    }
+
+    /// Converts this into a `Result`
+    pub fn into_result(self) -> Result<T, GraphQlError<ErrorExtensions>> {
+        self.0
+    }
+}
+
+impl<T, ErrorExtensions> From<Result<T, GraphQlError<ErrorExtensions>>>
+    for FieldResult<T, ErrorExtensions>
+{
+    fn from(result: Result<T, GraphQlError<ErrorExtensions>>) -> Self {
+        FieldResult(result)
+    }
+}
+
+impl<T, ErrorExtensions> From<FieldResult<T, ErrorExtensions>>
+    for Result<T, GraphQlError<ErrorExtensions>>
+{
+    fn from(result: FieldResult<T, ErrorExtensions>) -> Self {
+        result.0
+    }
+}
+
+/// Serializes the value of the field, or `null` if it errored - the same as
+/// it appeared in the response.
+impl<T, ErrorExtensions> serde::Serialize for FieldResult<T, ErrorExtensions>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.0 {
+            Ok(value) => value.serialize(serializer),
+            Err(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T, ErrorExtensions> serde::Deserialize<'de> for FieldResult<T, ErrorExtensions>
+where
+    T: serde::Deserialize<'de>,
+    ErrorExtensions: serde::de::DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::IntoDeserializer;
+
+        struct Visitor<T, ErrorExtensions>(PhantomData<fn() -> (T, ErrorExtensions)>);
+
+        impl<'de, T, ErrorExtensions> serde::de::Visitor<'de> for Visitor<T, ErrorExtensions>
+        where
+            T: serde::Deserialize<'de>,
+            ErrorExtensions: serde::de::DeserializeOwned,
+        {
+            type Value = FieldResult<T, ErrorExtensions>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a field value or null")
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if let Some(error) = __private::current_error() {
+                    return GraphQlError::deserialize(ContentDeserializer::<E>::new(error))
+                        .map(|error| FieldResult(Err(error)));
+                }
+
+                T::deserialize(().into_deserializer()).map(|value| FieldResult(Ok(value)))
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_none()
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                T::deserialize(deserializer).map(|value| FieldResult(Ok(value)))
+            }
+        }
+
+        deserializer.deserialize_option(Visitor(PhantomData))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde::de::IgnoredAny;
     use serde_json::json;
 
     use super::*;
@@ -122,10 +397,32 @@ mod tests {
                     },
                 ],
             ),
+            extensions: None,
         }
         "###);
     }
 
+    #[test]
+    fn test_graphql_response_extensions() {
+        #[derive(serde::Deserialize)]
+        struct Tracing {
+            #[serde(rename = "traceId")]
+            trace_id: String,
+        }
+
+        let response = json!({
+            "data": {"hello": "world"},
+            "extensions": {"traceId": "abc123"}
+        });
+        let response = serde_json::from_value::<
+            GraphQlResponse<serde_json::Value, IgnoredAny, Tracing>,
+        >(response)
+        .unwrap();
+
+        assert_eq!(response.data.unwrap()["hello"], "world");
+        assert_eq!(response.extensions.unwrap().trace_id, "abc123");
+    }
+
     #[test]
     fn test_graphql_response_fails_on_completely_invalid_response() {
         let response = json!({
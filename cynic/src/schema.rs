@@ -52,12 +52,40 @@ pub trait HasArgument<ArgumentMarker> {
     const NAME: &'static str;
 }
 
+/// Indicates that a struct represents a directive in a graphql schema.
+///
+/// Arguments to the directive are described with [`HasArgument`]
+/// implementations on the directive marker.  Where the directive can be
+/// used is described by the [`FieldDirective`], [`InlineFragmentDirective`]
+/// & [`OperationDirective`] traits.
+pub trait Directive {
+    /// The name of this directive
+    const NAME: &'static str;
+}
+
+/// Indicates that a directive can be used on fields.
+pub trait FieldDirective: Directive {}
+
+/// Indicates that a directive can be used on inline fragments.
+pub trait InlineFragmentDirective: Directive {}
+
+/// Indicates that a directive can be used on operations with the given root
+/// type.
+///
+/// `RootType` will be the query, mutation or subscription root of the schema,
+/// depending on whether the directive can be used on queries, mutations or
+/// subscriptions.
+pub trait OperationDirective<RootType>: Directive {}
+
 /// Indicates that a type is a scalar that maps to the given schema scalar.
 ///
 /// Note that this type is actually implemented on the users types.
 pub trait IsScalar<SchemaType> {
     /// The schema marker type this scalar represents.
     type SchemaType;
+
+    /// Whether this type contains a [`FieldResult`](crate::FieldResult)
+    const USES_FIELD_RESULTS: bool = false;
 }
 
 impl<T, U: ?Sized> IsScalar<T> for &U
@@ -65,6 +93,8 @@ where
     U: IsScalar<T>,
 {
     type SchemaType = U::SchemaType;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
 }
 
 impl<T, U> IsScalar<Option<T>> for Option<U>
@@ -72,6 +102,8 @@ where
     U: IsScalar<T>,
 {
     type SchemaType = Option<U::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
 }
 
 impl<T, U> IsScalar<Vec<T>> for Vec<U>
@@ -79,6 +111,8 @@ where
     U: IsScalar<T>,
 {
     type SchemaType = Vec<U::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
 }
 
 impl<T, U> IsScalar<Vec<T>> for [U]
@@ -86,6 +120,8 @@ where
     U: IsScalar<T>,
 {
     type SchemaType = Vec<U::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
 }
 
 impl<T, U, const SIZE: usize> IsScalar<Vec<T>> for [U; SIZE]
@@ -93,6 +129,8 @@ where
     U: IsScalar<T>,
 {
     type SchemaType = Vec<U::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
 }
 
 impl<T, U: ?Sized> IsScalar<Box<T>> for Box<U>
@@ -100,6 +138,17 @@ where
     U: IsScalar<T>,
 {
     type SchemaType = Box<U::SchemaType>;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
+}
+
+impl<T, U, ErrorExtensions> IsScalar<T> for crate::FieldResult<U, ErrorExtensions>
+where
+    U: IsScalar<T>,
+{
+    type SchemaType = U::SchemaType;
+
+    const USES_FIELD_RESULTS: bool = true;
 }
 
 impl<T, U: ?Sized> IsScalar<T> for std::borrow::Cow<'_, U>
@@ -107,6 +156,8 @@ where
     U: IsScalar<T> + ToOwned,
 {
     type SchemaType = U::SchemaType;
+
+    const USES_FIELD_RESULTS: bool = U::USES_FIELD_RESULTS;
 }
 
 impl IsScalar<bool> for bool {
@@ -174,5 +225,138 @@ impl NamedType for crate::Id {
     const NAME: &'static str = "ID";
 }
 
+/// The shape of an output type in a schema.
+///
+/// This is recorded against each field of a query when it's built, so that
+/// tools like [`cynic::testing`](crate::testing) can generate responses for
+/// the query.
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeInfo {
+    /// A list of some `TypeInfo`
+    List(&'static TypeInfo),
+    /// Some `TypeInfo` that may be null
+    Nullable(&'static TypeInfo),
+    /// A scalar with the given name
+    Scalar(&'static str),
+    /// An enum with the given name & values
+    Enum {
+        /// The name of the enum
+        name: &'static str,
+        /// The values of the enum
+        values: &'static [&'static str],
+    },
+    /// An object with the given name
+    Object(&'static str),
+    /// An interface with the given name & implementations
+    Interface {
+        /// The name of the interface
+        name: &'static str,
+        /// The names of the objects that implement this interface
+        possible_types: &'static [&'static str],
+    },
+    /// A union with the given name & members
+    Union {
+        /// The name of the union
+        name: &'static str,
+        /// The names of the members of this union
+        possible_types: &'static [&'static str],
+    },
+}
+
+/// Indicates that a marker type describes an output type in the schema.
+///
+/// This is implemented on all the output types that `use_schema` outputs when
+/// the `testing` feature is enabled.
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub trait HasTypeInfo {
+    /// The shape of this type
+    const TYPE_INFO: TypeInfo;
+}
+
+#[cfg(feature = "testing")]
+impl<T: HasTypeInfo> HasTypeInfo for Option<T> {
+    const TYPE_INFO: TypeInfo = TypeInfo::Nullable(&T::TYPE_INFO);
+}
+
+#[cfg(feature = "testing")]
+impl<T: HasTypeInfo> HasTypeInfo for Vec<T> {
+    const TYPE_INFO: TypeInfo = TypeInfo::List(&T::TYPE_INFO);
+}
+
+#[cfg(feature = "testing")]
+impl<T: HasTypeInfo> HasTypeInfo for Box<T> {
+    const TYPE_INFO: TypeInfo = T::TYPE_INFO;
+}
+
+#[cfg(feature = "testing")]
+macro_rules! impl_has_type_info_for_scalar {
+    ($target:ty) => {
+        impl HasTypeInfo for $target {
+            const TYPE_INFO: TypeInfo = TypeInfo::Scalar(<$target as NamedType>::NAME);
+        }
+    };
+}
+
+#[cfg(feature = "testing")]
+mod scalar_type_info {
+    use super::{HasTypeInfo, NamedType, TypeInfo};
+
+    impl_has_type_info_for_scalar!(i32);
+    impl_has_type_info_for_scalar!(f64);
+    impl_has_type_info_for_scalar!(String);
+    impl_has_type_info_for_scalar!(bool);
+    impl_has_type_info_for_scalar!(crate::Id);
+}
+
+/// A bound on the type of every selected field.
+///
+/// With the `testing` feature enabled this requires [`HasTypeInfo`], so that
+/// the shape of each field can be recorded for generating mocks.  Otherwise
+/// it's implemented for every type.
+#[doc(hidden)]
+pub trait MaybeHasTypeInfo {
+    #[cfg(feature = "testing")]
+    const TYPE_INFO_REF: &'static TypeInfo;
+}
+
+#[cfg(feature = "testing")]
+impl<T: HasTypeInfo> MaybeHasTypeInfo for T {
+    const TYPE_INFO_REF: &'static TypeInfo = &T::TYPE_INFO;
+}
+
+#[cfg(not(feature = "testing"))]
+impl<T: ?Sized> MaybeHasTypeInfo for T {}
+
+/// Implements `HasTypeInfo` for a schema type when the `testing` feature is
+/// enabled, and does nothing otherwise.
+///
+/// `use_schema` outputs calls to this so that its output doesn't depend on the
+/// features of cynic.
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_has_type_info {
+    ($target:ident, $type_info:expr) => {
+        impl $crate::schema::HasTypeInfo for $target {
+            const TYPE_INFO: $crate::schema::TypeInfo = $type_info;
+        }
+    };
+}
+
+/// Implements `HasTypeInfo` for a schema type when the `testing` feature is
+/// enabled, and does nothing otherwise.
+///
+/// `use_schema` outputs calls to this so that its output doesn't depend on the
+/// features of cynic.
+#[cfg(not(feature = "testing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_has_type_info {
+    ($target:ident, $type_info:expr) => {};
+}
+
 /// Indicates that a type is an `InputObject`
 pub trait InputObjectMarker {}
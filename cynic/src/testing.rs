@@ -0,0 +1,437 @@
+//! Helpers for generating mock responses in tests.
+//!
+//! [`mock`] builds the selection set of a `QueryFragment`, generates a
+//! response that matches it & the schema, and deserializes that response into
+//! the fragment:
+//!
+//! ```rust
+//! # mod schema {
+//! #   cynic::use_schema!("../schemas/starwars.schema.graphql");
+//! # }
+//! #
+//! # #[derive(cynic::QueryFragment, Debug)]
+//! # #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+//! # struct Film {
+//! #    title: Option<String>,
+//! #    director: Option<String>,
+//! # }
+//! #
+//! # #[derive(cynic::QueryFragment, Debug)]
+//! # #[cynic(schema_path = "../schemas/starwars.schema.graphql", graphql_type = "Root")]
+//! # struct FilmQuery {
+//! #     #[arguments(id: "ZmlsbXM6MQ==")]
+//! #     film: Option<Film>,
+//! # }
+//! use cynic::testing::{mock, MockGenerator};
+//!
+//! let query = mock::<FilmQuery>();
+//! assert!(query.film.unwrap().title.is_some());
+//!
+//! let query = MockGenerator::new()
+//!     .with_seed(42)
+//!     .with_override("film.title", "A New Hope")
+//!     .mock::<FilmQuery>();
+//! assert_eq!(query.film.unwrap().title.as_deref(), Some("A New Hope"));
+//! ```
+//!
+//! Generation is deterministic: the same `MockGenerator` always generates the
+//! same response for a given fragment.
+//!
+//! Enabling the `testing` feature makes `use_schema` output a
+//! [`HasTypeInfo`](crate::schema::HasTypeInfo) impl for each type in the
+//! schema, which is used to decide what to generate for each field.  Hand
+//! written schema modules will need to implement it themselves.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{
+    operation::build_selection,
+    queries::{FieldSelection, FragmentDefinitions, Selection, SelectionSet},
+    schema::TypeInfo,
+    QueryFragment,
+};
+
+/// Generates a mock response for `Fragment` using the default
+/// [`MockGenerator`] settings.
+///
+/// # Panics
+///
+/// Panics if the generated response can't be deserialized into `Fragment`,
+/// e.g. because it contains a custom scalar that needs a type override.
+pub fn mock<Fragment>() -> Fragment
+where
+    Fragment: QueryFragment + DeserializeOwned,
+{
+    MockGenerator::new().mock()
+}
+
+/// How a [`MockGenerator`] picks the concrete type of an interface or union.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PossibleTypeStrategy {
+    /// Picks the first type that the query has a fragment for, or the first
+    /// possible type in the schema if there are no fragments.
+    #[default]
+    First,
+    /// Picks one of the possible types in the schema at random.
+    ///
+    /// This may pick a type the query has no fragment for, which is useful
+    /// for testing fallbacks.
+    Random,
+    /// Picks the given type if it's a possible type, otherwise behaves like
+    /// `First`.
+    Prefer(String),
+}
+
+/// Generates mock responses for `QueryFragment`s.
+///
+/// Values are generated from a seeded random number generator, so the same
+/// settings always generate the same response.  Specific values can be
+/// provided with [`with_override`](Self::with_override) &
+/// [`with_type_override`](Self::with_type_override).
+///
+/// Paths are the response keys (i.e. aliases if present, otherwise field
+/// names) of each field from the root of the fragment, separated by `.`.  List
+/// indices aren't part of a path, so an override of a field inside a list
+/// applies to every item in the list.
+#[derive(Debug, Clone)]
+pub struct MockGenerator {
+    seed: u64,
+    list_length: usize,
+    list_lengths: HashMap<String, usize>,
+    overrides: HashMap<String, Value>,
+    type_overrides: HashMap<String, Value>,
+    possible_type_strategy: PossibleTypeStrategy,
+}
+
+impl Default for MockGenerator {
+    fn default() -> Self {
+        MockGenerator {
+            seed: 0,
+            list_length: 2,
+            list_lengths: HashMap::new(),
+            overrides: HashMap::new(),
+            type_overrides: HashMap::new(),
+            possible_type_strategy: PossibleTypeStrategy::default(),
+        }
+    }
+}
+
+impl MockGenerator {
+    /// Creates a new `MockGenerator` with the default settings
+    pub fn new() -> Self {
+        MockGenerator::default()
+    }
+
+    /// Sets the seed that values are generated from
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the number of items generated for lists, which defaults to 2
+    pub fn with_list_length(mut self, length: usize) -> Self {
+        self.list_length = length;
+        self
+    }
+
+    /// Sets the number of items generated for the list at `path`
+    pub fn with_list_length_at(mut self, path: impl Into<String>, length: usize) -> Self {
+        self.list_lengths.insert(path.into(), length);
+        self
+    }
+
+    /// Uses `value` for the field at `path` instead of generating one
+    pub fn with_override(mut self, path: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.overrides.insert(path.into(), value.into());
+        self
+    }
+
+    /// Uses `value` for every value of the GraphQL type `type_name` instead
+    /// of generating one.
+    ///
+    /// This is mostly useful for custom scalars, which are otherwise
+    /// generated as strings.
+    pub fn with_type_override(
+        mut self,
+        type_name: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.type_overrides.insert(type_name.into(), value.into());
+        self
+    }
+
+    /// Sets how the concrete types of interfaces & unions are picked
+    pub fn with_possible_type_strategy(mut self, strategy: PossibleTypeStrategy) -> Self {
+        self.possible_type_strategy = strategy;
+        self
+    }
+
+    /// Generates a mock response for `Fragment` as JSON
+    pub fn generate<Fragment>(&self) -> Value
+    where
+        Fragment: QueryFragment,
+    {
+        let (selection_set, fragments, _) = build_selection::<Fragment>(&HashSet::new());
+
+        let mut generator = Generator {
+            options: self,
+            fragments: &fragments,
+            rng: Rng::new(self.seed),
+            path: Vec::new(),
+        };
+
+        let typename = Fragment::TYPE.unwrap_or_default();
+        let mut output = Map::new();
+        generator.selections(&selection_set, &[typename], &mut output);
+
+        Value::Object(output)
+    }
+
+    /// Generates a mock response for `Fragment` and deserializes it
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generated response can't be deserialized into
+    /// `Fragment`, e.g. because it contains a custom scalar that needs a type
+    /// override.
+    pub fn mock<Fragment>(&self) -> Fragment
+    where
+        Fragment: QueryFragment + DeserializeOwned,
+    {
+        let response = self.generate::<Fragment>();
+        match serde_json::from_value(response.clone()) {
+            Ok(fragment) => fragment,
+            Err(error) => panic!(
+                "Couldn't deserialize mock response into {}: {error}\nResponse: {response:#}",
+                std::any::type_name::<Fragment>()
+            ),
+        }
+    }
+}
+
+struct Generator<'a> {
+    options: &'a MockGenerator,
+    fragments: &'a FragmentDefinitions,
+    rng: Rng,
+    path: Vec<&'a str>,
+}
+
+impl<'a> Generator<'a> {
+    /// Generates the fields of `selection_set` into `output`.
+    ///
+    /// `typenames` contains the concrete type of the object being generated,
+    /// followed by the type it was selected as if that was an interface or
+    /// union.  Fragments apply if their type condition is one of these.
+    fn selections(
+        &mut self,
+        selection_set: &'a SelectionSet,
+        typenames: &[&'a str],
+        output: &mut Map<String, Value>,
+    ) {
+        for selection in &selection_set.selections {
+            match selection {
+                Selection::Field(field) => {
+                    let key = field.alias.as_deref().unwrap_or(field.name);
+                    self.path.push(key);
+                    let value = match field.name {
+                        "__typename" => Value::from(typenames[0]),
+                        _ => self.field(field),
+                    };
+                    self.path.pop();
+
+                    merge(output, key, value);
+                }
+                Selection::InlineFragment(fragment) => {
+                    let applies = fragment
+                        .on_clause
+                        .map_or(true, |on_clause| typenames.contains(&on_clause));
+                    if applies {
+                        self.selections(&fragment.children, typenames, output);
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    let Some((type_condition, selection_set)) = self.fragments.get(&spread.name)
+                    else {
+                        continue;
+                    };
+                    if typenames.contains(&type_condition) {
+                        self.selections(selection_set, typenames, output);
+                    }
+                }
+            }
+        }
+    }
+
+    fn field(&mut self, field: &'a FieldSelection) -> Value {
+        if let Some(value) = self.options.overrides.get(&self.path.join(".")) {
+            return value.clone();
+        }
+
+        match field.type_info {
+            Some(type_info) => self.value(type_info, field),
+            None => Value::Null,
+        }
+    }
+
+    fn value(&mut self, type_info: &'static TypeInfo, field: &'a FieldSelection) -> Value {
+        let type_name = match type_info {
+            TypeInfo::Nullable(inner) => return self.value(inner, field),
+            TypeInfo::List(inner) => {
+                let length = self
+                    .options
+                    .list_lengths
+                    .get(&self.path.join("."))
+                    .copied()
+                    .unwrap_or(self.options.list_length);
+
+                return Value::Array((0..length).map(|_| self.value(inner, field)).collect());
+            }
+            TypeInfo::Scalar(name) | TypeInfo::Object(name) => name,
+            TypeInfo::Enum { name, .. }
+            | TypeInfo::Interface { name, .. }
+            | TypeInfo::Union { name, .. } => name,
+        };
+
+        if let Some(value) = self.options.type_overrides.get(*type_name) {
+            return value.clone();
+        }
+
+        match type_info {
+            TypeInfo::Scalar(name) => self.scalar(name, field),
+            TypeInfo::Enum { values, .. } => match values.len() {
+                0 => Value::Null,
+                len => Value::from(values[self.rng.below(len)]),
+            },
+            TypeInfo::Object(name) => self.object(field, &[name]),
+            TypeInfo::Interface {
+                name,
+                possible_types,
+            }
+            | TypeInfo::Union {
+                name,
+                possible_types,
+            } => {
+                let typename = self
+                    .pick_type(&field.children, possible_types)
+                    .unwrap_or(name);
+                self.object(field, &[typename, name])
+            }
+            TypeInfo::Nullable(_) | TypeInfo::List(_) => unreachable!(),
+        }
+    }
+
+    fn object(&mut self, field: &'a FieldSelection, typenames: &[&'a str]) -> Value {
+        let mut output = Map::new();
+        self.selections(&field.children, typenames, &mut output);
+        Value::Object(output)
+    }
+
+    fn scalar(&mut self, name: &str, field: &FieldSelection) -> Value {
+        match name {
+            "Int" => Value::from(self.rng.below(1000) as i32),
+            "Float" => Value::from(self.rng.below(100_000) as f64 / 100.0),
+            "Boolean" => Value::from(self.rng.below(2) == 1),
+            "ID" => Value::from(format!("{:x}", self.rng.next())),
+            "String" => Value::from(format!("{} {}", field.name, self.rng.below(1000))),
+            _ => Value::from(format!("{name} {}", self.rng.below(1000))),
+        }
+    }
+
+    /// Picks the concrete type of an interface or union.
+    fn pick_type(
+        &mut self,
+        selection_set: &SelectionSet,
+        possible_types: &'static [&'static str],
+    ) -> Option<&'static str> {
+        match &self.options.possible_type_strategy {
+            PossibleTypeStrategy::Random if !possible_types.is_empty() => {
+                Some(possible_types[self.rng.below(possible_types.len())])
+            }
+            PossibleTypeStrategy::Prefer(preferred) => possible_types
+                .iter()
+                .find(|ty| *ty == preferred)
+                .copied()
+                .or_else(|| self.first_type(selection_set, possible_types)),
+            _ => self.first_type(selection_set, possible_types),
+        }
+    }
+
+    /// Finds the first possible type that `selection_set` has a fragment for,
+    /// or the first possible type if there isn't one.
+    fn first_type(
+        &self,
+        selection_set: &SelectionSet,
+        possible_types: &'static [&'static str],
+    ) -> Option<&'static str> {
+        let type_conditions =
+            selection_set
+                .selections
+                .iter()
+                .filter_map(|selection| match selection {
+                    Selection::InlineFragment(fragment) => fragment.on_clause,
+                    Selection::FragmentSpread(spread) => Some(self.fragments.get(&spread.name)?.0),
+                    Selection::Field(_) => None,
+                });
+
+        for type_condition in type_conditions {
+            if let Some(ty) = possible_types.iter().find(|ty| **ty == type_condition) {
+                return Some(ty);
+            }
+        }
+
+        possible_types.first().copied()
+    }
+}
+
+/// Inserts `value` into `output`, merging it with any object that's already
+/// there.
+///
+/// Fields can be selected more than once (e.g. by several fragments), in
+/// which case we need the union of their selections.
+fn merge(output: &mut Map<String, Value>, key: &str, value: Value) {
+    match (output.get_mut(key), value) {
+        (Some(Value::Object(existing)), Value::Object(fields)) => {
+            for (key, value) in fields {
+                merge(existing, &key, value);
+            }
+        }
+        (Some(Value::Array(existing)), Value::Array(values)) => {
+            for (existing, value) in existing.iter_mut().zip(values) {
+                if let (Value::Object(existing), Value::Object(fields)) = (existing, value) {
+                    for (key, value) in fields {
+                        merge(existing, &key, value);
+                    }
+                }
+            }
+        }
+        (Some(_), _) => {}
+        (None, value) => {
+            output.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// A small deterministic random number generator (splitmix64).
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..max`
+    fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+}
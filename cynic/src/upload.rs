@@ -0,0 +1,195 @@
+use std::{cell::RefCell, fmt};
+
+use serde::{ser::Error, Serialize, Serializer};
+
+/// A file to be sent to a server alongside an operation, for use with an
+/// `Upload` scalar.
+///
+/// `Upload`s can be used in `QueryVariables` & `InputObject` fields once
+/// they've been registered as a scalar for the schema:
+///
+/// ```rust
+/// # mod schema {
+/// #   cynic::use_schema!("../schemas/uploads.graphql");
+/// # }
+/// cynic::impl_scalar!(cynic::Upload, schema::Upload);
+///
+/// #[derive(cynic::QueryVariables)]
+/// struct UploadVariables {
+///     file: cynic::Upload,
+/// }
+///
+/// let variables = UploadVariables {
+///     file: cynic::Upload::new("hello.txt", "Hello!").with_mime_type("text/plain"),
+/// };
+/// ```
+///
+/// Files can only be sent using the [GraphQL multipart request
+/// spec](https://github.com/jaydenseric/graphql-multipart-request-spec) - see
+/// the `run_graphql_multipart` functions of the HTTP integrations, which need
+/// the `http-multipart` feature.  Serializing an `Upload` as part of a normal
+/// JSON request is an error.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Upload {
+    file_name: String,
+    mime_type: Option<String>,
+    content: Vec<u8>,
+}
+
+impl Upload {
+    /// Constructs an `Upload` from a file name and the contents of the file
+    ///
+    /// ```
+    /// cynic::Upload::new("hello.txt", "Hello!");
+    /// ```
+    pub fn new(file_name: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+        Upload {
+            file_name: file_name.into(),
+            mime_type: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the MIME type of the file, e.g. `image/png`
+    ///
+    /// If this isn't set the server will usually treat the file as
+    /// `application/octet-stream`
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// The name of the file
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// The MIME type of the file, if one was provided
+    pub fn mime_type(&self) -> Option<&str> {
+        self.mime_type.as_deref()
+    }
+
+    /// The contents of the file
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// Converts this `Upload` into the contents of the file
+    pub fn into_content(self) -> Vec<u8> {
+        self.content
+    }
+}
+
+impl fmt::Debug for Upload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Upload")
+            .field("file_name", &self.file_name)
+            .field("mime_type", &self.mime_type)
+            .field("content", &format_args!("[{} bytes]", self.content.len()))
+            .finish()
+    }
+}
+
+thread_local! {
+    static UPLOADS: RefCell<Option<Vec<Upload>>> = RefCell::new(None);
+}
+
+/// The prefix of the placeholders that `Upload`s serialize as while they're
+/// being collected.
+const PLACEHOLDER_PREFIX: &str = "\u{0}cynic-upload:";
+
+impl Serialize for Upload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let index = UPLOADS.with(|uploads| {
+            let mut uploads = uploads.borrow_mut();
+            let uploads = uploads.as_mut()?;
+            uploads.push(self.clone());
+            Some(uploads.len() - 1)
+        });
+
+        match index {
+            Some(index) => serializer.serialize_str(&format!("{PLACEHOLDER_PREFIX}{index}")),
+            None => Err(S::Error::custom(
+                "Upload can only be sent with run_graphql_multipart",
+            )),
+        }
+    }
+}
+
+/// Runs `f`, collecting any `Upload`s that it serializes.
+///
+/// Inside `f` each `Upload` serializes as a placeholder string which can be
+/// turned back into an index into the returned `Vec` with `placeholder_index`
+#[cfg_attr(
+    not(all(feature = "http-reqwest", feature = "http-multipart")),
+    allow(dead_code)
+)]
+pub(crate) fn collect_uploads<R>(f: impl FnOnce() -> R) -> (R, Vec<Upload>) {
+    struct Guard(Option<Vec<Upload>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            UPLOADS.with(|uploads| *uploads.borrow_mut() = previous);
+        }
+    }
+
+    let previous = UPLOADS.with(|uploads| uploads.borrow_mut().replace(Vec::new()));
+    let guard = Guard(previous);
+
+    let result = f();
+    let uploads = UPLOADS
+        .with(|uploads| uploads.borrow_mut().take())
+        .unwrap_or_default();
+    drop(guard);
+
+    (result, uploads)
+}
+
+/// Returns the index of the `Upload` that serialized as `value` inside
+/// `collect_uploads`, if any.
+#[cfg_attr(
+    not(all(feature = "http-reqwest", feature = "http-multipart")),
+    allow(dead_code)
+)]
+pub(crate) fn placeholder_index(value: &str) -> Option<usize> {
+    value.strip_prefix(PLACEHOLDER_PREFIX)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uploads_dont_serialize_outside_multipart_requests() {
+        assert_eq!(
+            serde_json::to_value(Upload::new("hello.txt", "Hello!"))
+                .unwrap_err()
+                .to_string(),
+            "Upload can only be sent with run_graphql_multipart"
+        );
+    }
+
+    #[test]
+    fn test_collect_uploads() {
+        let (value, uploads) = collect_uploads(|| {
+            serde_json::to_value([Upload::new("one.txt", "1"), Upload::new("two.txt", "2")])
+                .unwrap()
+        });
+
+        assert_eq!(
+            uploads
+                .iter()
+                .map(|upload| upload.file_name())
+                .collect::<Vec<_>>(),
+            vec!["one.txt", "two.txt"]
+        );
+        assert_eq!(placeholder_index(value[1].as_str().unwrap()), Some(1));
+
+        // Uploads are only collected inside collect_uploads
+        assert!(serde_json::to_value(Upload::new("one.txt", "1")).is_err());
+    }
+}
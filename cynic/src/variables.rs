@@ -3,7 +3,7 @@
 use std::marker::PhantomData;
 
 /// The type of a variable
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VariableType {
     /// A list of some VariableType
     List(&'static VariableType),
@@ -49,7 +49,7 @@ pub struct VariableDefinition<Variables, Type> {
 
 impl<Variables, Type> VariableDefinition<Variables, Type> {
     /// Create a new variable with the given name.
-    pub fn new(name: &'static str) -> Self {
+    pub const fn new(name: &'static str) -> Self {
         VariableDefinition {
             name,
             phantom: PhantomData,
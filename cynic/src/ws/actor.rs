@@ -0,0 +1,228 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_channel::mpsc;
+use futures_util::{
+    future::{self, BoxFuture},
+    stream, Sink, SinkExt, Stream, StreamExt,
+};
+use serde_json::Value;
+
+use super::{
+    protocol::{ClientMessage, Protocol, ServerMessage},
+    WsError,
+};
+
+/// The messages a subscription receives from the `ConnectionActor`.
+pub(super) type SubscriptionEvent = Result<Value, WsError>;
+
+pub(super) enum Command {
+    Subscribe {
+        id: String,
+        payload: Value,
+        sender: mpsc::UnboundedSender<SubscriptionEvent>,
+    },
+    Stop {
+        id: String,
+    },
+}
+
+/// A future that drives a websocket connection.
+///
+/// This is returned from [`ClientBuilder::build`](super::ClientBuilder::build)
+/// and must be spawned onto an executor of your choice for any subscriptions
+/// on the associated [`Client`](super::Client) to make progress.  It will
+/// complete when the connection is closed or when the `Client` and all of its
+/// subscriptions have been dropped.
+#[must_use = "the ConnectionActor must be spawned for subscriptions to run"]
+pub struct ConnectionActor {
+    inner: BoxFuture<'static, ()>,
+}
+
+impl ConnectionActor {
+    pub(super) fn new<Tx, Rx>(
+        protocol: Protocol,
+        sender: Tx,
+        receiver: Rx,
+        commands: mpsc::UnboundedReceiver<Command>,
+    ) -> Self
+    where
+        Tx: Sink<String> + Unpin + Send + 'static,
+        Tx::Error: Display,
+        Rx: Stream<Item = String> + Unpin + Send + 'static,
+    {
+        ConnectionActor {
+            inner: Box::pin(run(protocol, sender, receiver, commands)),
+        }
+    }
+}
+
+impl Future for ConnectionActor {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+enum Event {
+    Message(String),
+    Disconnected,
+    Command(Command),
+    ClientDropped,
+}
+
+async fn run<Tx, Rx>(
+    protocol: Protocol,
+    mut sender: Tx,
+    receiver: Rx,
+    commands: mpsc::UnboundedReceiver<Command>,
+) where
+    Tx: Sink<String> + Unpin + Send + 'static,
+    Tx::Error: Display,
+    Rx: Stream<Item = String> + Unpin + Send + 'static,
+{
+    let mut subscriptions = HashMap::<String, mpsc::UnboundedSender<SubscriptionEvent>>::new();
+
+    let mut events = stream::select(
+        receiver
+            .map(Event::Message)
+            .chain(stream::once(future::ready(Event::Disconnected))),
+        commands
+            .map(Event::Command)
+            .chain(stream::once(future::ready(Event::ClientDropped))),
+    );
+
+    while let Some(event) = events.next().await {
+        let outcome = match event {
+            Event::Message(text) => {
+                handle_message(protocol, &text, &mut sender, &mut subscriptions).await
+            }
+            Event::Command(command) => {
+                handle_command(protocol, command, &mut sender, &mut subscriptions).await
+            }
+            Event::Disconnected => Err(WsError::ConnectionClosed),
+            Event::ClientDropped => {
+                send(protocol, &mut sender, &ClientMessage::Terminate)
+                    .await
+                    .ok();
+                sender.close().await.ok();
+                return;
+            }
+        };
+
+        if let Err(error) = outcome {
+            for (_, subscription) in subscriptions.drain() {
+                subscription.unbounded_send(Err(error.clone())).ok();
+            }
+            sender.close().await.ok();
+            return;
+        }
+    }
+}
+
+async fn handle_message<Tx>(
+    protocol: Protocol,
+    text: &str,
+    sender: &mut Tx,
+    subscriptions: &mut HashMap<String, mpsc::UnboundedSender<SubscriptionEvent>>,
+) -> Result<(), WsError>
+where
+    Tx: Sink<String> + Unpin,
+    Tx::Error: Display,
+{
+    match protocol.decode(text)? {
+        ServerMessage::Next { id, payload } => {
+            if let Some(subscription) = subscriptions.get(&id) {
+                subscription.unbounded_send(Ok(payload)).ok();
+            }
+        }
+        ServerMessage::Error { id, errors } => {
+            // Errors terminate a subscription, so we remove it here which
+            // closes the channel after this final message.
+            if let Some(subscription) = subscriptions.remove(&id) {
+                subscription
+                    .unbounded_send(Err(WsError::OperationError(errors)))
+                    .ok();
+            }
+        }
+        ServerMessage::Complete { id } => {
+            subscriptions.remove(&id);
+        }
+        ServerMessage::Ping => send(protocol, sender, &ClientMessage::Pong).await?,
+        ServerMessage::KeepAlive => {}
+        ServerMessage::ConnectionError { payload } => {
+            return Err(WsError::ConnectionError(payload));
+        }
+        ServerMessage::ConnectionAck => {
+            return Err(WsError::Protocol(
+                "received a connection_ack after the connection was initialised".into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_command<Tx>(
+    protocol: Protocol,
+    command: Command,
+    sender: &mut Tx,
+    subscriptions: &mut HashMap<String, mpsc::UnboundedSender<SubscriptionEvent>>,
+) -> Result<(), WsError>
+where
+    Tx: Sink<String> + Unpin,
+    Tx::Error: Display,
+{
+    match command {
+        Command::Subscribe {
+            id,
+            payload,
+            sender: subscription,
+        } => {
+            send(
+                protocol,
+                sender,
+                &ClientMessage::Subscribe {
+                    id: &id,
+                    payload: &payload,
+                },
+            )
+            .await?;
+            subscriptions.insert(id, subscription);
+        }
+        Command::Stop { id } => {
+            // If the server has already completed this subscription there's
+            // no need to tell it about it.
+            if subscriptions.remove(&id).is_some() {
+                send(protocol, sender, &ClientMessage::Stop { id: &id }).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(super) async fn send<Tx>(
+    protocol: Protocol,
+    sender: &mut Tx,
+    message: &ClientMessage<'_>,
+) -> Result<(), WsError>
+where
+    Tx: Sink<String> + Unpin,
+    Tx::Error: Display,
+{
+    let Some(text) = protocol.encode(message)? else {
+        return Ok(());
+    };
+
+    sender
+        .send(text)
+        .await
+        .map_err(|error| WsError::Transport(error.to_string()))
+}
@@ -0,0 +1,203 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use futures_channel::mpsc;
+use futures_util::{Sink, Stream, StreamExt};
+use serde_json::Value;
+
+use super::{
+    actor::{self, Command, ConnectionActor, SubscriptionEvent},
+    protocol::{ClientMessage, Protocol, ServerMessage},
+    WsError,
+};
+use crate::{GraphQlResponse, StreamingOperation};
+
+/// Builds a [`Client`] for a websocket connection.
+#[derive(Default)]
+pub struct ClientBuilder {
+    payload: Option<Value>,
+    protocol: Protocol,
+}
+
+impl ClientBuilder {
+    /// Creates a new `ClientBuilder`
+    pub fn new() -> Self {
+        ClientBuilder::default()
+    }
+
+    /// Sets the payload sent in the `connection_init` message.
+    ///
+    /// Servers will often use this for authentication.
+    pub fn payload(self, payload: Value) -> Self {
+        ClientBuilder {
+            payload: Some(payload),
+            ..self
+        }
+    }
+
+    /// Sets the protocol to use on the connection.
+    ///
+    /// This defaults to [`Protocol::GraphqlTransportWs`].  The protocol
+    /// should match the subprotocol that was negotiated when the websocket
+    /// was opened - [`Protocol::from_subprotocol`] can be used to find it.
+    pub fn protocol(self, protocol: Protocol) -> Self {
+        ClientBuilder { protocol, ..self }
+    }
+
+    /// Initialises a connection and returns a [`Client`] for it.
+    ///
+    /// `sender` & `receiver` should be the outgoing & incoming halves of a
+    /// websocket connection, mapped to send & receive text frames.
+    ///
+    /// The returned [`ConnectionActor`] must be spawned onto an executor for
+    /// the client to make any progress.
+    pub async fn build<Tx, Rx>(
+        self,
+        mut sender: Tx,
+        mut receiver: Rx,
+    ) -> Result<(Client, ConnectionActor), WsError>
+    where
+        Tx: Sink<String> + Unpin + Send + 'static,
+        Tx::Error: Display,
+        Rx: Stream<Item = String> + Unpin + Send + 'static,
+    {
+        let protocol = self.protocol;
+
+        actor::send(
+            protocol,
+            &mut sender,
+            &ClientMessage::Init {
+                payload: self.payload.as_ref(),
+            },
+        )
+        .await?;
+
+        loop {
+            let Some(text) = receiver.next().await else {
+                return Err(WsError::ConnectionClosed);
+            };
+
+            match protocol.decode(&text)? {
+                ServerMessage::ConnectionAck => break,
+                ServerMessage::Ping => {
+                    actor::send(protocol, &mut sender, &ClientMessage::Pong).await?
+                }
+                ServerMessage::KeepAlive => {}
+                ServerMessage::ConnectionError { payload } => {
+                    return Err(WsError::ConnectionError(payload))
+                }
+                other => {
+                    return Err(WsError::Protocol(format!(
+                        "expected connection_ack but received {other:?}"
+                    )))
+                }
+            }
+        }
+
+        let (commands_tx, commands_rx) = mpsc::unbounded();
+
+        Ok((
+            Client {
+                commands: commands_tx,
+                next_id: Arc::new(AtomicU64::new(1)),
+            },
+            ConnectionActor::new(protocol, sender, receiver, commands_rx),
+        ))
+    }
+}
+
+/// A client for running subscriptions over a single websocket connection.
+///
+/// Any number of subscriptions can be run at once, and they will all be
+/// multiplexed over the same connection.
+///
+/// This can be cloned cheaply, and all clones share the same connection.
+#[derive(Clone)]
+pub struct Client {
+    commands: mpsc::UnboundedSender<Command>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Client {
+    /// Returns a [`ClientBuilder`] for configuring a `Client`
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Starts a subscription, returning a stream of its responses.
+    ///
+    /// The subscription will be stopped when the returned `Subscription`
+    /// is dropped.
+    pub fn subscribe<ResponseData, Vars>(
+        &self,
+        operation: StreamingOperation<ResponseData, Vars>,
+    ) -> Result<Subscription<ResponseData>, WsError>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+        let payload = serde_json::to_value(&operation)?;
+        let (sender, receiver) = mpsc::unbounded();
+
+        self.commands
+            .unbounded_send(Command::Subscribe {
+                id: id.clone(),
+                payload,
+                sender,
+            })
+            .map_err(|_| WsError::ConnectionClosed)?;
+
+        Ok(Subscription {
+            id,
+            receiver,
+            commands: self.commands.clone(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// A stream of responses to a subscription.
+///
+/// Dropping this will stop the subscription on the server.
+pub struct Subscription<ResponseData> {
+    id: String,
+    receiver: mpsc::UnboundedReceiver<SubscriptionEvent>,
+    commands: mpsc::UnboundedSender<Command>,
+    phantom: PhantomData<fn() -> ResponseData>,
+}
+
+impl<ResponseData> Stream for Subscription<ResponseData>
+where
+    ResponseData: serde::de::DeserializeOwned + crate::UsesFieldResults,
+{
+    type Item = Result<GraphQlResponse<ResponseData>, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx).map(|event| {
+            event.map(|event| {
+                Ok(serde_json::from_value::<GraphQlResponse<ResponseData>>(
+                    event?,
+                )?)
+            })
+        })
+    }
+}
+
+impl<ResponseData> Drop for Subscription<ResponseData> {
+    fn drop(&mut self) {
+        self.commands
+            .unbounded_send(Command::Stop {
+                id: std::mem::take(&mut self.id),
+            })
+            .ok();
+    }
+}
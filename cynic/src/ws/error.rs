@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::GraphQlError;
+
+/// Errors that can occur when running subscriptions over a websocket.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum WsError {
+    /// The underlying connection returned an error when sending a message.
+    #[error("Error sending message: {0}")]
+    Transport(String),
+
+    /// A message couldn't be encoded or decoded.
+    #[error("Couldn't decode message: {0}")]
+    Decode(Arc<serde_json::Error>),
+
+    /// The server sent a message that was not expected at this point in the
+    /// protocol.
+    #[error("Protocol error: {0}")]
+    Protocol(String),
+
+    /// The server rejected the connection, or reported an error with it.
+    ///
+    /// This contains the payload of the servers `connection_error` message.
+    #[error("The server reported a connection error: {0}")]
+    ConnectionError(Value),
+
+    /// The server couldn't run a subscription, usually because it failed
+    /// validation.
+    #[error("The server returned errors for the subscription: {}", display_errors(.0))]
+    OperationError(Vec<GraphQlError<Value>>),
+
+    /// The connection was closed.
+    #[error("The connection was closed")]
+    ConnectionClosed,
+}
+
+impl From<serde_json::Error> for WsError {
+    fn from(error: serde_json::Error) -> Self {
+        WsError::Decode(Arc::new(error))
+    }
+}
+
+fn display_errors(errors: &[GraphQlError<Value>]) -> String {
+    errors
+        .iter()
+        .map(|error| error.message.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
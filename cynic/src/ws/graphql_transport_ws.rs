@@ -0,0 +1,113 @@
+//! Message types for the `graphql-transport-ws` protocol.
+//!
+//! See https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md for the
+//! details of the protocol.
+
+use serde_json::Value;
+
+use super::protocol::{ClientMessage, ErrorPayload, ServerMessage};
+
+#[derive(serde::Serialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Outgoing<'a> {
+    ConnectionInit {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        payload: Option<&'a Value>,
+    },
+    Subscribe {
+        id: &'a str,
+        payload: &'a Value,
+    },
+    Complete {
+        id: &'a str,
+    },
+    Pong {},
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Incoming {
+    ConnectionAck {},
+    Next { id: String, payload: Value },
+    Error { id: String, payload: ErrorPayload },
+    Complete { id: String },
+    Ping {},
+    Pong {},
+}
+
+pub(super) fn encode(message: &ClientMessage<'_>) -> Result<Option<String>, serde_json::Error> {
+    let message = match *message {
+        ClientMessage::Init { payload } => Outgoing::ConnectionInit { payload },
+        ClientMessage::Subscribe { id, payload } => Outgoing::Subscribe { id, payload },
+        ClientMessage::Stop { id } => Outgoing::Complete { id },
+        ClientMessage::Pong => Outgoing::Pong {},
+        // This protocol terminates by closing the websocket
+        ClientMessage::Terminate => return Ok(None),
+    };
+
+    serde_json::to_string(&message).map(Some)
+}
+
+pub(super) fn decode(text: &str) -> Result<ServerMessage, serde_json::Error> {
+    Ok(match serde_json::from_str(text)? {
+        Incoming::ConnectionAck {} => ServerMessage::ConnectionAck,
+        Incoming::Next { id, payload } => ServerMessage::Next { id, payload },
+        Incoming::Error { id, payload } => ServerMessage::Error {
+            id,
+            errors: payload.into(),
+        },
+        Incoming::Complete { id } => ServerMessage::Complete { id },
+        Incoming::Ping {} => ServerMessage::Ping,
+        Incoming::Pong {} => ServerMessage::KeepAlive,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_client_message_encoding() {
+        let payload = json!({"query": "subscription { interval }"});
+
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Init { payload: None }).unwrap().unwrap(),
+            @r###"{"type":"connection_init"}"###
+        );
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Subscribe { id: "1", payload: &payload }).unwrap().unwrap(),
+            @r###"{"type":"subscribe","id":"1","payload":{"query":"subscription { interval }"}}"###
+        );
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Stop { id: "1" }).unwrap().unwrap(),
+            @r###"{"type":"complete","id":"1"}"###
+        );
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Pong).unwrap().unwrap(),
+            @r###"{"type":"pong"}"###
+        );
+        assert_eq!(encode(&ClientMessage::Terminate).unwrap(), None);
+    }
+
+    #[test]
+    fn test_server_message_decoding() {
+        assert!(matches!(
+            decode(r#"{"type": "connection_ack", "payload": {"a": 1}}"#).unwrap(),
+            ServerMessage::ConnectionAck
+        ));
+        assert!(matches!(
+            decode(r#"{"type": "next", "id": "1", "payload": {"data": null}}"#).unwrap(),
+            ServerMessage::Next { id, .. } if id == "1"
+        ));
+        assert!(matches!(
+            decode(r#"{"type": "error", "id": "1", "payload": [{"message": "Oops"}]}"#).unwrap(),
+            ServerMessage::Error { errors, .. } if errors[0].message == "Oops"
+        ));
+        assert!(matches!(
+            decode(r#"{"type": "ping"}"#).unwrap(),
+            ServerMessage::Ping
+        ));
+    }
+}
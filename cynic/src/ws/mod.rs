@@ -0,0 +1,62 @@
+//! Websocket support for running subscriptions.
+//!
+//! This implements the [`graphql-transport-ws`][protocol] protocol and the
+//! legacy [`subscriptions-transport-ws`][legacy] protocol on top of any
+//! websocket library: you provide a [`Sink`](futures_util::Sink) &
+//! [`Stream`](futures_util::Stream) of text frames, and cynic handles the
+//! rest.  See [`Protocol`] for details on selecting a protocol.
+//!
+//! Many subscriptions can be run over a single connection, each of which
+//! returns a stream of typed `GraphQlResponse`s.
+//!
+//! ```rust,no_run
+//! # mod schema {
+//! #   cynic::use_schema!("../schemas/books.graphql");
+//! # }
+//! #[derive(cynic::QueryFragment, Debug)]
+//! #[cynic(schema_path = "../schemas/books.graphql", graphql_type = "SubscriptionRoot")]
+//! struct IntervalSubscription {
+//!     interval: i32,
+//! }
+//!
+//! # async fn run(
+//! #   sender: futures::channel::mpsc::UnboundedSender<String>,
+//! #   receiver: futures::channel::mpsc::UnboundedReceiver<String>
+//! # ) -> Result<(), cynic::ws::WsError> {
+//! use cynic::SubscriptionBuilder;
+//! use futures::StreamExt;
+//!
+//! // `sender` & `receiver` should come from your websocket library of choice,
+//! // mapped to send & receive text frames.
+//! let (client, actor) = cynic::ws::Client::builder()
+//!     .build(sender, receiver)
+//!     .await?;
+//!
+//! // The actor must be spawned on your executor for the connection to run.
+//! # let spawn = |_| {};
+//! spawn(actor);
+//!
+//! let mut subscription = client.subscribe(IntervalSubscription::build(()))?;
+//! while let Some(response) = subscription.next().await {
+//!     println!("{:?}", response?.data);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [protocol]: https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md
+//! [legacy]: https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md
+
+mod actor;
+mod client;
+mod error;
+mod graphql_transport_ws;
+mod protocol;
+mod subscriptions_transport_ws;
+
+pub use self::{
+    actor::ConnectionActor,
+    client::{Client, ClientBuilder, Subscription},
+    error::WsError,
+    protocol::Protocol,
+};
@@ -0,0 +1,145 @@
+use serde_json::Value;
+
+use super::{graphql_transport_ws, subscriptions_transport_ws, WsError};
+use crate::GraphQlError;
+
+/// The websocket protocols supported by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Protocol {
+    /// The [`graphql-transport-ws`][spec] protocol, as implemented by the
+    /// `graphql-ws` library.
+    ///
+    /// [spec]: https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md
+    #[default]
+    GraphqlTransportWs,
+
+    /// The legacy protocol implemented by Apollo's `subscriptions-transport-ws`
+    /// library.  Confusingly, this uses the `graphql-ws` subprotocol name.
+    SubscriptionsTransportWs,
+}
+
+impl Protocol {
+    /// All of the supported protocols, in order of preference.
+    pub const ALL: [Protocol; 2] = [
+        Protocol::GraphqlTransportWs,
+        Protocol::SubscriptionsTransportWs,
+    ];
+
+    /// The name of the websocket subprotocol for this protocol.
+    ///
+    /// This should be sent in the `Sec-WebSocket-Protocol` header when
+    /// opening a connection.
+    pub fn subprotocol(self) -> &'static str {
+        match self {
+            Protocol::GraphqlTransportWs => "graphql-transport-ws",
+            Protocol::SubscriptionsTransportWs => "graphql-ws",
+        }
+    }
+
+    /// Finds the `Protocol` for a subprotocol name.
+    ///
+    /// This can be used with the `Sec-WebSocket-Protocol` header a server
+    /// responds with to determine which protocol it selected.
+    pub fn from_subprotocol(name: &str) -> Option<Protocol> {
+        Protocol::ALL
+            .into_iter()
+            .find(|protocol| protocol.subprotocol() == name.trim())
+    }
+
+    /// Returns a value for the `Sec-WebSocket-Protocol` header that offers
+    /// all the supported protocols to a server.
+    pub fn negotiation_header() -> String {
+        Protocol::ALL
+            .iter()
+            .map(|protocol| protocol.subprotocol())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Encodes a message, returning None if the message has no equivalent in
+    /// this protocol.
+    pub(super) fn encode(self, message: &ClientMessage<'_>) -> Result<Option<String>, WsError> {
+        Ok(match self {
+            Protocol::GraphqlTransportWs => graphql_transport_ws::encode(message)?,
+            Protocol::SubscriptionsTransportWs => subscriptions_transport_ws::encode(message)?,
+        })
+    }
+
+    pub(super) fn decode(self, text: &str) -> Result<ServerMessage, WsError> {
+        Ok(match self {
+            Protocol::GraphqlTransportWs => graphql_transport_ws::decode(text)?,
+            Protocol::SubscriptionsTransportWs => subscriptions_transport_ws::decode(text)?,
+        })
+    }
+}
+
+/// The messages a client can send, independent of protocol.
+#[derive(Debug)]
+pub(super) enum ClientMessage<'a> {
+    Init { payload: Option<&'a Value> },
+    Subscribe { id: &'a str, payload: &'a Value },
+    Stop { id: &'a str },
+    Pong,
+    Terminate,
+}
+
+/// The messages a server can send, independent of protocol.
+#[derive(Debug)]
+pub(super) enum ServerMessage {
+    ConnectionAck,
+    ConnectionError {
+        payload: Value,
+    },
+    Next {
+        id: String,
+        payload: Value,
+    },
+    Error {
+        id: String,
+        errors: Vec<GraphQlError<Value>>,
+    },
+    Complete {
+        id: String,
+    },
+    Ping,
+    KeepAlive,
+}
+
+/// Servers can send either a single error or a list of them in error messages
+#[derive(serde::Deserialize, Debug)]
+#[serde(untagged)]
+pub(super) enum ErrorPayload {
+    Many(Vec<GraphQlError<Value>>),
+    One(GraphQlError<Value>),
+}
+
+impl From<ErrorPayload> for Vec<GraphQlError<Value>> {
+    fn from(payload: ErrorPayload) -> Self {
+        match payload {
+            ErrorPayload::Many(errors) => errors,
+            ErrorPayload::One(error) => vec![error],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subprotocol_negotiation() {
+        assert_eq!(
+            Protocol::negotiation_header(),
+            "graphql-transport-ws, graphql-ws"
+        );
+        assert_eq!(
+            Protocol::from_subprotocol("graphql-ws"),
+            Some(Protocol::SubscriptionsTransportWs)
+        );
+        assert_eq!(
+            Protocol::from_subprotocol("graphql-transport-ws"),
+            Some(Protocol::GraphqlTransportWs)
+        );
+        assert_eq!(Protocol::from_subprotocol("graphql-sse"), None);
+    }
+}
@@ -0,0 +1,125 @@
+//! Message types for the legacy `subscriptions-transport-ws` protocol.
+//!
+//! See https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md
+//! for the details of the protocol.
+
+use serde_json::Value;
+
+use super::protocol::{ClientMessage, ErrorPayload, ServerMessage};
+
+#[derive(serde::Serialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Outgoing<'a> {
+    ConnectionInit {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        payload: Option<&'a Value>,
+    },
+    Start {
+        id: &'a str,
+        payload: &'a Value,
+    },
+    Stop {
+        id: &'a str,
+    },
+    ConnectionTerminate {},
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Incoming {
+    ConnectionAck {},
+    ConnectionError {
+        #[serde(default)]
+        payload: Value,
+    },
+    #[serde(rename = "ka")]
+    ConnectionKeepAlive {},
+    Data {
+        id: String,
+        payload: Value,
+    },
+    Error {
+        id: String,
+        payload: ErrorPayload,
+    },
+    Complete {
+        id: String,
+    },
+}
+
+pub(super) fn encode(message: &ClientMessage<'_>) -> Result<Option<String>, serde_json::Error> {
+    let message = match *message {
+        ClientMessage::Init { payload } => Outgoing::ConnectionInit { payload },
+        ClientMessage::Subscribe { id, payload } => Outgoing::Start { id, payload },
+        ClientMessage::Stop { id } => Outgoing::Stop { id },
+        // Servers using this protocol never ping, so we should never need to pong
+        ClientMessage::Pong => return Ok(None),
+        ClientMessage::Terminate => Outgoing::ConnectionTerminate {},
+    };
+
+    serde_json::to_string(&message).map(Some)
+}
+
+pub(super) fn decode(text: &str) -> Result<ServerMessage, serde_json::Error> {
+    Ok(match serde_json::from_str(text)? {
+        Incoming::ConnectionAck {} => ServerMessage::ConnectionAck,
+        Incoming::ConnectionError { payload } => ServerMessage::ConnectionError { payload },
+        Incoming::ConnectionKeepAlive {} => ServerMessage::KeepAlive,
+        Incoming::Data { id, payload } => ServerMessage::Next { id, payload },
+        Incoming::Error { id, payload } => ServerMessage::Error {
+            id,
+            errors: payload.into(),
+        },
+        Incoming::Complete { id } => ServerMessage::Complete { id },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_client_message_encoding() {
+        let payload = json!({"query": "subscription { interval }"});
+
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Init { payload: Some(&json!({"token": "a"})) }).unwrap().unwrap(),
+            @r###"{"type":"connection_init","payload":{"token":"a"}}"###
+        );
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Subscribe { id: "1", payload: &payload }).unwrap().unwrap(),
+            @r###"{"type":"start","id":"1","payload":{"query":"subscription { interval }"}}"###
+        );
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Stop { id: "1" }).unwrap().unwrap(),
+            @r###"{"type":"stop","id":"1"}"###
+        );
+        insta::assert_snapshot!(
+            encode(&ClientMessage::Terminate).unwrap().unwrap(),
+            @r###"{"type":"connection_terminate"}"###
+        );
+        assert_eq!(encode(&ClientMessage::Pong).unwrap(), None);
+    }
+
+    #[test]
+    fn test_server_message_decoding() {
+        assert!(matches!(
+            decode(r#"{"type": "ka"}"#).unwrap(),
+            ServerMessage::KeepAlive
+        ));
+        assert!(matches!(
+            decode(r#"{"type": "data", "id": "1", "payload": {"data": null}}"#).unwrap(),
+            ServerMessage::Next { id, .. } if id == "1"
+        ));
+        assert!(matches!(
+            decode(r#"{"type": "error", "id": "1", "payload": {"message": "Oops"}}"#).unwrap(),
+            ServerMessage::Error { errors, .. } if errors[0].message == "Oops"
+        ));
+        assert!(matches!(
+            decode(r#"{"type": "connection_error", "payload": {"message": "Unauthorized"}}"#).unwrap(),
+            ServerMessage::ConnectionError { payload } if payload["message"] == "Unauthorized"
+        ));
+    }
+}
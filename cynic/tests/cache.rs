@@ -0,0 +1,295 @@
+#![cfg(feature = "cache")]
+
+use futures::{executor::block_on, StreamExt};
+use serde_json::json;
+
+use cynic::{
+    cache::{Cache, FetchPolicy},
+    client::{Client, MockTransport},
+    MutationBuilder, QueryBuilder,
+};
+
+mod schema {
+    cynic::use_schema!("../schemas/graphql.jobs.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/graphql.jobs.graphql")]
+struct Job {
+    title: String,
+    description: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/graphql.jobs.graphql")]
+struct Company {
+    name: String,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(graphql_type = "Job", schema_path = "../schemas/graphql.jobs.graphql")]
+struct JobWithCompany {
+    title: String,
+    company: Option<Company>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Query",
+    schema_path = "../schemas/graphql.jobs.graphql"
+)]
+struct JobsQuery {
+    jobs: Vec<Job>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Query",
+    schema_path = "../schemas/graphql.jobs.graphql"
+)]
+struct JobsWithCompaniesQuery {
+    #[cynic(rename = "jobs")]
+    jobs_with_companies: Vec<JobWithCompany>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(graphql_type = "Job", schema_path = "../schemas/graphql.jobs.graphql")]
+struct JobWithFeatures {
+    title: String,
+    #[cynic(feature = "descriptions")]
+    description: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Query",
+    schema_path = "../schemas/graphql.jobs.graphql"
+)]
+struct JobsWithFeaturesQuery {
+    jobs: Vec<JobWithFeatures>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(graphql_type = "Job", schema_path = "../schemas/graphql.jobs.graphql")]
+struct JobDescription {
+    description: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema_path = "../schemas/graphql.jobs.graphql"
+)]
+struct UpdateJobMutation {
+    #[arguments(input: { id: "1", description: "Writing GraphQL clients" }, adminSecret: "hunter2")]
+    update_job: JobDescription,
+}
+
+fn jobs_response() -> serde_json::Value {
+    json!({"data": {"jobs": [
+        {"__typename": "Job", "id": "1", "title": "Rust Developer", "description": "Writing Rust"},
+        {"__typename": "Job", "id": "2", "title": "Tester", "description": null}
+    ]}})
+}
+
+fn sent_query(transport: &MockTransport, index: usize) -> String {
+    let body: serde_json::Value =
+        serde_json::from_slice(&transport.requests()[index].body).unwrap();
+
+    body["query"].as_str().unwrap().to_string()
+}
+
+fn first<T>(stream: futures::stream::BoxStream<'_, T>) -> T {
+    block_on(stream.into_future()).0.unwrap()
+}
+
+#[test]
+fn test_cache_keys_are_added_to_query() {
+    let transport = MockTransport::new();
+    transport.push_json(json!({"data": {"jobs": []}}));
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    first(client.run_cached(JobsWithCompaniesQuery::build(()), FetchPolicy::NetworkOnly)).unwrap();
+
+    insta::assert_display_snapshot!(sent_query(&transport, 0), @r###"
+    query JobsWithCompaniesQuery {
+      jobs {
+        __typename
+        title
+        company {
+          __typename
+          name
+          id
+        }
+        id
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_cache_keys_keep_enabled_features() {
+    let transport = MockTransport::new();
+    transport.push_json(jobs_response());
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    let operation = || {
+        cynic::OperationBuilder::<JobsWithFeaturesQuery>::query()
+            .with_variables(())
+            .with_feature_enabled("descriptions")
+            .build()
+            .unwrap()
+    };
+
+    first(client.run_cached(operation(), FetchPolicy::CacheFirst)).unwrap();
+    let cached = first(client.run_cached(operation(), FetchPolicy::CacheFirst)).unwrap();
+
+    insta::assert_display_snapshot!(sent_query(&transport, 0), @r###"
+    query JobsWithFeaturesQuery {
+      jobs {
+        __typename
+        title
+        description
+        id
+      }
+    }
+
+    "###);
+    assert_eq!(transport.requests().len(), 1);
+    assert_eq!(
+        cached.data.unwrap().jobs[0].description.as_deref(),
+        Some("Writing Rust")
+    );
+}
+
+#[test]
+fn test_cache_first() {
+    let transport = MockTransport::new();
+    transport.push_json(jobs_response());
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    let network = first(client.run_cached(JobsQuery::build(()), FetchPolicy::CacheFirst)).unwrap();
+    let cached = first(client.run_cached(JobsQuery::build(()), FetchPolicy::CacheFirst)).unwrap();
+
+    assert_eq!(transport.requests().len(), 1);
+    assert_eq!(network.data, cached.data);
+    assert_eq!(
+        cached.data.unwrap().jobs[0],
+        Job {
+            title: "Rust Developer".into(),
+            description: Some("Writing Rust".into())
+        }
+    );
+}
+
+#[test]
+fn test_cache_miss_when_fields_are_missing() {
+    let transport = MockTransport::new();
+    transport.push_json(jobs_response());
+    transport.push_json(json!({"data": {"jobs": [
+        {"__typename": "Job", "id": "1", "title": "Rust Developer", "company": null}
+    ]}}));
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    first(client.run_cached(JobsQuery::build(()), FetchPolicy::CacheFirst)).unwrap();
+    let response =
+        first(client.run_cached(JobsWithCompaniesQuery::build(()), FetchPolicy::CacheFirst))
+            .unwrap();
+
+    assert_eq!(transport.requests().len(), 2);
+    assert_eq!(response.data.unwrap().jobs_with_companies.len(), 1);
+}
+
+#[test]
+fn test_network_only() {
+    let transport = MockTransport::new();
+    transport.push_json(jobs_response());
+    transport.push_json(jobs_response());
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    first(client.run_cached(JobsQuery::build(()), FetchPolicy::NetworkOnly)).unwrap();
+    first(client.run_cached(JobsQuery::build(()), FetchPolicy::NetworkOnly)).unwrap();
+
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[test]
+fn test_cache_and_network() {
+    let transport = MockTransport::new();
+    transport.push_json(jobs_response());
+    transport.push_json(json!({"data": {"jobs": [
+        {"__typename": "Job", "id": "1", "title": "Senior Rust Developer", "description": null}
+    ]}}));
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    first(client.run_cached(JobsQuery::build(()), FetchPolicy::CacheFirst)).unwrap();
+    let responses = block_on(
+        client
+            .run_cached(JobsQuery::build(()), FetchPolicy::CacheAndNetwork)
+            .collect::<Vec<_>>(),
+    );
+
+    let titles = responses
+        .into_iter()
+        .map(|response| response.unwrap().data.unwrap().jobs[0].title.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Rust Developer", "Senior Rust Developer"]);
+}
+
+#[test]
+fn test_mutations_update_cached_entities() {
+    let transport = MockTransport::new();
+    transport.push_json(jobs_response());
+    transport.push_json(json!({"data": {"updateJob": {
+        "__typename": "Job", "id": "1", "description": "Writing GraphQL clients"
+    }}}));
+    let client = Client::new(transport.clone()).with_cache(Cache::new());
+
+    first(client.run_cached(JobsQuery::build(()), FetchPolicy::CacheFirst)).unwrap();
+    block_on(client.run_mutation_cached(UpdateJobMutation::build(()))).unwrap();
+    let response = first(client.run_cached(JobsQuery::build(()), FetchPolicy::CacheFirst)).unwrap();
+
+    assert_eq!(transport.requests().len(), 2);
+    assert_eq!(
+        response.data.unwrap().jobs[0].description.as_deref(),
+        Some("Writing GraphQL clients")
+    );
+    assert_eq!(
+        client.cache().unwrap().entity("Job:1").unwrap()["description"],
+        json!("Writing GraphQL clients")
+    );
+}
+
+#[test]
+fn test_custom_key_fields() {
+    let cache = Cache::new().with_key_fields("Job", ["title", "description"]);
+
+    let operation = cynic::OperationBuilder::<JobsQuery>::query()
+        .with_variables(())
+        .with_cache_keys()
+        .build()
+        .unwrap();
+    cache.write(&operation, &jobs_response()["data"]).unwrap();
+
+    insta::assert_json_snapshot!(cache.entity("Query"), @r###"
+    {
+      "jobs": [
+        {
+          "__ref": "Job:[\"Rust Developer\",\"Writing Rust\"]"
+        },
+        {
+          "__typename": "Job",
+          "description": null,
+          "id": "2",
+          "title": "Tester"
+        }
+      ]
+    }
+    "###);
+    assert!(cache.entity(r#"Job:["Tester",null]"#).is_none());
+    assert_eq!(
+        cache.read(&operation).unwrap().jobs[0].title,
+        "Rust Developer"
+    );
+}
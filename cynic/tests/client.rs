@@ -0,0 +1,367 @@
+#![cfg(feature = "client")]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use assert_matches::assert_matches;
+use futures::executor::block_on;
+use serde_json::json;
+
+use cynic::{
+    client::{
+        middleware::{self, LogEvent},
+        BoxFuture, Client, ClientError, MockTransport, Request, Response,
+    },
+    MutationBuilder, QueryBuilder,
+};
+
+mod schema {
+    cynic::use_schema!("../schemas/books.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/books.graphql")]
+struct Book {
+    name: String,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/books.graphql", graphql_type = "QueryRoot")]
+struct BooksQuery {
+    books: Vec<Book>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/books.graphql",
+    graphql_type = "MutationRoot"
+)]
+struct DeleteBookMutation {
+    #[arguments(id: "1")]
+    delete_book: bool,
+}
+
+fn books_response() -> serde_json::Value {
+    json!({"data": {"books": [{"name": "Dune"}]}})
+}
+
+#[test]
+fn test_run_decodes_response() {
+    let transport = MockTransport::new();
+    transport.push_json(books_response());
+    let client = Client::new(transport.clone());
+
+    let response = block_on(client.run(BooksQuery::build(()))).unwrap();
+
+    assert_eq!(
+        response.data,
+        Some(BooksQuery {
+            books: vec![Book {
+                name: "Dune".into()
+            }]
+        })
+    );
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].operation_name.as_deref(), Some("BooksQuery"));
+    assert_eq!(requests[0].header("content-type"), Some("application/json"));
+    let body = serde_json::from_slice::<serde_json::Value>(&requests[0].body).unwrap();
+    insta::assert_snapshot!(body["query"].as_str().unwrap(), @r###"
+    query BooksQuery {
+      books {
+        name
+      }
+    }
+    "###);
+}
+
+#[test]
+fn test_error_responses() {
+    let transport = MockTransport::new();
+    transport.push_response(Response::new(
+        500,
+        br#"{"data": null, "errors": [{"message": "oops"}]}"#.to_vec(),
+    ));
+    transport.push_response(Response::new(502, b"Bad Gateway".to_vec()));
+    transport.push_error("connection refused");
+    let client = Client::new(transport);
+
+    let response = block_on(client.run(BooksQuery::build(()))).unwrap();
+    assert_eq!(response.errors.unwrap()[0].message, "oops");
+
+    assert_matches!(
+        block_on(client.run(BooksQuery::build(()))),
+        Err(ClientError::ErrorResponse(502, body)) if body == "Bad Gateway"
+    );
+    assert_matches!(
+        block_on(client.run(BooksQuery::build(()))),
+        Err(ClientError::Transport(_))
+    );
+}
+
+#[test]
+fn test_headers_middleware() {
+    let transport = MockTransport::new();
+    transport.push_json(books_response());
+    let client = Client::new(transport.clone()).with(
+        middleware::Headers::new()
+            .header("X-Api-Key", "abcd")
+            .header("Accept", "application/graphql-response+json"),
+    );
+
+    block_on(client.run(BooksQuery::build(()))).unwrap();
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.header("x-api-key"), Some("abcd"));
+    assert_eq!(
+        request.header("accept"),
+        Some("application/graphql-response+json")
+    );
+}
+
+#[test]
+fn test_retry_middleware() {
+    let transport = MockTransport::new();
+    transport.push_error("connection reset");
+    transport.push_response(Response::new(503, b"Unavailable".to_vec()));
+    transport.push_json(books_response());
+
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::new(transport.clone()).with(middleware::Retry::new(2).with_delay({
+        let delays = Arc::clone(&delays);
+        move |attempt| -> BoxFuture<'static, ()> {
+            delays.lock().unwrap().push(attempt);
+            Box::pin(async {})
+        }
+    }));
+
+    let response = block_on(client.run(BooksQuery::build(()))).unwrap();
+
+    assert!(response.data.is_some());
+    assert_eq!(transport.requests().len(), 3);
+    assert_eq!(*delays.lock().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_retry_middleware_gives_up() {
+    let transport = MockTransport::new();
+    transport.push_error("connection reset");
+    transport.push_error("connection reset");
+    transport.push_json(books_response());
+    let client = Client::new(transport.clone()).with(middleware::Retry::new(1));
+
+    assert_matches!(
+        block_on(client.run(BooksQuery::build(()))),
+        Err(ClientError::Transport(_))
+    );
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[test]
+fn test_retry_middleware_skips_mutations() {
+    let transport = MockTransport::new();
+    transport.push_response(Response::new(503, b"Unavailable".to_vec()));
+    transport.push_json(json!({"data": {"deleteBook": true}}));
+    let client = Client::new(transport.clone()).with(middleware::Retry::new(2));
+
+    assert_matches!(
+        block_on(client.run(DeleteBookMutation::build(()))),
+        Err(ClientError::ErrorResponse(503, _))
+    );
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn test_retry_middleware_can_retry_mutations() {
+    let transport = MockTransport::new();
+    transport.push_response(Response::new(503, b"Unavailable".to_vec()));
+    transport.push_json(json!({"data": {"deleteBook": true}}));
+    let client = Client::new(transport.clone()).with(middleware::Retry::new(2).retry_mutations());
+
+    let response = block_on(client.run(DeleteBookMutation::build(()))).unwrap();
+
+    assert_eq!(
+        response.data,
+        Some(DeleteBookMutation { delete_book: true })
+    );
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[test]
+fn test_auth_refresh_middleware() {
+    let transport = MockTransport::new();
+    transport.push_json(books_response());
+    transport.push_response(Response::new(401, b"Unauthorized".to_vec()));
+    transport.push_json(books_response());
+
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let client = Client::new(transport.clone()).with(middleware::AuthRefresh::new({
+        let fetches = Arc::clone(&fetches);
+        move || -> BoxFuture<'static, Result<String, ClientError>> {
+            let count = fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            Box::pin(async move { Ok(format!("token-{count}")) })
+        }
+    }));
+
+    block_on(client.run(BooksQuery::build(()))).unwrap();
+    block_on(client.run(BooksQuery::build(()))).unwrap();
+
+    let tokens = transport
+        .requests()
+        .iter()
+        .map(|request| request.header("Authorization").unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        vec!["Bearer token-1", "Bearer token-1", "Bearer token-2"]
+    );
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_logging_and_timing_middleware() {
+    let transport = MockTransport::new();
+    transport.push_json(books_response());
+    transport.push_error("connection refused");
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let timings = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::new(transport)
+        .with(middleware::Timing::new({
+            let timings = Arc::clone(&timings);
+            move |operation_name: Option<&str>, _: Duration| {
+                timings
+                    .lock()
+                    .unwrap()
+                    .push(operation_name.map(ToString::to_string));
+            }
+        }))
+        .with(middleware::Logging::new({
+            let log = Arc::clone(&log);
+            move |event: LogEvent<'_>| {
+                let line = match event {
+                    LogEvent::Request(request) => {
+                        format!("request {}", request.operation_name.as_deref().unwrap())
+                    }
+                    LogEvent::Response(_, response) => format!("response {}", response.status),
+                    LogEvent::Error(_, error) => format!("error {error}"),
+                };
+                log.lock().unwrap().push(line);
+            }
+        }));
+
+    block_on(client.run(BooksQuery::build(()))).unwrap();
+    block_on(client.run(BooksQuery::build(()))).unwrap_err();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "request BooksQuery",
+            "response 200",
+            "request BooksQuery",
+            "error Transport error: connection refused",
+        ]
+    );
+    assert_eq!(
+        *timings.lock().unwrap(),
+        vec![
+            Some("BooksQuery".to_string()),
+            Some("BooksQuery".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_middleware_order() {
+    struct Tag(&'static str);
+
+    impl middleware::Middleware for Tag {
+        fn handle<'a>(
+            &'a self,
+            mut request: Request,
+            next: middleware::Next<'a>,
+        ) -> BoxFuture<'a, Result<Response, ClientError>> {
+            let existing = request.header("X-Tags").unwrap_or_default().to_string();
+            request.set_header("X-Tags", format!("{existing}{}", self.0));
+            next.run(request)
+        }
+    }
+
+    let transport = MockTransport::new();
+    transport.push_json(books_response());
+    let client = Client::new(transport.clone())
+        .with(Tag("a"))
+        .with(Tag("b"))
+        .with(Tag("c"));
+
+    block_on(client.run(BooksQuery::build(()))).unwrap();
+
+    assert_eq!(transport.requests()[0].header("X-Tags"), Some("abc"));
+}
+
+#[test]
+fn test_run_persisted() {
+    let transport = MockTransport::new();
+    transport.push_json(json!({
+        "errors": [{
+            "message": "PersistedQueryNotFound",
+            "extensions": {"code": "PERSISTED_QUERY_NOT_FOUND"}
+        }]
+    }));
+    transport.push_json(books_response());
+    transport.push_json(books_response());
+    let client = Client::new(transport.clone());
+
+    let operation = BooksQuery::build(());
+    let hash = operation.query_hash().to_string();
+    let response = block_on(client.run_persisted(operation)).unwrap();
+    assert!(response.data.is_some());
+
+    block_on(client.run_persisted(BooksQuery::build(()))).unwrap();
+
+    let bodies = transport
+        .requests()
+        .iter()
+        .map(|request| serde_json::from_slice::<serde_json::Value>(&request.body).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(bodies.len(), 3);
+    for body in &bodies {
+        assert_eq!(
+            body["extensions"],
+            json!({"persistedQuery": {"version": 1, "sha256Hash": hash}})
+        );
+        assert_eq!(body["operationName"], "BooksQuery");
+    }
+    assert!(bodies[0].get("query").is_none());
+    assert!(bodies[1]["query"].is_string());
+    assert!(bodies[2].get("query").is_none());
+}
+
+#[test]
+fn test_run_persisted_detects_error_code() {
+    let transport = MockTransport::new();
+    transport.push_json(json!({
+        "errors": [{
+            "message": "Persisted query not found",
+            "extensions": {"code": "PERSISTED_QUERY_NOT_FOUND"}
+        }]
+    }));
+    transport.push_json(books_response());
+    let client = Client::new(transport.clone());
+
+    let response = block_on(client.run_persisted(BooksQuery::build(()))).unwrap();
+    assert!(response.data.is_some());
+    assert!(response.errors.is_none());
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    let retry = serde_json::from_slice::<serde_json::Value>(&requests[1].body).unwrap();
+    assert!(retry["query"].is_string());
+}
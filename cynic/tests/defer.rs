@@ -0,0 +1,181 @@
+use serde_json::json;
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Film",
+    schema_path = "../schemas/starwars.schema.graphql"
+)]
+struct FilmDetails {
+    director: Option<String>,
+    release_date: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Film {
+    title: Option<String>,
+    #[cynic(defer)]
+    opening_crawl: Option<String>,
+    #[cynic(spread, defer)]
+    details: Option<FilmDetails>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct FilmsConnection {
+    #[cynic(stream)]
+    films: Option<Vec<Option<Film>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Root",
+    schema_path = "../schemas/starwars.schema.graphql"
+)]
+struct AllFilmsQuery {
+    all_films: Option<FilmsConnection>,
+}
+
+#[test]
+fn test_defer_and_stream_query_output() {
+    use cynic::QueryBuilder;
+
+    let operation = AllFilmsQuery::build(());
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query AllFilmsQuery {
+      allFilms {
+        films @stream {
+          title
+          ... @defer {
+            openingCrawl
+          }
+          ... @defer {
+            director
+            releaseDate
+          }
+        }
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_decoding_before_deferred_fields_arrive() {
+    let data = json!({"allFilms": {"films": [{"title": "A New Hope"}]}});
+
+    assert_eq!(
+        serde_json::from_value::<AllFilmsQuery>(data).unwrap(),
+        AllFilmsQuery {
+            all_films: Some(FilmsConnection {
+                films: Some(vec![Some(Film {
+                    title: Some("A New Hope".into()),
+                    opening_crawl: None,
+                    details: None
+                })])
+            })
+        }
+    );
+}
+
+#[test]
+fn test_decoding_after_deferred_fields_arrive() {
+    let data = json!({"allFilms": {"films": [{
+        "title": "A New Hope",
+        "openingCrawl": "It is a period of civil war",
+        "director": "George Lucas",
+        "releaseDate": "1977-05-25"
+    }]}});
+
+    assert_eq!(
+        serde_json::from_value::<AllFilmsQuery>(data).unwrap(),
+        AllFilmsQuery {
+            all_films: Some(FilmsConnection {
+                films: Some(vec![Some(Film {
+                    title: Some("A New Hope".into()),
+                    opening_crawl: Some("It is a period of civil war".into()),
+                    details: Some(FilmDetails {
+                        director: Some("George Lucas".into()),
+                        release_date: Some("1977-05-25".into())
+                    })
+                })])
+            })
+        }
+    );
+}
+
+#[cfg(feature = "http-streaming")]
+#[test]
+fn test_assembling_incremental_response() {
+    use cynic::http::incremental::IncrementalResponse;
+
+    let mut response = IncrementalResponse::<AllFilmsQuery>::new();
+
+    response
+        .apply(json!({
+            "data": {"allFilms": {"films": [{"title": "A New Hope"}]}},
+            "hasNext": true
+        }))
+        .unwrap();
+    response
+        .apply(json!({
+            "incremental": [
+                {"items": [{"title": "The Empire Strikes Back"}], "path": ["allFilms", "films", 1]},
+                {
+                    "data": {"director": "George Lucas", "releaseDate": "1977-05-25"},
+                    "path": ["allFilms", "films", 0]
+                }
+            ],
+            "hasNext": true
+        }))
+        .unwrap();
+
+    let films = response
+        .current()
+        .unwrap()
+        .data
+        .unwrap()
+        .all_films
+        .unwrap()
+        .films
+        .unwrap();
+    assert_eq!(films.len(), 2);
+    assert_eq!(
+        films[0].as_ref().unwrap().details,
+        Some(FilmDetails {
+            director: Some("George Lucas".into()),
+            release_date: Some("1977-05-25".into())
+        })
+    );
+    assert_eq!(films[0].as_ref().unwrap().opening_crawl, None);
+    assert_eq!(films[1].as_ref().unwrap().details, None);
+
+    response
+        .apply(json!({
+            "incremental": [
+                {"data": {"openingCrawl": "It is a period of civil war"}, "path": ["allFilms", "films", 0]}
+            ],
+            "hasNext": false
+        }))
+        .unwrap();
+
+    assert!(!response.has_next());
+    let films = response
+        .into_response()
+        .unwrap()
+        .data
+        .unwrap()
+        .all_films
+        .unwrap()
+        .films
+        .unwrap();
+    assert_eq!(
+        films[0].as_ref().unwrap().opening_crawl.as_deref(),
+        Some("It is a period of civil war")
+    );
+}
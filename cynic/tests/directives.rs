@@ -0,0 +1,172 @@
+use serde_json::json;
+
+mod schema {
+    cynic::use_schema!("../schemas/books.graphql");
+}
+
+#[derive(cynic::QueryVariables)]
+struct BookVariables {
+    flag: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/books.graphql",
+    graphql_type = "QueryRoot",
+    variables = "BookVariables"
+)]
+struct BooksQuery {
+    books: Vec<Book>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/books.graphql", variables = "BookVariables")]
+struct Book {
+    id: String,
+    #[directives(skip(if: $flag))]
+    name: Option<String>,
+    #[directives(include(if: $flag))]
+    author: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/books.graphql", graphql_type = "QueryRoot")]
+struct LiteralDirectivesQuery {
+    books: Vec<LiteralBook>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/books.graphql", graphql_type = "Book")]
+struct LiteralBook {
+    #[directives(include(if: false), skip(if: true))]
+    name: Option<String>,
+}
+
+#[test]
+fn test_directives_query_output() {
+    use cynic::QueryBuilder;
+
+    let operation = BooksQuery::build(BookVariables { flag: true });
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query BooksQuery($flag: Boolean!) {
+      books {
+        id
+        name @skip(if: $flag)
+        author @include(if: $flag)
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_literal_directives_query_output() {
+    use cynic::QueryBuilder;
+
+    let operation = LiteralDirectivesQuery::build(());
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query LiteralDirectivesQuery {
+      books {
+        name @include(if: false) @skip(if: true)
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_decoding_with_skipped_fields() {
+    let data = serde_json::from_value::<BooksQuery>(json!({
+        "books": [{"id": "1", "author": "Iain M. Banks"}]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        data,
+        BooksQuery {
+            books: vec![Book {
+                id: "1".into(),
+                name: None,
+                author: Some("Iain M. Banks".into())
+            }]
+        }
+    );
+}
+
+mod schema_directives {
+    mod schema {
+        cynic::use_schema!("tests/test-schema.graphql");
+    }
+
+    #[derive(cynic::QueryVariables)]
+    struct Variables {
+        ttl: i32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "tests/test-schema.graphql",
+        graphql_type = "Query",
+        variables = "Variables"
+    )]
+    #[directives(cached(ttl: $ttl, scope: PUBLIC), tracked(label: "all-posts"))]
+    #[allow(dead_code)]
+    struct AllPostsQuery {
+        #[directives(cached(ttl: 60))]
+        all_posts: Vec<Post>,
+        all_data: Vec<PostOrAuthor>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "tests/test-schema.graphql", graphql_type = "BlogPost")]
+    #[allow(dead_code)]
+    struct Post {
+        id: Option<cynic::Id>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "tests/test-schema.graphql")]
+    #[allow(dead_code)]
+    struct Author {
+        #[directives(lowercase)]
+        name: Option<String>,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    #[cynic(schema_path = "tests/test-schema.graphql")]
+    #[allow(dead_code)]
+    enum PostOrAuthor {
+        #[directives(tracked(label: "post"))]
+        BlogPost(Post),
+        Author(Author),
+        #[cynic(fallback)]
+        Other,
+    }
+
+    #[test]
+    fn test_schema_directives_query_output() {
+        use cynic::QueryBuilder;
+
+        let operation = AllPostsQuery::build(Variables { ttl: 10 });
+
+        insta::assert_display_snapshot!(operation.query, @r###"
+        query AllPostsQuery($ttl: Int!) @cached(ttl: $ttl, scope: PUBLIC) @tracked(label: "all-posts") {
+          allPosts @cached(ttl: 60) {
+            id
+          }
+          allData {
+            __typename
+            ... on BlogPost @tracked(label: "post") {
+              id
+            }
+            ... on Author {
+              name @lowercase
+            }
+          }
+        }
+
+        "###);
+    }
+}
@@ -0,0 +1,284 @@
+use cynic::{FieldResult, GraphQlResponse};
+use serde_json::json;
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Film {
+    title: FieldResult<Option<String>>,
+    director: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "Root"
+)]
+struct FilmsQuery {
+    #[arguments(id: "ZmlsbXM6MQ==")]
+    #[cynic(rename = "film", alias)]
+    a_new_hope: FieldResult<Option<Film>>,
+
+    #[arguments(id: "ZmlsbXM6Mg==")]
+    #[cynic(rename = "film", alias)]
+    empire_strikes_back: FieldResult<Option<Film>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "Root"
+)]
+struct AllFilmsQuery {
+    all_films: Option<FilmsConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct FilmsConnection {
+    films: Option<Vec<FieldResult<Option<Film>>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "Root"
+)]
+struct SpreadQuery {
+    #[cynic(spread)]
+    films: FilmsQuery,
+}
+
+#[test]
+fn test_uses_field_results_is_derived() {
+    use cynic::QueryFragment;
+
+    assert_eq!(
+        [
+            <Film as QueryFragment>::USES_FIELD_RESULTS,
+            <FilmsQuery as QueryFragment>::USES_FIELD_RESULTS,
+            <FilmsConnection as QueryFragment>::USES_FIELD_RESULTS,
+            <AllFilmsQuery as QueryFragment>::USES_FIELD_RESULTS,
+            <SpreadQuery as QueryFragment>::USES_FIELD_RESULTS,
+        ],
+        [true; 5]
+    );
+}
+
+#[test]
+fn test_errors_at_aliased_fields() {
+    let response = serde_json::from_value::<GraphQlResponse<FilmsQuery>>(json!({
+        "data": {
+            "a_new_hope": {"title": "A New Hope", "director": "George Lucas"},
+            "empire_strikes_back": null
+        },
+        "errors": [{"message": "Film not found", "path": ["empire_strikes_back"]}]
+    }))
+    .unwrap();
+
+    let data = response.data.unwrap();
+    assert_eq!(
+        data.a_new_hope.into_result().unwrap().unwrap().title,
+        Ok(Some("A New Hope".to_string())).into()
+    );
+    assert_eq!(
+        data.empire_strikes_back.into_result().unwrap_err().message,
+        "Film not found"
+    );
+    assert_eq!(response.errors.unwrap().len(), 1);
+}
+
+#[test]
+fn test_errors_in_nested_fields() {
+    let response = serde_json::from_value::<GraphQlResponse<FilmsQuery>>(json!({
+        "errors": [{"message": "Title unavailable", "path": ["a_new_hope", "title"]}],
+        "data": {
+            "a_new_hope": {"title": null, "director": "George Lucas"},
+            "empire_strikes_back": {"title": null, "director": "Irvin Kershner"}
+        }
+    }))
+    .unwrap();
+
+    let data = response.data.unwrap();
+    let a_new_hope = data.a_new_hope.into_result().unwrap().unwrap();
+    assert_eq!(
+        a_new_hope.title.into_result().unwrap_err().message,
+        "Title unavailable"
+    );
+    assert_eq!(a_new_hope.director.as_deref(), Some("George Lucas"));
+
+    let empire_strikes_back = data.empire_strikes_back.into_result().unwrap().unwrap();
+    assert_eq!(empire_strikes_back.title.into_result(), Ok(None));
+}
+
+#[test]
+fn test_errors_at_list_indices() {
+    let response = serde_json::from_value::<GraphQlResponse<AllFilmsQuery>>(json!({
+        "data": {
+            "allFilms": {
+                "films": [
+                    {"title": "A New Hope", "director": "George Lucas"},
+                    null,
+                    {"title": null, "director": "Richard Marquand"}
+                ]
+            }
+        },
+        "errors": [
+            {"message": "Film 1 is missing", "path": ["allFilms", "films", 1]},
+            {"message": "Title 2 is missing", "path": ["allFilms", "films", 2, "title"]}
+        ]
+    }))
+    .unwrap();
+
+    let films = response.data.unwrap().all_films.unwrap().films.unwrap();
+    let results = films
+        .into_iter()
+        .map(|film| match film.into_result() {
+            Ok(Some(film)) => film
+                .title
+                .into_result()
+                .map_err(|error| error.message)
+                .map(Option::unwrap),
+            Ok(None) => panic!("film shouldn't be null without an error"),
+            Err(error) => Err(error.message),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        results,
+        vec![
+            Ok("A New Hope".to_string()),
+            Err("Film 1 is missing".to_string()),
+            Err("Title 2 is missing".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_errors_in_spread_fields() {
+    let response = serde_json::from_value::<GraphQlResponse<SpreadQuery>>(json!({
+        "data": {
+            "a_new_hope": null,
+            "empire_strikes_back": {"title": "The Empire Strikes Back", "director": null}
+        },
+        "errors": [{"message": "Film not found", "path": ["a_new_hope"]}]
+    }))
+    .unwrap();
+
+    let data = response.data.unwrap().films;
+    assert_eq!(
+        data.a_new_hope.into_result().unwrap_err().message,
+        "Film not found"
+    );
+    assert!(data.empire_strikes_back.is_ok());
+}
+
+#[test]
+fn test_error_extensions() {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Extensions {
+        code: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../schemas/starwars.schema.graphql",
+        graphql_type = "Root"
+    )]
+    struct FilmQuery {
+        #[arguments(id: "ZmlsbXM6MQ==")]
+        film: FieldResult<Option<Film>, Extensions>,
+    }
+
+    let response = serde_json::from_value::<GraphQlResponse<FilmQuery>>(json!({
+        "data": {"film": null},
+        "errors": [{
+            "message": "Film not found",
+            "path": ["film"],
+            "extensions": {"code": "NOT_FOUND"}
+        }]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        response
+            .data
+            .unwrap()
+            .film
+            .into_result()
+            .unwrap_err()
+            .extensions,
+        Some(Extensions {
+            code: "NOT_FOUND".into()
+        })
+    );
+}
+
+#[test]
+fn test_null_without_errors() {
+    let data = serde_json::from_value::<FilmsQuery>(json!({
+        "a_new_hope": null,
+        "empire_strikes_back": {"title": null, "director": null}
+    }))
+    .unwrap();
+
+    assert_eq!(data.a_new_hope.into_result(), Ok(None));
+    assert!(data.empire_strikes_back.is_ok());
+}
+
+#[test]
+fn test_responses_without_field_results_keep_error_positions() {
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../schemas/starwars.schema.graphql",
+        graphql_type = "Film"
+    )]
+    #[allow(dead_code)]
+    struct PlainFilm {
+        title: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(
+        schema_path = "../schemas/starwars.schema.graphql",
+        graphql_type = "Root"
+    )]
+    #[allow(dead_code)]
+    struct PlainFilmQuery {
+        #[arguments(id: "ZmlsbXM6MQ==")]
+        film: Option<PlainFilm>,
+    }
+
+    assert_eq!(
+        [<PlainFilmQuery as cynic::QueryFragment>::USES_FIELD_RESULTS],
+        [false]
+    );
+
+    let error = serde_json::from_str::<GraphQlResponse<PlainFilmQuery>>(
+        "{\n  \"data\": {\n    \"film\": {\"title\": 1}\n  }\n}",
+    )
+    .unwrap_err();
+
+    assert_eq!(error.line(), 3);
+}
+
+#[test]
+fn test_errors_after_data_in_a_string() {
+    let response = serde_json::from_str::<GraphQlResponse<FilmsQuery>>(
+        r#"{
+            "data": {"a_new_hope": null, "empire_strikes_back": null},
+            "errors": [{"message": "Film not found", "path": ["a_new_hope"]}]
+        }"#,
+    )
+    .unwrap();
+
+    let data = response.data.unwrap();
+    assert_eq!(
+        data.a_new_hope.into_result().unwrap_err().message,
+        "Film not found"
+    );
+    assert_eq!(data.empire_strikes_back.into_result(), Ok(None));
+}
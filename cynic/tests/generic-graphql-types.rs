@@ -0,0 +1,169 @@
+//! Tests of QueryFragments that are generic over several graphql_types
+
+use cynic::QueryBuilder;
+use serde_json::json;
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "FilmsConnection",
+    graphql_type = "PeopleConnection",
+    graphql_type = "PlanetsConnection",
+    graphql_type = "PersonFilmsConnection"
+)]
+struct Connection<N> {
+    edges: Option<Vec<Option<Edge<N>>>>,
+    page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "FilmsEdge",
+    graphql_type = "PeopleEdge",
+    graphql_type = "PlanetsEdge",
+    graphql_type = "PersonFilmsEdge"
+)]
+struct Edge<N> {
+    cursor: String,
+    node: Option<N>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Film {
+    title: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Person {
+    name: Option<String>,
+    // PersonFilmsConnection has the same node type as FilmsConnection, so
+    // the field decides which graphql_type is used
+    film_connection: Option<Connection<Film>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Planet {
+    name: Option<String>,
+}
+
+#[derive(cynic::QueryVariables)]
+struct PageVariables {
+    first: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "Root",
+    variables = "PageVariables"
+)]
+struct EverythingQuery {
+    #[arguments(first: $first)]
+    all_films: Option<Connection<Film>>,
+    #[arguments(first: $first)]
+    all_people: Option<Connection<Person>>,
+    all_planets: Option<Connection<Planet>>,
+}
+
+#[test]
+fn test_generic_fragment_query() {
+    let operation = EverythingQuery::build(PageVariables { first: Some(1) });
+
+    insta::assert_snapshot!(operation.query);
+}
+
+#[test]
+fn test_generic_fragment_decoding() {
+    let page_info = json!({"hasNextPage": false, "endCursor": null});
+    let data = json!({
+        "allFilms": {
+            "edges": [{"cursor": "a", "node": {"title": "A New Hope"}}],
+            "pageInfo": page_info
+        },
+        "allPeople": {
+            "edges": [{
+                "cursor": "b",
+                "node": {
+                    "name": "Luke Skywalker",
+                    "filmConnection": {
+                        "edges": [{"cursor": "c", "node": {"title": "A New Hope"}}],
+                        "pageInfo": page_info
+                    }
+                }
+            }],
+            "pageInfo": page_info
+        },
+        "allPlanets": {"edges": [], "pageInfo": page_info}
+    });
+
+    let result = serde_json::from_value::<EverythingQuery>(data).unwrap();
+
+    assert_eq!(
+        result.all_films.unwrap().edges.unwrap()[0]
+            .as_ref()
+            .unwrap()
+            .node,
+        Some(Film {
+            title: Some("A New Hope".into())
+        })
+    );
+    assert_eq!(
+        result.all_people.unwrap().edges.unwrap()[0]
+            .as_ref()
+            .unwrap()
+            .node,
+        Some(Person {
+            name: Some("Luke Skywalker".into()),
+            film_connection: Some(Connection {
+                edges: Some(vec![Some(Edge {
+                    cursor: "c".into(),
+                    node: Some(Film {
+                        title: Some("A New Hope".into())
+                    })
+                })]),
+                page_info: PageInfo {
+                    has_next_page: false,
+                    end_cursor: None
+                }
+            })
+        })
+    );
+    assert_eq!(result.all_planets.unwrap().edges, Some(vec![]));
+}
+
+#[test]
+fn test_generic_fragment_type() {
+    use cynic::QueryFragmentFor;
+
+    assert_eq!(
+        <Connection<Film> as QueryFragmentFor<schema::FilmsConnection>>::TYPE,
+        Some("FilmsConnection")
+    );
+    assert_eq!(
+        <Connection<Film> as QueryFragmentFor<schema::PersonFilmsConnection>>::TYPE,
+        Some("PersonFilmsConnection")
+    );
+    assert_eq!(
+        <Connection<Planet> as QueryFragmentFor<schema::PlanetsConnection>>::TYPE,
+        Some("PlanetsConnection")
+    );
+    assert_eq!(
+        <Edge<Person> as QueryFragmentFor<schema::PeopleEdge>>::TYPE,
+        Some("PeopleEdge")
+    );
+}
@@ -0,0 +1,110 @@
+//! Static documents are built by `const fn`s, which have to stay fast enough
+//! that large queries don't hit the compilers const evaluation limits.
+
+use std::borrow::Cow;
+
+use cynic::{OperationBuilder, QueryFragment, QueryHash};
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+macro_rules! film {
+    ($($field:ident)*) => {
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+        #[allow(dead_code)]
+        struct Film {
+            $(
+                #[cynic(rename = "title", alias)]
+                $field: Option<String>,
+            )*
+        }
+    };
+}
+
+film! {
+        aliased_film_title_0 aliased_film_title_1 aliased_film_title_2 aliased_film_title_3 aliased_film_title_4 aliased_film_title_5 aliased_film_title_6 aliased_film_title_7 aliased_film_title_8 aliased_film_title_9
+        aliased_film_title_10 aliased_film_title_11 aliased_film_title_12 aliased_film_title_13 aliased_film_title_14 aliased_film_title_15 aliased_film_title_16 aliased_film_title_17 aliased_film_title_18
+        aliased_film_title_19 aliased_film_title_20 aliased_film_title_21 aliased_film_title_22 aliased_film_title_23 aliased_film_title_24 aliased_film_title_25 aliased_film_title_26 aliased_film_title_27
+        aliased_film_title_28 aliased_film_title_29 aliased_film_title_30 aliased_film_title_31 aliased_film_title_32 aliased_film_title_33 aliased_film_title_34 aliased_film_title_35 aliased_film_title_36
+        aliased_film_title_37 aliased_film_title_38 aliased_film_title_39 aliased_film_title_40 aliased_film_title_41 aliased_film_title_42 aliased_film_title_43 aliased_film_title_44 aliased_film_title_45
+        aliased_film_title_46 aliased_film_title_47 aliased_film_title_48 aliased_film_title_49 aliased_film_title_50 aliased_film_title_51 aliased_film_title_52 aliased_film_title_53 aliased_film_title_54
+        aliased_film_title_55 aliased_film_title_56 aliased_film_title_57 aliased_film_title_58 aliased_film_title_59 aliased_film_title_60 aliased_film_title_61 aliased_film_title_62 aliased_film_title_63
+        aliased_film_title_64 aliased_film_title_65 aliased_film_title_66 aliased_film_title_67 aliased_film_title_68 aliased_film_title_69 aliased_film_title_70 aliased_film_title_71 aliased_film_title_72
+        aliased_film_title_73 aliased_film_title_74 aliased_film_title_75 aliased_film_title_76 aliased_film_title_77 aliased_film_title_78 aliased_film_title_79 aliased_film_title_80 aliased_film_title_81
+        aliased_film_title_82 aliased_film_title_83 aliased_film_title_84 aliased_film_title_85 aliased_film_title_86 aliased_film_title_87 aliased_film_title_88 aliased_film_title_89 aliased_film_title_90
+        aliased_film_title_91 aliased_film_title_92 aliased_film_title_93 aliased_film_title_94 aliased_film_title_95 aliased_film_title_96 aliased_film_title_97 aliased_film_title_98 aliased_film_title_99
+        aliased_film_title_100 aliased_film_title_101 aliased_film_title_102 aliased_film_title_103 aliased_film_title_104 aliased_film_title_105 aliased_film_title_106 aliased_film_title_107
+        aliased_film_title_108 aliased_film_title_109 aliased_film_title_110 aliased_film_title_111 aliased_film_title_112 aliased_film_title_113 aliased_film_title_114 aliased_film_title_115
+        aliased_film_title_116 aliased_film_title_117 aliased_film_title_118 aliased_film_title_119 aliased_film_title_120 aliased_film_title_121 aliased_film_title_122 aliased_film_title_123
+        aliased_film_title_124 aliased_film_title_125 aliased_film_title_126 aliased_film_title_127 aliased_film_title_128 aliased_film_title_129 aliased_film_title_130 aliased_film_title_131
+        aliased_film_title_132 aliased_film_title_133 aliased_film_title_134 aliased_film_title_135 aliased_film_title_136 aliased_film_title_137 aliased_film_title_138 aliased_film_title_139
+        aliased_film_title_140 aliased_film_title_141 aliased_film_title_142 aliased_film_title_143 aliased_film_title_144 aliased_film_title_145 aliased_film_title_146 aliased_film_title_147
+        aliased_film_title_148 aliased_film_title_149 aliased_film_title_150 aliased_film_title_151 aliased_film_title_152 aliased_film_title_153 aliased_film_title_154 aliased_film_title_155
+        aliased_film_title_156 aliased_film_title_157 aliased_film_title_158 aliased_film_title_159 aliased_film_title_160 aliased_film_title_161 aliased_film_title_162 aliased_film_title_163
+        aliased_film_title_164 aliased_film_title_165 aliased_film_title_166 aliased_film_title_167 aliased_film_title_168 aliased_film_title_169 aliased_film_title_170 aliased_film_title_171
+        aliased_film_title_172 aliased_film_title_173 aliased_film_title_174 aliased_film_title_175 aliased_film_title_176 aliased_film_title_177 aliased_film_title_178 aliased_film_title_179
+        aliased_film_title_180 aliased_film_title_181 aliased_film_title_182 aliased_film_title_183 aliased_film_title_184 aliased_film_title_185 aliased_film_title_186 aliased_film_title_187
+        aliased_film_title_188 aliased_film_title_189 aliased_film_title_190 aliased_film_title_191 aliased_film_title_192 aliased_film_title_193 aliased_film_title_194 aliased_film_title_195
+        aliased_film_title_196 aliased_film_title_197 aliased_film_title_198 aliased_film_title_199 aliased_film_title_200 aliased_film_title_201 aliased_film_title_202 aliased_film_title_203
+        aliased_film_title_204 aliased_film_title_205 aliased_film_title_206 aliased_film_title_207 aliased_film_title_208 aliased_film_title_209 aliased_film_title_210 aliased_film_title_211
+        aliased_film_title_212 aliased_film_title_213 aliased_film_title_214 aliased_film_title_215 aliased_film_title_216 aliased_film_title_217 aliased_film_title_218 aliased_film_title_219
+        aliased_film_title_220 aliased_film_title_221 aliased_film_title_222 aliased_film_title_223 aliased_film_title_224 aliased_film_title_225 aliased_film_title_226 aliased_film_title_227
+        aliased_film_title_228 aliased_film_title_229 aliased_film_title_230 aliased_film_title_231 aliased_film_title_232 aliased_film_title_233 aliased_film_title_234 aliased_film_title_235
+        aliased_film_title_236 aliased_film_title_237 aliased_film_title_238 aliased_film_title_239 aliased_film_title_240 aliased_film_title_241 aliased_film_title_242 aliased_film_title_243
+        aliased_film_title_244 aliased_film_title_245 aliased_film_title_246 aliased_film_title_247 aliased_film_title_248 aliased_film_title_249 aliased_film_title_250 aliased_film_title_251
+        aliased_film_title_252 aliased_film_title_253 aliased_film_title_254 aliased_film_title_255 aliased_film_title_256 aliased_film_title_257 aliased_film_title_258 aliased_film_title_259
+        aliased_film_title_260 aliased_film_title_261 aliased_film_title_262 aliased_film_title_263 aliased_film_title_264 aliased_film_title_265 aliased_film_title_266 aliased_film_title_267
+        aliased_film_title_268 aliased_film_title_269 aliased_film_title_270 aliased_film_title_271 aliased_film_title_272 aliased_film_title_273 aliased_film_title_274 aliased_film_title_275
+        aliased_film_title_276 aliased_film_title_277 aliased_film_title_278 aliased_film_title_279 aliased_film_title_280 aliased_film_title_281 aliased_film_title_282 aliased_film_title_283
+        aliased_film_title_284 aliased_film_title_285 aliased_film_title_286 aliased_film_title_287 aliased_film_title_288 aliased_film_title_289 aliased_film_title_290 aliased_film_title_291
+        aliased_film_title_292 aliased_film_title_293 aliased_film_title_294 aliased_film_title_295 aliased_film_title_296 aliased_film_title_297 aliased_film_title_298 aliased_film_title_299
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+#[allow(dead_code)]
+struct FilmsConnection {
+    films: Option<Vec<Option<Film>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "Root"
+)]
+#[allow(dead_code)]
+struct LargeQuery {
+    #[arguments(first: 1)]
+    #[cynic(rename = "allFilms", alias)]
+    first_films: Option<FilmsConnection>,
+
+    #[arguments(first: 2)]
+    #[cynic(rename = "allFilms", alias)]
+    second_films: Option<FilmsConnection>,
+
+    #[arguments(first: 3)]
+    #[cynic(rename = "allFilms", alias)]
+    third_films: Option<FilmsConnection>,
+
+    #[arguments(first: 4)]
+    #[cynic(rename = "allFilms", alias)]
+    fourth_films: Option<FilmsConnection>,
+}
+
+#[test]
+fn test_large_nested_documents_are_built_at_compile_time() {
+    let static_operation = LargeQuery::STATIC_OPERATION.expect("a static operation");
+    // Enabling a feature forces the query to be built at runtime
+    let operation = OperationBuilder::<LargeQuery, ()>::query()
+        .with_variables(())
+        .with_feature_enabled("unused")
+        .build()
+        .unwrap();
+
+    assert!(static_operation.document().len() > 40_000);
+    assert!(matches!(operation.query, Cow::Owned(_)));
+    assert_eq!(static_operation.document(), operation.query);
+    assert_eq!(static_operation.hash(), QueryHash::new(&operation.query));
+}
@@ -0,0 +1,171 @@
+use serde_json::json;
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Film {
+    title: Option<String>,
+}
+
+#[derive(cynic::QueryVariables)]
+struct FilmVariables {
+    id: Option<cynic::Id>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Root",
+    schema_path = "../schemas/starwars.schema.graphql",
+    variables = "FilmVariables"
+)]
+struct FilmQuery {
+    #[arguments(id: $id)]
+    film: Option<Film>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Root",
+    schema_path = "../schemas/starwars.schema.graphql"
+)]
+struct DefaultFilmQuery {
+    #[arguments(id: "ZmlsbXM6MQ==")]
+    film: Option<Film>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Planet {
+    name: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Root",
+    schema_path = "../schemas/starwars.schema.graphql"
+)]
+struct PlanetQuery {
+    #[arguments(id: "cGxhbmV0czox")]
+    planet: Option<Planet>,
+}
+
+#[derive(cynic::QueryVariables)]
+struct PlanetVariables {
+    planet_id: cynic::Id,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Root",
+    schema_path = "../schemas/starwars.schema.graphql",
+    variables = "PlanetVariables"
+)]
+struct PlanetByIdQuery {
+    #[arguments(id: $planet_id)]
+    planet: Option<Planet>,
+}
+
+#[derive(cynic::QueryVariables)]
+struct FilmAndPlanetVariables {
+    #[cynic(flatten)]
+    film: FilmVariables,
+    #[cynic(flatten)]
+    planet: PlanetVariables,
+}
+
+type MergedQuery = cynic::Merged<(FilmQuery, DefaultFilmQuery, PlanetQuery), FilmVariables>;
+
+#[test]
+fn test_merged_query_output() {
+    use cynic::QueryBuilder;
+
+    let operation = MergedQuery::build(FilmVariables { id: None });
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query FilmQueryAndDefaultFilmQueryAndPlanetQuery($id: ID) {
+      _0_film: film(id: $id) {
+        title
+      }
+      _1_film: film(id: "ZmlsbXM6MQ==") {
+        title
+      }
+      _2_planet: planet(id: "cGxhbmV0czox") {
+        name
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_merged_query_decoding() {
+    let data = json!({
+        "_0_film": {"title": "The Empire Strikes Back"},
+        "_1_film": {"title": "A New Hope"},
+        "_2_planet": {"name": "Tatooine"}
+    });
+
+    let (film, default_film, planet) = serde_json::from_value::<MergedQuery>(data)
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        film.film.unwrap().title.as_deref(),
+        Some("The Empire Strikes Back")
+    );
+    assert_eq!(
+        default_film.film.unwrap().title.as_deref(),
+        Some("A New Hope")
+    );
+    assert_eq!(planet.planet.unwrap().name.as_deref(), Some("Tatooine"));
+}
+
+#[test]
+fn test_merging_fragments_with_different_variables() {
+    use cynic::QueryBuilder;
+
+    let operation = cynic::Merged::<(FilmQuery, PlanetByIdQuery), FilmAndPlanetVariables>::build(
+        FilmAndPlanetVariables {
+            film: FilmVariables {
+                id: Some(cynic::Id::new("ZmlsbXM6MQ==")),
+            },
+            planet: PlanetVariables {
+                planet_id: cynic::Id::new("cGxhbmV0czox"),
+            },
+        },
+    );
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query FilmQueryAndPlanetByIdQuery($id: ID, $planetId: ID!) {
+      _0_film: film(id: $id) {
+        title
+      }
+      _1_planet: planet(id: $planetId) {
+        name
+      }
+    }
+
+    "###);
+    insta::assert_json_snapshot!(operation.variables, @r###"
+    {
+      "id": "ZmlsbXM6MQ==",
+      "planetId": "cGxhbmV0czox"
+    }
+    "###);
+
+    let data = json!({
+        "_0_film": {"title": "A New Hope"},
+        "_1_planet": {"name": "Tatooine"}
+    });
+    let (film, planet) = serde_json::from_value::<
+        cynic::Merged<(FilmQuery, PlanetByIdQuery), FilmAndPlanetVariables>,
+    >(data)
+    .unwrap()
+    .into_inner();
+
+    assert_eq!(film.film.unwrap().title.as_deref(), Some("A New Hope"));
+    assert_eq!(planet.planet.unwrap().name.as_deref(), Some("Tatooine"));
+}
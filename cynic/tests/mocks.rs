@@ -0,0 +1,245 @@
+#![cfg(feature = "testing")]
+
+use serde_json::json;
+
+use cynic::testing::{mock, MockGenerator, PossibleTypeStrategy};
+
+mod schema {
+    cynic::use_schema!("tests/test-schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(graphql_type = "Query", schema_path = "tests/test-schema.graphql")]
+struct AllPostsQuery {
+    all_posts: Vec<Post>,
+    all_data: Vec<PostOrAuthor>,
+    #[arguments(id: "123")]
+    node: Option<Node>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(graphql_type = "BlogPost", schema_path = "tests/test-schema.graphql")]
+struct Post {
+    id: Option<cynic::Id>,
+    has_metadata: Option<bool>,
+    state: Option<PostState>,
+    author: Author,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(graphql_type = "Author", schema_path = "tests/test-schema.graphql")]
+struct Author {
+    name: Option<String>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq)]
+#[cynic(schema_path = "tests/test-schema.graphql")]
+enum PostState {
+    Posted,
+    Draft,
+}
+
+#[derive(cynic::InlineFragments, Debug, PartialEq)]
+#[cynic(schema_path = "tests/test-schema.graphql")]
+enum PostOrAuthor {
+    Post(Post),
+    Author(Author),
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(cynic::InlineFragments, Debug, PartialEq)]
+#[cynic(schema_path = "tests/test-schema.graphql")]
+enum Node {
+    Author(Author),
+    #[cynic(fallback)]
+    Other,
+}
+
+#[test]
+fn test_mock_response() {
+    insta::assert_json_snapshot!(MockGenerator::new().generate::<AllPostsQuery>(), @r###"
+    {
+      "allData": [
+        {
+          "__typename": "BlogPost",
+          "author": {
+            "name": "name 726"
+          },
+          "hasMetadata": false,
+          "id": "3ee5789041c98ac3",
+          "state": "DRAFT"
+        },
+        {
+          "__typename": "BlogPost",
+          "author": {
+            "name": "name 907"
+          },
+          "hasMetadata": true,
+          "id": "8621a03fe0bbdb7b",
+          "state": "DRAFT"
+        }
+      ],
+      "allPosts": [
+        {
+          "author": {
+            "name": "name 444"
+          },
+          "hasMetadata": false,
+          "id": "e220a8397b1dcdaf",
+          "state": "DRAFT"
+        },
+        {
+          "author": {
+            "name": "name 940"
+          },
+          "hasMetadata": false,
+          "id": "1b39896a51a8749b",
+          "state": "DRAFT"
+        }
+      ],
+      "node": {
+        "__typename": "Author",
+        "name": "name 925"
+      }
+    }
+    "###);
+
+    let query = mock::<AllPostsQuery>();
+    assert_eq!(query.all_posts.len(), 2);
+    assert!(matches!(query.all_data[0], PostOrAuthor::Post(_)));
+    assert!(matches!(query.node, Some(Node::Author(_))));
+}
+
+#[test]
+fn test_mocks_are_deterministic() {
+    let generator = MockGenerator::new().with_seed(7);
+
+    assert_eq!(
+        generator.generate::<AllPostsQuery>(),
+        generator.generate::<AllPostsQuery>()
+    );
+    assert_ne!(
+        generator.generate::<AllPostsQuery>(),
+        MockGenerator::new()
+            .with_seed(8)
+            .generate::<AllPostsQuery>()
+    );
+}
+
+#[test]
+fn test_overrides() {
+    let query = MockGenerator::new()
+        .with_override("allPosts.author.name", "Graham")
+        .with_override("node", json!(null))
+        .with_type_override("PostState", "POSTED")
+        .mock::<AllPostsQuery>();
+
+    for post in &query.all_posts {
+        assert_eq!(post.author.name.as_deref(), Some("Graham"));
+        assert_eq!(post.state, Some(PostState::Posted));
+    }
+    assert_eq!(query.node, None);
+}
+
+#[test]
+fn test_list_lengths() {
+    let query = MockGenerator::new()
+        .with_list_length(3)
+        .with_list_length_at("allData", 0)
+        .mock::<AllPostsQuery>();
+
+    assert_eq!(query.all_posts.len(), 3);
+    assert!(query.all_data.is_empty());
+}
+
+#[test]
+fn test_possible_type_strategies() {
+    let query = MockGenerator::new()
+        .with_possible_type_strategy(PossibleTypeStrategy::Prefer("Author".into()))
+        .mock::<AllPostsQuery>();
+
+    assert!(matches!(query.all_data[0], PostOrAuthor::Author(_)));
+
+    // Comments implement Node, but aren't selected so should hit the fallback
+    let query = MockGenerator::new()
+        .with_possible_type_strategy(PossibleTypeStrategy::Prefer("Comment".into()))
+        .mock::<AllPostsQuery>();
+
+    assert_eq!(query.node, Some(Node::Other));
+
+    let nodes = (0..20)
+        .map(|seed| {
+            MockGenerator::new()
+                .with_seed(seed)
+                .with_possible_type_strategy(PossibleTypeStrategy::Random)
+                .mock::<AllPostsQuery>()
+                .node
+        })
+        .collect::<Vec<_>>();
+
+    assert!(nodes.contains(&Some(Node::Other)));
+    assert!(nodes
+        .iter()
+        .any(|node| matches!(node, Some(Node::Author(_)))));
+}
+
+mod test_cases_schema {
+    cynic::use_schema!("../schemas/test_cases.graphql");
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+struct Uuid(String);
+
+impl<'de> serde::Deserialize<'de> for Uuid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uuid = String::deserialize(deserializer)?;
+        if uuid.len() != 36 {
+            return Err(serde::de::Error::custom("not a uuid"));
+        }
+        Ok(Uuid(uuid))
+    }
+}
+
+cynic::impl_scalar!(Uuid, test_cases_schema::UUID);
+
+#[derive(cynic::QueryVariables)]
+#[cynic(schema_module = "test_cases_schema")]
+struct BarVariables {
+    id: Uuid,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    graphql_type = "Foo",
+    schema_path = "../schemas/test_cases.graphql",
+    schema_module = "test_cases_schema",
+    variables = "BarVariables"
+)]
+struct BarQuery {
+    #[arguments(id: $id)]
+    bar: Option<Bar>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/test_cases.graphql",
+    schema_module = "test_cases_schema"
+)]
+struct Bar {
+    id: Uuid,
+}
+
+#[test]
+fn test_custom_scalars() {
+    assert!(std::panic::catch_unwind(mock::<BarQuery>).is_err());
+
+    let query = MockGenerator::new()
+        .with_type_override("UUID", "a1c4e8e6-6a2b-4a2c-9f6e-0e7c7e4b1f3d")
+        .mock::<BarQuery>();
+
+    assert_eq!(
+        query.bar.unwrap().id,
+        Uuid("a1c4e8e6-6a2b-4a2c-9f6e-0e7c7e4b1f3d".into())
+    );
+}
@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+
+use serde_json::json;
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql", named_fragment)]
+struct Film {
+    title: Option<String>,
+    director: Option<String>,
+    planet_connection: Option<FilmPlanetsConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct FilmPlanetsConnection {
+    planets: Option<Vec<Option<Planet>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql", named_fragment)]
+struct Planet {
+    name: Option<String>,
+}
+
+mod other {
+    use super::schema;
+
+    #[derive(cynic::QueryFragment, Debug, PartialEq)]
+    #[cynic(schema_path = "../schemas/starwars.schema.graphql", named_fragment)]
+    pub struct Film {
+        pub id: cynic::Id,
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct FilmsConnection {
+    films: Option<Vec<Option<Film>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    graphql_type = "Root",
+    schema_path = "../schemas/starwars.schema.graphql"
+)]
+struct FilmsQuery {
+    #[arguments(id: "ZmlsbXM6MQ==")]
+    film: Option<Film>,
+    #[arguments(id: "ZmlsbXM6MQ==")]
+    #[cynic(rename = "film", alias)]
+    other_film: Option<other::Film>,
+    all_films: Option<FilmsConnection>,
+    #[arguments(id: "cGxhbmV0czox")]
+    planet: Option<Planet>,
+}
+
+#[test]
+fn test_named_fragment_query_output() {
+    use cynic::QueryBuilder;
+
+    let operation = FilmsQuery::build(());
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query FilmsQuery {
+      film(id: "ZmlsbXM6MQ==") {
+        ...Film
+      }
+      other_film: film(id: "ZmlsbXM6MQ==") {
+        ...Film2
+      }
+      allFilms {
+        films {
+          ...Film
+        }
+      }
+      planet(id: "cGxhbmV0czox") {
+        ...Planet
+      }
+    }
+
+    fragment Film on Film {
+      title
+      director
+      planetConnection {
+        planets {
+          ...Planet
+        }
+      }
+    }
+
+    fragment Planet on Planet {
+      name
+    }
+
+    fragment Film2 on Film {
+      id
+    }
+
+    "###);
+}
+
+#[test]
+fn test_named_fragment_decoding() {
+    let data = json!({
+        "film": {"title": "A New Hope", "director": "George Lucas", "planetConnection": null},
+        "other_film": {"id": "ZmlsbXM6MQ=="},
+        "allFilms": {"films": []},
+        "planet": {"name": "Tatooine"}
+    });
+
+    let decoded = serde_json::from_value::<FilmsQuery>(data).unwrap();
+
+    assert_eq!(decoded.film.unwrap().title.as_deref(), Some("A New Hope"));
+    assert_eq!(decoded.other_film.unwrap().id.inner(), "ZmlsbXM6MQ==");
+    assert_eq!(decoded.planet.unwrap().name.as_deref(), Some("Tatooine"));
+}
+
+mod recursive {
+    mod schema {
+        cynic::use_schema!("tests/test-schema.graphql");
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "tests/test-schema.graphql", graphql_type = "Query")]
+    pub struct AllAuthorsQuery {
+        pub all_authors: Vec<Author>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_path = "tests/test-schema.graphql", named_fragment)]
+    pub struct Author {
+        pub name: Option<String>,
+        #[cynic(recurse = "2")]
+        pub friends: Option<Vec<Author>>,
+    }
+
+    #[test]
+    fn test_recursive_named_fragment_query_output() {
+        use cynic::QueryBuilder;
+
+        let operation = AllAuthorsQuery::build(());
+
+        insta::assert_display_snapshot!(operation.query, @r###"
+        query AllAuthorsQuery {
+          allAuthors {
+            ...Author
+          }
+        }
+
+        fragment Author on Author {
+          name
+          friends {
+            name
+            friends {
+              name
+            }
+          }
+        }
+
+        "###);
+    }
+}
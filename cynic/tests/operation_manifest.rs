@@ -0,0 +1,128 @@
+use cynic::{MutationBuilder, OperationKind, OperationManifest, QueryBuilder};
+
+mod schema {
+    cynic::use_schema!("../schemas/books.graphql");
+}
+
+#[derive(cynic::QueryFragment)]
+#[allow(dead_code)]
+#[cynic(schema_path = "../schemas/books.graphql")]
+struct Book {
+    name: String,
+}
+
+#[derive(cynic::QueryFragment)]
+#[allow(dead_code)]
+#[cynic(schema_path = "../schemas/books.graphql", graphql_type = "QueryRoot")]
+struct BooksQuery {
+    books: Vec<Book>,
+}
+
+#[derive(cynic::QueryVariables)]
+struct DeleteBookVariables {
+    id: cynic::Id,
+}
+
+#[derive(cynic::QueryFragment)]
+#[allow(dead_code)]
+#[cynic(
+    schema_path = "../schemas/books.graphql",
+    graphql_type = "MutationRoot",
+    variables = "DeleteBookVariables"
+)]
+struct DeleteBook {
+    #[arguments(id: $id)]
+    delete_book: bool,
+}
+
+fn manifest() -> OperationManifest {
+    let mut manifest = OperationManifest::new();
+    manifest
+        .add_query::<BooksQuery, ()>()
+        .add_mutation::<DeleteBook, DeleteBookVariables>();
+    manifest
+}
+
+#[test]
+fn test_apollo_manifest() {
+    let manifest = manifest();
+    assert_eq!(manifest.operations().count(), 2);
+
+    insta::assert_json_snapshot!(manifest.apollo(), @r###"
+    {
+      "format": "apollo-persisted-query-manifest",
+      "version": 1,
+      "operations": [
+        {
+          "id": "620851a3ad78a8edf4fc5ed9c173b181d4382f1584fc6b634cdf085562c6fe6f",
+          "name": "BooksQuery",
+          "type": "query",
+          "body": "query BooksQuery {\n  books {\n    name\n  }\n}\n\n"
+        },
+        {
+          "id": "922c303d1c5b2884f2bd3121a4bac2ed35c21e65c2e0dd7d9fa83dbde9709612",
+          "name": "DeleteBook",
+          "type": "mutation",
+          "body": "mutation DeleteBook($id: ID!) {\n  deleteBook(id: $id)\n}\n\n"
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn test_relay_manifest() {
+    insta::assert_json_snapshot!(manifest().relay(), @r###"
+    {
+      "620851a3ad78a8edf4fc5ed9c173b181d4382f1584fc6b634cdf085562c6fe6f": "query BooksQuery {\n  books {\n    name\n  }\n}\n\n",
+      "922c303d1c5b2884f2bd3121a4bac2ed35c21e65c2e0dd7d9fa83dbde9709612": "mutation DeleteBook($id: ID!) {\n  deleteBook(id: $id)\n}\n\n"
+    }
+    "###);
+}
+
+#[test]
+fn test_manifest_ids_match_built_operations() {
+    let manifest = manifest();
+
+    let query = BooksQuery::build(());
+    let mutation = DeleteBook::build(DeleteBookVariables { id: "1".into() });
+
+    for (operation_name, id, document, kind) in [
+        (
+            "BooksQuery",
+            query.persisted_query().hash(),
+            &query.query,
+            OperationKind::Query,
+        ),
+        (
+            "DeleteBook",
+            mutation.persisted_query().hash(),
+            &mutation.query,
+            OperationKind::Mutation,
+        ),
+    ] {
+        let entry = manifest
+            .operations()
+            .find(|entry| entry.name.as_deref() == Some(operation_name))
+            .unwrap();
+
+        assert_eq!(entry.id, id);
+        assert_eq!(entry.kind, kind);
+        assert_eq!(&entry.document, document);
+    }
+}
+
+#[test]
+fn test_built_operations_can_be_added() {
+    let mut manifest = OperationManifest::new();
+    manifest
+        .add_operation(&DeleteBook::build(DeleteBookVariables { id: "1".into() }))
+        // Variables don't change the document, so this shouldn't add an entry
+        .add_operation(&DeleteBook::build(DeleteBookVariables { id: "2".into() }))
+        .add_mutation::<DeleteBook, DeleteBookVariables>();
+
+    let entries = manifest.operations().collect::<Vec<_>>();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].kind, OperationKind::Mutation);
+    assert_eq!(entries[0].name.as_deref(), Some("DeleteBook"));
+}
@@ -0,0 +1,359 @@
+#![cfg(feature = "pagination")]
+
+use std::{
+    collections::VecDeque,
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use futures::{executor::block_on, StreamExt};
+use serde_json::json;
+
+use cynic::{
+    pagination::{
+        BackwardVariables, Connection, ForwardVariables, OffsetVariables, PageInfo,
+        PaginationError, Paginator,
+    },
+    GraphQlResponse, Operation, QueryBuilder,
+};
+
+mod schema {
+    cynic::use_schema!("../schemas/starwars.schema.graphql");
+}
+
+mod jobs_schema {
+    cynic::use_schema!("../schemas/graphql.jobs.graphql");
+}
+
+#[derive(cynic::QueryVariables, Clone, Default, Debug, PartialEq)]
+struct FilmsVariables {
+    first: Option<i32>,
+    after: Option<String>,
+    last: Option<i32>,
+    before: Option<String>,
+}
+
+impl ForwardVariables for FilmsVariables {
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+}
+
+impl BackwardVariables for FilmsVariables {
+    fn set_before(&mut self, cursor: String) {
+        self.before = Some(cursor);
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "Root",
+    variables = "FilmsVariables"
+)]
+struct FilmsQuery {
+    #[arguments(first: $first, after: $after, last: $last, before: $before)]
+    all_films: Option<FilmsConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct FilmsConnection {
+    edges: Option<Vec<Option<FilmEdge>>>,
+    page_info: FilmsPageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "FilmsEdge"
+)]
+struct FilmEdge {
+    node: Option<Film>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/starwars.schema.graphql",
+    graphql_type = "PageInfo"
+)]
+struct FilmsPageInfo {
+    has_next_page: bool,
+    has_previous_page: bool,
+    start_cursor: Option<String>,
+    end_cursor: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "../schemas/starwars.schema.graphql")]
+struct Film {
+    title: Option<String>,
+}
+
+impl Connection for FilmsConnection {
+    type Node = Film;
+
+    fn page_info(&self) -> PageInfo {
+        PageInfo {
+            has_next_page: self.page_info.has_next_page,
+            has_previous_page: self.page_info.has_previous_page,
+            start_cursor: self.page_info.start_cursor.clone(),
+            end_cursor: self.page_info.end_cursor.clone(),
+        }
+    }
+
+    fn into_nodes(self) -> Vec<Film> {
+        self.edges
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .filter_map(|edge| edge.node)
+            .collect()
+    }
+}
+
+/// Returns canned responses, recording the variables of each operation
+#[derive(Clone)]
+struct MockServer<Variables> {
+    responses: Arc<Mutex<VecDeque<serde_json::Value>>>,
+    variables: Arc<Mutex<Vec<Variables>>>,
+}
+
+impl<Variables: Clone> MockServer<Variables> {
+    fn new(responses: impl IntoIterator<Item = serde_json::Value>) -> Self {
+        MockServer {
+            responses: Arc::new(Mutex::new(responses.into_iter().collect())),
+            variables: Arc::default(),
+        }
+    }
+
+    fn run<ResponseData>(
+        &self,
+        operation: Operation<ResponseData, Variables>,
+    ) -> Result<GraphQlResponse<ResponseData>, Infallible>
+    where
+        ResponseData: serde::de::DeserializeOwned + cynic::UsesFieldResults,
+    {
+        self.variables.lock().unwrap().push(operation.variables);
+        let response = self.responses.lock().unwrap().pop_front().unwrap();
+
+        Ok(serde_json::from_value(response).unwrap())
+    }
+
+    fn variables(&self) -> Vec<Variables> {
+        self.variables.lock().unwrap().clone()
+    }
+}
+
+fn films_page(titles: &[&str], page_info: serde_json::Value) -> serde_json::Value {
+    json!({"data": {"allFilms": {
+        "edges": titles.iter().map(|title| json!({"node": {"title": title}})).collect::<Vec<_>>(),
+        "pageInfo": page_info
+    }}})
+}
+
+fn titles<E: std::fmt::Debug>(films: Vec<Result<Film, PaginationError<E>>>) -> Vec<String> {
+    films
+        .into_iter()
+        .map(|film| film.unwrap().title.unwrap())
+        .collect()
+}
+
+#[test]
+fn test_forward_pagination_stream() {
+    let server = MockServer::new([
+        films_page(
+            &["A New Hope", "The Empire Strikes Back"],
+            json!({"hasNextPage": true, "hasPreviousPage": false, "startCursor": "0", "endCursor": "1"}),
+        ),
+        films_page(
+            &["Return of the Jedi"],
+            json!({"hasNextPage": false, "hasPreviousPage": true, "startCursor": "2", "endCursor": "2"}),
+        ),
+    ]);
+
+    let paginator = Paginator::forward(
+        FilmsQuery::build(FilmsVariables {
+            first: Some(2),
+            ..Default::default()
+        }),
+        |data: FilmsQuery| data.all_films,
+    );
+
+    let films = block_on(
+        paginator
+            .into_stream(|operation| futures::future::ready(server.run(operation)))
+            .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(
+        titles(films),
+        vec![
+            "A New Hope",
+            "The Empire Strikes Back",
+            "Return of the Jedi"
+        ]
+    );
+    assert_eq!(
+        server.variables(),
+        vec![
+            FilmsVariables {
+                first: Some(2),
+                ..Default::default()
+            },
+            FilmsVariables {
+                first: Some(2),
+                after: Some("1".into()),
+                ..Default::default()
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_backward_pagination_iter() {
+    let server = MockServer::new([
+        films_page(
+            &["Return of the Jedi"],
+            json!({"hasNextPage": false, "hasPreviousPage": true, "startCursor": "2", "endCursor": "2"}),
+        ),
+        films_page(
+            &["A New Hope", "The Empire Strikes Back"],
+            json!({"hasNextPage": true, "hasPreviousPage": false, "startCursor": "0", "endCursor": "1"}),
+        ),
+    ]);
+
+    let paginator = Paginator::backward(
+        FilmsQuery::build(FilmsVariables {
+            last: Some(2),
+            ..Default::default()
+        }),
+        |data: FilmsQuery| data.all_films,
+    );
+
+    let films = paginator
+        .into_blocking_iter(|operation| server.run(operation))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        titles(films),
+        vec![
+            "Return of the Jedi",
+            "A New Hope",
+            "The Empire Strikes Back"
+        ]
+    );
+    assert_eq!(server.variables()[1].before.as_deref(), Some("2"));
+}
+
+#[test]
+fn test_pagination_stops_after_errors() {
+    let server = MockServer::new([json!({
+        "data": null,
+        "errors": [{"message": "Rate limited"}]
+    })]);
+
+    let paginator = Paginator::forward(
+        FilmsQuery::build(FilmsVariables::default()),
+        |data: FilmsQuery| data.all_films,
+    );
+
+    let mut films = paginator.into_blocking_iter(|operation| server.run(operation));
+
+    assert_eq!(
+        films.next().unwrap().unwrap_err().to_string(),
+        "The server returned errors when fetching a page: Rate limited"
+    );
+    assert!(films.next().is_none());
+}
+
+#[test]
+fn test_pagination_stops_on_null_connection() {
+    let server = MockServer::new([json!({"data": {"allFilms": null}})]);
+
+    let paginator = Paginator::forward(
+        FilmsQuery::build(FilmsVariables::default()),
+        |data: FilmsQuery| data.all_films,
+    );
+
+    assert_eq!(
+        paginator
+            .into_blocking_iter(|operation| server.run(operation))
+            .count(),
+        0
+    );
+    assert_eq!(server.variables().len(), 1);
+}
+
+#[derive(cynic::QueryVariables, Clone, Debug)]
+struct JobsVariables {
+    skip: Option<i32>,
+}
+
+impl OffsetVariables for JobsVariables {
+    fn set_offset(&mut self, offset: usize) {
+        self.skip = Some(offset as i32);
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/graphql.jobs.graphql",
+    schema_module = "jobs_schema",
+    graphql_type = "Query",
+    variables = "JobsVariables"
+)]
+struct CountryJobsQuery {
+    #[arguments(input: { slug: "germany" })]
+    country: Country,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/graphql.jobs.graphql",
+    schema_module = "jobs_schema",
+    variables = "JobsVariables"
+)]
+struct Country {
+    #[arguments(skip: $skip, first: 2)]
+    jobs: Option<Vec<Job>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/graphql.jobs.graphql",
+    schema_module = "jobs_schema"
+)]
+struct Job {
+    title: String,
+}
+
+#[test]
+fn test_offset_pagination() {
+    let server = MockServer::new([
+        json!({"data": {"country": {"jobs": [{"title": "One"}, {"title": "Two"}]}}}),
+        json!({"data": {"country": {"jobs": [{"title": "Three"}, {"title": "Four"}]}}}),
+        json!({"data": {"country": {"jobs": [{"title": "Five"}]}}}),
+    ]);
+
+    let paginator = Paginator::offset(
+        CountryJobsQuery::build(JobsVariables { skip: None }),
+        2,
+        |data: CountryJobsQuery| data.country.jobs,
+    );
+
+    let jobs = paginator
+        .into_blocking_iter(|operation| server.run(operation))
+        .map(|job| job.unwrap().title)
+        .collect::<Vec<_>>();
+
+    assert_eq!(jobs, vec!["One", "Two", "Three", "Four", "Five"]);
+    assert_eq!(
+        server
+            .variables()
+            .into_iter()
+            .map(|variables| variables.skip)
+            .collect::<Vec<_>>(),
+        vec![None, Some(2), Some(4)]
+    );
+}
@@ -0,0 +1,87 @@
+use cynic::{MutationBuilder, OperationManifest, QueryBuilder};
+
+mod schema {
+    cynic::use_schema!("../schemas/books.graphql");
+}
+
+#[derive(cynic::QueryFragment)]
+#[allow(dead_code)]
+#[cynic(schema_path = "../schemas/books.graphql")]
+struct Book {
+    name: String,
+}
+
+#[derive(cynic::QueryFragment)]
+#[allow(dead_code)]
+#[cynic(schema_path = "../schemas/books.graphql", graphql_type = "QueryRoot")]
+struct BooksQuery {
+    books: Vec<Book>,
+}
+
+#[derive(cynic::QueryVariables)]
+struct DeleteBookVariables {
+    id: cynic::Id,
+}
+
+#[derive(cynic::QueryFragment)]
+#[allow(dead_code)]
+#[cynic(
+    schema_path = "../schemas/books.graphql",
+    graphql_type = "MutationRoot",
+    variables = "DeleteBookVariables"
+)]
+struct DeleteBook {
+    #[arguments(id: $id)]
+    delete_book: bool,
+}
+
+#[test]
+fn test_persisted_document_sends_only_the_doc_id() {
+    let operation = DeleteBook::build(DeleteBookVariables { id: "1".into() });
+
+    insta::assert_json_snapshot!(operation.persisted_document("delete-book-v1"), @r###"
+    {
+      "doc_id": "delete-book-v1",
+      "variables": {
+        "id": "1"
+      },
+      "operationName": "DeleteBook"
+    }
+    "###);
+}
+
+#[test]
+fn test_persisted_document_without_variables() {
+    let operation = BooksQuery::build(());
+
+    insta::assert_json_snapshot!(operation.persisted_document("books-v1"), @r###"
+    {
+      "doc_id": "books-v1",
+      "variables": null,
+      "operationName": "BooksQuery"
+    }
+    "###);
+}
+
+#[test]
+fn test_derived_operations_can_be_sent_by_document_id() {
+    let mut manifest = OperationManifest::new();
+    manifest.add_mutation::<DeleteBook, DeleteBookVariables>();
+
+    let operation = DeleteBook::build(DeleteBookVariables { id: "1".into() });
+    let document_id = operation.document_id();
+
+    assert_eq!(
+        manifest.operations().next().unwrap().id.to_string(),
+        document_id
+    );
+    insta::assert_json_snapshot!(operation.persisted_document(&document_id), @r###"
+    {
+      "doc_id": "922c303d1c5b2884f2bd3121a4bac2ed35c21e65c2e0dd7d9fa83dbde9709612",
+      "variables": {
+        "id": "1"
+      },
+      "operationName": "DeleteBook"
+    }
+    "###);
+}
@@ -106,3 +106,121 @@ struct WeirdInput {
 enum WeirdEnum {
     AVariant,
 }
+
+#[derive(QueryFragment, Serialize)]
+#[cynic(graphql_type = "Query", schema_path = "tests/test-schema.graphql")]
+struct RenameAllQuery {
+    snake_things: Vec<SnakeThing>,
+    shouty_type: Option<ShoutyType>,
+}
+
+#[derive(QueryFragment, Serialize)]
+#[cynic(
+    graphql_type = "snake_person",
+    schema_path = "tests/test-schema.graphql",
+    rename_all = "snake_case"
+)]
+struct SnakePerson {
+    __typename: String,
+    first_name: Option<String>,
+    #[cynic(rename = "is_admin")]
+    admin: Option<bool>,
+}
+
+#[derive(QueryFragment, Serialize)]
+#[cynic(
+    graphql_type = "snake_robot",
+    schema_path = "tests/test-schema.graphql",
+    rename_all = "snake_case"
+)]
+struct SnakeRobot {
+    model_number: String,
+}
+
+#[derive(InlineFragments, Serialize)]
+#[cynic(
+    graphql_type = "snake_thing",
+    schema_path = "tests/test-schema.graphql",
+    rename_all = "snake_case",
+    exhaustive
+)]
+enum SnakeThing {
+    SnakePerson(SnakePerson),
+    SnakeRobot(SnakeRobot),
+    #[cynic(fallback)]
+    Other,
+}
+
+#[derive(QueryFragment, Serialize)]
+#[cynic(
+    graphql_type = "SHOUTY_TYPE",
+    schema_path = "tests/test-schema.graphql",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+struct ShoutyType {
+    model_number: String,
+    is_active: Option<bool>,
+}
+
+#[test]
+fn test_rename_all_query_output() {
+    use cynic::QueryBuilder;
+
+    let operation = RenameAllQuery::build(());
+
+    insta::assert_display_snapshot!(operation.query, @r###"
+    query RenameAllQuery {
+      snakeThings {
+        __typename
+        ... on snake_person {
+          __typename
+          first_name
+          is_admin
+        }
+        ... on snake_robot {
+          model_number
+        }
+      }
+      shoutyType {
+        MODEL_NUMBER
+        IS_ACTIVE
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_rename_all_decoding() {
+    let data = json!({
+        "snakeThings": [
+            {
+                "__typename": "snake_person",
+                "first_name": "Grace",
+                "is_admin": true
+            },
+            {
+                "__typename": "snake_robot",
+                "model_number": "T-800"
+            }
+        ],
+        "shoutyType": {
+            "MODEL_NUMBER": "R2-D2",
+            "IS_ACTIVE": false
+        }
+    });
+
+    insta::assert_yaml_snapshot!(serde_json::from_value::<RenameAllQuery>(data).unwrap(), @r###"
+    ---
+    snake_things:
+      - SnakePerson:
+          __typename: snake_person
+          first_name: Grace
+          admin: true
+      - SnakeRobot:
+          model_number: T-800
+    shouty_type:
+      model_number: R2-D2
+      is_active: false
+    "###);
+}
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+
+use cynic::{OperationBuilder, QueryBuilder, QueryFragment, QueryHash};
+
+mod schema {
+    cynic::use_schema!("../schemas/simple.graphql");
+}
+
+#[derive(cynic::QueryVariables)]
+struct TestArgs {
+    dessert: Dessert,
+    unused: Option<i32>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/simple.graphql")]
+enum Dessert {
+    Cheesecake,
+    IceCream,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/simple.graphql", variables = "TestArgs")]
+struct TestStruct {
+    #[arguments(x: 1, y: "hello \"world\"\n")]
+    field_one: String,
+
+    #[arguments(first: $dessert, second: null)]
+    #[cynic(rename = "tastyCakes", alias)]
+    cakes: Dessert,
+
+    #[arguments(input: { favouriteDessert: ICE_CREAM })]
+    field_with_input: Dessert,
+
+    nested: Nested,
+    opt_nested: Option<Nested>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/simple.graphql")]
+struct Nested {
+    a_string: String,
+    opt_string: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/simple.graphql",
+    graphql_type = "Query",
+    variables = "TestArgs"
+)]
+struct TestQuery {
+    test_struct: Option<TestStruct>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/simple.graphql", graphql_type = "TestStruct")]
+struct TestStructWithFeature {
+    #[cynic(feature = "nested")]
+    nested: Option<Nested>,
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(schema_path = "../schemas/simple.graphql", graphql_type = "Query")]
+struct QueryWithFeature {
+    test_struct: Option<TestStructWithFeature>,
+}
+
+const TEST_QUERY_HASH: QueryHash = match TestQuery::STATIC_OPERATION {
+    Some(operation) => operation.hash(),
+    None => panic!("TestQuery should be built at compile time"),
+};
+
+fn variables() -> TestArgs {
+    TestArgs {
+        dessert: Dessert::Cheesecake,
+        unused: None,
+    }
+}
+
+#[test]
+fn test_static_operation_document() {
+    let operation = TestQuery::STATIC_OPERATION.unwrap();
+
+    insta::assert_snapshot!(operation.document(), @r###"
+    query TestQuery($dessert: Dessert!) {
+      testStruct {
+        fieldOne(x: 1, y: "hello \"world\"\n")
+        cakes: tastyCakes(first: $dessert, second: null)
+        fieldWithInput(input: {favouriteDessert: ICE_CREAM, })
+        nested {
+          aString
+          optString
+        }
+        optNested {
+          aString
+          optString
+        }
+      }
+    }
+
+    "###);
+}
+
+#[test]
+fn test_static_operation_matches_runtime_operation() {
+    // Enabling a feature forces the query to be built at runtime
+    let operation = OperationBuilder::<TestQuery, TestArgs>::query()
+        .with_variables(variables())
+        .with_feature_enabled("unused")
+        .build()
+        .unwrap();
+
+    assert!(matches!(operation.query, Cow::Owned(_)));
+    assert_eq!(
+        operation.query,
+        TestQuery::STATIC_OPERATION.unwrap().document()
+    );
+    assert_eq!(TEST_QUERY_HASH, QueryHash::new(&operation.query));
+}
+
+#[test]
+fn test_build_borrows_static_operation() {
+    let operation = TestQuery::build(variables());
+
+    assert!(matches!(operation.query, Cow::Borrowed(_)));
+    assert_eq!(
+        operation.query,
+        TestQuery::STATIC_OPERATION.unwrap().document()
+    );
+}
+
+#[test]
+fn test_renamed_operation_is_built_at_runtime() {
+    let operation = OperationBuilder::<TestQuery, TestArgs>::query()
+        .with_variables(variables())
+        .with_operation_name("Renamed")
+        .build()
+        .unwrap();
+
+    assert!(matches!(operation.query, Cow::Owned(_)));
+    assert!(operation
+        .query
+        .starts_with("query Renamed($dessert: Dessert!)"));
+}
+
+#[test]
+fn test_features_prevent_static_operations() {
+    assert!(TestStructWithFeature::STATIC_SELECTION.is_none());
+    assert!(QueryWithFeature::STATIC_OPERATION.is_none());
+    assert!(TestStruct::STATIC_OPERATION.is_none());
+
+    let operation = QueryWithFeature::build(());
+
+    assert!(matches!(operation.query, Cow::Owned(_)));
+}
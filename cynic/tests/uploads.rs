@@ -0,0 +1,165 @@
+#![cfg(all(feature = "http-reqwest-blocking", feature = "http-multipart"))]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+use cynic::{http::ReqwestBlockingExt, MutationBuilder, Upload};
+
+mod schema {
+    cynic::use_schema!("../schemas/uploads.graphql");
+}
+
+cynic::impl_scalar!(Upload, schema::Upload);
+
+#[derive(cynic::QueryVariables)]
+struct UploadVariables {
+    file: Upload,
+    input: UploadFilesInput,
+}
+
+#[derive(cynic::InputObject)]
+#[cynic(schema_path = "../schemas/uploads.graphql")]
+struct UploadFilesInput {
+    folder: Option<String>,
+    files: Vec<Upload>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../schemas/uploads.graphql",
+    graphql_type = "Mutation",
+    variables = "UploadVariables"
+)]
+struct UploadMutation {
+    #[arguments(file: $file)]
+    upload_file: File,
+    #[arguments(input: $input)]
+    upload_files: Vec<File>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "../schemas/uploads.graphql")]
+struct File {
+    file_name: String,
+}
+
+/// Starts a server that responds to a single request with `response`,
+/// returning its URL and a handle that resolves to the body of the request.
+fn serve_once(response: serde_json::Value) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+
+        let response = response.to_string();
+        write!(
+            reader.get_mut(),
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        )
+        .unwrap();
+
+        String::from_utf8_lossy(&body).into_owned()
+    });
+
+    (url, handle)
+}
+
+#[test]
+fn test_multipart_upload() {
+    let (url, server) = serve_once(serde_json::json!({"data": {
+        "uploadFile": {"fileName": "hello.txt"},
+        "uploadFiles": [{"fileName": "one.txt"}, {"fileName": "two.png"}]
+    }}));
+
+    let operation = UploadMutation::build(UploadVariables {
+        file: Upload::new("hello.txt", "Hello!").with_mime_type("text/plain"),
+        input: UploadFilesInput {
+            folder: Some("docs".into()),
+            files: vec![
+                Upload::new("one.txt", "One"),
+                Upload::new("two.png", b"PNG".to_vec()).with_mime_type("image/png"),
+            ],
+        },
+    });
+
+    let response = reqwest::blocking::Client::new()
+        .post(url)
+        .run_graphql_multipart(operation)
+        .unwrap();
+
+    let data = response.data.unwrap();
+    assert_eq!(data.upload_file.file_name, "hello.txt");
+    assert_eq!(data.upload_files.len(), 2);
+
+    let body = server.join().unwrap();
+    let boundary = body.lines().next().unwrap().to_string();
+    let parts = body
+        .split(&boundary)
+        .map(str::trim)
+        .filter(|part| !part.is_empty() && *part != "--")
+        .collect::<Vec<_>>();
+
+    insta::assert_snapshot!(parts.join("\n\n"), @r###"
+    Content-Disposition: form-data; name="operations"
+
+    {"operationName":"UploadMutation","query":"mutation UploadMutation($file: Upload!, $input: UploadFilesInput!) {\n  uploadFile(file: $file) {\n    fileName\n  }\n  uploadFiles(input: $input) {\n    fileName\n  }\n}\n\n","variables":{"file":null,"input":{"files":[null,null],"folder":"docs"}}}
+
+    Content-Disposition: form-data; name="map"
+
+    {"0":["variables.file"],"1":["variables.input.files.0"],"2":["variables.input.files.1"]}
+
+    Content-Disposition: form-data; name="0"; filename="hello.txt"
+    Content-Type: text/plain
+
+    Hello!
+
+    Content-Disposition: form-data; name="1"; filename="one.txt"
+
+    One
+
+    Content-Disposition: form-data; name="2"; filename="two.png"
+    Content-Type: image/png
+
+    PNG
+    "###);
+}
+
+#[test]
+fn test_uploads_cant_be_sent_in_json_requests() {
+    let operation = UploadMutation::build(UploadVariables {
+        file: Upload::new("hello.txt", "Hello!"),
+        input: UploadFilesInput {
+            folder: None,
+            files: vec![Upload::new("one.txt", "One")],
+        },
+    });
+
+    assert_eq!(
+        serde_json::to_value(operation).unwrap_err().to_string(),
+        "Upload can only be sent with run_graphql_multipart"
+    );
+}
@@ -0,0 +1,311 @@
+#![cfg(feature = "ws")]
+
+use assert_matches::assert_matches;
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    executor::LocalPool,
+    task::LocalSpawnExt,
+    SinkExt, StreamExt,
+};
+use serde_json::{json, Value};
+
+use cynic::{
+    ws::{Protocol, WsError},
+    SubscriptionBuilder,
+};
+
+mod schema {
+    cynic::use_schema!("../schemas/books.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug, PartialEq)]
+#[cynic(
+    schema_path = "../schemas/books.graphql",
+    graphql_type = "SubscriptionRoot"
+)]
+struct IntervalSubscription {
+    interval: i32,
+}
+
+#[test]
+fn test_subscription_lifecycle() {
+    run_test(|client, mut server| async move {
+        let mut subscription = client.subscribe(IntervalSubscription::build(())).unwrap();
+
+        let subscribe = server.receive().await;
+        assert_eq!(subscribe["type"], "subscribe");
+        assert_eq!(subscribe["id"], "1");
+        insta::assert_snapshot!(subscribe["payload"]["query"].as_str().unwrap(), @r###"
+        subscription IntervalSubscription {
+          interval
+        }
+        "###);
+
+        server.next("1", 1).await;
+        server.next("1", 2).await;
+        server.send(json!({"type": "complete", "id": "1"})).await;
+
+        assert_eq!(data(subscription.next().await), 1);
+        assert_eq!(data(subscription.next().await), 2);
+        assert!(subscription.next().await.is_none());
+
+        // The server already completed the subscription, so dropping it
+        // should not send a complete message.
+        drop(subscription);
+        drop(client);
+        assert!(server.incoming.next().await.is_none());
+    });
+}
+
+#[test]
+fn test_multiplexed_subscriptions() {
+    run_test(|client, mut server| async move {
+        let mut first = client.subscribe(IntervalSubscription::build(())).unwrap();
+        let mut second = client.subscribe(IntervalSubscription::build(())).unwrap();
+
+        assert_eq!(server.receive().await["id"], "1");
+        assert_eq!(server.receive().await["id"], "2");
+
+        server.next("2", 20).await;
+        server.next("1", 10).await;
+        server.next("2", 21).await;
+
+        assert_eq!(data(second.next().await), 20);
+        assert_eq!(data(second.next().await), 21);
+        assert_eq!(data(first.next().await), 10);
+
+        drop(first);
+        assert_eq!(
+            server.receive().await,
+            json!({"type": "complete", "id": "1"})
+        );
+
+        server.next("2", 22).await;
+        assert_eq!(data(second.next().await), 22);
+    });
+}
+
+#[test]
+fn test_subscription_errors() {
+    run_test(|client, mut server| async move {
+        let mut subscription = client.subscribe(IntervalSubscription::build(())).unwrap();
+        server.receive().await;
+
+        server
+            .send(json!({"type": "error", "id": "1", "payload": [{"message": "Nope"}]}))
+            .await;
+
+        assert_matches!(
+            subscription.next().await,
+            Some(Err(WsError::OperationError(errors))) if errors[0].message == "Nope"
+        );
+        assert!(subscription.next().await.is_none());
+    });
+}
+
+#[test]
+fn test_ping_pong() {
+    run_test(|_client, mut server| async move {
+        server.send(json!({"type": "ping"})).await;
+        assert_eq!(server.receive().await, json!({"type": "pong"}));
+    });
+}
+
+#[test]
+fn test_connection_closed() {
+    run_test(|client, mut server| async move {
+        let mut subscription = client.subscribe(IntervalSubscription::build(())).unwrap();
+        server.receive().await;
+        drop(server);
+
+        assert_matches!(
+            subscription.next().await,
+            Some(Err(WsError::ConnectionClosed))
+        );
+        assert!(subscription.next().await.is_none());
+    });
+}
+
+#[test]
+fn test_connection_init_payload() {
+    let (client_tx, server_rx) = mpsc::unbounded();
+    let (server_tx, client_rx) = mpsc::unbounded();
+    let mut server = FakeServer {
+        incoming: server_rx,
+        outgoing: server_tx,
+    };
+
+    futures::executor::block_on(async move {
+        let (result, _) = futures::join!(
+            cynic::ws::Client::builder()
+                .payload(json!({"token": "abcd"}))
+                .build(client_tx, client_rx),
+            async {
+                assert_eq!(
+                    server.receive().await,
+                    json!({"type": "connection_init", "payload": {"token": "abcd"}})
+                );
+                server
+                    .send(json!({"type": "next", "id": "1", "payload": {}}))
+                    .await;
+            }
+        );
+
+        assert_matches!(result.err(), Some(WsError::Protocol(_)));
+    });
+}
+
+#[test]
+fn test_legacy_subscription_lifecycle() {
+    run_test_with_protocol(
+        Protocol::SubscriptionsTransportWs,
+        |client, mut server| async move {
+            let mut first = client.subscribe(IntervalSubscription::build(())).unwrap();
+            let mut second = client.subscribe(IntervalSubscription::build(())).unwrap();
+
+            let start = server.receive().await;
+            assert_eq!(start["type"], "start");
+            assert_eq!(start["id"], "1");
+            assert_eq!(server.receive().await["id"], "2");
+
+            server.send(json!({"type": "ka"})).await;
+            server
+                .send(json!({"type": "data", "id": "1", "payload": {"data": {"interval": 1}}}))
+                .await;
+            server
+                .send(json!({"type": "data", "id": "2", "payload": {"data": {"interval": 2}}}))
+                .await;
+            server.send(json!({"type": "complete", "id": "1"})).await;
+
+            assert_eq!(data(first.next().await), 1);
+            assert!(first.next().await.is_none());
+            assert_eq!(data(second.next().await), 2);
+
+            drop(second);
+            assert_eq!(server.receive().await, json!({"type": "stop", "id": "2"}));
+
+            drop(first);
+            drop(client);
+            assert_eq!(
+                server.receive().await,
+                json!({"type": "connection_terminate"})
+            );
+        },
+    );
+}
+
+#[test]
+fn test_legacy_subscription_errors() {
+    run_test_with_protocol(
+        Protocol::SubscriptionsTransportWs,
+        |client, mut server| async move {
+            let mut subscription = client.subscribe(IntervalSubscription::build(())).unwrap();
+            server.receive().await;
+
+            server
+                .send(json!({"type": "error", "id": "1", "payload": {"message": "Nope"}}))
+                .await;
+
+            assert_matches!(
+                subscription.next().await,
+                Some(Err(WsError::OperationError(errors))) if errors[0].message == "Nope"
+            );
+            assert!(subscription.next().await.is_none());
+        },
+    );
+}
+
+#[test]
+fn test_legacy_connection_error() {
+    let (client_tx, server_rx) = mpsc::unbounded();
+    let (server_tx, client_rx) = mpsc::unbounded();
+    let mut server = FakeServer {
+        incoming: server_rx,
+        outgoing: server_tx,
+    };
+
+    futures::executor::block_on(async move {
+        let (result, _) = futures::join!(
+            cynic::ws::Client::builder()
+                .protocol(Protocol::SubscriptionsTransportWs)
+                .build(client_tx, client_rx),
+            async {
+                assert_eq!(server.receive().await, json!({"type": "connection_init"}));
+                server
+                    .send(
+                        json!({"type": "connection_error", "payload": {"message": "Unauthorized"}}),
+                    )
+                    .await;
+            }
+        );
+
+        assert_matches!(
+            result.err(),
+            Some(WsError::ConnectionError(payload)) if payload["message"] == "Unauthorized"
+        );
+    });
+}
+
+fn data(response: Option<Result<cynic::GraphQlResponse<IntervalSubscription>, WsError>>) -> i32 {
+    response.unwrap().unwrap().data.unwrap().interval
+}
+
+struct FakeServer {
+    incoming: UnboundedReceiver<String>,
+    outgoing: UnboundedSender<String>,
+}
+
+impl FakeServer {
+    async fn receive(&mut self) -> Value {
+        serde_json::from_str(&self.incoming.next().await.unwrap()).unwrap()
+    }
+
+    async fn send(&mut self, message: Value) {
+        self.outgoing.send(message.to_string()).await.unwrap();
+    }
+
+    async fn next(&mut self, id: &str, interval: i32) {
+        self.send(json!({"type": "next", "id": id, "payload": {"data": {"interval": interval}}}))
+            .await
+    }
+}
+
+fn run_test<F, Fut>(test: F)
+where
+    F: FnOnce(cynic::ws::Client, FakeServer) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    run_test_with_protocol(Protocol::GraphqlTransportWs, test)
+}
+
+fn run_test_with_protocol<F, Fut>(protocol: Protocol, test: F)
+where
+    F: FnOnce(cynic::ws::Client, FakeServer) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let (client_tx, server_rx) = mpsc::unbounded();
+    let (server_tx, client_rx) = mpsc::unbounded();
+    let mut server = FakeServer {
+        incoming: server_rx,
+        outgoing: server_tx,
+    };
+
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+    pool.run_until(async move {
+        let (result, _) = futures::join!(
+            cynic::ws::Client::builder()
+                .protocol(protocol)
+                .build(client_tx, client_rx),
+            async {
+                assert_eq!(server.receive().await, json!({"type": "connection_init"}));
+                server.send(json!({"type": "connection_ack"})).await;
+            }
+        );
+        let (client, actor) = result.unwrap();
+
+        spawner.spawn_local(actor).unwrap();
+
+        test(client, server).await;
+    });
+}
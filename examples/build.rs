@@ -7,4 +7,13 @@ fn main() {
     cynic_codegen::register_schema("github")
         .from_sdl_file("../schemas/github.graphql")
         .unwrap();
+
+    // Extract the operations used by the query-document example into a
+    // persisted operation manifest, which CI could upload to a server
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    cynic_codegen::operation_manifest()
+        .add_document_file("queries/film-director.graphql")
+        .unwrap()
+        .write_relay(format!("{out_dir}/persisted-operations.json"))
+        .unwrap();
 }
@@ -0,0 +1,62 @@
+//! An example of generating query structs from a GraphQL document with the
+//! query_document macro
+
+// Pull in the Star Wars schema we registered in build.rs
+#[cynic::schema("starwars")]
+mod schema {}
+
+// Generate the structs for queries/film-director.graphql.  This outputs a
+// FilmDirectorQuery fragment, FilmDirectorQueryVariables & a Film fragment
+cynic::query_document!("queries/film-director.graphql", schema = "starwars");
+
+fn main() {
+    match run_query().data {
+        Some(FilmDirectorQuery { film: Some(film) }) => {
+            println!("{:?} was directed by {:?}", film.title, film.director)
+        }
+        _ => {
+            println!("No film found");
+        }
+    }
+}
+
+fn run_query() -> cynic::GraphQlResponse<FilmDirectorQuery> {
+    use cynic::http::ReqwestBlockingExt;
+
+    let id = cynic::Id::new("ZmlsbXM6MQ==");
+    let query = build_query(&id);
+
+    reqwest::blocking::Client::new()
+        .post("https://swapi-graphql.netlify.app/.netlify/functions/index")
+        .run_graphql(query)
+        .unwrap()
+}
+
+fn build_query(
+    id: &cynic::Id,
+) -> cynic::Operation<FilmDirectorQuery, FilmDirectorQueryVariables<'_>> {
+    use cynic::QueryBuilder;
+
+    FilmDirectorQuery::build(FilmDirectorQueryVariables { id: Some(id) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn snapshot_test_query() {
+        let id = cynic::Id::new("ZmlsbXM6MQ==");
+        let query = build_query(&id);
+
+        insta::assert_snapshot!(query.query);
+    }
+
+    #[test]
+    fn test_document_id_is_in_the_manifest() {
+        // Generated by build.rs
+        let manifest = include_str!(concat!(env!("OUT_DIR"), "/persisted-operations.json"));
+
+        assert!(manifest.contains(FilmDirectorQuery::DOCUMENT_ID));
+    }
+}
@@ -6,7 +6,7 @@ pub fn send<ResponseData, Vars>(
 ) -> Result<ResponseData, Box<dyn std::error::Error>>
 where
     Vars: cynic::serde::Serialize,
-    ResponseData: cynic::serde::de::DeserializeOwned,
+    ResponseData: cynic::serde::de::DeserializeOwned + cynic::UsesFieldResults,
 {
     let response = ureq::post(url)
         .set("User-Agent", "obmarg/cynic")
@@ -0,0 +1,44 @@
+fn main() {}
+
+#[cynic::schema_for_derives(
+    file = r#"./../../../../cynic/tests/test-schema.graphql"#,
+    module = "schema"
+)]
+mod queries {
+    use super::schema;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct BlogPost {
+        #[cynic(defer)]
+        pub author: Author,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "BlogPost")]
+    pub struct StreamedBlogPost {
+        #[cynic(stream)]
+        pub state: Option<PostState>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Author {
+        pub name: Option<String>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Author")]
+    pub struct AuthorWithPosts {
+        #[cynic(spread, defer)]
+        pub author: Author,
+    }
+
+    #[derive(cynic::Enum, Clone, Copy, Debug)]
+    pub enum PostState {
+        Posted,
+        Draft,
+    }
+}
+
+mod schema {
+    cynic::use_schema!(r#"./../../../../cynic/tests/test-schema.graphql"#);
+}
@@ -0,0 +1,44 @@
+#![deny(deprecated)]
+
+fn main() {}
+
+#[cynic::schema_for_derives(file = r#"./../../../../schemas/deprecations.graphql"#, module = "schema")]
+mod queries {
+    use super::schema;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Film {
+        pub title: String,
+        pub director: Option<String>,
+        #[cynic(allow_deprecated)]
+        pub rating: Option<Rating>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query")]
+    pub struct FilmQuery {
+        #[arguments(filmId: "ZmlsbXM6MQ==")]
+        pub film: Option<Film>,
+        #[arguments(filter: { director: "George Lucas" })]
+        pub films: Vec<Film>,
+    }
+
+    #[derive(cynic::Enum, Clone, Copy, Debug)]
+    pub enum Rating {
+        Good,
+        Bad,
+        Meh,
+    }
+
+    #[derive(cynic::InputObject, Debug)]
+    pub struct FilmFilter {
+        pub title: Option<String>,
+        pub director: Option<String>,
+        #[cynic(allow_deprecated)]
+        pub directors: Option<Vec<String>>,
+    }
+}
+
+mod schema {
+    cynic::use_schema!(r#"./../../../../schemas/deprecations.graphql"#);
+}
@@ -0,0 +1,46 @@
+fn main() {}
+
+#[cynic::schema_for_derives(
+    file = r#"./../../../../cynic/tests/test-schema.graphql"#,
+    module = "schema"
+)]
+mod queries {
+    use super::schema;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query")]
+    pub struct AllPosts {
+        #[directives(unknown)]
+        pub all_posts: Vec<BlogPost>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct BlogPost {
+        #[directives(key(fields: "id"))]
+        pub id: Option<cynic::Id>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Author {
+        #[directives(skip(if: true))]
+        pub name: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum PostOrAuthor {
+        #[directives(lowercase)]
+        BlogPost(BlogPost),
+        #[cynic(fallback)]
+        Other,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[directives(lowercase)]
+    pub struct Comment {
+        pub id: Option<cynic::Id>,
+    }
+}
+
+mod schema {
+    cynic::use_schema!(r#"./../../../../cynic/tests/test-schema.graphql"#);
+}
@@ -0,0 +1,16 @@
+fn main() {}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./../../../../schemas/starwars.schema.graphql",
+    graphql_type = "FilmsEdge",
+    graphql_type = "PeopleEdge",
+    named_fragment
+)]
+struct NamedFragment<N> {
+    node: Option<N>,
+}
+
+mod schema {
+    cynic::use_schema!(r#"./../../../../schemas/starwars.schema.graphql"#);
+}
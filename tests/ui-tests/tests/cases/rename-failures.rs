@@ -17,3 +17,43 @@ struct AliasWithoutRename {
     #[cynic(alias)]
     episode_id: Option<i32>,
 }
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../../../../schemas/starwars.schema.graphql",
+    graphql_type = "Film",
+    rename_all = "snake_case"
+)]
+struct RenameAllFilm {
+    release_date: Option<String>,
+}
+
+mod test_schema {
+    cynic::use_schema!("../../../../cynic/tests/test-schema.graphql");
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "../../../../cynic/tests/test-schema.graphql",
+    schema_module = "test_schema",
+    graphql_type = "snake_robot",
+    rename_all = "snake_case"
+)]
+struct SnakeRobot {
+    model_number: String,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(
+    schema_path = "../../../../cynic/tests/test-schema.graphql",
+    schema_module = "test_schema",
+    graphql_type = "snake_thing",
+    rename_all = "snake_case",
+    exhaustive
+)]
+enum SnakeThing {
+    SnakeRobot(SnakeRobot),
+    SnakePersn(SnakeRobot),
+    #[cynic(fallback)]
+    Other,
+}
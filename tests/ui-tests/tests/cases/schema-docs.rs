@@ -0,0 +1,30 @@
+#![deny(warnings)]
+
+fn main() {}
+
+#[cynic::schema_for_derives(file = r#"./../../../../schemas/deprecations.graphql"#, module = "schema")]
+mod queries {
+    use super::schema;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(schema_docs)]
+    pub struct Film {
+        pub title: String,
+        pub directors: Vec<String>,
+        #[cynic(allow_deprecated)]
+        pub rating: Option<Rating>,
+    }
+
+    #[derive(cynic::Enum, Clone, Copy, Debug)]
+    #[cynic(schema_docs)]
+    pub enum Rating {
+        Good,
+        Bad,
+        #[cynic(allow_deprecated)]
+        Meh,
+    }
+}
+
+mod schema {
+    cynic::use_schema!(r#"./../../../../schemas/deprecations.graphql"#);
+}